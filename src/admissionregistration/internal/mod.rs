@@ -142,7 +142,7 @@ pub struct MutatingAdmissionPolicyList {
     pub metadata: ListMeta,
 
     /// List of MutatingAdmissionPolicy.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<MutatingAdmissionPolicy>,
 }
 
@@ -201,7 +201,7 @@ pub struct MutatingAdmissionPolicyBindingList {
     pub metadata: ListMeta,
 
     /// List of PolicyBinding.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<MutatingAdmissionPolicyBinding>,
 }
 
@@ -299,7 +299,7 @@ pub struct ValidatingAdmissionPolicyList {
     pub metadata: ListMeta,
 
     /// List of ValidatingAdmissionPolicy.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ValidatingAdmissionPolicy>,
 }
 
@@ -362,7 +362,7 @@ pub struct ValidatingAdmissionPolicyBindingList {
     pub metadata: ListMeta,
 
     /// List of ValidatingAdmissionPolicyBinding.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ValidatingAdmissionPolicyBinding>,
 }
 
@@ -512,7 +512,7 @@ pub struct ValidatingWebhookConfigurationList {
     pub metadata: ListMeta,
 
     /// List of ValidatingWebhookConfiguration.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ValidatingWebhookConfiguration>,
 }
 
@@ -548,7 +548,7 @@ pub struct MutatingWebhookConfigurationList {
     pub metadata: ListMeta,
 
     /// List of MutatingWebhookConfiguration.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<MutatingWebhookConfiguration>,
 }
 
@@ -12,9 +12,10 @@ use crate::admissionregistration::v1::{
     FailurePolicyType, MatchCondition, MatchPolicyType, OperationType, Rule, RuleWithOperations,
     ServiceReference, SideEffectClass, WebhookClientConfig,
 };
+use crate::common::LabelSelector;
 use crate::common::validation::{
-    BadValue, ErrorList, Path, duplicate, invalid, is_dns1035_label, not_supported, required,
-    validate_qualified_name,
+    BadValue, ErrorList, Path, duplicate, forbidden, invalid, is_dns1035_label, not_supported,
+    required, validate_label_selector, validate_qualified_name,
 };
 
 pub use mutating_webhook_configuration::validate_mutating_webhook_configuration;
@@ -72,6 +73,19 @@ fn validate_webhook(webhook: &dyn WebhookAdapter, path: &Path, _validating: bool
         ));
     }
 
+    if let Some(selector) = webhook.namespace_selector() {
+        all_errs.extend(validate_label_selector(
+            selector,
+            &path.child("namespaceSelector"),
+        ));
+    }
+    if let Some(selector) = webhook.object_selector() {
+        all_errs.extend(validate_label_selector(
+            selector,
+            &path.child("objectSelector"),
+        ));
+    }
+
     if let Some(policy) = webhook.failure_policy() {
         if !matches!(policy, FailurePolicyType::Ignore | FailurePolicyType::Fail) {
             all_errs.push(not_supported(
@@ -216,9 +230,8 @@ fn validate_rule_with_operations(
     if rule.operations.is_empty() {
         all_errs.push(required(&path.child("operations"), ""));
     } else if rule.operations.len() > 1 && rule.operations.contains(&OperationType::All) {
-        all_errs.push(invalid(
+        all_errs.push(forbidden(
             &path.child("operations"),
-            BadValue::String(format!("{:?}", rule.operations)),
             "if '*' is present, must not specify other operations",
         ));
     }
@@ -444,6 +457,8 @@ trait WebhookAdapter {
     fn admission_review_versions(&self) -> &[String];
     fn client_config(&self) -> &WebhookClientConfig;
     fn match_conditions(&self) -> &[MatchCondition];
+    fn namespace_selector(&self) -> Option<&LabelSelector>;
+    fn object_selector(&self) -> Option<&LabelSelector>;
 }
 
 impl WebhookAdapter for ValidatingWebhook {
@@ -474,6 +489,12 @@ impl WebhookAdapter for ValidatingWebhook {
     fn match_conditions(&self) -> &[MatchCondition] {
         &self.match_conditions
     }
+    fn namespace_selector(&self) -> Option<&LabelSelector> {
+        self.namespace_selector.as_ref()
+    }
+    fn object_selector(&self) -> Option<&LabelSelector> {
+        self.object_selector.as_ref()
+    }
 }
 
 impl WebhookAdapter for MutatingWebhook {
@@ -504,6 +525,12 @@ impl WebhookAdapter for MutatingWebhook {
     fn match_conditions(&self) -> &[MatchCondition] {
         &self.match_conditions
     }
+    fn namespace_selector(&self) -> Option<&LabelSelector> {
+        self.namespace_selector.as_ref()
+    }
+    fn object_selector(&self) -> Option<&LabelSelector> {
+        self.object_selector.as_ref()
+    }
 }
 
 #[cfg(test)]
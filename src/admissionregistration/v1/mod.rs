@@ -7,6 +7,8 @@ pub mod conversion;
 pub mod defaults;
 pub mod validation;
 
+pub use validation::validate_validating_admission_policy;
+
 use crate::common::{
     HasTypeMeta, LabelSelector, ListMeta, ObjectMeta, ResourceSchema, TypeMeta, VersionedObject,
 };
@@ -690,7 +692,7 @@ pub struct ValidatingAdmissionPolicyList {
     pub metadata: Option<ListMeta>,
 
     /// List of ValidatingAdmissionPolicy.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ValidatingAdmissionPolicy>,
 }
 
@@ -754,7 +756,7 @@ pub struct ValidatingAdmissionPolicyBindingList {
     pub metadata: Option<ListMeta>,
 
     /// List of ValidatingAdmissionPolicyBinding.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ValidatingAdmissionPolicyBinding>,
 }
 
@@ -794,7 +796,7 @@ pub struct ValidatingWebhookConfigurationList {
     pub metadata: Option<ListMeta>,
 
     /// List of ValidatingWebhookConfiguration.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ValidatingWebhookConfiguration>,
 }
 
@@ -834,7 +836,7 @@ pub struct MutatingWebhookConfigurationList {
     pub metadata: Option<ListMeta>,
 
     /// List of MutatingWebhookConfiguration.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<MutatingWebhookConfiguration>,
 }
 
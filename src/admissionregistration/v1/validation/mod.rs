@@ -1,7 +1,11 @@
 //! Validation for Kubernetes AdmissionRegistration v1 API types
 
 mod mutating_webhook_configuration;
+mod validating_admission_policy;
 mod validating_webhook_configuration;
 
 pub use mutating_webhook_configuration::validate_mutating_webhook_configuration;
+pub use validating_admission_policy::validate_validating_admission_policy;
 pub use validating_webhook_configuration::validate_validating_webhook_configuration;
+
+pub use crate::admissionregistration::validation::validate_match_resources;
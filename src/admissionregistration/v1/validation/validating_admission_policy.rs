@@ -0,0 +1,124 @@
+use crate::admissionregistration::v1::ValidatingAdmissionPolicy;
+use crate::admissionregistration::validation as policy_validation;
+use crate::common::validation::{ErrorList, Path};
+
+/// Validates a ValidatingAdmissionPolicy rooted at `path`; pass `Path::nil()`
+/// for a top-level object.
+///
+/// Only the structure of the CEL fields is checked; expressions are not compiled.
+pub fn validate_validating_admission_policy(
+    obj: &ValidatingAdmissionPolicy,
+    path: &Path,
+) -> ErrorList {
+    policy_validation::validate_validating_admission_policy_with_path(obj, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::admissionregistration::v1::{
+        MatchResources, NamedRuleWithOperations, OperationType, ValidatingAdmissionPolicySpec,
+        Validation, Variable,
+    };
+    use crate::common::ObjectMeta;
+    use crate::common::validation::ErrorType;
+
+    fn policy() -> ValidatingAdmissionPolicy {
+        ValidatingAdmissionPolicy {
+            metadata: Some(ObjectMeta {
+                name: Some("replica-limit".to_string()),
+                ..Default::default()
+            }),
+            spec: ValidatingAdmissionPolicySpec {
+                match_constraints: Some(MatchResources {
+                    resource_rules: vec![NamedRuleWithOperations {
+                        operations: vec![OperationType::Create, OperationType::Update],
+                        api_groups: vec!["apps".to_string()],
+                        api_versions: vec!["v1".to_string()],
+                        resources: vec!["deployments".to_string()],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+                validations: vec![Validation {
+                    expression: "object.spec.replicas <= variables.limit".to_string(),
+                    ..Default::default()
+                }],
+                variables: vec![Variable {
+                    name: "limit".to_string(),
+                    expression: "5".to_string(),
+                }],
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_validating_admission_policy_valid() {
+        let errs = validate_validating_admission_policy(&policy(), &Path::new("policy"));
+        assert!(errs.is_empty(), "unexpected errors: {:?}", errs);
+    }
+
+    #[test]
+    fn test_validate_validating_admission_policy_empty_expression() {
+        let mut obj = policy();
+        obj.spec.validations[0].expression.clear();
+
+        let errs = validate_validating_admission_policy(&obj, &Path::new("policy"));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Required);
+        assert_eq!(
+            errs.errors[0].field,
+            "policy.spec.validations[0].expression"
+        );
+    }
+
+    #[test]
+    fn test_validate_validating_admission_policy_duplicate_variable() {
+        let mut obj = policy();
+        obj.spec.variables.push(Variable {
+            name: "limit".to_string(),
+            expression: "10".to_string(),
+        });
+
+        let errs = validate_validating_admission_policy(&obj, &Path::new("policy"));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Duplicate);
+        assert_eq!(errs.errors[0].field, "policy.spec.variables[1].name");
+    }
+
+    #[test]
+    fn test_validate_validating_admission_policy_requires_match_constraints() {
+        let mut obj = policy();
+        obj.spec.match_constraints = None;
+        obj.spec.variables[0].name = "1limit".to_string();
+
+        let errs = validate_validating_admission_policy(&obj, &Path::new("policy"));
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "policy.spec.matchConstraints",
+                "policy.spec.variables[0].name"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_validating_admission_policy_rooted_at_path() {
+        let mut obj = policy();
+        obj.metadata.as_mut().unwrap().name = None;
+        obj.spec.validations[0].expression.clear();
+
+        let errs = validate_validating_admission_policy(&obj, &Path::new("items").index(2));
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "items[2].metadata.name",
+                "items[2].spec.validations[0].expression"
+            ]
+        );
+    }
+}
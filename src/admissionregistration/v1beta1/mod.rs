@@ -151,7 +151,7 @@ pub struct MutatingAdmissionPolicyList {
     pub metadata: ListMeta,
 
     /// List of MutatingAdmissionPolicy.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<MutatingAdmissionPolicy>,
 }
 
@@ -210,7 +210,7 @@ pub struct MutatingAdmissionPolicyBindingList {
     pub metadata: ListMeta,
 
     /// List of MutatingAdmissionPolicyBinding.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<MutatingAdmissionPolicyBinding>,
 }
 
@@ -16,11 +16,12 @@ use crate::admissionregistration::v1::{
     ValidatingAdmissionPolicyStatus, ValidatingWebhook, ValidatingWebhookConfiguration, Validation,
     ValidationAction, Variable, WebhookClientConfig,
 };
-use crate::common::ObjectMeta;
 use crate::common::validation::{
-    BadValue, ErrorList, Path, duplicate, invalid, is_dns1035_label, is_dns1123_subdomain,
-    not_supported, required, validate_object_meta, validate_qualified_name,
+    BadValue, ErrorList, Path, duplicate, forbidden, invalid, is_dns1035_label,
+    is_dns1123_subdomain, not_supported, required, validate_label_selector, validate_object_meta,
+    validate_qualified_name,
 };
+use crate::common::{LabelSelector, ObjectMeta};
 
 // ============================================================================
 // Public Validation Entry Points
@@ -37,15 +38,24 @@ pub fn validate_mutating_webhook_configuration(obj: &MutatingWebhookConfiguratio
 }
 
 pub fn validate_validating_admission_policy(obj: &ValidatingAdmissionPolicy) -> ErrorList {
+    validate_validating_admission_policy_with_path(obj, &Path::nil())
+}
+
+/// Validates a ValidatingAdmissionPolicy rooted at `base_path`, so metadata and
+/// spec errors are reported under `base_path.metadata` and `base_path.spec`.
+pub fn validate_validating_admission_policy_with_path(
+    obj: &ValidatingAdmissionPolicy,
+    base_path: &Path,
+) -> ErrorList {
     let mut all_errs = validate_object_meta(
         obj.metadata.as_ref().unwrap_or(&ObjectMeta::default()),
         false,
         name_is_dns_subdomain,
-        &Path::new("metadata"),
+        &base_path.child("metadata"),
     );
     all_errs.extend(validate_validating_admission_policy_spec(
         &obj.spec,
-        &Path::new("spec"),
+        &base_path.child("spec"),
     ));
     all_errs
 }
@@ -172,6 +182,19 @@ fn validate_webhook(webhook: &dyn WebhookAdapter, path: &Path, _validating: bool
         ));
     }
 
+    if let Some(selector) = webhook.namespace_selector() {
+        all_errs.extend(validate_label_selector(
+            selector,
+            &path.child("namespaceSelector"),
+        ));
+    }
+    if let Some(selector) = webhook.object_selector() {
+        all_errs.extend(validate_label_selector(
+            selector,
+            &path.child("objectSelector"),
+        ));
+    }
+
     if let Some(policy) = webhook.failure_policy() {
         if !matches!(policy, FailurePolicyType::Ignore | FailurePolicyType::Fail) {
             all_errs.push(not_supported(
@@ -343,11 +366,16 @@ fn validate_validating_admission_policy_spec(
         ));
     }
 
+    let mut variable_names = BTreeSet::new();
     for (i, variable) in spec.variables.iter().enumerate() {
-        all_errs.extend(validate_variable(
-            variable,
-            &path.child("variables").index(i),
-        ));
+        let variable_path = path.child("variables").index(i);
+        all_errs.extend(validate_variable(variable, &variable_path));
+        if !variable.name.is_empty() && !variable_names.insert(variable.name.as_str()) {
+            all_errs.push(duplicate(
+                &variable_path.child("name"),
+                BadValue::String(variable.name.clone()),
+            ));
+        }
     }
 
     if let Some(ref param_kind) = spec.param_kind {
@@ -453,7 +481,12 @@ fn validate_mutating_admission_policy_binding_spec(
 // Rule/Match Validation
 // ============================================================================
 
-fn validate_match_resources(match_resources: &MatchResources, path: &Path) -> ErrorList {
+/// Validates the match policy and the include/exclude resource rules of a
+/// MatchResources.
+///
+/// Every rule needs operations (`*` alone or explicit ones), API groups, API
+/// versions and resources, and a supported scope.
+pub fn validate_match_resources(match_resources: &MatchResources, path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
     if let Some(policy) = match_resources.match_policy.as_ref() {
@@ -466,6 +499,19 @@ fn validate_match_resources(match_resources: &MatchResources, path: &Path) -> Er
         }
     }
 
+    if let Some(selector) = match_resources.namespace_selector.as_ref() {
+        all_errs.extend(validate_label_selector(
+            selector,
+            &path.child("namespaceSelector"),
+        ));
+    }
+    if let Some(selector) = match_resources.object_selector.as_ref() {
+        all_errs.extend(validate_label_selector(
+            selector,
+            &path.child("objectSelector"),
+        ));
+    }
+
     for (i, rule) in match_resources.resource_rules.iter().enumerate() {
         all_errs.extend(validate_named_rule_with_operations(
             rule,
@@ -552,9 +598,8 @@ fn validate_rule_with_operations(
     if rule.operations.is_empty() {
         all_errs.push(required(&path.child("operations"), ""));
     } else if rule.operations.len() > 1 && rule.operations.contains(&OperationType::All) {
-        all_errs.push(invalid(
+        all_errs.push(forbidden(
             &path.child("operations"),
-            BadValue::String(format!("{:?}", rule.operations)),
             "if '*' is present, must not specify other operations",
         ));
     }
@@ -943,6 +988,8 @@ trait WebhookAdapter {
     fn admission_review_versions(&self) -> &[String];
     fn client_config(&self) -> &WebhookClientConfig;
     fn match_conditions(&self) -> &[MatchCondition];
+    fn namespace_selector(&self) -> Option<&LabelSelector>;
+    fn object_selector(&self) -> Option<&LabelSelector>;
 }
 
 impl WebhookAdapter for ValidatingWebhook {
@@ -973,6 +1020,12 @@ impl WebhookAdapter for ValidatingWebhook {
     fn match_conditions(&self) -> &[MatchCondition] {
         &self.match_conditions
     }
+    fn namespace_selector(&self) -> Option<&LabelSelector> {
+        self.namespace_selector.as_ref()
+    }
+    fn object_selector(&self) -> Option<&LabelSelector> {
+        self.object_selector.as_ref()
+    }
 }
 
 impl WebhookAdapter for MutatingWebhook {
@@ -1003,6 +1056,12 @@ impl WebhookAdapter for MutatingWebhook {
     fn match_conditions(&self) -> &[MatchCondition] {
         &self.match_conditions
     }
+    fn namespace_selector(&self) -> Option<&LabelSelector> {
+        self.namespace_selector.as_ref()
+    }
+    fn object_selector(&self) -> Option<&LabelSelector> {
+        self.object_selector.as_ref()
+    }
 }
 
 // ============================================================================
@@ -1013,6 +1072,7 @@ impl WebhookAdapter for MutatingWebhook {
 mod tests {
     use super::*;
     use crate::common::TypeMeta;
+    use crate::common::validation::ErrorType;
 
     #[test]
     fn test_validate_validating_webhook_configuration_requires_side_effects() {
@@ -1053,4 +1113,59 @@ mod tests {
         let errs = validate_validating_admission_policy(&obj);
         assert!(!errs.is_empty());
     }
+
+    fn named_rule(operations: Vec<OperationType>, resources: &[&str]) -> NamedRuleWithOperations {
+        NamedRuleWithOperations {
+            operations,
+            api_groups: vec!["apps".to_string()],
+            api_versions: vec!["v1".to_string()],
+            resources: resources.iter().map(|r| r.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn match_resources_errors(rule: NamedRuleWithOperations) -> Vec<(String, ErrorType)> {
+        let match_resources = MatchResources {
+            resource_rules: vec![rule],
+            ..Default::default()
+        };
+        validate_match_resources(
+            &match_resources,
+            &Path::new("spec").child("matchConstraints"),
+        )
+        .errors
+        .into_iter()
+        .map(|err| (err.field, err.error_type))
+        .collect()
+    }
+
+    #[test]
+    fn test_validate_match_resources_wildcard_with_other_operations() {
+        let valid = named_rule(vec![OperationType::All], &["deployments"]);
+        assert!(match_resources_errors(valid).is_empty());
+
+        let rule = named_rule(
+            vec![OperationType::All, OperationType::Create],
+            &["deployments"],
+        );
+        assert_eq!(
+            match_resources_errors(rule),
+            vec![(
+                "spec.matchConstraints.resourceRules[0].operations".to_string(),
+                ErrorType::Forbidden
+            )]
+        );
+    }
+
+    #[test]
+    fn test_validate_match_resources_empty_resources() {
+        let rule = named_rule(vec![OperationType::Create], &[]);
+        assert_eq!(
+            match_resources_errors(rule),
+            vec![(
+                "spec.matchConstraints.resourceRules[0].resources".to_string(),
+                ErrorType::Required
+            )]
+        );
+    }
 }
@@ -388,7 +388,7 @@ pub struct CustomResourceDefinitionList {
     pub metadata: Option<ListMeta>,
 
     /// Items list individual CustomResourceDefinition objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<CustomResourceDefinition>,
 }
 
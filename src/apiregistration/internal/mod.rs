@@ -65,7 +65,7 @@ pub struct APIServiceList {
     pub metadata: ListMeta,
 
     /// List of APIService.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<APIService>,
 }
 
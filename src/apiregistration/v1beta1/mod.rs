@@ -67,7 +67,7 @@ pub struct APIServiceList {
     pub metadata: Option<ListMeta>,
 
     /// Items is the list of APIService.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<APIService>,
 }
 
@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::apiserverinternal::internal;
+use crate::common::validation::names::{empty_error, prefix_each, regex_error};
 use crate::common::validation::{
     BadValue, Error, ErrorList, Path, duplicate, invalid, is_dns1035_label, is_dns1123_subdomain,
     name_is_dns_subdomain, required, validate_object_meta, validate_qualified_name,
@@ -284,35 +285,6 @@ fn is_valid_api_version(api_version: &str) -> Vec<String> {
     errs
 }
 
-fn regex_error(msg: &str, fmt: &str, examples: &[&str]) -> String {
-    if examples.is_empty() {
-        return format!("{} (regex used for validation is '{}')", msg, fmt);
-    }
-
-    let mut out = format!("{} (e.g. ", msg);
-    for (i, example) in examples.iter().enumerate() {
-        if i > 0 {
-            out.push_str(" or ");
-        }
-        out.push('\'');
-        out.push_str(example);
-        out.push_str("', ");
-    }
-    out.push_str(&format!("regex used for validation is '{}')", fmt));
-    out
-}
-
-fn empty_error() -> &'static str {
-    "must be non-empty"
-}
-
-fn prefix_each(mut msgs: Vec<String>, prefix: &str) -> Vec<String> {
-    for msg in &mut msgs {
-        *msg = format!("{}{}", prefix, msg);
-    }
-    msgs
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -11,6 +11,7 @@ mod placeholders;
 mod schema;
 mod storage_version;
 mod traits;
+mod updater;
 pub mod validation;
 
 #[cfg(test)]
@@ -24,3 +25,4 @@ pub use storage_version::{
     ServerStorageVersion, StorageVersion, StorageVersionList, StorageVersionSpec,
     StorageVersionStatus,
 };
+pub use updater::merge_storage_version_status;
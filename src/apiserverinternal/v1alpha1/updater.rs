@@ -0,0 +1,176 @@
+//! StorageVersion status aggregation
+//!
+//! Based on k8s.io/apiserver/pkg/storageversion/updater.go
+
+use crate::common::Timestamp;
+
+use super::{
+    ConditionStatus, ServerStorageVersion, StorageVersion, StorageVersionCondition,
+    StorageVersionConditionType,
+};
+
+/// Records the storage version reported by one API server and recomputes the
+/// aggregated status.
+///
+/// The entry for `server_id` is replaced (or appended), then
+/// `commonEncodingVersion` and the `AllEncodingVersionsEqual` condition are
+/// recomputed from all reported entries. Served versions already recorded for
+/// the server are kept.
+pub fn merge_storage_version_status(
+    sv: &mut StorageVersion,
+    server_id: &str,
+    encoding: &str,
+    decodable: &[String],
+) {
+    let status = &mut sv.status;
+    match status
+        .storage_versions
+        .iter_mut()
+        .find(|ssv| ssv.api_server_id == server_id)
+    {
+        Some(ssv) => {
+            ssv.encoding_version = encoding.to_string();
+            ssv.decodable_versions = decodable.to_vec();
+        }
+        None => status.storage_versions.push(ServerStorageVersion {
+            api_server_id: server_id.to_string(),
+            encoding_version: encoding.to_string(),
+            decodable_versions: decodable.to_vec(),
+            served_versions: Vec::new(),
+        }),
+    }
+
+    set_common_encoding_version(sv);
+}
+
+/// Recomputes `commonEncodingVersion` and the `AllEncodingVersionsEqual` condition.
+fn set_common_encoding_version(sv: &mut StorageVersion) {
+    let generation = sv
+        .metadata
+        .as_ref()
+        .and_then(|meta| meta.generation)
+        .unwrap_or_default();
+    let status = &mut sv.status;
+    let old_common = status.common_encoding_version.take();
+
+    if let Some(first) = status.storage_versions.first()
+        && status
+            .storage_versions
+            .iter()
+            .all(|ssv| ssv.encoding_version == first.encoding_version)
+    {
+        status.common_encoding_version = Some(first.encoding_version.clone());
+    }
+
+    let (condition_status, reason, message) = match status.common_encoding_version {
+        Some(_) => (
+            ConditionStatus::TRUE,
+            "CommonEncodingVersionSet",
+            "Common encoding version set",
+        ),
+        None => (
+            ConditionStatus::FALSE,
+            "CommonEncodingVersionUnset",
+            "Common encoding version unset",
+        ),
+    };
+    let force_transition = matches!(
+        (&old_common, &status.common_encoding_version),
+        (Some(old), Some(new)) if old != new
+    );
+
+    let condition = StorageVersionCondition {
+        type_: StorageVersionConditionType::ALL_ENCODING_VERSIONS_EQUAL.into(),
+        status: condition_status.into(),
+        observed_generation: generation,
+        last_transition_time: Timestamp::now(),
+        reason: reason.to_string(),
+        message: message.to_string(),
+    };
+    set_status_condition(&mut status.conditions, condition, force_transition);
+}
+
+/// Upserts a condition, only moving `lastTransitionTime` when the status changes
+/// or a transition is forced.
+fn set_status_condition(
+    conditions: &mut Vec<StorageVersionCondition>,
+    new_condition: StorageVersionCondition,
+    force_transition: bool,
+) {
+    let Some(existing) = conditions
+        .iter_mut()
+        .find(|condition| condition.type_ == new_condition.type_)
+    else {
+        conditions.push(new_condition);
+        return;
+    };
+
+    if existing.status != new_condition.status || force_transition {
+        existing.status = new_condition.status;
+        existing.last_transition_time = new_condition.last_transition_time;
+    }
+    existing.reason = new_condition.reason;
+    existing.message = new_condition.message;
+    existing.observed_generation = new_condition.observed_generation;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versions(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    fn condition(sv: &StorageVersion) -> &StorageVersionCondition {
+        assert_eq!(sv.status.conditions.len(), 1);
+        &sv.status.conditions[0]
+    }
+
+    #[test]
+    fn test_merge_storage_version_status_agree_then_disagree() {
+        let mut sv = StorageVersion::default();
+        let decodable = versions(&["apps/v1", "apps/v1beta1"]);
+
+        merge_storage_version_status(&mut sv, "server-a", "apps/v1", &decodable);
+        merge_storage_version_status(&mut sv, "server-b", "apps/v1", &decodable);
+        assert_eq!(sv.status.storage_versions.len(), 2);
+        assert_eq!(
+            sv.status.common_encoding_version.as_deref(),
+            Some("apps/v1")
+        );
+        assert_eq!(condition(&sv).status.as_ref(), ConditionStatus::TRUE);
+        assert_eq!(condition(&sv).reason, "CommonEncodingVersionSet");
+
+        merge_storage_version_status(&mut sv, "server-b", "apps/v1beta1", &decodable);
+        assert_eq!(sv.status.storage_versions.len(), 2);
+        assert_eq!(
+            sv.status.storage_versions[1].encoding_version,
+            "apps/v1beta1"
+        );
+        assert_eq!(sv.status.common_encoding_version, None);
+        assert_eq!(
+            condition(&sv).type_.as_ref(),
+            StorageVersionConditionType::ALL_ENCODING_VERSIONS_EQUAL
+        );
+        assert_eq!(condition(&sv).status.as_ref(), ConditionStatus::FALSE);
+        assert_eq!(condition(&sv).reason, "CommonEncodingVersionUnset");
+    }
+
+    #[test]
+    fn test_merge_storage_version_status_keeps_transition_time_when_unchanged() {
+        let mut sv = StorageVersion::default();
+        let decodable = versions(&["v1"]);
+
+        merge_storage_version_status(&mut sv, "server-a", "v1", &decodable);
+        let first_transition = condition(&sv).last_transition_time.clone();
+        sv.status.storage_versions[0].served_versions = versions(&["v1"]);
+
+        merge_storage_version_status(&mut sv, "server-a", "v1", &decodable);
+        assert_eq!(condition(&sv).last_transition_time, first_transition);
+        assert_eq!(
+            sv.status.storage_versions[0].served_versions,
+            versions(&["v1"])
+        );
+    }
+}
@@ -11,7 +11,9 @@ use crate::core::v1::validation::helpers::validate_nonnegative_field;
 // ControllerRevision validation
 // =============================================================================
 
-pub fn validate_controller_revision_create(revision: &ControllerRevision) -> ErrorList {
+/// Validates a ControllerRevision: metadata, a non-null `data` object and a
+/// non-negative `revision`.
+pub fn validate_controller_revision(revision: &ControllerRevision) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
     all_errs.extend(validate_object_meta(
@@ -41,6 +43,10 @@ pub fn validate_controller_revision_create(revision: &ControllerRevision) -> Err
     all_errs
 }
 
+pub fn validate_controller_revision_create(revision: &ControllerRevision) -> ErrorList {
+    validate_controller_revision(revision)
+}
+
 pub fn validate_controller_revision_update(
     new_revision: &ControllerRevision,
     old_revision: &ControllerRevision,
@@ -53,7 +59,7 @@ pub fn validate_controller_revision_update(
         &Path::new("metadata"),
     ));
 
-    all_errs.extend(validate_controller_revision_create(new_revision));
+    all_errs.extend(validate_controller_revision(new_revision));
     if new_revision.data != old_revision.data {
         all_errs.push(forbidden(&Path::new("data"), "field is immutable"));
     }
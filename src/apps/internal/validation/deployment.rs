@@ -8,7 +8,9 @@ use crate::common::validation::{
     BadValue, ErrorList, Path, forbidden, invalid, name_is_dns_subdomain, required,
     validate_object_meta, validate_object_meta_update,
 };
-use crate::core::v1::validation::helpers::validate_nonnegative_field;
+use crate::core::v1::validation::helpers::{
+    validate_immutable_field_option, validate_nonnegative_field,
+};
 
 use super::{
     get_int_or_percent_value, is_not_more_than_100_percent, validate_label_selector,
@@ -52,20 +54,16 @@ pub fn validate_deployment_update(
 
     if let Some(ref spec) = deployment.spec {
         all_errs.extend(validate_deployment_spec(spec, &Path::new("spec")));
-        if let (Some(new_selector), Some(old_selector)) = (
-            deployment.spec.as_ref().and_then(|s| s.selector.as_ref()),
-            old_deployment
-                .spec
-                .as_ref()
-                .and_then(|s| s.selector.as_ref()),
-        ) {
-            if new_selector != old_selector {
-                all_errs.push(forbidden(
-                    &Path::new("spec").child("selector"),
-                    "field is immutable",
-                ));
-            }
-        }
+        // Setting or clearing the selector is as much a change as editing it.
+        let old_selector = old_deployment
+            .spec
+            .as_ref()
+            .and_then(|s| s.selector.clone());
+        all_errs.extend(validate_immutable_field_option(
+            &spec.selector,
+            &old_selector,
+            &Path::new("spec").child("selector"),
+        ));
     }
 
     all_errs
@@ -7,8 +7,7 @@ use std::collections::BTreeMap;
 use crate::common::FromInternal;
 use crate::common::meta::label_selector_operator;
 use crate::common::validation::{
-    BadValue, ErrorList, Path, forbidden, invalid, not_supported, required, validate_labels,
-    validate_qualified_name,
+    BadValue, ErrorList, Path, forbidden, invalid, not_supported, validate_label_selector,
 };
 use crate::common::{IntOrString, LabelSelector};
 use crate::core::v1::pod::restart_policy;
@@ -22,7 +21,8 @@ pub mod replicaset;
 pub mod statefulset;
 
 pub use controllerrevision::{
-    validate_controller_revision_create, validate_controller_revision_update,
+    validate_controller_revision, validate_controller_revision_create,
+    validate_controller_revision_update,
 };
 pub use daemonset::{
     validate_daemon_set, validate_daemon_set_status_update, validate_daemon_set_update,
@@ -41,70 +41,6 @@ pub use statefulset::{
 // Selector helpers
 // =============================================================================
 
-fn validate_label_selector(selector: &LabelSelector, path: &Path) -> ErrorList {
-    let mut all_errs = ErrorList::new();
-    all_errs.extend(validate_labels(
-        &selector.match_labels,
-        &path.child("matchLabels"),
-    ));
-
-    for (i, requirement) in selector.match_expressions.iter().enumerate() {
-        let req_path = path.child("matchExpressions").index(i);
-        if requirement.key.is_empty() {
-            all_errs.push(required(&req_path.child("key"), "key is required"));
-        } else {
-            all_errs.extend(validate_qualified_name(
-                &requirement.key,
-                &req_path.child("key"),
-            ));
-        }
-
-        let operator = requirement.operator.as_str();
-        match operator {
-            label_selector_operator::IN | label_selector_operator::NOT_IN => {
-                if requirement.values.is_empty() {
-                    all_errs.push(required(
-                        &req_path.child("values"),
-                        "values must be non-empty for In/NotIn operators",
-                    ));
-                }
-            }
-            label_selector_operator::EXISTS | label_selector_operator::DOES_NOT_EXIST => {
-                if !requirement.values.is_empty() {
-                    all_errs.push(invalid(
-                        &req_path.child("values"),
-                        BadValue::String(format!("{:?}", requirement.values)),
-                        "values must be empty for Exists/DoesNotExist operators",
-                    ));
-                }
-            }
-            _ => {
-                all_errs.push(not_supported(
-                    &req_path.child("operator"),
-                    BadValue::String(requirement.operator.clone()),
-                    &[
-                        label_selector_operator::IN,
-                        label_selector_operator::NOT_IN,
-                        label_selector_operator::EXISTS,
-                        label_selector_operator::DOES_NOT_EXIST,
-                    ],
-                ));
-            }
-        }
-
-        for (j, value) in requirement.values.iter().enumerate() {
-            if value.is_empty() {
-                all_errs.push(required(
-                    &req_path.child("values").index(j),
-                    "value must be non-empty",
-                ));
-            }
-        }
-    }
-
-    all_errs
-}
-
 fn selector_matches_labels(selector: &LabelSelector, labels: &BTreeMap<String, String>) -> bool {
     for (key, value) in &selector.match_labels {
         if labels.get(key) != Some(value) {
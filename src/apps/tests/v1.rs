@@ -61,6 +61,32 @@ fn versioned_object_metadata_access() {
     assert_eq!(resource.metadata().name.as_deref(), Some("deployment"));
 }
 
+#[test]
+fn versioned_object_metadata_accessors() {
+    let resource = Deployment::default();
+    assert_eq!(resource.name(), "");
+    assert_eq!(resource.namespace(), "");
+    assert!(resource.labels().is_empty());
+
+    let mut resource = Deployment::default();
+    let meta = resource.metadata_mut();
+    meta.name = Some("web".to_string());
+    meta.namespace = Some("prod".to_string());
+    meta.uid = Some("0b1c2d3e".to_string());
+    meta.resource_version = Some("42".to_string());
+    meta.labels.insert("app".to_string(), "web".to_string());
+
+    assert_eq!(resource.name(), "web");
+    assert_eq!(resource.namespace(), "prod");
+    assert_eq!(resource.uid(), "0b1c2d3e");
+    assert_eq!(resource.resource_version(), "42");
+    assert_eq!(
+        resource.labels().get("app").map(String::as_str),
+        Some("web")
+    );
+    assert!(resource.annotations().is_empty());
+}
+
 #[test]
 fn apply_default_sets_type_meta() {
     let mut resource = Deployment::default();
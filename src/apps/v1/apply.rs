@@ -0,0 +1,184 @@
+//! Apply configurations for server-side apply
+//!
+//! An apply configuration is a partial object: every field is optional and unset
+//! fields are omitted from the request body, so the field manager only claims
+//! ownership of the fields it actually sets.
+//!
+//! Based on k8s.io/client-go/applyconfigurations/apps/v1
+
+use serde::{Deserialize, Serialize};
+
+use super::DeploymentStrategy;
+use crate::common::{LabelSelector, ObjectMeta};
+use crate::core::v1::PodTemplateSpec;
+
+/// DeploymentApplyConfiguration is a partial Deployment for server-side apply.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentApplyConfiguration {
+    /// APIVersion of the object; set by [`DeploymentApplyConfiguration::new`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    /// Kind of the object; set by [`DeploymentApplyConfiguration::new`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Metadata to apply. Name and namespace identify the target object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ObjectMeta>,
+    /// Spec fields to apply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec: Option<DeploymentSpecApplyConfiguration>,
+}
+
+/// DeploymentSpecApplyConfiguration mirrors `DeploymentSpec` with every field optional.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentSpecApplyConfiguration {
+    /// Number of desired pods.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<i32>,
+    /// Label selector for pods.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selector: Option<LabelSelector>,
+    /// Template describes the pods that will be created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<PodTemplateSpec>,
+    /// The deployment strategy to use to replace existing pods with new ones.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<DeploymentStrategy>,
+    /// Minimum number of seconds a new pod must be ready to be considered available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_ready_seconds: Option<i32>,
+    /// The number of old ReplicaSets to retain to allow rollback.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision_history_limit: Option<i32>,
+    /// Indicates that the deployment is paused.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paused: Option<bool>,
+    /// Maximum time in seconds for a deployment to make progress.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress_deadline_seconds: Option<i32>,
+}
+
+impl DeploymentApplyConfiguration {
+    /// Starts an apply configuration for the Deployment `name` in `namespace`.
+    pub fn new(name: impl Into<String>, namespace: impl Into<String>) -> Self {
+        Self {
+            api_version: Some("apps/v1".to_string()),
+            kind: Some("Deployment".to_string()),
+            metadata: Some(ObjectMeta {
+                name: Some(name.into()),
+                namespace: Some(namespace.into()),
+                ..Default::default()
+            }),
+            spec: None,
+        }
+    }
+
+    /// Sets `spec.replicas`.
+    pub fn with_replicas(mut self, replicas: i32) -> Self {
+        self.spec_mut().replicas = Some(replicas);
+        self
+    }
+
+    /// Sets `spec.selector`.
+    pub fn with_selector(mut self, selector: LabelSelector) -> Self {
+        self.spec_mut().selector = Some(selector);
+        self
+    }
+
+    /// Sets `spec.template`.
+    pub fn with_template(mut self, template: PodTemplateSpec) -> Self {
+        self.spec_mut().template = Some(template);
+        self
+    }
+
+    /// Sets `spec.strategy`.
+    pub fn with_strategy(mut self, strategy: DeploymentStrategy) -> Self {
+        self.spec_mut().strategy = Some(strategy);
+        self
+    }
+
+    /// Sets `spec.minReadySeconds`.
+    pub fn with_min_ready_seconds(mut self, min_ready_seconds: i32) -> Self {
+        self.spec_mut().min_ready_seconds = Some(min_ready_seconds);
+        self
+    }
+
+    /// Sets `spec.revisionHistoryLimit`.
+    pub fn with_revision_history_limit(mut self, revision_history_limit: i32) -> Self {
+        self.spec_mut().revision_history_limit = Some(revision_history_limit);
+        self
+    }
+
+    /// Sets `spec.paused`.
+    pub fn with_paused(mut self, paused: bool) -> Self {
+        self.spec_mut().paused = Some(paused);
+        self
+    }
+
+    /// Sets `spec.progressDeadlineSeconds`.
+    pub fn with_progress_deadline_seconds(mut self, progress_deadline_seconds: i32) -> Self {
+        self.spec_mut().progress_deadline_seconds = Some(progress_deadline_seconds);
+        self
+    }
+
+    /// Returns the body of the apply request (`application/apply-patch+yaml`,
+    /// which accepts JSON).
+    pub fn into_patch(self) -> Result<serde_json::Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+
+    fn spec_mut(&mut self) -> &mut DeploymentSpecApplyConfiguration {
+        self.spec.get_or_insert_with(Default::default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::FieldManager;
+
+    #[test]
+    fn test_apply_patch_sets_only_replicas() {
+        let patch = DeploymentApplyConfiguration::new("web", "default")
+            .with_replicas(5)
+            .into_patch()
+            .unwrap();
+
+        assert_eq!(
+            patch,
+            serde_json::json!({
+                "apiVersion": "apps/v1",
+                "kind": "Deployment",
+                "metadata": {"name": "web", "namespace": "default"},
+                "spec": {"replicas": 5}
+            })
+        );
+
+        let manager = FieldManager {
+            force: true,
+            ..FieldManager::new("autoscaler")
+        };
+        assert_eq!(manager.query(), "fieldManager=autoscaler&force=true");
+    }
+
+    #[test]
+    fn test_apply_patch_sets_rollout_fields() {
+        let patch = DeploymentApplyConfiguration::new("web", "default")
+            .with_min_ready_seconds(10)
+            .with_revision_history_limit(3)
+            .with_progress_deadline_seconds(300)
+            .into_patch()
+            .unwrap();
+
+        assert_eq!(
+            patch["spec"],
+            serde_json::json!({
+                "minReadySeconds": 10,
+                "revisionHistoryLimit": 3,
+                "progressDeadlineSeconds": 300
+            })
+        );
+    }
+}
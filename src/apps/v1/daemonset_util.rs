@@ -0,0 +1,150 @@
+//! DaemonSet rollout helpers
+//!
+//! Based on k8s.io/kubectl/pkg/polymorphichelpers/rollout_status.go
+
+use super::DaemonSet;
+
+/// Rollout state as reported by `kubectl rollout status daemonset`.
+enum RolloutState {
+    /// The controller has not yet observed the latest spec.
+    Pending,
+    /// Nodes are still being updated to the latest pod template.
+    Updating { updated: i32, desired: i32 },
+    /// Updated pods exist but are not yet available.
+    WaitingAvailable { available: i32, desired: i32 },
+    /// The rollout has finished.
+    Complete,
+}
+
+impl DaemonSet {
+    /// Returns true once the rollout has finished: the latest generation was observed
+    /// and every node that should run the daemon pod runs an updated, available one.
+    pub fn is_rolled_out(&self) -> bool {
+        matches!(self.rollout_state(), RolloutState::Complete)
+    }
+
+    /// Returns the human-readable rollout status line printed by `kubectl rollout status`.
+    pub fn rollout_message(&self) -> String {
+        let name = self
+            .metadata
+            .as_ref()
+            .and_then(|meta| meta.name.as_deref())
+            .unwrap_or_default();
+        match self.rollout_state() {
+            RolloutState::Pending => "Waiting for daemon set spec update to be observed...".into(),
+            RolloutState::Updating { updated, desired } => format!(
+                "Waiting for daemon set {name:?} rollout to finish: {updated} out of {desired} new pods have been updated..."
+            ),
+            RolloutState::WaitingAvailable { available, desired } => format!(
+                "Waiting for daemon set {name:?} rollout to finish: {available} of {desired} updated pods are available..."
+            ),
+            RolloutState::Complete => format!("daemon set {name:?} successfully rolled out"),
+        }
+    }
+
+    fn rollout_state(&self) -> RolloutState {
+        let generation = self
+            .metadata
+            .as_ref()
+            .and_then(|meta| meta.generation)
+            .unwrap_or_default();
+        let Some(status) = self.status.as_ref() else {
+            return RolloutState::Pending;
+        };
+        if status.observed_generation.unwrap_or_default() < generation {
+            return RolloutState::Pending;
+        }
+
+        let desired = status.desired_number_scheduled;
+        let updated = status.updated_number_scheduled.unwrap_or_default();
+        let available = status.number_available.unwrap_or_default();
+
+        if updated < desired {
+            RolloutState::Updating { updated, desired }
+        } else if available < desired {
+            RolloutState::WaitingAvailable { available, desired }
+        } else {
+            RolloutState::Complete
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apps::v1::DaemonSetStatus;
+    use crate::common::ObjectMeta;
+
+    fn daemon_set(status: DaemonSetStatus) -> DaemonSet {
+        DaemonSet {
+            metadata: Some(ObjectMeta {
+                name: Some("node-exporter".to_string()),
+                generation: Some(5),
+                ..Default::default()
+            }),
+            status: Some(status),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_daemon_set_rollout_in_progress() {
+        let updating = daemon_set(DaemonSetStatus {
+            observed_generation: Some(5),
+            desired_number_scheduled: 4,
+            updated_number_scheduled: Some(3),
+            number_available: Some(4),
+            ..Default::default()
+        });
+        assert!(!updating.is_rolled_out());
+        assert_eq!(
+            updating.rollout_message(),
+            "Waiting for daemon set \"node-exporter\" rollout to finish: 3 out of 4 new pods have been updated..."
+        );
+
+        let unavailable = daemon_set(DaemonSetStatus {
+            observed_generation: Some(5),
+            desired_number_scheduled: 4,
+            updated_number_scheduled: Some(4),
+            number_available: Some(2),
+            ..Default::default()
+        });
+        assert!(!unavailable.is_rolled_out());
+        assert_eq!(
+            unavailable.rollout_message(),
+            "Waiting for daemon set \"node-exporter\" rollout to finish: 2 of 4 updated pods are available..."
+        );
+
+        let stale = daemon_set(DaemonSetStatus {
+            observed_generation: Some(4),
+            desired_number_scheduled: 4,
+            updated_number_scheduled: Some(4),
+            number_available: Some(4),
+            ..Default::default()
+        });
+        assert!(!stale.is_rolled_out());
+        assert_eq!(
+            stale.rollout_message(),
+            "Waiting for daemon set spec update to be observed..."
+        );
+    }
+
+    #[test]
+    fn test_daemon_set_rollout_complete() {
+        let daemon_set = daemon_set(DaemonSetStatus {
+            observed_generation: Some(5),
+            current_number_scheduled: 4,
+            desired_number_scheduled: 4,
+            number_ready: 4,
+            updated_number_scheduled: Some(4),
+            number_available: Some(4),
+            ..Default::default()
+        });
+
+        assert!(daemon_set.is_rolled_out());
+        assert_eq!(
+            daemon_set.rollout_message(),
+            "daemon set \"node-exporter\" successfully rolled out"
+        );
+    }
+}
@@ -0,0 +1,479 @@
+//! Deployment rollout and ReplicaSet helpers
+//!
+//! Based on k8s.io/kubernetes/pkg/controller/deployment/util/deployment_util.go
+//! and k8s.io/kubectl/pkg/polymorphichelpers/rollout_status.go
+
+use super::{
+    DEFAULT_DEPLOYMENT_UNIQUE_LABEL_KEY, Deployment, DeploymentConditionType, ReplicaSet,
+    ReplicaSetSpec,
+};
+use crate::common::meta::LAST_APPLIED_CONFIG_ANNOTATION;
+use crate::common::{ObjectMeta, OwnerReference};
+use crate::core::v1::PodTemplateSpec;
+
+/// RevisionAnnotation is the revision annotation of a deployment's replica sets which records its rollout sequence.
+pub const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+
+/// Characters used by SafeEncodeString; vowels and confusable characters are omitted.
+const SAFE_ALPHANUMS: &[u8] = b"bcdfghjklmnpqrstvwxz2456789";
+
+/// TimedOutReason is added in a deployment when its newest replica set fails to show
+/// any progress within the given deadline (progressDeadlineSeconds).
+pub const TIMED_OUT_REASON: &str = "ProgressDeadlineExceeded";
+
+/// Rollout state as reported by `kubectl rollout status`.
+enum RolloutState {
+    /// The controller has not yet observed the latest spec.
+    Pending,
+    /// The Progressing condition reports that the progress deadline was exceeded.
+    Stalled,
+    /// Updated replicas are still being rolled out.
+    Updating { updated: i32, desired: i32 },
+    /// Replicas from old revisions are still running.
+    OldReplicasPending { old: i32 },
+    /// Updated replicas exist but are not yet available.
+    WaitingAvailable { available: i32, updated: i32 },
+    /// The rollout has finished.
+    Complete,
+}
+
+/// Replica counts shown in the `READY`, `UP-TO-DATE` and `AVAILABLE` columns of
+/// `kubectl get deployments`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct ReplicaSummary {
+    /// Pods that are ready (`status.readyReplicas`).
+    pub ready: i32,
+    /// Desired pods (`spec.replicas`, defaulting to 1).
+    pub desired: i32,
+    /// Pods running the latest template (`status.updatedReplicas`).
+    pub up_to_date: i32,
+    /// Pods available for at least minReadySeconds (`status.availableReplicas`).
+    pub available: i32,
+}
+
+/// Computes a pod-template-hash label value for a pod template.
+///
+/// The template is hashed with FNV-1a over its JSON encoding, salted with the
+/// collision count when set, and encoded with the same alphabet as
+/// `rand.SafeEncodeString`. This is a local hash only: upstream `ComputeHash`
+/// feeds FNV-1a with `DeepHashObject`'s Go-specific dump of the template, so
+/// the values never match those of ReplicaSets created by a real cluster.
+///
+/// Returns an error if the template cannot be serialized to JSON.
+pub fn compute_pod_template_hash(
+    template: &PodTemplateSpec,
+    collision_count: Option<i32>,
+) -> Result<String, serde_json::Error> {
+    const FNV_OFFSET_BASIS: u32 = 0x811c_9dc5;
+    const FNV_PRIME: u32 = 0x0100_0193;
+
+    let mut bytes = serde_json::to_vec(template)?;
+    if let Some(collision_count) = collision_count {
+        bytes.extend_from_slice(&(collision_count as u32).to_le_bytes());
+    }
+    let hash = bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u32::from(*byte)).wrapping_mul(FNV_PRIME)
+    });
+
+    Ok(hash
+        .to_string()
+        .bytes()
+        .map(|byte| SAFE_ALPHANUMS[usize::from(byte) % SAFE_ALPHANUMS.len()] as char)
+        .collect())
+}
+
+impl Deployment {
+    /// Returns true once the rollout has finished: the latest generation was observed,
+    /// all desired replicas are updated and available, and progress has not stalled.
+    pub fn is_complete(&self) -> bool {
+        matches!(self.rollout_state(), RolloutState::Complete)
+    }
+
+    /// Returns the human-readable rollout status line printed by `kubectl rollout status`.
+    pub fn rollout_message(&self) -> String {
+        let name = self
+            .metadata
+            .as_ref()
+            .and_then(|meta| meta.name.as_deref())
+            .unwrap_or_default();
+        match self.rollout_state() {
+            RolloutState::Pending => "Waiting for deployment spec update to be observed...".into(),
+            RolloutState::Stalled => format!("deployment {name:?} exceeded its progress deadline"),
+            RolloutState::Updating { updated, desired } => format!(
+                "Waiting for deployment {name:?} rollout to finish: {updated} out of {desired} new replicas have been updated..."
+            ),
+            RolloutState::OldReplicasPending { old } => format!(
+                "Waiting for deployment {name:?} rollout to finish: {old} old replicas are pending termination..."
+            ),
+            RolloutState::WaitingAvailable { available, updated } => format!(
+                "Waiting for deployment {name:?} rollout to finish: {available} of {updated} updated replicas are available..."
+            ),
+            RolloutState::Complete => format!("deployment {name:?} successfully rolled out"),
+        }
+    }
+
+    /// Returns the replica counts used when printing the deployment as a table row.
+    pub fn replica_summary(&self) -> ReplicaSummary {
+        let desired = self
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.replicas)
+            .unwrap_or(1);
+        let status = self.status.as_ref();
+        ReplicaSummary {
+            ready: status
+                .and_then(|status| status.ready_replicas)
+                .unwrap_or_default(),
+            desired,
+            up_to_date: status
+                .and_then(|status| status.updated_replicas)
+                .unwrap_or_default(),
+            available: status
+                .and_then(|status| status.available_replicas)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Builds the ReplicaSet the deployment controller would create for the current
+    /// pod template at the given revision.
+    ///
+    /// Follows `getNewReplicaSet`: the pod-template-hash label is injected into the
+    /// template labels and selector, the deployment becomes the controlling owner,
+    /// and the revision is recorded in the `deployment.kubernetes.io/revision` annotation.
+    /// The hash comes from [`compute_pod_template_hash`], so the name and label
+    /// differ from the ones a cluster would pick.
+    ///
+    /// Returns an error if the pod template cannot be serialized to JSON.
+    pub fn new_replica_set(&self, revision: i64) -> Result<ReplicaSet, serde_json::Error> {
+        let meta = self.metadata.clone().unwrap_or_default();
+        let spec = self.spec.clone().unwrap_or_default();
+        let collision_count = self
+            .status
+            .as_ref()
+            .and_then(|status| status.collision_count);
+
+        let mut template = spec.template.unwrap_or_default();
+        let hash = compute_pod_template_hash(&template, collision_count)?;
+        template
+            .metadata
+            .get_or_insert_with(ObjectMeta::default)
+            .labels
+            .insert(
+                DEFAULT_DEPLOYMENT_UNIQUE_LABEL_KEY.to_string(),
+                hash.clone(),
+            );
+
+        let mut selector = spec.selector.unwrap_or_default();
+        selector.match_labels.insert(
+            DEFAULT_DEPLOYMENT_UNIQUE_LABEL_KEY.to_string(),
+            hash.clone(),
+        );
+
+        let mut annotations = meta.annotations.clone();
+        annotations.remove(LAST_APPLIED_CONFIG_ANNOTATION);
+        annotations.insert(REVISION_ANNOTATION.to_string(), revision.to_string());
+
+        let name = meta.name.clone().unwrap_or_default();
+        Ok(ReplicaSet {
+            metadata: Some(ObjectMeta {
+                name: Some(format!("{name}-{hash}")),
+                namespace: meta.namespace.clone(),
+                labels: template
+                    .metadata
+                    .as_ref()
+                    .map(|meta| meta.labels.clone())
+                    .unwrap_or_default(),
+                annotations,
+                owner_references: vec![OwnerReference {
+                    api_version: "apps/v1".to_string(),
+                    kind: "Deployment".to_string(),
+                    name,
+                    uid: meta.uid.clone().unwrap_or_default(),
+                    controller: Some(true),
+                    block_owner_deletion: Some(true),
+                }],
+                ..Default::default()
+            }),
+            spec: Some(ReplicaSetSpec {
+                replicas: spec.replicas,
+                min_ready_seconds: spec.min_ready_seconds,
+                selector: Some(selector),
+                template: Some(template),
+            }),
+            ..Default::default()
+        })
+    }
+
+    fn rollout_state(&self) -> RolloutState {
+        let generation = self
+            .metadata
+            .as_ref()
+            .and_then(|meta| meta.generation)
+            .unwrap_or_default();
+        let Some(status) = self.status.as_ref() else {
+            return RolloutState::Pending;
+        };
+        if status.observed_generation.unwrap_or_default() < generation {
+            return RolloutState::Pending;
+        }
+
+        let stalled = status.conditions.iter().any(|condition| {
+            condition.r#type == DeploymentConditionType::Progressing
+                && condition.reason == TIMED_OUT_REASON
+        });
+        if stalled {
+            return RolloutState::Stalled;
+        }
+
+        let desired = self
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.replicas)
+            .unwrap_or(1);
+        let replicas = status.replicas.unwrap_or_default();
+        let updated = status.updated_replicas.unwrap_or_default();
+        let available = status.available_replicas.unwrap_or_default();
+
+        if updated < desired {
+            RolloutState::Updating { updated, desired }
+        } else if replicas > updated {
+            RolloutState::OldReplicasPending {
+                old: replicas - updated,
+            }
+        } else if available < updated {
+            RolloutState::WaitingAvailable { available, updated }
+        } else {
+            RolloutState::Complete
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apps::v1::{DeploymentCondition, DeploymentSpec, DeploymentStatus};
+    use crate::common::LabelSelector;
+    use std::collections::BTreeMap;
+
+    fn deployment(status: DeploymentStatus) -> Deployment {
+        Deployment {
+            metadata: Some(ObjectMeta {
+                name: Some("web".to_string()),
+                generation: Some(3),
+                ..Default::default()
+            }),
+            spec: Some(DeploymentSpec {
+                replicas: Some(3),
+                ..Default::default()
+            }),
+            status: Some(status),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_deployment_new_replica_set() {
+        let mut deployment = deployment(DeploymentStatus::default());
+        let meta = deployment.metadata.as_mut().unwrap();
+        meta.namespace = Some("prod".to_string());
+        meta.uid = Some("6a1d2c3b".to_string());
+        meta.annotations
+            .insert(LAST_APPLIED_CONFIG_ANNOTATION.to_string(), "{}".to_string());
+        let spec = deployment.spec.as_mut().unwrap();
+        spec.selector = Some(LabelSelector {
+            match_labels: BTreeMap::from([("app".to_string(), "web".to_string())]),
+            ..Default::default()
+        });
+        spec.template = Some(PodTemplateSpec {
+            metadata: Some(ObjectMeta {
+                labels: BTreeMap::from([("app".to_string(), "web".to_string())]),
+                ..Default::default()
+            }),
+            spec: None,
+        });
+
+        let replica_set = deployment.new_replica_set(4).unwrap();
+        let hash = compute_pod_template_hash(
+            deployment.spec.as_ref().unwrap().template.as_ref().unwrap(),
+            None,
+        )
+        .unwrap();
+        assert!(!hash.is_empty());
+
+        let meta = replica_set.metadata.as_ref().unwrap();
+        assert_eq!(meta.name.as_deref(), Some(format!("web-{hash}").as_str()));
+        assert_eq!(meta.namespace.as_deref(), Some("prod"));
+        assert_eq!(
+            meta.owner_references,
+            vec![OwnerReference {
+                api_version: "apps/v1".to_string(),
+                kind: "Deployment".to_string(),
+                name: "web".to_string(),
+                uid: "6a1d2c3b".to_string(),
+                controller: Some(true),
+                block_owner_deletion: Some(true),
+            }]
+        );
+        assert_eq!(
+            meta.annotations
+                .get(REVISION_ANNOTATION)
+                .map(String::as_str),
+            Some("4")
+        );
+        assert!(
+            !meta
+                .annotations
+                .contains_key(LAST_APPLIED_CONFIG_ANNOTATION)
+        );
+        assert_eq!(
+            meta.labels.get(DEFAULT_DEPLOYMENT_UNIQUE_LABEL_KEY),
+            Some(&hash)
+        );
+
+        let spec = replica_set.spec.as_ref().unwrap();
+        assert_eq!(spec.replicas, Some(3));
+        assert_eq!(
+            spec.selector
+                .as_ref()
+                .unwrap()
+                .match_labels
+                .get(DEFAULT_DEPLOYMENT_UNIQUE_LABEL_KEY),
+            Some(&hash)
+        );
+        let template_labels = &spec
+            .template
+            .as_ref()
+            .unwrap()
+            .metadata
+            .as_ref()
+            .unwrap()
+            .labels;
+        assert_eq!(template_labels.get("app").map(String::as_str), Some("web"));
+        assert_eq!(
+            template_labels.get(DEFAULT_DEPLOYMENT_UNIQUE_LABEL_KEY),
+            Some(&hash)
+        );
+    }
+
+    #[test]
+    fn test_compute_pod_template_hash_uses_collision_count() {
+        let template = PodTemplateSpec::default();
+        assert_eq!(
+            compute_pod_template_hash(&template, None).unwrap(),
+            compute_pod_template_hash(&template, None).unwrap()
+        );
+        assert_ne!(
+            compute_pod_template_hash(&template, None).unwrap(),
+            compute_pod_template_hash(&template, Some(1)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_deployment_rollout_in_progress() {
+        let deployment = deployment(DeploymentStatus {
+            observed_generation: Some(3),
+            replicas: Some(4),
+            updated_replicas: Some(2),
+            available_replicas: Some(2),
+            ..Default::default()
+        });
+
+        assert!(!deployment.is_complete());
+        assert_eq!(
+            deployment.rollout_message(),
+            "Waiting for deployment \"web\" rollout to finish: 2 out of 3 new replicas have been updated..."
+        );
+    }
+
+    #[test]
+    fn test_deployment_rollout_waits_for_observed_generation() {
+        let deployment = deployment(DeploymentStatus {
+            observed_generation: Some(2),
+            replicas: Some(3),
+            updated_replicas: Some(3),
+            available_replicas: Some(3),
+            ..Default::default()
+        });
+
+        assert!(!deployment.is_complete());
+        assert_eq!(
+            deployment.rollout_message(),
+            "Waiting for deployment spec update to be observed..."
+        );
+    }
+
+    #[test]
+    fn test_deployment_rollout_stalled() {
+        let deployment = deployment(DeploymentStatus {
+            observed_generation: Some(3),
+            replicas: Some(3),
+            updated_replicas: Some(3),
+            available_replicas: Some(3),
+            conditions: vec![DeploymentCondition {
+                r#type: DeploymentConditionType::Progressing,
+                status: "False".to_string(),
+                reason: TIMED_OUT_REASON.to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+
+        assert!(!deployment.is_complete());
+        assert_eq!(
+            deployment.rollout_message(),
+            "deployment \"web\" exceeded its progress deadline"
+        );
+    }
+
+    #[test]
+    fn test_deployment_replica_summary_partially_available() {
+        let deployment = deployment(DeploymentStatus {
+            observed_generation: Some(3),
+            replicas: Some(4),
+            updated_replicas: Some(2),
+            ready_replicas: Some(2),
+            available_replicas: Some(1),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            deployment.replica_summary(),
+            ReplicaSummary {
+                ready: 2,
+                desired: 3,
+                up_to_date: 2,
+                available: 1,
+            }
+        );
+
+        let unscaled = Deployment {
+            spec: Some(DeploymentSpec::default()),
+            ..Default::default()
+        };
+        assert_eq!(
+            unscaled.replica_summary(),
+            ReplicaSummary {
+                desired: 1,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_deployment_rollout_complete() {
+        let deployment = deployment(DeploymentStatus {
+            observed_generation: Some(3),
+            replicas: Some(3),
+            updated_replicas: Some(3),
+            ready_replicas: Some(3),
+            available_replicas: Some(3),
+            ..Default::default()
+        });
+
+        assert!(deployment.is_complete());
+        assert_eq!(
+            deployment.rollout_message(),
+            "deployment \"web\" successfully rolled out"
+        );
+    }
+}
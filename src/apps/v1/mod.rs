@@ -2,16 +2,22 @@
 //!
 //! This module contains types from the Kubernetes apps/v1 API group.
 
-use crate::common::{IntOrString, LabelSelector, ListMeta, ObjectMeta, TypeMeta};
+use crate::common::{HasStatus, IntOrString, LabelSelector, ListMeta, ObjectMeta, TypeMeta};
 use crate::core::v1::template::apply_pod_template_spec_defaults;
 use crate::core::v1::{PersistentVolumeClaim, PodTemplateSpec};
 use crate::impl_versioned_object;
 use serde::{Deserialize, Serialize};
 
+pub mod apply;
 mod as_str_ref_impls;
 pub mod conversion;
+pub mod daemonset_util;
+pub mod deployment_util;
+mod replication_controller;
 pub mod validation;
 
+pub use apply::{DeploymentApplyConfiguration, DeploymentSpecApplyConfiguration};
+
 // ============================================================================
 // StatefulSet Related Types
 // ============================================================================
@@ -341,6 +347,14 @@ pub struct Deployment {
 }
 impl_versioned_object!(Deployment);
 
+impl HasStatus for Deployment {
+    type Status = DeploymentStatus;
+
+    fn status(&self) -> Option<&DeploymentStatus> {
+        self.status.as_ref()
+    }
+}
+
 /// DeploymentSpec is the specification of the desired behavior of the Deployment.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
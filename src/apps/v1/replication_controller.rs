@@ -0,0 +1,156 @@
+//! ReplicationController to ReplicaSet conversion
+//!
+//! Based on k8s.io/kubernetes/pkg/controller/replication/conversion.go
+
+use super::{
+    ReplicaSet, ReplicaSetCondition, ReplicaSetConditionType, ReplicaSetSpec, ReplicaSetStatus,
+    replica_set_condition_type,
+};
+use crate::common::{LabelSelector, TypeMeta};
+use crate::core::v1::{ReplicationController, ReplicationControllerCondition};
+
+impl ReplicationController {
+    /// Converts this controller into the equivalent apps/v1 ReplicaSet.
+    ///
+    /// The map-based selector becomes `matchLabels`, while replicas,
+    /// minReadySeconds, the pod template and status carry over unchanged.
+    /// Status conditions of a type ReplicaSet does not define are dropped; see
+    /// [`ReplicationController::replica_set_conversion_warnings`].
+    pub fn to_replica_set(self) -> ReplicaSet {
+        ReplicaSet {
+            type_meta: TypeMeta {
+                api_version: "apps/v1".to_string(),
+                kind: "ReplicaSet".to_string(),
+            },
+            metadata: self.metadata,
+            spec: self.spec.map(|spec| ReplicaSetSpec {
+                replicas: spec.replicas,
+                min_ready_seconds: spec.min_ready_seconds,
+                selector: Some(LabelSelector {
+                    match_labels: spec.selector,
+                    ..Default::default()
+                }),
+                template: spec.template,
+            }),
+            status: self.status.map(|status| ReplicaSetStatus {
+                replicas: status.replicas,
+                fully_labeled_replicas: Some(status.fully_labeled_replicas),
+                ready_replicas: Some(status.ready_replicas),
+                available_replicas: Some(status.available_replicas),
+                terminating_replicas: None,
+                observed_generation: status.observed_generation,
+                conditions: status
+                    .conditions
+                    .into_iter()
+                    .filter_map(to_replica_set_condition)
+                    .collect(),
+            }),
+        }
+    }
+
+    /// Returns a warning for each field that [`ReplicationController::to_replica_set`]
+    /// cannot carry over faithfully.
+    pub fn replica_set_conversion_warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if let Some(spec) = self.spec.as_ref()
+            && spec.selector.is_empty()
+        {
+            warnings.push(
+                "spec.selector: empty selector converts to a ReplicaSet selector that matches all pods; apply defaults first to select the template labels"
+                    .to_string(),
+            );
+        }
+        if let Some(status) = self.status.as_ref() {
+            for (i, condition) in status.conditions.iter().enumerate() {
+                if condition.r#type != replica_set_condition_type::REPLICA_FAILURE {
+                    warnings.push(format!(
+                        "status.conditions[{i}].type: {:?} has no ReplicaSet equivalent and is dropped",
+                        condition.r#type
+                    ));
+                }
+            }
+        }
+        warnings
+    }
+}
+
+fn to_replica_set_condition(
+    condition: ReplicationControllerCondition,
+) -> Option<ReplicaSetCondition> {
+    if condition.r#type != replica_set_condition_type::REPLICA_FAILURE {
+        return None;
+    }
+    Some(ReplicaSetCondition {
+        r#type: ReplicaSetConditionType::ReplicaFailure,
+        status: condition.status,
+        last_transition_time: condition.last_transition_time.map(|time| time.to_rfc3339()),
+        reason: condition.reason.unwrap_or_default(),
+        message: condition.message.unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_to_replica_set_two_key_selector() {
+        let rc: ReplicationController = serde_json::from_value(serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "ReplicationController",
+            "metadata": {"name": "frontend", "namespace": "default"},
+            "spec": {
+                "replicas": 3,
+                "minReadySeconds": 10,
+                "selector": {"app": "guestbook", "tier": "frontend"},
+                "template": {
+                    "metadata": {"labels": {"app": "guestbook", "tier": "frontend"}},
+                    "spec": {"containers": [{"name": "php-redis", "image": "gb-frontend:v4"}]}
+                }
+            },
+            "status": {
+                "replicas": 3,
+                "readyReplicas": 2,
+                "conditions": [
+                    {"type": "ReplicaFailure", "status": "True", "reason": "FailedCreate"},
+                    {"type": "Progressing", "status": "True"}
+                ]
+            }
+        }))
+        .unwrap();
+
+        let warnings = rc.replica_set_conversion_warnings();
+        assert_eq!(warnings.len(), 1, "unexpected warnings: {warnings:?}");
+        assert!(warnings[0].starts_with("status.conditions[1].type"));
+
+        let template = rc.spec.as_ref().and_then(|spec| spec.template.clone());
+        let rs = rc.to_replica_set();
+        assert_eq!(rs.type_meta.api_version, "apps/v1");
+        assert_eq!(rs.type_meta.kind, "ReplicaSet");
+
+        let spec = rs.spec.unwrap();
+        assert_eq!(spec.replicas, Some(3));
+        assert_eq!(spec.min_ready_seconds, Some(10));
+        assert_eq!(spec.template, template);
+        assert_eq!(
+            spec.selector,
+            Some(LabelSelector {
+                match_labels: BTreeMap::from([
+                    ("app".to_string(), "guestbook".to_string()),
+                    ("tier".to_string(), "frontend".to_string()),
+                ]),
+                ..Default::default()
+            })
+        );
+
+        let status = rs.status.unwrap();
+        assert_eq!(status.ready_replicas, Some(2));
+        assert_eq!(status.conditions.len(), 1);
+        assert_eq!(
+            status.conditions[0].r#type,
+            ReplicaSetConditionType::ReplicaFailure
+        );
+        assert_eq!(status.conditions[0].reason, "FailedCreate");
+    }
+}
@@ -121,6 +121,10 @@ pub fn validate_daemon_set_status_update(
 // ControllerRevision validation
 // =============================================================================
 
+pub fn validate_controller_revision(revision: &ControllerRevision) -> ErrorList {
+    internal_validation::validate_controller_revision(&revision.clone().to_internal())
+}
+
 pub fn validate_controller_revision_create(revision: &ControllerRevision) -> ErrorList {
     internal_validation::validate_controller_revision_create(&revision.clone().to_internal())
 }
@@ -143,7 +147,8 @@ pub fn validate_controller_revision_update(
 mod tests {
     use super::*;
     use crate::apps::v1::{DeploymentSpec, ReplicaSetSpec};
-    use crate::common::ObjectMeta;
+    use crate::common::validation::ErrorType;
+    use crate::common::{ApplyDefault, ObjectMeta};
     use crate::core::v1::PodTemplateSpec;
     use crate::core::v1::pod::{Container, PodSpec, restart_policy};
 
@@ -207,4 +212,85 @@ mod tests {
         let errs = validate_replica_set(&rs);
         assert!(!errs.is_empty());
     }
+
+    fn deployment(app: &str) -> Deployment {
+        let labels: std::collections::BTreeMap<String, String> =
+            [("app".to_string(), app.to_string())].into();
+        let mut deployment = Deployment {
+            metadata: Some(ObjectMeta {
+                name: Some("web".to_string()),
+                namespace: Some("default".to_string()),
+                resource_version: Some("1".to_string()),
+                ..Default::default()
+            }),
+            spec: Some(DeploymentSpec {
+                replicas: Some(2),
+                selector: Some(crate::common::LabelSelector {
+                    match_labels: labels.clone(),
+                    match_expressions: Vec::new(),
+                }),
+                template: Some(base_template(labels)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        deployment.apply_default();
+        deployment
+    }
+
+    #[test]
+    fn deployment_update_unchanged_selector() {
+        let old = deployment("web");
+        let mut new = old.clone();
+        new.spec.as_mut().unwrap().replicas = Some(5);
+
+        let errs = validate_deployment_update(&new, &old);
+        assert!(errs.is_empty(), "unexpected errors: {:?}", errs);
+    }
+
+    #[test]
+    fn deployment_update_rejects_selector_change() {
+        let old = deployment("web");
+        let new = deployment("web-v2");
+
+        let errs = validate_deployment_update(&new, &old);
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Forbidden);
+        assert_eq!(errs.errors[0].field, "spec.selector");
+        assert_eq!(errs.errors[0].detail, "field is immutable");
+    }
+
+    fn controller_revision(data: Option<serde_json::Value>, revision: i64) -> ControllerRevision {
+        ControllerRevision {
+            metadata: Some(ObjectMeta {
+                name: Some("web-6d4cf56db6".to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            }),
+            data,
+            revision,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn controller_revision_requires_data() {
+        let valid = controller_revision(Some(serde_json::json!({"spec": {}})), 1);
+        assert!(validate_controller_revision(&valid).is_empty());
+
+        let errs = validate_controller_revision(&controller_revision(None, 1));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Required);
+        assert_eq!(errs.errors[0].field, "data");
+        assert_eq!(errs.errors[0].detail, "data is mandatory");
+    }
+
+    #[test]
+    fn controller_revision_rejects_negative_revision() {
+        let revision = controller_revision(Some(serde_json::json!({})), -1);
+        let errs = validate_controller_revision(&revision);
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(errs.errors[0].field, "revision");
+    }
 }
@@ -553,7 +553,7 @@ fn static_default_object_meta() -> &'static ObjectMeta {
 // Protobuf Placeholder
 // ----------------------------------------------------------------------------
 
-impl_unimplemented_prost_message!(TokenReview);
+// TokenReview: see proto.rs
 impl_unimplemented_prost_message!(TokenRequest);
 impl_unimplemented_prost_message!(SelfSubjectReview);
 
@@ -565,3 +565,8 @@ mod serde_roundtrip_tests;
 
 #[cfg(test)]
 mod conversion_roundtrip_tests;
+
+mod proto;
+
+#[cfg(test)]
+mod proto_roundtrip_tests;
@@ -0,0 +1,244 @@
+//! Protobuf encodings for authentication/v1 types
+//!
+//! Field numbers follow k8s.io/api/authentication/v1/generated.proto. TypeMeta is
+//! carried by the runtime envelope, not by the messages themselves.
+
+use prost::bytes::{Buf, BufMut};
+use prost::encoding::{
+    DecodeContext, WireType, bool, btree_map, check_wire_type, decode_key, encode_key,
+    encode_varint, encoded_len_varint, key_len, merge_loop, message, skip_field, string,
+};
+use prost::{DecodeError, Message};
+
+use super::{ExtraValue, TokenReview, TokenReviewSpec, TokenReviewStatus, UserInfo};
+
+/// Encoding for the `ExtraValue` message (`repeated string items = 1`).
+///
+/// `ExtraValue` is an alias for `Vec<String>`, so it cannot implement `Message`
+/// itself; these functions plug into `btree_map` as its value codec.
+mod extra_value {
+    use super::*;
+
+    pub fn encode<B>(tag: u32, value: &ExtraValue, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        encode_key(tag, WireType::LengthDelimited, buf);
+        encode_varint(string::encoded_len_repeated(1, value) as u64, buf);
+        string::encode_repeated(1, value, buf);
+    }
+
+    pub fn merge<B>(
+        wire_type: WireType,
+        value: &mut ExtraValue,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        check_wire_type(WireType::LengthDelimited, wire_type)?;
+        merge_loop(value, buf, ctx, |value, buf, ctx| {
+            let (tag, wire_type) = decode_key(buf)?;
+            match tag {
+                1 => string::merge_repeated(wire_type, value, buf, ctx),
+                _ => skip_field(wire_type, tag, buf, ctx),
+            }
+        })
+    }
+
+    pub fn encoded_len(tag: u32, value: &ExtraValue) -> usize {
+        let len = string::encoded_len_repeated(1, value);
+        key_len(tag) + encoded_len_varint(len as u64) + len
+    }
+}
+
+// ============================================================================
+// TokenReview
+// ============================================================================
+
+impl Message for TokenReview {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref metadata) = self.metadata {
+            message::encode(1, metadata, buf);
+        }
+        message::encode(2, &self.spec, buf);
+        if let Some(ref status) = self.status {
+            message::encode(3, status, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.metadata.get_or_insert_default(), buf, ctx),
+            2 => message::merge(wire_type, &mut self.spec, buf, ctx),
+            3 => message::merge(wire_type, self.status.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.metadata
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + message::encoded_len(2, &self.spec)
+            + self
+                .status
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+    }
+
+    fn clear(&mut self) {
+        *self = TokenReview::default();
+    }
+}
+
+impl Message for TokenReviewSpec {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.token, buf);
+        string::encode_repeated(2, &self.audiences, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.token, buf, ctx),
+            2 => string::merge_repeated(wire_type, &mut self.audiences, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.token) + string::encoded_len_repeated(2, &self.audiences)
+    }
+
+    fn clear(&mut self) {
+        *self = TokenReviewSpec::default();
+    }
+}
+
+impl Message for TokenReviewStatus {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        bool::encode(1, &self.authenticated, buf);
+        if let Some(ref user) = self.user {
+            message::encode(2, user, buf);
+        }
+        string::encode(3, &self.error, buf);
+        string::encode_repeated(4, &self.audiences, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => bool::merge(wire_type, &mut self.authenticated, buf, ctx),
+            2 => message::merge(wire_type, self.user.get_or_insert_default(), buf, ctx),
+            3 => string::merge(wire_type, &mut self.error, buf, ctx),
+            4 => string::merge_repeated(wire_type, &mut self.audiences, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        bool::encoded_len(1, &self.authenticated)
+            + self.user.as_ref().map_or(0, |v| message::encoded_len(2, v))
+            + string::encoded_len(3, &self.error)
+            + string::encoded_len_repeated(4, &self.audiences)
+    }
+
+    fn clear(&mut self) {
+        *self = TokenReviewStatus::default();
+    }
+}
+
+// ============================================================================
+// UserInfo
+// ============================================================================
+
+impl Message for UserInfo {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.username, buf);
+        string::encode(2, &self.uid, buf);
+        string::encode_repeated(3, &self.groups, buf);
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            extra_value::encode,
+            extra_value::encoded_len,
+            4,
+            &self.extra,
+            buf,
+        );
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.username, buf, ctx),
+            2 => string::merge(wire_type, &mut self.uid, buf, ctx),
+            3 => string::merge_repeated(wire_type, &mut self.groups, buf, ctx),
+            4 => btree_map::merge(string::merge, extra_value::merge, &mut self.extra, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.username)
+            + string::encoded_len(2, &self.uid)
+            + string::encoded_len_repeated(3, &self.groups)
+            + btree_map::encoded_len(
+                string::encoded_len,
+                extra_value::encoded_len,
+                4,
+                &self.extra,
+            )
+    }
+
+    fn clear(&mut self) {
+        *self = UserInfo::default();
+    }
+}
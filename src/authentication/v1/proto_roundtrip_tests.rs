@@ -0,0 +1,50 @@
+use super::{TokenReview, TokenReviewSpec, TokenReviewStatus, UserInfo};
+use crate::common::ObjectMeta;
+use prost::Message;
+use std::collections::BTreeMap;
+
+#[test]
+fn proto_roundtrip_authenticated_token_review() {
+    let review = TokenReview {
+        metadata: Some(ObjectMeta {
+            name: Some("review-1".to_string()),
+            ..Default::default()
+        }),
+        spec: TokenReviewSpec {
+            token: "eyJhbGciOiJSUzI1NiJ9.payload.signature".to_string(),
+            audiences: vec!["https://kubernetes.default.svc".to_string()],
+        },
+        status: Some(TokenReviewStatus {
+            authenticated: true,
+            user: Some(UserInfo {
+                username: "system:serviceaccount:default:builder".to_string(),
+                uid: "0b8f3c2e-91d4-4e57-a3c6-2d7f5e1a9b40".to_string(),
+                groups: vec![
+                    "system:serviceaccounts".to_string(),
+                    "system:serviceaccounts:default".to_string(),
+                    "system:authenticated".to_string(),
+                ],
+                extra: BTreeMap::from([
+                    (
+                        "authentication.kubernetes.io/pod-name".to_string(),
+                        vec!["builder-7d9f".to_string()],
+                    ),
+                    (
+                        "scopes".to_string(),
+                        vec!["read".to_string(), "write".to_string()],
+                    ),
+                    ("empty".to_string(), Vec::new()),
+                ]),
+            }),
+            audiences: vec!["https://kubernetes.default.svc".to_string()],
+            error: String::new(),
+        }),
+        ..Default::default()
+    };
+
+    let bytes = review.encode_to_vec();
+    assert_eq!(bytes.len(), review.encoded_len());
+
+    let decoded = TokenReview::decode(bytes.as_slice()).expect("decode TokenReview");
+    assert_eq!(decoded, review);
+}
@@ -7,13 +7,13 @@ mod self_subject_access_review;
 mod subject_access_review;
 
 use crate::authorization::internal::{
-    FieldSelectorAttributes, LabelSelectorAttributes, ResourceAttributes,
+    FieldSelectorAttributes, LabelSelectorAttributes, NonResourceAttributes, ResourceAttributes,
     SelfSubjectAccessReviewSpec, SubjectAccessReviewSpec,
 };
 use crate::common::ObjectMeta;
 use crate::common::meta::{field_selector_operator, label_selector_operator};
 use crate::common::validation::{
-    BadValue, ErrorList, Path, invalid, required, validate_qualified_name,
+    BadValue, ErrorList, Path, forbidden, invalid, required, validate_qualified_name,
 };
 
 pub use local_subject_access_review::validate_local_subject_access_review;
@@ -24,24 +24,11 @@ fn validate_subject_access_review_spec(
     spec: &SubjectAccessReviewSpec,
     fld_path: &Path,
 ) -> ErrorList {
-    let mut all_errs = ErrorList::new();
-    let resource_path = fld_path.child("resourceAttributes");
-    let non_resource_path = fld_path.child("nonResourceAttributes");
-
-    if spec.resource_attributes.is_some() && spec.non_resource_attributes.is_some() {
-        all_errs.push(invalid(
-            &non_resource_path,
-            BadValue::String("set".to_string()),
-            "cannot be specified in combination with resourceAttributes",
-        ));
-    }
-    if spec.resource_attributes.is_none() && spec.non_resource_attributes.is_none() {
-        all_errs.push(invalid(
-            &resource_path,
-            BadValue::String("null".to_string()),
-            "exactly one of nonResourceAttributes or resourceAttributes must be specified",
-        ));
-    }
+    let mut all_errs = validate_attributes(
+        spec.resource_attributes.as_ref(),
+        spec.non_resource_attributes.as_ref(),
+        fld_path,
+    );
     if spec.user.is_empty() && spec.groups.is_empty() {
         all_errs.push(invalid(
             &fld_path.child("user"),
@@ -50,30 +37,38 @@ fn validate_subject_access_review_spec(
         ));
     }
 
-    all_errs.extend(validate_resource_attributes(
-        spec.resource_attributes.as_ref(),
-        &resource_path,
-    ));
-
     all_errs
 }
 
 fn validate_self_subject_access_review_spec(
     spec: &SelfSubjectAccessReviewSpec,
     fld_path: &Path,
+) -> ErrorList {
+    validate_attributes(
+        spec.resource_attributes.as_ref(),
+        spec.non_resource_attributes.as_ref(),
+        fld_path,
+    )
+}
+
+/// Validates that exactly one of resourceAttributes and nonResourceAttributes
+/// is set, and the attributes that are set.
+fn validate_attributes(
+    resource_attributes: Option<&ResourceAttributes>,
+    non_resource_attributes: Option<&NonResourceAttributes>,
+    fld_path: &Path,
 ) -> ErrorList {
     let mut all_errs = ErrorList::new();
     let resource_path = fld_path.child("resourceAttributes");
     let non_resource_path = fld_path.child("nonResourceAttributes");
 
-    if spec.resource_attributes.is_some() && spec.non_resource_attributes.is_some() {
-        all_errs.push(invalid(
+    if resource_attributes.is_some() && non_resource_attributes.is_some() {
+        all_errs.push(forbidden(
             &non_resource_path,
-            BadValue::String("set".to_string()),
             "cannot be specified in combination with resourceAttributes",
         ));
     }
-    if spec.resource_attributes.is_none() && spec.non_resource_attributes.is_none() {
+    if resource_attributes.is_none() && non_resource_attributes.is_none() {
         all_errs.push(invalid(
             &resource_path,
             BadValue::String("null".to_string()),
@@ -82,13 +77,27 @@ fn validate_self_subject_access_review_spec(
     }
 
     all_errs.extend(validate_resource_attributes(
-        spec.resource_attributes.as_ref(),
+        resource_attributes,
         &resource_path,
     ));
+    if let Some(non_resource_attributes) = non_resource_attributes {
+        all_errs.extend(validate_verb(
+            &non_resource_attributes.verb,
+            &non_resource_path.child("verb"),
+        ));
+    }
 
     all_errs
 }
 
+fn validate_verb(verb: &str, fld_path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    if verb.is_empty() {
+        all_errs.push(required(fld_path, "verb is required"));
+    }
+    all_errs
+}
+
 fn validate_metadata_empty(
     metadata: &ObjectMeta,
     allow_namespace: bool,
@@ -124,7 +133,7 @@ fn validate_resource_attributes(
     let Some(resource_attributes) = resource_attributes else {
         return ErrorList::new();
     };
-    let mut all_errs = ErrorList::new();
+    let mut all_errs = validate_verb(&resource_attributes.verb, &fld_path.child("verb"));
 
     all_errs.extend(validate_field_selector_attributes(
         resource_attributes.field_selector.as_ref(),
@@ -306,6 +315,7 @@ mod tests {
         SubjectAccessReviewSpec,
     };
     use crate::common::TypeMeta;
+    use crate::common::validation::ErrorType;
 
     #[test]
     fn test_validate_subject_access_review_spec_requires_attributes() {
@@ -337,6 +347,57 @@ mod tests {
         assert!(!errs.is_empty());
     }
 
+    #[test]
+    fn test_validate_subject_access_review_attributes_are_exclusive() {
+        let spec = SubjectAccessReviewSpec {
+            resource_attributes: Some(ResourceAttributes {
+                verb: "get".to_string(),
+                resource: "pods".to_string(),
+                ..ResourceAttributes::default()
+            }),
+            non_resource_attributes: Some(NonResourceAttributes {
+                path: "/healthz".to_string(),
+                verb: "get".to_string(),
+            }),
+            user: "alice".to_string(),
+            groups: vec![],
+            extra: Default::default(),
+            uid: String::new(),
+        };
+
+        let errs = validate_subject_access_review_spec(&spec, &Path::new("spec"));
+        assert_eq!(errs.len(), 1, "unexpected errors: {:?}", errs);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Forbidden);
+        assert_eq!(errs.errors[0].field, "spec.nonResourceAttributes");
+
+        let non_resource_only = SubjectAccessReviewSpec {
+            resource_attributes: None,
+            ..spec
+        };
+        let errs = validate_subject_access_review_spec(&non_resource_only, &Path::new("spec"));
+        assert!(errs.is_empty(), "unexpected errors: {:?}", errs);
+    }
+
+    #[test]
+    fn test_validate_subject_access_review_requires_verb() {
+        let spec = SubjectAccessReviewSpec {
+            resource_attributes: Some(ResourceAttributes {
+                resource: "pods".to_string(),
+                ..ResourceAttributes::default()
+            }),
+            non_resource_attributes: None,
+            user: String::new(),
+            groups: vec!["system:authenticated".to_string()],
+            extra: Default::default(),
+            uid: String::new(),
+        };
+
+        let errs = validate_subject_access_review_spec(&spec, &Path::new("spec"));
+        assert_eq!(errs.len(), 1, "unexpected errors: {:?}", errs);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Required);
+        assert_eq!(errs.errors[0].field, "spec.resourceAttributes.verb");
+    }
+
     #[test]
     fn test_validate_self_subject_access_review_metadata_must_be_empty() {
         let obj = SelfSubjectAccessReview {
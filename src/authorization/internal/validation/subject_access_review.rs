@@ -3,12 +3,13 @@ use crate::common::validation::{ErrorList, Path};
 
 use super::{validate_metadata_empty, validate_subject_access_review_spec};
 
-pub fn validate_subject_access_review(obj: &SubjectAccessReview) -> ErrorList {
-    let mut all_errs = validate_subject_access_review_spec(&obj.spec, &Path::new("spec"));
+/// Validates a SubjectAccessReview whose fields live under `fld_path`.
+pub fn validate_subject_access_review(obj: &SubjectAccessReview, fld_path: &Path) -> ErrorList {
+    let mut all_errs = validate_subject_access_review_spec(&obj.spec, &fld_path.child("spec"));
     all_errs.extend(validate_metadata_empty(
         &obj.metadata,
         false,
-        &Path::new("metadata"),
+        &fld_path.child("metadata"),
     ));
     all_errs
 }
@@ -0,0 +1,119 @@
+use super::{
+    FieldSelectorAttributes, LabelSelectorAttributes, LocalSubjectAccessReview, ResourceAttributes,
+    SelfSubjectAccessReview, SelfSubjectAccessReviewSpec, SubjectAccessReview,
+    SubjectAccessReviewSpec, SubjectAccessReviewStatus,
+};
+use crate::authorization::internal;
+use crate::common::test_utils::{assert_conversion_roundtrip, assert_serde_roundtrip};
+use crate::common::{
+    FieldSelectorRequirement, LabelSelectorRequirement, ObjectMeta, ToInternal, TypeMeta,
+};
+use serde_json::json;
+
+fn pods_on_node_attributes() -> ResourceAttributes {
+    ResourceAttributes {
+        namespace: "default".to_string(),
+        verb: "list".to_string(),
+        version: "v1".to_string(),
+        resource: "pods".to_string(),
+        field_selector: Some(FieldSelectorAttributes {
+            raw_selector: String::new(),
+            requirements: vec![FieldSelectorRequirement {
+                key: "spec.nodeName".to_string(),
+                operator: "In".to_string(),
+                values: vec!["node-a".to_string(), "node-b".to_string()],
+            }],
+        }),
+        label_selector: Some(LabelSelectorAttributes {
+            raw_selector: String::new(),
+            requirements: vec![LabelSelectorRequirement {
+                key: "app".to_string(),
+                operator: "NotIn".to_string(),
+                values: vec!["debug".to_string()],
+            }],
+        }),
+        ..Default::default()
+    }
+}
+
+fn subject_access_review_field_selector() -> SubjectAccessReview {
+    SubjectAccessReview {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta::default()),
+        spec: SubjectAccessReviewSpec {
+            resource_attributes: Some(pods_on_node_attributes()),
+            user: "system:node:node-a".to_string(),
+            groups: vec!["system:nodes".to_string()],
+            ..Default::default()
+        },
+        status: Some(SubjectAccessReviewStatus {
+            allowed: true,
+            ..Default::default()
+        }),
+    }
+}
+
+#[test]
+fn conversion_roundtrip_subject_access_review_field_selector() {
+    let review = subject_access_review_field_selector();
+    let internal: internal::SubjectAccessReview = review.clone().to_internal();
+    let attributes = internal
+        .spec
+        .resource_attributes
+        .expect("resource attributes");
+    let field_selector = attributes.field_selector.expect("field selector");
+    assert_eq!(field_selector.requirements[0].operator, "In");
+    assert_eq!(
+        field_selector.requirements[0].values,
+        vec!["node-a".to_string(), "node-b".to_string()]
+    );
+    let label_selector = attributes.label_selector.expect("label selector");
+    assert_eq!(label_selector.requirements[0].operator, "NotIn");
+
+    assert_conversion_roundtrip::<SubjectAccessReview, internal::SubjectAccessReview>(review);
+}
+
+#[test]
+fn conversion_roundtrip_local_and_self_subject_access_review_selectors() {
+    let local = LocalSubjectAccessReview {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta {
+            namespace: Some("default".to_string()),
+            ..Default::default()
+        }),
+        spec: subject_access_review_field_selector().spec,
+        status: Some(SubjectAccessReviewStatus::default()),
+    };
+    assert_conversion_roundtrip::<LocalSubjectAccessReview, internal::LocalSubjectAccessReview>(
+        local,
+    );
+
+    let self_review = SelfSubjectAccessReview {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta::default()),
+        spec: SelfSubjectAccessReviewSpec {
+            resource_attributes: Some(pods_on_node_attributes()),
+            non_resource_attributes: None,
+        },
+        status: Some(SubjectAccessReviewStatus::default()),
+    };
+    assert_conversion_roundtrip::<SelfSubjectAccessReview, internal::SelfSubjectAccessReview>(
+        self_review,
+    );
+}
+
+#[test]
+fn serde_subject_access_review_field_selector() {
+    let review = subject_access_review_field_selector();
+    assert_serde_roundtrip(&review);
+
+    let value = serde_json::to_value(&review).unwrap();
+    assert_eq!(
+        value["spec"]["resourceAttributes"]["fieldSelector"],
+        json!({
+            "requirements": [
+                {"key": "spec.nodeName", "operator": "In", "values": ["node-a", "node-b"]}
+            ]
+        })
+    );
+}
@@ -1133,3 +1133,6 @@ impl_unimplemented_prost_message!(SelfSubjectRulesReview);
 
 #[cfg(test)]
 mod trait_tests;
+
+#[cfg(test)]
+mod conversion_roundtrip_tests;
@@ -1,8 +1,99 @@
 use crate::authorization::internal::validation as internal_validation;
 use crate::authorization::v1::SubjectAccessReview;
 use crate::common::ToInternal;
-use crate::common::validation::ErrorList;
+use crate::common::validation::{ErrorList, Path};
 
-pub fn validate_subject_access_review(obj: &SubjectAccessReview) -> ErrorList {
-    internal_validation::validate_subject_access_review(&obj.clone().to_internal())
+/// Validates a SubjectAccessReview whose fields live under `path`.
+pub fn validate_subject_access_review(sar: &SubjectAccessReview, path: &Path) -> ErrorList {
+    internal_validation::validate_subject_access_review(&sar.clone().to_internal(), path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorization::v1::{
+        NonResourceAttributes, ResourceAttributes, SubjectAccessReviewSpec,
+    };
+    use crate::common::validation::ErrorType;
+
+    fn review(
+        resource_attributes: Option<ResourceAttributes>,
+        non_resource_attributes: Option<NonResourceAttributes>,
+    ) -> SubjectAccessReview {
+        SubjectAccessReview::new(SubjectAccessReviewSpec {
+            resource_attributes,
+            non_resource_attributes,
+            user: "alice".to_string(),
+            ..Default::default()
+        })
+    }
+
+    fn pods() -> ResourceAttributes {
+        ResourceAttributes {
+            verb: "get".to_string(),
+            resource: "pods".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn healthz() -> NonResourceAttributes {
+        NonResourceAttributes {
+            path: "/healthz".to_string(),
+            verb: "get".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_subject_access_review_attributes_are_exclusive() {
+        let errs = validate_subject_access_review(
+            &review(Some(pods()), Some(healthz())),
+            &Path::new("review"),
+        );
+        let errors: Vec<_> = errs
+            .errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.error_type))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![("review.spec.nonResourceAttributes", ErrorType::Forbidden)]
+        );
+
+        assert!(
+            validate_subject_access_review(&review(Some(pods()), None), &Path::new("review"))
+                .is_empty()
+        );
+        assert!(
+            validate_subject_access_review(&review(None, Some(healthz())), &Path::new("review"))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_validate_subject_access_review_requires_one_attribute_kind() {
+        let errs = validate_subject_access_review(&review(None, None), &Path::new("review"));
+        let errors: Vec<_> = errs
+            .errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.error_type))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![("review.spec.resourceAttributes", ErrorType::Invalid)]
+        );
+    }
+
+    #[test]
+    fn test_validate_subject_access_review_under_path() {
+        let mut sar = review(Some(pods()), None);
+        sar.spec.user.clear();
+        sar.spec.resource_attributes.as_mut().unwrap().verb.clear();
+
+        let errs = validate_subject_access_review(&sar, &Path::new("review"));
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec!["review.spec.resourceAttributes.verb", "review.spec.user"]
+        );
+    }
 }
@@ -6,12 +6,21 @@ use crate::autoscaling::internal;
 use crate::common::ObjectMeta;
 use crate::common::validation::{
     BadValue, ErrorList, Path, forbidden, invalid, is_dns1123_label, name_is_dns_subdomain,
-    required, validate_object_meta, validate_object_meta_update, validate_qualified_name,
+    not_supported, required, validate_object_meta, validate_object_meta_update,
+    validate_qualified_name,
 };
 
 const MAX_PERIOD_SECONDS: i32 = 1800;
 const MAX_STABILIZATION_WINDOW_SECONDS: i32 = 3600;
 
+/// Built-in kinds that implement the scale subresource.
+const SCALABLE_KINDS: &[&str] = &[
+    "Deployment",
+    "StatefulSet",
+    "ReplicaSet",
+    "ReplicationController",
+];
+
 #[derive(Clone, Debug, Default)]
 pub struct CrossVersionObjectReferenceValidationOptions {
     pub allow_empty_api_group: bool,
@@ -21,6 +30,10 @@ pub struct CrossVersionObjectReferenceValidationOptions {
 #[derive(Clone, Debug)]
 pub struct HorizontalPodAutoscalerSpecValidationOptions {
     pub min_replicas_lower_bound: i32,
+    /// Reject scale targets outside the built-in scalable kinds. Off by
+    /// default, since custom resources with a scale subresource are valid
+    /// targets.
+    pub restrict_scale_target_kind: bool,
     pub scale_target_ref_validation_options: CrossVersionObjectReferenceValidationOptions,
     pub object_metrics_validation_options: CrossVersionObjectReferenceValidationOptions,
 }
@@ -29,6 +42,7 @@ impl Default for HorizontalPodAutoscalerSpecValidationOptions {
     fn default() -> Self {
         Self {
             min_replicas_lower_bound: 1,
+            restrict_scale_target_kind: false,
             scale_target_ref_validation_options:
                 CrossVersionObjectReferenceValidationOptions::default(),
             object_metrics_validation_options:
@@ -187,6 +201,14 @@ fn validate_horizontal_pod_autoscaler_spec(
         &fld_path.child("scaleTargetRef"),
         &opts.scale_target_ref_validation_options,
     ));
+    let kind = autoscaler.scale_target_ref.kind.as_str();
+    if opts.restrict_scale_target_kind && !kind.is_empty() && !SCALABLE_KINDS.contains(&kind) {
+        all_errs.push(not_supported(
+            &fld_path.child("scaleTargetRef").child("kind"),
+            BadValue::String(kind.to_string()),
+            SCALABLE_KINDS,
+        ));
+    }
 
     all_errs.extend(validate_metrics(
         &autoscaler.metrics,
@@ -543,13 +565,6 @@ fn validate_external_source(src: &internal::ExternalMetricSource, fld_path: &Pat
         ));
     }
 
-    if src.target.value.is_some() && src.target.average_value.is_some() {
-        all_errs.push(forbidden(
-            &fld_path.child("target").child("value"),
-            "may not set both a target value for metric and a per-pod target",
-        ));
-    }
-
     all_errs
 }
 
@@ -615,13 +630,6 @@ fn validate_container_resource_source(
         ));
     }
 
-    if src.target.average_utilization.is_some() && src.target.average_value.is_some() {
-        all_errs.push(forbidden(
-            &fld_path.child("target").child("averageValue"),
-            "may not set both a target raw value and a target utilization",
-        ));
-    }
-
     all_errs
 }
 
@@ -647,19 +655,44 @@ fn validate_resource_source(src: &internal::ResourceMetricSource, fld_path: &Pat
         ));
     }
 
-    if src.target.average_utilization.is_some() && src.target.average_value.is_some() {
-        all_errs.push(forbidden(
-            &fld_path.child("target").child("averageValue"),
-            "may not set both a target raw value and a target utilization",
-        ));
-    }
-
     all_errs
 }
 
 fn validate_metric_target(mt: &internal::MetricTarget, fld_path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
+    // Exactly the field named by `type` may be set. A target with no value at
+    // all is reported by the source-specific checks.
+    let expected_field = match mt.type_ {
+        internal::MetricTargetType::Utilization => "averageUtilization",
+        internal::MetricTargetType::Value => "value",
+        internal::MetricTargetType::AverageValue => "averageValue",
+    };
+    let mut fields_present = Vec::new();
+    if mt.value.is_some() {
+        fields_present.push("value");
+    }
+    if mt.average_value.is_some() {
+        fields_present.push("averageValue");
+    }
+    if mt.average_utilization.is_some() {
+        fields_present.push("averageUtilization");
+    }
+    for name in fields_present.iter() {
+        if *name != expected_field {
+            all_errs.push(forbidden(
+                &fld_path.child(name),
+                &format!("must not be set when type is {}", mt.type_.as_ref()),
+            ));
+        }
+    }
+    if !fields_present.is_empty() && !fields_present.contains(&expected_field) {
+        all_errs.push(required(
+            &fld_path.child(expected_field),
+            &format!("must be set when type is {}", mt.type_.as_ref()),
+        ));
+    }
+
     if let Some(ref value) = mt.value {
         if let Some(err) = validate_quantity_positive(value, &fld_path.child("value")) {
             all_errs.push(err);
@@ -825,6 +858,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_metric_spec_source_count() {
+        let opts = CrossVersionObjectReferenceValidationOptions::default();
+        let path = Path::new("metrics").index(0);
+        let mut spec = valid_hpa_spec().metrics.remove(0);
+
+        // A second source alongside the one named by `type` is forbidden.
+        spec.pods = Some(internal::PodsMetricSource::default());
+        let errs = validate_metric_spec(&spec, &path, &opts);
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.field.as_str()).collect();
+        assert!(fields.contains(&"metrics[0].pods"), "got: {errs:?}");
+        assert!(
+            errs.errors.iter().any(|e| e
+                .detail
+                .contains("must populate the given metric source only")),
+            "got: {errs:?}"
+        );
+
+        // A single source that does not match `type` is reported as missing.
+        spec.resource = None;
+        let errs = validate_metric_spec(&spec, &path, &opts);
+        assert!(
+            errs.errors.iter().any(|e| e.field == "metrics[0].resource"
+                && e.detail == "must populate information for the given metric source"),
+            "got: {errs:?}"
+        );
+        assert!(
+            !errs.errors.iter().any(|e| e.field == "metrics[0].pods"),
+            "got: {errs:?}"
+        );
+    }
+
     #[test]
     fn test_validate_scale_negative_replicas() {
         let scale = internal::Scale {
@@ -6,6 +6,7 @@
 
 pub mod conversion;
 pub mod defaults;
+pub mod validation;
 
 use crate::common::{
     ApplyDefault, HasTypeMeta, LabelSelector, ListMeta, ObjectMeta, Quantity, ResourceSchema,
@@ -54,7 +55,7 @@ pub struct HorizontalPodAutoscalerList {
     pub metadata: Option<ListMeta>,
 
     /// items is the list of horizontal pod autoscaler objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<HorizontalPodAutoscaler>,
 }
 
@@ -0,0 +1,227 @@
+//! Validation for Kubernetes Autoscaling v2 API types
+//!
+//! Wrapper around internal validation (v2 -> internal -> validate)
+
+use crate::autoscaling::internal::validation as internal_validation;
+use crate::common::ToInternal;
+use crate::common::validation::ErrorList;
+
+use super::HorizontalPodAutoscaler;
+
+pub use crate::autoscaling::validation::{
+    CrossVersionObjectReferenceValidationOptions, HorizontalPodAutoscalerSpecValidationOptions,
+};
+
+// =============================================================================
+// HorizontalPodAutoscaler Validation
+// =============================================================================
+
+pub fn validate_horizontal_pod_autoscaler(
+    autoscaler: &HorizontalPodAutoscaler,
+    opts: &HorizontalPodAutoscalerSpecValidationOptions,
+) -> ErrorList {
+    internal_validation::validate_horizontal_pod_autoscaler(&autoscaler.clone().to_internal(), opts)
+}
+
+pub fn validate_horizontal_pod_autoscaler_update(
+    new_autoscaler: &HorizontalPodAutoscaler,
+    old_autoscaler: &HorizontalPodAutoscaler,
+    opts: &HorizontalPodAutoscalerSpecValidationOptions,
+) -> ErrorList {
+    internal_validation::validate_horizontal_pod_autoscaler_update(
+        &new_autoscaler.clone().to_internal(),
+        &old_autoscaler.clone().to_internal(),
+        opts,
+    )
+}
+
+pub fn validate_horizontal_pod_autoscaler_status_update(
+    new_autoscaler: &HorizontalPodAutoscaler,
+    old_autoscaler: &HorizontalPodAutoscaler,
+) -> ErrorList {
+    internal_validation::validate_horizontal_pod_autoscaler_status_update(
+        &new_autoscaler.clone().to_internal(),
+        &old_autoscaler.clone().to_internal(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::autoscaling::v2::{
+        CrossVersionObjectReference, HPAScalingPolicy, HPAScalingPolicyType, HPAScalingRules,
+        HorizontalPodAutoscalerBehavior, HorizontalPodAutoscalerSpec, MetricIdentifier,
+        MetricSourceType, MetricSpec, MetricTarget, MetricTargetType, PodsMetricSource,
+        ResourceMetricSource,
+    };
+    use crate::common::validation::ErrorType;
+    use crate::common::{ObjectMeta, Quantity};
+
+    fn cpu_metric() -> MetricSpec {
+        MetricSpec {
+            type_: MetricSourceType::Resource,
+            resource: Some(ResourceMetricSource {
+                name: "cpu".to_string(),
+                target: MetricTarget {
+                    type_: MetricTargetType::Utilization,
+                    average_utilization: Some(80),
+                    ..Default::default()
+                },
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn hpa(metrics: Vec<MetricSpec>) -> HorizontalPodAutoscaler {
+        HorizontalPodAutoscaler {
+            metadata: Some(ObjectMeta {
+                name: Some("web".to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            }),
+            spec: Some(HorizontalPodAutoscalerSpec {
+                scale_target_ref: CrossVersionObjectReference {
+                    kind: "Deployment".to_string(),
+                    name: "web".to_string(),
+                    api_version: Some("apps/v1".to_string()),
+                },
+                min_replicas: Some(1),
+                max_replicas: 5,
+                metrics,
+                behavior: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn validate(autoscaler: &HorizontalPodAutoscaler) -> ErrorList {
+        validate_horizontal_pod_autoscaler(
+            autoscaler,
+            &HorizontalPodAutoscalerSpecValidationOptions::default(),
+        )
+    }
+
+    #[test]
+    fn test_validate_hpa_v2_valid() {
+        let errs = validate(&hpa(vec![cpu_metric()]));
+        assert!(errs.is_empty(), "expected no errors, got: {errs:?}");
+    }
+
+    #[test]
+    fn test_validate_hpa_v2_metric_with_two_sources() {
+        let mut metric = cpu_metric();
+        metric.pods = Some(PodsMetricSource {
+            metric: MetricIdentifier {
+                name: "requests_per_second".to_string(),
+                selector: None,
+            },
+            target: MetricTarget {
+                type_: MetricTargetType::AverageValue,
+                average_value: Some(Quantity::from("10")),
+                ..Default::default()
+            },
+        });
+
+        let errs = validate(&hpa(vec![metric]));
+        assert!(
+            errs.errors
+                .iter()
+                .any(|err| err.error_type == ErrorType::Forbidden
+                    && err.field == "spec.metrics[0].pods"),
+            "expected forbidden pods source, got: {errs:?}"
+        );
+    }
+
+    #[test]
+    fn test_validate_hpa_v2_target_field_must_match_type() {
+        let mut metric = cpu_metric();
+        let target = &mut metric.resource.as_mut().unwrap().target;
+        target.average_utilization = None;
+        target.average_value = Some(Quantity::from("500m"));
+
+        let errs = validate(&hpa(vec![metric]));
+        let path = "spec.metrics[0].resource.target";
+        assert!(
+            errs.errors
+                .iter()
+                .any(|err| err.error_type == ErrorType::Forbidden
+                    && err.field == format!("{path}.averageValue")),
+            "expected forbidden averageValue, got: {errs:?}"
+        );
+        assert!(
+            errs.errors
+                .iter()
+                .any(|err| err.error_type == ErrorType::Required
+                    && err.field == format!("{path}.averageUtilization")),
+            "expected required averageUtilization, got: {errs:?}"
+        );
+    }
+
+    #[test]
+    fn test_validate_hpa_v2_stabilization_window_out_of_range() {
+        let mut autoscaler = hpa(vec![cpu_metric()]);
+        autoscaler.spec.as_mut().unwrap().behavior = Some(HorizontalPodAutoscalerBehavior {
+            scale_up: None,
+            scale_down: Some(HPAScalingRules {
+                stabilization_window_seconds: Some(3601),
+                policies: vec![HPAScalingPolicy {
+                    type_: HPAScalingPolicyType::Pods,
+                    value: 1,
+                    period_seconds: 60,
+                }],
+                ..Default::default()
+            }),
+        });
+
+        let errs = validate(&autoscaler);
+        assert!(
+            errs.errors
+                .iter()
+                .any(|err| err.error_type == ErrorType::Invalid
+                    && err.field == "spec.behavior.scaleDown.stabilizationWindowSeconds"),
+            "expected invalid stabilization window, got: {errs:?}"
+        );
+    }
+
+    #[test]
+    fn test_validate_hpa_v2_empty_scale_target() {
+        let mut autoscaler = hpa(vec![cpu_metric()]);
+        autoscaler.spec.as_mut().unwrap().scale_target_ref = CrossVersionObjectReference {
+            kind: "Deployment".to_string(),
+            name: String::new(),
+            api_version: None,
+        };
+
+        let errs = validate(&autoscaler);
+        let got: Vec<_> = errs
+            .errors
+            .iter()
+            .map(|err| (err.field.as_str(), err.error_type))
+            .collect();
+        assert_eq!(
+            got,
+            vec![
+                ("spec.scaleTargetRef.name", ErrorType::Required),
+                ("spec.scaleTargetRef.apiVersion", ErrorType::Invalid),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_hpa_v2_scale_target_kind() {
+        let mut autoscaler = hpa(vec![cpu_metric()]);
+        autoscaler.spec.as_mut().unwrap().scale_target_ref.kind = "DaemonSet".to_string();
+
+        // Any kind with a scale subresource is accepted by default.
+        assert!(validate(&autoscaler).is_empty());
+
+        let opts = HorizontalPodAutoscalerSpecValidationOptions {
+            restrict_scale_target_kind: true,
+            ..Default::default()
+        };
+        let errs = validate_horizontal_pod_autoscaler(&autoscaler, &opts);
+        assert_eq!(errs.len(), 1, "unexpected errors: {errs:?}");
+        assert_eq!(errs.errors[0].error_type, ErrorType::NotSupported);
+        assert_eq!(errs.errors[0].field, "spec.scaleTargetRef.kind");
+    }
+}
@@ -110,7 +110,7 @@ pub struct HorizontalPodAutoscalerList {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<ListMeta>,
     /// items is the list of horizontal pod autoscaler objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<HorizontalPodAutoscaler>,
 }
 
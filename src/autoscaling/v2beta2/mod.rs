@@ -54,7 +54,7 @@ pub struct HorizontalPodAutoscalerList {
     pub metadata: Option<ListMeta>,
 
     /// items is the list of horizontal pod autoscaler objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<HorizontalPodAutoscaler>,
 }
 
@@ -0,0 +1,384 @@
+//! Standard cron schedule parsing
+//!
+//! Ported from github.com/robfig/cron/v3 (parser.go), which the CronJob
+//! controller uses via `cron.ParseStandard`. Error messages match upstream.
+
+use chrono_tz::Tz;
+
+/// A parsed standard cron schedule.
+///
+/// Each field is a bitset where bit `n` is set when value `n` matches.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Schedule {
+    /// A five-field (or `@descriptor`) schedule.
+    Spec {
+        minute: u64,
+        hour: u64,
+        dom: u64,
+        month: u64,
+        dow: u64,
+        location: Option<Tz>,
+    },
+    /// An `@every <duration>` schedule, in seconds.
+    Every { seconds: u64, location: Option<Tz> },
+}
+
+struct Bounds {
+    min: u32,
+    max: u32,
+    names: &'static [(&'static str, u32)],
+}
+
+const MINUTES: Bounds = Bounds {
+    min: 0,
+    max: 59,
+    names: &[],
+};
+const HOURS: Bounds = Bounds {
+    min: 0,
+    max: 23,
+    names: &[],
+};
+const DOM: Bounds = Bounds {
+    min: 1,
+    max: 31,
+    names: &[],
+};
+const MONTHS: Bounds = Bounds {
+    min: 1,
+    max: 12,
+    names: &[
+        ("jan", 1),
+        ("feb", 2),
+        ("mar", 3),
+        ("apr", 4),
+        ("may", 5),
+        ("jun", 6),
+        ("jul", 7),
+        ("aug", 8),
+        ("sep", 9),
+        ("oct", 10),
+        ("nov", 11),
+        ("dec", 12),
+    ],
+};
+const DOW: Bounds = Bounds {
+    min: 0,
+    max: 6,
+    names: &[
+        ("sun", 0),
+        ("mon", 1),
+        ("tue", 2),
+        ("wed", 3),
+        ("thu", 4),
+        ("fri", 5),
+        ("sat", 6),
+    ],
+};
+
+/// Set when a field was `*` or `?`, mirroring upstream's `starBit`.
+const STAR_BIT: u64 = 1 << 63;
+
+/// Loads an IANA time zone by name, like Go's `time.LoadLocation`.
+///
+/// `Local` is not accepted since it depends on the host configuration.
+pub fn load_location(name: &str) -> Result<Tz, String> {
+    if name.is_empty() {
+        return Ok(Tz::UTC);
+    }
+    name.parse::<Tz>()
+        .map_err(|_| format!("unknown time zone {}", name))
+}
+
+/// Parses a standard cron spec: five fields (minute, hour, day of month,
+/// month, day of week) or one of the `@` descriptors, optionally prefixed
+/// with `TZ=<zone>` or `CRON_TZ=<zone>`.
+pub fn parse_standard(spec: &str) -> Result<Schedule, String> {
+    if spec.is_empty() {
+        return Err("empty spec string".to_string());
+    }
+
+    let mut spec = spec;
+    let mut location = None;
+    if spec.starts_with("TZ=") || spec.starts_with("CRON_TZ=") {
+        let i = spec.find(' ').unwrap_or(spec.len());
+        let eq = spec.find('=').unwrap_or_default();
+        let name = &spec[eq + 1..i];
+        location = Some(
+            load_location(name)
+                .map_err(|err| format!("provided bad location {}: {}", name, err))?,
+        );
+        spec = spec[i..].trim();
+    }
+
+    if spec.starts_with('@') {
+        return parse_descriptor(spec, location);
+    }
+
+    let fields: Vec<&str> = spec.split_whitespace().collect();
+    if fields.len() != 5 {
+        return Err(format!(
+            "expected exactly 5 fields, found {}: [{}]",
+            fields.len(),
+            fields.join(" ")
+        ));
+    }
+
+    Ok(Schedule::Spec {
+        minute: get_field(fields[0], &MINUTES)?,
+        hour: get_field(fields[1], &HOURS)?,
+        dom: get_field(fields[2], &DOM)?,
+        month: get_field(fields[3], &MONTHS)?,
+        dow: get_field(fields[4], &DOW)?,
+        location,
+    })
+}
+
+fn parse_descriptor(descriptor: &str, location: Option<Tz>) -> Result<Schedule, String> {
+    let spec = |minute, hour, dom, month, dow| Schedule::Spec {
+        minute,
+        hour,
+        dom,
+        month,
+        dow,
+        location,
+    };
+    match descriptor {
+        "@yearly" | "@annually" => Ok(spec(
+            1 << MINUTES.min,
+            1 << HOURS.min,
+            1 << DOM.min,
+            1 << MONTHS.min,
+            all(&DOW),
+        )),
+        "@monthly" => Ok(spec(
+            1 << MINUTES.min,
+            1 << HOURS.min,
+            1 << DOM.min,
+            all(&MONTHS),
+            all(&DOW),
+        )),
+        "@weekly" => Ok(spec(
+            1 << MINUTES.min,
+            1 << HOURS.min,
+            all(&DOM),
+            all(&MONTHS),
+            1 << DOW.min,
+        )),
+        "@daily" | "@midnight" => Ok(spec(
+            1 << MINUTES.min,
+            1 << HOURS.min,
+            all(&DOM),
+            all(&MONTHS),
+            all(&DOW),
+        )),
+        "@hourly" => Ok(spec(
+            1 << MINUTES.min,
+            all(&HOURS),
+            all(&DOM),
+            all(&MONTHS),
+            all(&DOW),
+        )),
+        _ => match descriptor.strip_prefix("@every ") {
+            Some(duration) => {
+                let seconds = parse_duration(duration).ok_or_else(|| {
+                    format!(
+                        "failed to parse duration {}: time: invalid duration \"{}\"",
+                        descriptor, duration
+                    )
+                })?;
+                Ok(Schedule::Every { seconds, location })
+            }
+            None => Err(format!("unrecognized descriptor: {}", descriptor)),
+        },
+    }
+}
+
+/// Parses a Go-style duration such as `1h30m` or `90s`, truncated to seconds.
+fn parse_duration(s: &str) -> Option<u64> {
+    if s == "0" {
+        return Some(0);
+    }
+    let mut rest = s;
+    let mut nanos: f64 = 0.0;
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let value: f64 = rest[..number_len].parse().ok()?;
+        rest = &rest[number_len..];
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        let scale = match &rest[..unit_len] {
+            "ns" => 1.0,
+            "us" | "µs" | "μs" => 1e3,
+            "ms" => 1e6,
+            "s" => 1e9,
+            "m" => 60e9,
+            "h" => 3600e9,
+            _ => return None,
+        };
+        rest = &rest[unit_len..];
+        nanos += value * scale;
+    }
+    Some((nanos / 1e9) as u64)
+}
+
+/// Returns the bitset for a comma-separated list of ranges.
+fn get_field(field: &str, bounds: &Bounds) -> Result<u64, String> {
+    let mut bits = 0;
+    for expr in field.split(',') {
+        bits |= get_range(expr, bounds)?;
+    }
+    Ok(bits)
+}
+
+/// Parses `number | number "-" number [ "/" number ]` or `*`/`?` with an
+/// optional step.
+fn get_range(expr: &str, bounds: &Bounds) -> Result<u64, String> {
+    let range_and_step: Vec<&str> = expr.split('/').collect();
+    let low_and_high: Vec<&str> = range_and_step[0].split('-').collect();
+    let single_digit = low_and_high.len() == 1;
+
+    let (start, mut end, mut extra) = if low_and_high[0] == "*" || low_and_high[0] == "?" {
+        (bounds.min, bounds.max, STAR_BIT)
+    } else {
+        let start = parse_int_or_name(low_and_high[0], bounds.names)?;
+        let end = match low_and_high.len() {
+            1 => start,
+            2 => parse_int_or_name(low_and_high[1], bounds.names)?,
+            _ => return Err(format!("too many hyphens: {}", expr)),
+        };
+        (start, end, 0)
+    };
+
+    let step = match range_and_step.len() {
+        1 => 1,
+        2 => {
+            let step = must_parse_int(range_and_step[1])?;
+            // Special handling: "N/step" means "N-max/step".
+            if single_digit {
+                end = bounds.max;
+            }
+            if step > 1 {
+                extra = 0;
+            }
+            step
+        }
+        _ => return Err(format!("too many slashes: {}", expr)),
+    };
+
+    if start < bounds.min {
+        return Err(format!(
+            "beginning of range ({}) below minimum ({}): {}",
+            start, bounds.min, expr
+        ));
+    }
+    if end > bounds.max {
+        return Err(format!(
+            "end of range ({}) above maximum ({}): {}",
+            end, bounds.max, expr
+        ));
+    }
+    if start > end {
+        return Err(format!(
+            "beginning of range ({}) beyond end of range ({}): {}",
+            start, end, expr
+        ));
+    }
+    if step == 0 {
+        return Err(format!(
+            "step of range should be a positive number: {}",
+            expr
+        ));
+    }
+
+    Ok(get_bits(start, end, step) | extra)
+}
+
+fn parse_int_or_name(expr: &str, names: &[(&str, u32)]) -> Result<u32, String> {
+    let lower = expr.to_ascii_lowercase();
+    match names.iter().find(|(name, _)| *name == lower) {
+        Some((_, value)) => Ok(*value),
+        None => must_parse_int(expr),
+    }
+}
+
+fn must_parse_int(expr: &str) -> Result<u32, String> {
+    let num: i64 = expr
+        .parse()
+        .map_err(|err| format!("failed to parse int from {}: {}", expr, err))?;
+    if num < 0 {
+        return Err(format!("negative number ({}) not allowed: {}", num, expr));
+    }
+    u32::try_from(num).map_err(|err| format!("failed to parse int from {}: {}", expr, err))
+}
+
+fn get_bits(min: u32, max: u32, step: u32) -> u64 {
+    if step == 1 {
+        return !(u64::MAX << (max + 1)) & (u64::MAX << min);
+    }
+    (min..=max)
+        .step_by(step as usize)
+        .fold(0, |bits, i| bits | (1 << i))
+}
+
+fn all(bounds: &Bounds) -> u64 {
+    get_bits(bounds.min, bounds.max, 1) | STAR_BIT
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_standard_valid() {
+        for spec in [
+            "*/5 * * * *",
+            "0 0 1 jan-jun mon-fri",
+            "30 3 ? * SUN",
+            "0 9-17/2 * * 1,3,5",
+            "@hourly",
+            "@every 1h30m",
+            "CRON_TZ=Europe/Berlin 0 6 * * *",
+        ] {
+            assert!(parse_standard(spec).is_ok(), "expected {spec:?} to parse");
+        }
+
+        assert_eq!(
+            parse_standard("0 12 * * *").unwrap(),
+            Schedule::Spec {
+                minute: 1,
+                hour: 1 << 12,
+                dom: all(&DOM),
+                month: all(&MONTHS),
+                dow: all(&DOW),
+                location: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_standard_errors() {
+        let cases = [
+            ("", "empty spec string"),
+            ("* * * *", "expected exactly 5 fields, found 4: [* * * *]"),
+            ("@fortnightly", "unrecognized descriptor: @fortnightly"),
+            ("60 * * * *", "end of range (60) above maximum (59): 60"),
+            ("* * 0 * *", "beginning of range (0) below minimum (1): 0"),
+            (
+                "5-1 * * * *",
+                "beginning of range (5) beyond end of range (1): 5-1",
+            ),
+            (
+                "*/0 * * * *",
+                "step of range should be a positive number: */0",
+            ),
+            ("1-2-3 * * * *", "too many hyphens: 1-2-3"),
+        ];
+        for (spec, expected) in cases {
+            assert_eq!(parse_standard(spec).unwrap_err(), expected, "spec {spec:?}");
+        }
+    }
+}
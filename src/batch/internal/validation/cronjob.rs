@@ -1,12 +1,21 @@
 //! CronJob validation for Kubernetes batch internal API
 
 use crate::batch::internal::{CronJob, CronJobList, CronJobSpec};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
 use crate::common::validation::{
     BadValue, ErrorList, Path, invalid, name_is_dns_subdomain, required, validate_object_meta,
 };
 
+use super::cron::{load_location, parse_standard};
 use super::job::validate_job_spec;
 
+/// Characters allowed in each `/`-separated part of a time zone name.
+static VALID_TIME_ZONE_CHARACTERS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Za-z\.\-_0-9+]{1,14}$").unwrap());
+
 // =============================================================================
 // CronJob Validation
 // =============================================================================
@@ -36,7 +45,11 @@ fn validate_cron_job_with_path(cron_job: &CronJob, base_path: &Path) -> ErrorLis
     all_errs
 }
 
-fn validate_cron_job_spec(spec: &CronJobSpec, base_path: &Path) -> ErrorList {
+/// Validates a CronJobSpec.
+///
+/// `concurrencyPolicy` needs no check here: it is an enum, so only `Allow`,
+/// `Forbid` and `Replace` can be represented.
+pub fn validate_cron_job_spec(spec: &CronJobSpec, base_path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
     // Schedule is required and must not be empty
@@ -45,8 +58,18 @@ fn validate_cron_job_spec(spec: &CronJobSpec, base_path: &Path) -> ErrorList {
             &base_path.child("schedule"),
             "schedule is required",
         ));
+    } else {
+        all_errs.extend(validate_schedule_format(
+            &spec.schedule,
+            &base_path.child("schedule"),
+        ));
     }
 
+    all_errs.extend(validate_time_zone(
+        spec.time_zone.as_deref(),
+        &base_path.child("timeZone"),
+    ));
+
     // Validate starting_deadline_seconds
     if let Some(deadline) = spec.starting_deadline_seconds {
         if deadline < 0 {
@@ -88,6 +111,67 @@ fn validate_cron_job_spec(spec: &CronJobSpec, base_path: &Path) -> ErrorList {
     all_errs
 }
 
+fn validate_schedule_format(schedule: &str, path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+
+    if let Err(err) = parse_standard(schedule) {
+        all_errs.push(invalid(path, BadValue::String(schedule.to_string()), &err));
+    }
+    if schedule.contains("TZ") {
+        all_errs.push(invalid(
+            path,
+            BadValue::String(schedule.to_string()),
+            "cannot use TZ or CRON_TZ in schedule, use timeZone field instead",
+        ));
+    }
+
+    all_errs
+}
+
+fn validate_time_zone(time_zone: Option<&str>, path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    let Some(time_zone) = time_zone else {
+        return all_errs;
+    };
+    let bad_value = || BadValue::String(time_zone.to_string());
+
+    if time_zone.is_empty() {
+        all_errs.push(invalid(
+            path,
+            bad_value(),
+            "timeZone must be nil or non-empty string",
+        ));
+        return all_errs;
+    }
+
+    for part in time_zone.split('/') {
+        if part == "."
+            || part == ".."
+            || part.starts_with('-')
+            || !VALID_TIME_ZONE_CHARACTERS.is_match(part)
+        {
+            all_errs.push(invalid(
+                path,
+                bad_value(),
+                &format!("unknown time zone {}", time_zone),
+            ));
+            return all_errs;
+        }
+    }
+
+    if time_zone.eq_ignore_ascii_case("Local") {
+        all_errs.push(invalid(
+            path,
+            bad_value(),
+            "timeZone must be an explicit time zone as defined in https://www.iana.org/time-zones",
+        ));
+    } else if let Err(err) = load_location(time_zone) {
+        all_errs.push(invalid(path, bad_value(), &err));
+    }
+
+    all_errs
+}
+
 /// Validates a CronJobList object.
 pub fn validate_cron_job_list(list: &CronJobList) -> ErrorList {
     let mut all_errs = ErrorList::new();
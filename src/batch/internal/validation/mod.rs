@@ -2,10 +2,11 @@
 //!
 //! Ported from k8s.io/kubernetes/pkg/apis/batch/validation/validation.go
 
+pub mod cron;
 pub mod cronjob;
 pub mod job;
 
-pub use cronjob::{validate_cron_job, validate_cron_job_list};
+pub use cronjob::{validate_cron_job, validate_cron_job_list, validate_cron_job_spec};
 pub use job::{validate_job, validate_job_list};
 
 // =============================================================================
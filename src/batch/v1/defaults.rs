@@ -7,7 +7,10 @@ use crate::common::ApplyDefault;
 use crate::core::internal::ConditionStatus;
 use crate::core::v1::template::apply_pod_template_spec_defaults;
 
-use super::{CronJob, CronJobList, Job, JobList, JobSpec, PodFailurePolicyOnPodConditionsPattern};
+use super::{
+    CronJob, CronJobList, Job, JobList, JobSpec, JobTemplateSpec,
+    PodFailurePolicyOnPodConditionsPattern,
+};
 
 fn set_defaults_pod_failure_policy_on_pod_conditions_pattern(
     pattern: &mut PodFailurePolicyOnPodConditionsPattern,
@@ -17,7 +20,7 @@ fn set_defaults_pod_failure_policy_on_pod_conditions_pattern(
     }
 }
 
-fn set_defaults_job_spec(spec: &mut JobSpec) {
+pub(crate) fn set_defaults_job_spec(spec: &mut JobSpec) {
     if spec.completions.is_none() && spec.parallelism.is_none() {
         spec.completions = Some(1);
         spec.parallelism = Some(1);
@@ -78,19 +81,28 @@ fn set_defaults_job(obj: &mut Job) {
 
 fn set_defaults_cron_job(obj: &mut CronJob) {
     if let Some(spec) = obj.spec.as_mut() {
-        if spec.suspend.is_none() {
-            spec.suspend = Some(false);
-        }
-        if spec.successful_jobs_history_limit.is_none() {
-            spec.successful_jobs_history_limit = Some(3);
-        }
-        if spec.failed_jobs_history_limit.is_none() {
-            spec.failed_jobs_history_limit = Some(1);
-        }
+        set_defaults_cron_job_spec(
+            &mut spec.suspend,
+            &mut spec.successful_jobs_history_limit,
+            &mut spec.failed_jobs_history_limit,
+            &mut spec.job_template,
+        );
+    }
+}
 
-        if let Some(job_spec) = spec.job_template.spec.as_mut() {
-            set_defaults_job_spec(job_spec);
-        }
+/// Defaults the CronJobSpec fields shared by batch/v1 and batch/v1beta1.
+pub(crate) fn set_defaults_cron_job_spec(
+    suspend: &mut Option<bool>,
+    successful_jobs_history_limit: &mut Option<i32>,
+    failed_jobs_history_limit: &mut Option<i32>,
+    job_template: &mut JobTemplateSpec,
+) {
+    suspend.get_or_insert(false);
+    successful_jobs_history_limit.get_or_insert(3);
+    failed_jobs_history_limit.get_or_insert(1);
+
+    if let Some(job_spec) = job_template.spec.as_mut() {
+        set_defaults_job_spec(job_spec);
     }
 }
 
@@ -4,9 +4,9 @@
 
 use crate::batch::internal::validation as internal_validation;
 use crate::common::ToInternal;
-use crate::common::validation::ErrorList;
+use crate::common::validation::{ErrorList, Path};
 
-use super::{CronJob, CronJobList, Job, JobList};
+use super::{CronJob, CronJobList, CronJobSpec, Job, JobList};
 
 // =============================================================================
 // Job Validation
@@ -31,6 +31,11 @@ pub fn validate_cron_job(cron_job: &CronJob) -> ErrorList {
     internal_validation::validate_cron_job(&cron_job.clone().to_internal())
 }
 
+/// Validates a CronJobSpec rooted at `path`.
+pub fn validate_cron_job_spec(spec: &CronJobSpec, path: &Path) -> ErrorList {
+    internal_validation::validate_cron_job_spec(&spec.clone().to_internal(), path)
+}
+
 /// Validates a CronJobList object.
 pub fn validate_cron_job_list(list: &CronJobList) -> ErrorList {
     internal_validation::validate_cron_job_list(&list.clone().to_internal())
@@ -44,6 +49,7 @@ pub fn validate_cron_job_list(list: &CronJobList) -> ErrorList {
 mod tests {
     use super::*;
     use crate::batch::v1::{CronJobSpec, JobSpec, JobTemplateSpec};
+    use crate::common::validation::ErrorType;
     use crate::common::{LabelSelector, ObjectMeta, TypeMeta};
     use crate::core::v1::PodTemplateSpec;
 
@@ -236,4 +242,60 @@ mod tests {
             "Expected error for missing schedule"
         );
     }
+
+    fn cron_job_spec(schedule: &str, time_zone: Option<&str>) -> CronJobSpec {
+        CronJobSpec {
+            schedule: schedule.to_string(),
+            time_zone: time_zone.map(str::to_string),
+            job_template: JobTemplateSpec {
+                metadata: None,
+                spec: Some(JobSpec {
+                    selector: Some(LabelSelector::default()),
+                    template: PodTemplateSpec::default(),
+                    ..Default::default()
+                }),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_cron_job_spec_bad_schedule() {
+        let spec = cron_job_spec("61 * * * *", None);
+        let errors = validate_cron_job_spec(&spec, &Path::new("spec"));
+        assert_eq!(errors.len(), 1, "{:?}", errors.errors);
+        assert_eq!(errors.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(errors.errors[0].field, "spec.schedule");
+        assert_eq!(
+            errors.errors[0].detail,
+            "end of range (61) above maximum (59): 61"
+        );
+
+        let spec = cron_job_spec("TZ=UTC 0 * * * *", None);
+        let errors = validate_cron_job_spec(&spec, &Path::new("spec"));
+        assert_eq!(errors.len(), 1, "{:?}", errors.errors);
+        assert_eq!(errors.errors[0].field, "spec.schedule");
+    }
+
+    #[test]
+    fn test_validate_cron_job_spec_time_zone() {
+        let spec = cron_job_spec("0 6 * * *", Some("America/New_York"));
+        assert!(validate_cron_job_spec(&spec, &Path::new("spec")).is_empty());
+
+        for (time_zone, detail) in [
+            ("Mars/Olympus_Mons", "unknown time zone Mars/Olympus_Mons"),
+            ("../etc/passwd", "unknown time zone ../etc/passwd"),
+            (
+                "Local",
+                "timeZone must be an explicit time zone as defined in https://www.iana.org/time-zones",
+            ),
+        ] {
+            let spec = cron_job_spec("0 6 * * *", Some(time_zone));
+            let errors = validate_cron_job_spec(&spec, &Path::new("spec"));
+            assert_eq!(errors.len(), 1, "{time_zone}: {:?}", errors.errors);
+            assert_eq!(errors.errors[0].error_type, ErrorType::Invalid);
+            assert_eq!(errors.errors[0].field, "spec.timeZone");
+            assert_eq!(errors.errors[0].detail, detail);
+        }
+    }
 }
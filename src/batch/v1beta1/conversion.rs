@@ -0,0 +1,108 @@
+//! Conversions between v1beta1, v1 and internal batch types
+//!
+//! v1beta1 CronJob has the same layout as v1 apart from the missing `timeZone`,
+//! and shares the job template and status types. Converting to v1 leaves
+//! `timeZone` unset; converting from v1 drops it. Internal conversions go
+//! through v1.
+
+use crate::batch::{internal, v1};
+use crate::common::{FromInternal, ToInternal, TypeMeta};
+
+use super::{CronJob, CronJobList, CronJobSpec};
+
+// ============================================================================
+// v1beta1 <-> v1 Conversions
+// ============================================================================
+
+impl From<CronJobSpec> for v1::CronJobSpec {
+    fn from(value: CronJobSpec) -> Self {
+        Self {
+            schedule: value.schedule,
+            time_zone: None,
+            starting_deadline_seconds: value.starting_deadline_seconds,
+            concurrency_policy: value.concurrency_policy,
+            suspend: value.suspend,
+            job_template: value.job_template,
+            successful_jobs_history_limit: value.successful_jobs_history_limit,
+            failed_jobs_history_limit: value.failed_jobs_history_limit,
+        }
+    }
+}
+
+impl From<v1::CronJobSpec> for CronJobSpec {
+    fn from(value: v1::CronJobSpec) -> Self {
+        Self {
+            schedule: value.schedule,
+            starting_deadline_seconds: value.starting_deadline_seconds,
+            concurrency_policy: value.concurrency_policy,
+            suspend: value.suspend,
+            job_template: value.job_template,
+            successful_jobs_history_limit: value.successful_jobs_history_limit,
+            failed_jobs_history_limit: value.failed_jobs_history_limit,
+        }
+    }
+}
+
+impl From<CronJob> for v1::CronJob {
+    fn from(value: CronJob) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+            status: value.status,
+        }
+    }
+}
+
+impl From<v1::CronJob> for CronJob {
+    fn from(value: v1::CronJob) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            spec: value.spec.map(Into::into),
+            status: value.status,
+        }
+    }
+}
+
+// ============================================================================
+// CronJob Conversions
+// ============================================================================
+
+impl ToInternal<internal::CronJob> for CronJob {
+    fn to_internal(self) -> internal::CronJob {
+        v1::CronJob::from(self).to_internal()
+    }
+}
+
+impl FromInternal<internal::CronJob> for CronJob {
+    fn from_internal(value: internal::CronJob) -> Self {
+        v1::CronJob::from_internal(value).into()
+    }
+}
+
+// ============================================================================
+// CronJobList Conversions
+// ============================================================================
+
+impl ToInternal<internal::CronJobList> for CronJobList {
+    fn to_internal(self) -> internal::CronJobList {
+        v1::CronJobList {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            items: self.items.into_iter().map(Into::into).collect(),
+        }
+        .to_internal()
+    }
+}
+
+impl FromInternal<internal::CronJobList> for CronJobList {
+    fn from_internal(value: internal::CronJobList) -> Self {
+        let value = v1::CronJobList::from_internal(value);
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            items: value.items.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use super::{CronJob, CronJobList, CronJobSpec, CronJobStatus, JobTemplateSpec};
+use crate::batch::internal::ConcurrencyPolicy;
+use crate::batch::{internal, v1};
+use crate::common::test_utils::assert_conversion_roundtrip;
+use crate::common::{ApplyDefault, FromInternal, ListMeta, ObjectMeta, ToInternal, TypeMeta};
+use crate::core::v1::{Container, PodSpec, PodTemplateSpec};
+
+fn cron_job() -> CronJob {
+    CronJob {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta {
+            name: Some("nightly-report".to_string()),
+            namespace: Some("default".to_string()),
+            ..Default::default()
+        }),
+        spec: Some(CronJobSpec {
+            schedule: "0 2 * * *".to_string(),
+            starting_deadline_seconds: Some(120),
+            concurrency_policy: ConcurrencyPolicy::Forbid,
+            suspend: Some(true),
+            job_template: JobTemplateSpec {
+                metadata: None,
+                spec: Some(v1::JobSpec {
+                    template: PodTemplateSpec {
+                        spec: Some(PodSpec {
+                            containers: vec![Container {
+                                name: "report".to_string(),
+                                image: Some("busybox:1.36".to_string()),
+                                ..Default::default()
+                            }],
+                            restart_policy: Some("OnFailure".to_string()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }),
+            },
+            successful_jobs_history_limit: Some(5),
+            failed_jobs_history_limit: Some(2),
+        }),
+        status: Some(CronJobStatus::default()),
+    }
+}
+
+#[test]
+fn conversion_roundtrip_cron_job() {
+    assert_conversion_roundtrip::<CronJob, internal::CronJob>(cron_job());
+}
+
+#[test]
+fn conversion_roundtrip_cron_job_list() {
+    let mut item = cron_job();
+    item.apply_default();
+
+    assert_conversion_roundtrip::<CronJobList, internal::CronJobList>(CronJobList {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ListMeta {
+            resource_version: Some("42".to_string()),
+            ..Default::default()
+        }),
+        items: vec![item],
+    });
+}
+
+#[test]
+fn cron_job_converts_to_v1_preserving_spec() {
+    let v1beta1 = cron_job();
+
+    let v1_cron_job = v1::CronJob::from(v1beta1.clone());
+    let spec = v1_cron_job.spec.as_ref().unwrap();
+    assert_eq!(spec.schedule, "0 2 * * *");
+    assert_eq!(spec.time_zone, None);
+    assert_eq!(spec.concurrency_policy, ConcurrencyPolicy::Forbid);
+    assert_eq!(spec.suspend, Some(true));
+    assert_eq!(spec.successful_jobs_history_limit, Some(5));
+    assert_eq!(spec.failed_jobs_history_limit, Some(2));
+    assert_eq!(CronJob::from(v1_cron_job.clone()), v1beta1);
+
+    let via_internal = CronJob::from_internal(v1_cron_job.to_internal());
+    assert_eq!(via_internal, v1beta1);
+}
@@ -0,0 +1,252 @@
+//! Kubernetes Batch v1beta1 API types
+//!
+//! This module contains the CronJob types from batch/v1beta1.
+//!
+//! Source: https://github.com/kubernetes/api/blob/master/batch/v1beta1/types.go
+
+use crate::batch::internal::ConcurrencyPolicy;
+use crate::batch::v1::defaults::set_defaults_cron_job_spec;
+use crate::common::{
+    ApplyDefault, HasTypeMeta, ListMeta, ObjectMeta, ResourceSchema, TypeMeta, VersionedObject,
+};
+use crate::impl_unimplemented_prost_message;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+pub use crate::batch::v1::{CronJobStatus, JobTemplateSpec};
+
+pub mod conversion;
+
+// ============================================================================
+// CronJob
+// ============================================================================
+
+/// CronJob represents the configuration of a single cron job.
+///
+/// Corresponds to [Kubernetes CronJob](https://github.com/kubernetes/api/blob/master/batch/v1beta1/types.go#L66)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CronJob {
+    /// Standard type metadata.
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard object's metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ObjectMeta>,
+    /// Specification of the desired behavior of a cron job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec: Option<CronJobSpec>,
+    /// Current status of a cron job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<CronJobStatus>,
+}
+
+/// CronJobList is a collection of cron jobs.
+///
+/// Corresponds to [Kubernetes CronJobList](https://github.com/kubernetes/api/blob/master/batch/v1beta1/types.go#L87)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CronJobList {
+    /// Standard type metadata.
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard list metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMeta>,
+    /// items is the list of CronJobs.
+    #[serde(default)]
+    pub items: Vec<CronJob>,
+}
+
+/// CronJobSpec describes how the job execution will look like and when it will run.
+///
+/// Unlike batch/v1 there is no `timeZone`; schedules are interpreted in the
+/// time zone of the kube-controller-manager.
+///
+/// Corresponds to [Kubernetes CronJobSpec](https://github.com/kubernetes/api/blob/master/batch/v1beta1/types.go#L100)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CronJobSpec {
+    /// The schedule in Cron format.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub schedule: String,
+    /// Optional deadline in seconds for starting the job if it misses scheduled time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starting_deadline_seconds: Option<i64>,
+    /// Specifies how to treat concurrent executions of a Job.
+    #[serde(default)]
+    pub concurrency_policy: ConcurrencyPolicy,
+    /// This flag tells the controller to suspend subsequent executions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suspend: Option<bool>,
+    /// Specifies the job that will be created when executing a CronJob.
+    #[serde(default)]
+    pub job_template: JobTemplateSpec,
+    /// The number of successful finished jobs to retain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub successful_jobs_history_limit: Option<i32>,
+    /// The number of failed finished jobs to retain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failed_jobs_history_limit: Option<i32>,
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+
+// ----------------------------------------------------------------------------
+// ResourceSchema Implementation
+// ----------------------------------------------------------------------------
+
+impl ResourceSchema for CronJob {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "batch"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "CronJob"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "cronjobs"
+    }
+
+    fn group_static() -> &'static str {
+        "batch"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "CronJob"
+    }
+    fn resource_static() -> &'static str {
+        "cronjobs"
+    }
+}
+
+impl ResourceSchema for CronJobList {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "batch"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "CronJobList"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "cronjobs"
+    }
+
+    fn group_static() -> &'static str {
+        "batch"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "CronJobList"
+    }
+    fn resource_static() -> &'static str {
+        "cronjobs"
+    }
+}
+
+// ----------------------------------------------------------------------------
+// HasTypeMeta Implementation
+// ----------------------------------------------------------------------------
+
+impl HasTypeMeta for CronJob {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for CronJobList {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+// ----------------------------------------------------------------------------
+// VersionedObject Implementation
+// ----------------------------------------------------------------------------
+
+impl VersionedObject for CronJob {
+    fn metadata(&self) -> &ObjectMeta {
+        self.metadata.as_ref().unwrap_or_else(|| {
+            static DEFAULT: OnceLock<ObjectMeta> = OnceLock::new();
+            DEFAULT.get_or_init(ObjectMeta::default)
+        })
+    }
+
+    fn metadata_mut(&mut self) -> &mut ObjectMeta {
+        self.metadata.get_or_insert_with(ObjectMeta::default)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ApplyDefaults Implementation
+// ----------------------------------------------------------------------------
+
+impl ApplyDefault for CronJob {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "batch/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "CronJob".to_string();
+        }
+
+        if let Some(spec) = self.spec.as_mut() {
+            set_defaults_cron_job_spec(
+                &mut spec.suspend,
+                &mut spec.successful_jobs_history_limit,
+                &mut spec.failed_jobs_history_limit,
+                &mut spec.job_template,
+            );
+        }
+    }
+}
+
+impl ApplyDefault for CronJobList {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "batch/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "CronJobList".to_string();
+        }
+        for item in &mut self.items {
+            item.apply_default();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Protobuf Placeholder
+// ----------------------------------------------------------------------------
+
+impl_unimplemented_prost_message!(CronJob);
+impl_unimplemented_prost_message!(CronJobList);
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod conversion_roundtrip_tests;
+#[cfg(test)]
+mod trait_tests;
@@ -66,7 +66,7 @@ pub struct CertificateSigningRequestList {
     pub metadata: ListMeta,
 
     /// List of certificate signing requests
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<CertificateSigningRequest>,
 }
 
@@ -115,7 +115,7 @@ pub struct ClusterTrustBundleList {
     pub metadata: Option<crate::common::ListMeta>,
 
     /// Items is a collection of ClusterTrustBundle objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ClusterTrustBundle>,
 }
 
@@ -223,7 +223,7 @@ pub struct PodCertificateRequestList {
     pub metadata: Option<crate::common::ListMeta>,
 
     /// Items is a collection of PodCertificateRequest objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<PodCertificateRequest>,
 }
 
@@ -13,7 +13,6 @@ use std::collections::BTreeMap;
 
 use crate::common::{HasTypeMeta, ObjectMeta, ResourceSchema, TypeMeta, VersionedObject};
 use crate::impl_as_str_ref;
-use crate::impl_unimplemented_prost_message;
 
 // ============================================================================
 // Certificate Signing Request
@@ -190,7 +189,7 @@ pub struct CertificateSigningRequestList {
     pub metadata: Option<crate::common::ListMeta>,
 
     /// items is a collection of CertificateSigningRequest objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<CertificateSigningRequest>,
 }
 
@@ -451,12 +450,8 @@ impl VersionedObject for CertificateSigningRequest {
     }
 }
 
-// ----------------------------------------------------------------------------
-// Protobuf Placeholder
-// ----------------------------------------------------------------------------
-
-impl_unimplemented_prost_message!(CertificateSigningRequest);
-impl_unimplemented_prost_message!(CertificateSigningRequestList);
+// Protobuf: see proto.rs
+mod proto;
 
 #[cfg(test)]
 mod trait_tests;
@@ -466,3 +461,6 @@ mod serde_roundtrip_tests;
 
 #[cfg(test)]
 mod conversion_roundtrip_tests;
+
+#[cfg(test)]
+mod proto_roundtrip_tests;
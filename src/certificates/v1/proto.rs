@@ -0,0 +1,379 @@
+//! Protobuf encodings for certificates/v1 types
+//!
+//! Field numbers follow k8s.io/api/certificates/v1/generated.proto. TypeMeta is
+//! carried by the runtime envelope, not by the messages themselves. Enum fields
+//! are carried as their string values.
+
+use prost::bytes::{Buf, BufMut};
+use prost::encoding::{
+    DecodeContext, WireType, btree_map, bytes, int32, message, skip_field, string,
+};
+use prost::{DecodeError, Message};
+use serde::de::DeserializeOwned;
+
+use super::{
+    CertificateSigningRequest, CertificateSigningRequestCondition, CertificateSigningRequestList,
+    CertificateSigningRequestSpec, CertificateSigningRequestStatus, ExtraValue,
+};
+use crate::common::traits::AsRefStr;
+use crate::core::internal::helper::ByteString;
+
+/// Decodes a string-valued enum field using its JSON (serde) representation.
+fn merge_enum<T, B>(
+    wire_type: WireType,
+    buf: &mut B,
+    ctx: DecodeContext,
+    field: &'static str,
+) -> Result<T, DecodeError>
+where
+    T: DeserializeOwned,
+    B: Buf,
+{
+    let mut value = String::new();
+    string::merge(wire_type, &mut value, buf, ctx)?;
+    serde_json::from_value(serde_json::Value::String(value))
+        .map_err(|_| DecodeError::new(format!("unknown {field} value")))
+}
+
+// ============================================================================
+// CertificateSigningRequest
+// ============================================================================
+
+impl Message for CertificateSigningRequest {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref metadata) = self.metadata {
+            message::encode(1, metadata, buf);
+        }
+        message::encode(2, &self.spec, buf);
+        if let Some(ref status) = self.status {
+            message::encode(3, status, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.metadata.get_or_insert_default(), buf, ctx),
+            2 => message::merge(wire_type, &mut self.spec, buf, ctx),
+            3 => message::merge(wire_type, self.status.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.metadata
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + message::encoded_len(2, &self.spec)
+            + self
+                .status
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+    }
+
+    fn clear(&mut self) {
+        *self = CertificateSigningRequest::default();
+    }
+}
+
+impl Message for CertificateSigningRequestList {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref metadata) = self.metadata {
+            message::encode(1, metadata, buf);
+        }
+        message::encode_repeated(2, &self.items, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.metadata.get_or_insert_default(), buf, ctx),
+            2 => message::merge_repeated(wire_type, &mut self.items, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.metadata
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + message::encoded_len_repeated(2, &self.items)
+    }
+
+    fn clear(&mut self) {
+        *self = CertificateSigningRequestList::default();
+    }
+}
+
+// ============================================================================
+// CertificateSigningRequestSpec
+// ============================================================================
+
+impl CertificateSigningRequestSpec {
+    fn usage_strings(&self) -> Vec<String> {
+        self.usages
+            .iter()
+            .map(|usage| usage.as_str().to_string())
+            .collect()
+    }
+}
+
+impl Message for CertificateSigningRequestSpec {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        bytes::encode(1, &self.request.0, buf);
+        string::encode(2, &self.username, buf);
+        string::encode(3, &self.uid, buf);
+        string::encode_repeated(4, &self.groups, buf);
+        string::encode_repeated(5, &self.usage_strings(), buf);
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            message::encode,
+            message::encoded_len,
+            6,
+            &self.extra,
+            buf,
+        );
+        string::encode(7, &self.signer_name, buf);
+        if let Some(ref value) = self.expiration_seconds {
+            int32::encode(8, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => bytes::merge(wire_type, &mut self.request.0, buf, ctx),
+            2 => string::merge(wire_type, &mut self.username, buf, ctx),
+            3 => string::merge(wire_type, &mut self.uid, buf, ctx),
+            4 => string::merge_repeated(wire_type, &mut self.groups, buf, ctx),
+            5 => {
+                let usage = merge_enum(wire_type, buf, ctx, "CertificateSigningRequest usage")?;
+                self.usages.push(usage);
+                Ok(())
+            }
+            6 => btree_map::merge(string::merge, message::merge, &mut self.extra, buf, ctx),
+            7 => string::merge(wire_type, &mut self.signer_name, buf, ctx),
+            8 => int32::merge(
+                wire_type,
+                self.expiration_seconds.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        bytes::encoded_len(1, &self.request.0)
+            + string::encoded_len(2, &self.username)
+            + string::encoded_len(3, &self.uid)
+            + string::encoded_len_repeated(4, &self.groups)
+            + string::encoded_len_repeated(5, &self.usage_strings())
+            + btree_map::encoded_len(string::encoded_len, message::encoded_len, 6, &self.extra)
+            + string::encoded_len(7, &self.signer_name)
+            + self
+                .expiration_seconds
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(8, v))
+    }
+
+    fn clear(&mut self) {
+        *self = CertificateSigningRequestSpec::default();
+    }
+}
+
+impl Message for ExtraValue {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode_repeated(1, &self.inner, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge_repeated(wire_type, &mut self.inner, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len_repeated(1, &self.inner)
+    }
+
+    fn clear(&mut self) {
+        self.inner.clear();
+    }
+}
+
+// ============================================================================
+// CertificateSigningRequestStatus
+// ============================================================================
+
+impl Message for CertificateSigningRequestStatus {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode_repeated(1, &self.conditions, buf);
+        if let Some(ref certificate) = self.certificate {
+            bytes::encode(2, &certificate.0, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge_repeated(wire_type, &mut self.conditions, buf, ctx),
+            2 => bytes::merge(
+                wire_type,
+                &mut self.certificate.get_or_insert_with(ByteString::default).0,
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len_repeated(1, &self.conditions)
+            + self
+                .certificate
+                .as_ref()
+                .map_or(0, |v| bytes::encoded_len(2, &v.0))
+    }
+
+    fn clear(&mut self) {
+        *self = CertificateSigningRequestStatus::default();
+    }
+}
+
+impl Message for CertificateSigningRequestCondition {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.type_.as_str().to_string(), buf);
+        string::encode(2, &self.reason, buf);
+        string::encode(3, &self.message, buf);
+        if let Some(ref value) = self.last_update_time {
+            message::encode(4, value, buf);
+        }
+        if let Some(ref value) = self.last_transition_time {
+            message::encode(5, value, buf);
+        }
+        string::encode(6, &self.status.as_str().to_string(), buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => {
+                self.type_ = merge_enum(
+                    wire_type,
+                    buf,
+                    ctx,
+                    "CertificateSigningRequest condition type",
+                )?;
+                Ok(())
+            }
+            2 => string::merge(wire_type, &mut self.reason, buf, ctx),
+            3 => string::merge(wire_type, &mut self.message, buf, ctx),
+            4 => message::merge(
+                wire_type,
+                self.last_update_time.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            5 => message::merge(
+                wire_type,
+                self.last_transition_time.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            6 => {
+                self.status = merge_enum(wire_type, buf, ctx, "condition status")?;
+                Ok(())
+            }
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.type_.as_str().to_string())
+            + string::encoded_len(2, &self.reason)
+            + string::encoded_len(3, &self.message)
+            + self
+                .last_update_time
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(4, v))
+            + self
+                .last_transition_time
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(5, v))
+            + string::encoded_len(6, &self.status.as_str().to_string())
+    }
+
+    fn clear(&mut self) {
+        *self = CertificateSigningRequestCondition::default();
+    }
+}
@@ -0,0 +1,87 @@
+use super::{
+    CertificateSigningRequest, CertificateSigningRequestCondition, CertificateSigningRequestList,
+    CertificateSigningRequestSpec, CertificateSigningRequestStatus, ExtraValue, KeyUsage,
+    RequestConditionType,
+};
+use crate::common::{ListMeta, ObjectMeta, Timestamp};
+use crate::core::internal::ConditionStatus;
+use crate::core::internal::helper::ByteString;
+use prost::Message;
+use std::collections::BTreeMap;
+
+fn approved_csr() -> CertificateSigningRequest {
+    CertificateSigningRequest {
+        metadata: Some(ObjectMeta {
+            name: Some("csr-node-a".to_string()),
+            resource_version: Some("88".to_string()),
+            ..Default::default()
+        }),
+        spec: CertificateSigningRequestSpec {
+            request: ByteString(b"-----BEGIN CERTIFICATE REQUEST-----\n\0\xff".to_vec()),
+            signer_name: CertificateSigningRequestSpec::KUBE_APISERVER_CLIENT_KUBELET_SIGNER_NAME
+                .to_string(),
+            expiration_seconds: Some(86400),
+            usages: vec![KeyUsage::DigitalSignature, KeyUsage::ClientAuth],
+            username: "system:node:node-a".to_string(),
+            uid: "6f1c1b7e".to_string(),
+            groups: vec![
+                "system:nodes".to_string(),
+                "system:authenticated".to_string(),
+            ],
+            extra: BTreeMap::from([
+                (
+                    "scopes".to_string(),
+                    ExtraValue::from(vec!["read".to_string(), "write".to_string()]),
+                ),
+                ("empty".to_string(), ExtraValue::default()),
+            ]),
+        },
+        status: Some(CertificateSigningRequestStatus {
+            conditions: vec![CertificateSigningRequestCondition {
+                type_: RequestConditionType::Approved,
+                status: ConditionStatus::True,
+                reason: "AutoApproved".to_string(),
+                message: "Auto approving kubelet client certificate after SubjectAccessReview."
+                    .to_string(),
+                last_update_time: Some(Timestamp::from_str("2024-01-15T10:00:00Z").unwrap()),
+                last_transition_time: Some(Timestamp::from_str("2024-01-15T10:00:00Z").unwrap()),
+            }],
+            certificate: Some(ByteString(b"-----BEGIN CERTIFICATE-----\n".to_vec())),
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn proto_roundtrip_approved_csr_with_certificate() {
+    let csr = approved_csr();
+    let bytes = csr.encode_to_vec();
+    assert_eq!(bytes.len(), csr.encoded_len());
+
+    let decoded = CertificateSigningRequest::decode(bytes.as_slice()).expect("decode CSR");
+    assert_eq!(decoded, csr);
+}
+
+#[test]
+fn proto_roundtrip_pending_csr_list() {
+    let pending = CertificateSigningRequest {
+        spec: CertificateSigningRequestSpec {
+            request: ByteString(b"csr".to_vec()),
+            signer_name: "example.com/signer".to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let list = CertificateSigningRequestList {
+        metadata: Some(ListMeta {
+            resource_version: Some("90".to_string()),
+            ..Default::default()
+        }),
+        items: vec![approved_csr(), pending],
+        ..Default::default()
+    };
+
+    let decoded = CertificateSigningRequestList::decode(list.encode_to_vec().as_slice())
+        .expect("decode CertificateSigningRequestList");
+    assert_eq!(decoded, list);
+}
@@ -61,7 +61,7 @@ pub struct ClusterTrustBundleList {
     pub metadata: Option<ListMeta>,
 
     /// items is a collection of ClusterTrustBundle objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ClusterTrustBundle>,
 }
 
@@ -169,7 +169,7 @@ pub struct PodCertificateRequestList {
     pub metadata: Option<ListMeta>,
 
     /// items is a collection of PodCertificateRequest objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<PodCertificateRequest>,
 }
 
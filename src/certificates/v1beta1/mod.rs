@@ -95,7 +95,7 @@ pub struct CertificateSigningRequestList {
     pub metadata: Option<crate::common::ListMeta>,
 
     /// items is a collection of CertificateSigningRequest objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<CertificateSigningRequest>,
 }
 
@@ -146,7 +146,7 @@ pub struct ClusterTrustBundleList {
     pub metadata: Option<crate::common::ListMeta>,
 
     /// Items is a collection of ClusterTrustBundle objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ClusterTrustBundle>,
 }
 
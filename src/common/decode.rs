@@ -0,0 +1,156 @@
+//! JSON decoding with defaulting
+//!
+//! Kubernetes treats an empty `metadata` map or string the same as an absent
+//! one (`"labels": {}`, `"labels": null` and no `labels` are equivalent), but a
+//! typed decode keeps the difference: for example `"metadata": {"labels": {}}`
+//! decodes to `Some(ObjectMeta::default())` and re-encodes as `"metadata": {}`.
+//! Decoding through [`decode_and_default`] canonicalizes these first so that
+//! comparisons and re-encoding match what the server returns.
+//!
+//! Besides the top-level `metadata`, the nested object metadata of list
+//! `items`, pod and job templates, StatefulSet `volumeClaimTemplates` and
+//! ephemeral volume claim templates is normalized too. Other fields named
+//! `metadata` are left alone, since they need not be an `ObjectMeta`.
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::common::ApplyDefault;
+
+/// Decodes `json` into `T`, normalizes its `metadata` and applies defaults.
+pub fn decode_and_default<T>(json: &str) -> serde_json::Result<T>
+where
+    T: DeserializeOwned + ApplyDefault,
+{
+    let mut value: Value = serde_json::from_str(json)?;
+    normalize_object_meta(&mut value);
+    let mut obj: T = serde_json::from_value(value)?;
+    obj.apply_default();
+    Ok(obj)
+}
+
+/// Drops null and empty fields from an object's `metadata`, and drops
+/// `metadata` itself when nothing is left.
+///
+/// Recurses into the nested objects that carry their own metadata; see the
+/// module documentation for the list.
+pub fn normalize_object_meta(value: &mut Value) {
+    let Some(object) = value.as_object_mut() else {
+        return;
+    };
+    if let Some(metadata) = object.get_mut("metadata") {
+        if let Some(fields) = metadata.as_object_mut() {
+            fields.retain(|_, field| !is_empty_value(field));
+        }
+        if is_empty_value(metadata) {
+            object.remove("metadata");
+        }
+    }
+
+    if let Some(Value::Array(items)) = object.get_mut("items") {
+        items.iter_mut().for_each(normalize_object_meta);
+    }
+    if let Some(template) = object.get_mut("template") {
+        normalize_object_meta(template);
+    }
+    let Some(spec) = object.get_mut("spec").and_then(Value::as_object_mut) else {
+        return;
+    };
+    for key in ["template", "jobTemplate"] {
+        if let Some(template) = spec.get_mut(key) {
+            normalize_object_meta(template);
+        }
+    }
+    if let Some(Value::Array(templates)) = spec.get_mut("volumeClaimTemplates") {
+        templates.iter_mut().for_each(normalize_object_meta);
+    }
+    if let Some(Value::Array(volumes)) = spec.get_mut("volumes") {
+        for volume in volumes {
+            if let Some(template) = volume.pointer_mut("/ephemeral/volumeClaimTemplate") {
+                normalize_object_meta(template);
+            }
+        }
+    }
+}
+
+fn is_empty_value(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        Value::Object(fields) => fields.is_empty(),
+        Value::Bool(_) | Value::Number(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::v1::ConfigMap;
+
+    #[test]
+    fn test_decode_and_default_empty_labels() {
+        let decoded: ConfigMap = decode_and_default(r#"{"metadata":{"labels":{}}}"#).unwrap();
+        assert_eq!(decoded.metadata, None);
+        assert_eq!(decoded.type_meta.kind, "ConfigMap");
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            serde_json::json!({"apiVersion": "v1", "kind": "ConfigMap"})
+        );
+
+        let plain: ConfigMap = serde_json::from_str(r#"{"metadata":{"labels":{}}}"#).unwrap();
+        assert!(plain.metadata.is_some());
+    }
+
+    #[test]
+    fn test_decode_and_default_null_maps() {
+        let decoded: ConfigMap = decode_and_default(
+            r#"{"metadata":{"name":"settings","namespace":"","labels":null,"annotations":{}}}"#,
+        )
+        .unwrap();
+        let metadata = decoded.metadata.unwrap();
+        assert_eq!(metadata.name.as_deref(), Some("settings"));
+        assert_eq!(metadata.namespace, None);
+        assert!(metadata.labels.is_empty());
+        assert!(metadata.annotations.is_empty());
+    }
+
+    #[test]
+    fn test_normalize_object_meta_nested() {
+        let mut value = serde_json::json!({
+            "items": [{
+                "metadata": {"name": "web", "labels": {}},
+                "spec": {
+                    "template": {
+                        "metadata": {"annotations": {}},
+                        "spec": {"volumes": [{
+                            "name": "scratch",
+                            "ephemeral": {"volumeClaimTemplate": {"metadata": {"labels": {}}}}
+                        }]}
+                    },
+                    "volumeClaimTemplates": [{"metadata": {"name": "data", "labels": null}}]
+                }
+            }],
+            "data": {"metadata": {}}
+        });
+        normalize_object_meta(&mut value);
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "items": [{
+                    "metadata": {"name": "web"},
+                    "spec": {
+                        "template": {
+                            "spec": {"volumes": [{
+                                "name": "scratch",
+                                "ephemeral": {"volumeClaimTemplate": {}}
+                            }]}
+                        },
+                        "volumeClaimTemplates": [{"metadata": {"name": "data"}}]
+                    }
+                }],
+                "data": {"metadata": {}}
+            })
+        );
+    }
+}
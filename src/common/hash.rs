@@ -0,0 +1,94 @@
+//! Content hashing
+//!
+//! Computes stable hashes of resources for change detection, e.g. the value a
+//! controller stores in an annotation to notice that a spec was edited.
+
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/// Metadata fields populated by the API server rather than by the client.
+const SERVER_SET_METADATA_FIELDS: [&str; 8] = [
+    "uid",
+    "resourceVersion",
+    "generation",
+    "creationTimestamp",
+    "deletionTimestamp",
+    "deletionGracePeriodSeconds",
+    "selfLink",
+    "managedFields",
+];
+
+/// Returns the hex-encoded SHA-256 of `obj`'s canonical JSON form.
+///
+/// `status` and server-set metadata (uid, resourceVersion, generation,
+/// timestamps, managedFields, ...) are dropped before hashing, so the hash only
+/// changes when the user-owned content does. Object keys are serialized in
+/// sorted order, making the result independent of field order in the source.
+///
+/// Returns an error if `obj` cannot be serialized to JSON, e.g. a map with
+/// non-string keys.
+pub fn spec_hash<T: Serialize>(obj: &T) -> Result<String, serde_json::Error> {
+    let mut value = serde_json::to_value(obj)?;
+    if let Value::Object(fields) = &mut value {
+        fields.remove("status");
+        if let Some(Value::Object(metadata)) = fields.get_mut("metadata") {
+            for field in SERVER_SET_METADATA_FIELDS {
+                metadata.remove(field);
+            }
+        }
+    }
+
+    let canonical = serde_json::to_vec(&value)?;
+    Ok(Sha256::digest(&canonical)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apps::v1::{Deployment, DeploymentSpec, DeploymentStatus};
+    use crate::common::ObjectMeta;
+
+    fn deployment(replicas: i32) -> Deployment {
+        Deployment {
+            metadata: Some(ObjectMeta {
+                name: Some("web".to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            }),
+            spec: Some(DeploymentSpec {
+                replicas: Some(replicas),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_spec_hash_ignores_status_and_server_fields() {
+        let original = deployment(3);
+        let mut observed = deployment(3);
+        let metadata = observed.metadata.as_mut().unwrap();
+        metadata.resource_version = Some("1201".to_string());
+        metadata.uid = Some("0b8f3c2e-91d4-4e57-a3c6-2d7f5e1a9b40".to_string());
+        metadata.generation = Some(4);
+        observed.status = Some(DeploymentStatus {
+            ready_replicas: Some(3),
+            ..Default::default()
+        });
+
+        let hash = spec_hash(&original).unwrap();
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, spec_hash(&observed).unwrap());
+        assert_ne!(hash, spec_hash(&deployment(5)).unwrap());
+    }
+
+    #[test]
+    fn test_spec_hash_reports_unserializable_input() {
+        let map = std::collections::BTreeMap::from([((1, 2), "pair")]);
+        assert!(spec_hash(&map).is_err());
+    }
+}
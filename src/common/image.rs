@@ -0,0 +1,149 @@
+//! Container image reference parsing
+//!
+//! Mirrors the normalization done by k8s.io/kubernetes/pkg/util/parsers
+//! (which wraps the docker reference library): short names are expanded to
+//! `docker.io/library/...`, and a registry host may carry a port.
+
+/// Registry used when an image reference does not name one.
+pub const DEFAULT_IMAGE_REGISTRY: &str = "docker.io";
+
+/// Repository namespace used for single-component Docker Hub images.
+const OFFICIAL_REPOSITORY_PREFIX: &str = "library/";
+
+/// Legacy Docker Hub host, normalized to [`DEFAULT_IMAGE_REGISTRY`].
+const LEGACY_DEFAULT_REGISTRY: &str = "index.docker.io";
+
+/// Tag implied when a reference carries neither a tag nor a digest.
+pub const DEFAULT_IMAGE_TAG: &str = "latest";
+
+/// ImageRef is a container image reference split into its components.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct ImageRef {
+    /// Registry host, including the port if any (e.g. `registry:5000`).
+    pub registry: String,
+    /// Repository path within the registry (e.g. `library/nginx`).
+    pub repository: String,
+    /// Tag, if one was given.
+    pub tag: Option<String>,
+    /// Digest, if one was given (e.g. `sha256:...`).
+    pub digest: Option<String>,
+}
+
+impl ImageRef {
+    /// Returns the fully qualified repository name, `registry/repository`.
+    pub fn name(&self) -> String {
+        format!("{}/{}", self.registry, self.repository)
+    }
+
+    /// Returns true if the reference resolves to the `latest` tag.
+    ///
+    /// This is the case for an explicit `:latest` tag, and for references
+    /// with neither a tag nor a digest. Container defaulting uses this to pick
+    /// `imagePullPolicy: Always`.
+    pub fn is_latest(&self) -> bool {
+        match (&self.tag, &self.digest) {
+            (Some(tag), _) => tag == DEFAULT_IMAGE_TAG,
+            (None, digest) => digest.is_none(),
+        }
+    }
+}
+
+/// Parses a container image reference into registry, repository, tag and digest.
+///
+/// # Example
+/// ```ignore
+/// let image = parse_image_ref("registry:5000/app@sha256:abcd");
+/// assert_eq!(image.registry, "registry:5000");
+/// assert_eq!(image.digest.as_deref(), Some("sha256:abcd"));
+/// ```
+pub fn parse_image_ref(image: &str) -> ImageRef {
+    let (name, digest) = match image.split_once('@') {
+        Some((name, digest)) => (name, Some(digest.to_string())),
+        None => (image, None),
+    };
+
+    // A tag follows the last ':' only if it comes after the last '/';
+    // otherwise the colon belongs to a registry port.
+    let (name, tag) = match name.rfind(':') {
+        Some(colon) if name.rfind('/').is_none_or(|slash| colon > slash) => {
+            (&name[..colon], Some(name[colon + 1..].to_string()))
+        }
+        _ => (name, None),
+    };
+
+    let (registry, repository) = match name.split_once('/') {
+        Some((first, rest))
+            if first.contains('.') || first.contains(':') || first == "localhost" =>
+        {
+            (first, rest)
+        }
+        _ => (DEFAULT_IMAGE_REGISTRY, name),
+    };
+    let registry = if registry == LEGACY_DEFAULT_REGISTRY {
+        DEFAULT_IMAGE_REGISTRY
+    } else {
+        registry
+    };
+
+    let repository = if registry == DEFAULT_IMAGE_REGISTRY && !repository.contains('/') {
+        format!("{}{}", OFFICIAL_REPOSITORY_PREFIX, repository)
+    } else {
+        repository.to_string()
+    };
+
+    ImageRef {
+        registry: registry.to_string(),
+        repository,
+        tag: tag.filter(|tag| !tag.is_empty()),
+        digest: digest.filter(|digest| !digest.is_empty()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_image_ref_short_name() {
+        let image = parse_image_ref("nginx");
+        assert_eq!(image.registry, "docker.io");
+        assert_eq!(image.repository, "library/nginx");
+        assert_eq!(image.tag, None);
+        assert_eq!(image.digest, None);
+        assert_eq!(image.name(), "docker.io/library/nginx");
+        assert!(image.is_latest());
+    }
+
+    #[test]
+    fn test_parse_image_ref_with_tag() {
+        let image = parse_image_ref("nginx:1.25");
+        assert_eq!(image.repository, "library/nginx");
+        assert_eq!(image.tag.as_deref(), Some("1.25"));
+        assert!(!image.is_latest());
+
+        assert!(parse_image_ref("nginx:latest").is_latest());
+    }
+
+    #[test]
+    fn test_parse_image_ref_registry_port_and_digest() {
+        let image = parse_image_ref("registry:5000/app@sha256:abcd");
+        assert_eq!(image.registry, "registry:5000");
+        assert_eq!(image.repository, "app");
+        assert_eq!(image.tag, None);
+        assert_eq!(image.digest.as_deref(), Some("sha256:abcd"));
+        assert!(!image.is_latest());
+
+        let image = parse_image_ref("registry:5000/team/app");
+        assert_eq!(image.registry, "registry:5000");
+        assert_eq!(image.repository, "team/app");
+        assert!(image.is_latest());
+    }
+
+    #[test]
+    fn test_parse_image_ref_user_repository() {
+        let image = parse_image_ref("bitnami/redis:7.2");
+        assert_eq!(image.registry, "docker.io");
+        assert_eq!(image.repository, "bitnami/redis");
+        assert_eq!(image.tag.as_deref(), Some("7.2"));
+    }
+}
@@ -6,6 +6,9 @@ use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 use super::time::Timestamp;
+use super::validation::{
+    BadValue, ErrorList, Path, invalid, is_dns1123_label, is_dns1123_subdomain, required,
+};
 use crate::impl_unimplemented_prost_message;
 
 /// TypeMeta describes an individual object in an API response or request
@@ -152,7 +155,8 @@ pub struct ObjectMeta {
     pub deletion_grace_period_seconds: Option<i64>,
 }
 
-impl_unimplemented_prost_message!(ObjectMeta);
+/// Annotation in which `kubectl apply` records the last applied configuration.
+pub const LAST_APPLIED_CONFIG_ANNOTATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
 
 impl ObjectMeta {
     /// Returns true when the metadata contains no non-default fields.
@@ -199,11 +203,99 @@ impl ObjectMeta {
     pub fn deletion_grace_period_seconds(&self) -> i64 {
         self.deletion_grace_period_seconds.unwrap_or(0)
     }
+
+    /// Returns true if `finalizer` is present.
+    pub fn has_finalizer(&self, finalizer: &str) -> bool {
+        self.finalizers.iter().any(|f| f == finalizer)
+    }
+
+    /// Appends `finalizer` unless it is already present. Returns whether the
+    /// finalizers changed (mirrors controllerutil.AddFinalizer).
+    pub fn add_finalizer(&mut self, finalizer: &str) -> bool {
+        if self.has_finalizer(finalizer) {
+            return false;
+        }
+        self.finalizers.push(finalizer.to_string());
+        true
+    }
+
+    /// Removes every occurrence of `finalizer`. Returns whether the finalizers
+    /// changed (mirrors controllerutil.RemoveFinalizer).
+    pub fn remove_finalizer(&mut self, finalizer: &str) -> bool {
+        let len = self.finalizers.len();
+        self.finalizers.retain(|f| f != finalizer);
+        self.finalizers.len() != len
+    }
+
+    /// Returns the configuration recorded by `kubectl apply`, if any.
+    pub fn last_applied_configuration(&self) -> Option<&str> {
+        self.annotations
+            .get(LAST_APPLIED_CONFIG_ANNOTATION)
+            .map(String::as_str)
+    }
+
+    /// Removes the `kubectl apply` last-applied annotation, e.g. before diffing.
+    pub fn strip_last_applied(&mut self) {
+        self.annotations.remove(LAST_APPLIED_CONFIG_ANNOTATION);
+    }
+
+    /// Applies the user-mutable fields of `incoming` to this (stored) metadata.
+    ///
+    /// Labels, annotations, owner references and finalizers are taken from
+    /// `incoming`. Identity and server-managed fields (name, namespace, uid,
+    /// resourceVersion, generation, creation and deletion timestamps, managed
+    /// fields) keep their stored values, as an update handler would enforce.
+    pub fn merge_for_update(&mut self, incoming: &ObjectMeta) {
+        self.labels = incoming.labels.clone();
+        self.annotations = incoming.annotations.clone();
+        self.owner_references = incoming.owner_references.clone();
+        self.finalizers = incoming.finalizers.clone();
+    }
+}
+
+/// Merges the labels and annotations of an applied configuration into live metadata.
+///
+/// This is the metadata portion of `kubectl apply`'s three-way merge: keys that
+/// were in `last_applied` but have been dropped from `original` are removed,
+/// keys set in `original` take its values, and keys only present in `live`
+/// (added by the server or other clients) are kept. Every other field comes from
+/// `live`.
+pub fn three_way_merge_meta(
+    original: &ObjectMeta,
+    last_applied: &ObjectMeta,
+    live: &ObjectMeta,
+) -> ObjectMeta {
+    let mut merged = live.clone();
+    merged.labels = three_way_merge_map(&original.labels, &last_applied.labels, &live.labels);
+    merged.annotations = three_way_merge_map(
+        &original.annotations,
+        &last_applied.annotations,
+        &live.annotations,
+    );
+    merged
+}
+
+fn three_way_merge_map(
+    original: &BTreeMap<String, String>,
+    last_applied: &BTreeMap<String, String>,
+    live: &BTreeMap<String, String>,
+) -> BTreeMap<String, String> {
+    let mut merged: BTreeMap<String, String> = live
+        .iter()
+        .filter(|(key, _)| !last_applied.contains_key(*key) || original.contains_key(*key))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    merged.extend(
+        original
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone())),
+    );
+    merged
 }
 
 /// ManagedFieldsEntry is a workflow-id, a FieldSet and the group version of the resource
 /// that the fieldset applies to.
-#[derive(Serialize, Deserialize, Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, Hash, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ManagedFieldsEntry {
     /// Manager is an identifier of the workflow managing these fields.
@@ -272,8 +364,6 @@ pub struct OwnerReference {
     pub block_owner_deletion: Option<bool>,
 }
 
-impl_unimplemented_prost_message!(OwnerReference);
-
 /// Condition defines an observation of a resource's state.
 ///
 /// Corresponds to [Kubernetes Condition](https://github.com/kubernetes/apimachinery/blob/master/pkg/apis/meta/v1/types.go#L1339)
@@ -322,7 +412,7 @@ pub struct LabelSelector {
 /// LabelSelectorRequirement is a selector that contains values, a key, and an operator.
 ///
 /// Corresponds to [Kubernetes LabelSelectorRequirement](https://github.com/kubernetes/apimachinery/blob/master/pkg/apis/meta/v1/types.go#L1246)
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct LabelSelectorRequirement {
     /// key is the label key that the selector applies to.
@@ -348,6 +438,37 @@ pub mod label_selector_operator {
     pub const DOES_NOT_EXIST: &str = "DoesNotExist";
 }
 
+impl LabelSelector {
+    /// Returns true if both selectors select the same set of labels.
+    ///
+    /// `matchLabels` entries are treated as `In` requirements with a single value
+    /// (as `LabelSelectorAsSelector` does), and requirements as well as their
+    /// `values` are compared regardless of order or duplicates.
+    pub fn is_equivalent(&self, other: &LabelSelector) -> bool {
+        self.normalized_requirements() == other.normalized_requirements()
+    }
+
+    fn normalized_requirements(&self) -> Vec<(&str, &str, Vec<&str>)> {
+        let labels = self.match_labels.iter().map(|(key, value)| {
+            (
+                key.as_str(),
+                label_selector_operator::IN,
+                vec![value.as_str()],
+            )
+        });
+        let expressions = self.match_expressions.iter().map(|req| {
+            let mut values: Vec<&str> = req.values.iter().map(String::as_str).collect();
+            values.sort_unstable();
+            values.dedup();
+            (req.key.as_str(), req.operator.as_str(), values)
+        });
+        let mut requirements: Vec<_> = labels.chain(expressions).collect();
+        requirements.sort_unstable();
+        requirements.dedup();
+        requirements
+    }
+}
+
 /// FieldSelectorRequirement is a selector that contains values, a key, and an operator.
 ///
 /// Corresponds to [Kubernetes FieldSelectorRequirement](https://github.com/kubernetes/apimachinery/blob/master/pkg/apis/meta/v1/types.go#L1283)
@@ -411,6 +532,69 @@ pub struct GroupVersionResource {
     pub resource: String,
 }
 
+impl GroupVersionResource {
+    /// Returns the REST path for this resource.
+    ///
+    /// Core resources live under `/api/{version}` and grouped resources under
+    /// `/apis/{group}/{version}`. A namespace adds `/namespaces/{namespace}`
+    /// and a name selects a single object; without a name the path lists the
+    /// collection.
+    pub fn request_path(&self, namespace: Option<&str>, name: Option<&str>) -> String {
+        let mut path = if self.group.is_empty() {
+            format!("/api/{}", self.version)
+        } else {
+            format!("/apis/{}/{}", self.group, self.version)
+        };
+        if let Some(namespace) = namespace.filter(|ns| !ns.is_empty()) {
+            path.push_str("/namespaces/");
+            path.push_str(namespace);
+        }
+        path.push('/');
+        path.push_str(&self.resource);
+        if let Some(name) = name.filter(|name| !name.is_empty()) {
+            path.push('/');
+            path.push_str(name);
+        }
+        path
+    }
+
+    /// Validates that the GVR can be used to build a request path.
+    ///
+    /// The group must be empty or a DNS subdomain, the version a DNS label, and
+    /// the resource a lowercase DNS label such as `deployments` (a Kind such as
+    /// `Deployment` is rejected). Whether the resource name is plural is not
+    /// checked.
+    pub fn validate(&self) -> ErrorList {
+        let mut all_errs = ErrorList::new();
+
+        if !self.group.is_empty() {
+            for msg in is_dns1123_subdomain(&self.group) {
+                all_errs.push(invalid(
+                    &Path::new("group"),
+                    BadValue::String(self.group.clone()),
+                    &msg,
+                ));
+            }
+        }
+
+        for (field, value) in [("version", &self.version), ("resource", &self.resource)] {
+            if value.is_empty() {
+                all_errs.push(required(&Path::new(field), ""));
+                continue;
+            }
+            for msg in is_dns1123_label(value) {
+                all_errs.push(invalid(
+                    &Path::new(field),
+                    BadValue::String(value.clone()),
+                    &msg,
+                ));
+            }
+        }
+
+        all_errs
+    }
+}
+
 /// GroupResource identifies a resource by group and resource name.
 ///
 /// Corresponds to [Kubernetes GroupResource](https://github.com/kubernetes/apimachinery/blob/master/pkg/apis/meta/v1/types.go#L1198)
@@ -499,5 +683,340 @@ pub mod status {
     pub const FAILURE: &str = "Failure";
 }
 
+/// DeletionPropagation decides whether and how garbage collection is performed
+/// for the dependents of a deleted object.
+///
+/// Corresponds to [Kubernetes DeletionPropagation](https://github.com/kubernetes/apimachinery/blob/master/pkg/apis/meta/v1/types.go#L530)
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub enum DeletionPropagation {
+    /// Orphan the dependents.
+    Orphan,
+
+    /// Delete the object immediately and let the garbage collector delete the
+    /// dependents in the background.
+    Background,
+
+    /// Keep the object in the key-value store until the garbage collector has
+    /// deleted all foreground dependents.
+    Foreground,
+}
+
+/// DeletionPropagation constants
+pub mod deletion_propagation {
+    /// Orphan the dependents
+    pub const ORPHAN: &str = "Orphan";
+
+    /// Delete the dependents in the background
+    pub const BACKGROUND: &str = "Background";
+
+    /// Delete the dependents before the owner
+    pub const FOREGROUND: &str = "Foreground";
+}
+
+/// Preconditions must be fulfilled before an operation (update, delete, etc.) is carried out.
+///
+/// Corresponds to [Kubernetes Preconditions](https://github.com/kubernetes/apimachinery/blob/master/pkg/apis/meta/v1/types.go#L572)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Preconditions {
+    /// Specifies the target UID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+    /// Specifies the target ResourceVersion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_version: Option<String>,
+}
+
+/// DeleteOptions may be provided when deleting an API object.
+///
+/// Corresponds to [Kubernetes DeleteOptions](https://github.com/kubernetes/apimachinery/blob/master/pkg/apis/meta/v1/types.go#L482)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteOptions {
+    /// TypeMeta describes the type of this object.
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// The duration in seconds before the object should be deleted. Zero means
+    /// delete immediately; unset uses the default grace period for the type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grace_period_seconds: Option<i64>,
+    /// Must be fulfilled before a deletion is carried out.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preconditions: Option<Preconditions>,
+    /// Whether and how garbage collection will be performed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub propagation_policy: Option<DeletionPropagation>,
+    /// When present, indicates that modifications should not be persisted.
+    /// The only valid value is "All".
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dry_run: Vec<String>,
+}
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    fn meta(annotations: &[(&str, &str)]) -> ObjectMeta {
+        ObjectMeta {
+            name: Some("web".to_string()),
+            annotations: annotations
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn requirement(key: &str, operator: &str, values: &[&str]) -> LabelSelectorRequirement {
+        LabelSelectorRequirement {
+            key: key.to_string(),
+            operator: operator.to_string(),
+            values: values.iter().map(|value| value.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_label_selector_is_equivalent_reordered_expressions() {
+        let a = LabelSelector {
+            match_labels: BTreeMap::from([("app".to_string(), "web".to_string())]),
+            match_expressions: vec![
+                requirement("tier", label_selector_operator::IN, &["frontend"]),
+                requirement("canary", label_selector_operator::DOES_NOT_EXIST, &[]),
+            ],
+        };
+        let b = LabelSelector {
+            match_labels: BTreeMap::from([("app".to_string(), "web".to_string())]),
+            match_expressions: vec![
+                requirement("canary", label_selector_operator::DOES_NOT_EXIST, &[]),
+                requirement("tier", label_selector_operator::IN, &["frontend"]),
+            ],
+        };
+        assert!(a.is_equivalent(&b));
+
+        // matchLabels is shorthand for a single-value In requirement.
+        let c = LabelSelector {
+            match_expressions: vec![
+                requirement("canary", label_selector_operator::DOES_NOT_EXIST, &[]),
+                requirement("app", label_selector_operator::IN, &["web"]),
+                requirement("tier", label_selector_operator::IN, &["frontend"]),
+            ],
+            ..Default::default()
+        };
+        assert!(a.is_equivalent(&c));
+
+        let d = LabelSelector {
+            match_expressions: vec![requirement(
+                "tier",
+                label_selector_operator::NOT_IN,
+                &["frontend"],
+            )],
+            ..a.clone()
+        };
+        assert!(!a.is_equivalent(&d));
+    }
+
+    #[test]
+    fn test_label_selector_is_equivalent_reordered_values() {
+        let a = LabelSelector {
+            match_expressions: vec![requirement(
+                "env",
+                label_selector_operator::IN,
+                &["prod", "staging", "dev"],
+            )],
+            ..Default::default()
+        };
+        let b = LabelSelector {
+            match_expressions: vec![requirement(
+                "env",
+                label_selector_operator::IN,
+                &["dev", "prod", "staging", "prod"],
+            )],
+            ..Default::default()
+        };
+        assert!(a.is_equivalent(&b));
+
+        let c = LabelSelector {
+            match_expressions: vec![requirement(
+                "env",
+                label_selector_operator::IN,
+                &["dev", "prod"],
+            )],
+            ..Default::default()
+        };
+        assert!(!a.is_equivalent(&c));
+    }
+
+    fn gvr(group: &str, version: &str, resource: &str) -> GroupVersionResource {
+        GroupVersionResource {
+            group: group.to_string(),
+            version: version.to_string(),
+            resource: resource.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_gvr_request_path_core_namespaced() {
+        let pods = gvr("", "v1", "pods");
+        assert!(pods.validate().is_empty());
+        assert_eq!(
+            pods.request_path(Some("kube-system"), Some("coredns-0")),
+            "/api/v1/namespaces/kube-system/pods/coredns-0"
+        );
+    }
+
+    #[test]
+    fn test_gvr_request_path_grouped_cluster_scoped() {
+        let cluster_roles = gvr("rbac.authorization.k8s.io", "v1", "clusterroles");
+        assert!(cluster_roles.validate().is_empty());
+        assert_eq!(
+            cluster_roles.request_path(None, Some("view")),
+            "/apis/rbac.authorization.k8s.io/v1/clusterroles/view"
+        );
+    }
+
+    #[test]
+    fn test_gvr_request_path_list() {
+        let deployments = gvr("apps", "v1", "deployments");
+        assert_eq!(
+            deployments.request_path(Some("default"), None),
+            "/apis/apps/v1/namespaces/default/deployments"
+        );
+        assert_eq!(
+            deployments.request_path(None, None),
+            "/apis/apps/v1/deployments"
+        );
+
+        let errs = gvr("apps", "v1", "Deployment").validate();
+        assert_eq!(errs.len(), 1, "{:?}", errs);
+        assert_eq!(errs.errors[0].field, "resource");
+    }
+
+    #[test]
+    fn test_add_finalizer_is_idempotent() {
+        let mut meta = ObjectMeta::default();
+        assert!(!meta.has_finalizer("example.com/cleanup"));
+
+        assert!(meta.add_finalizer("example.com/cleanup"));
+        assert!(!meta.add_finalizer("example.com/cleanup"));
+        assert!(meta.has_finalizer("example.com/cleanup"));
+        assert_eq!(meta.finalizers, vec!["example.com/cleanup".to_string()]);
+    }
+
+    #[test]
+    fn test_remove_finalizer() {
+        let mut meta = ObjectMeta {
+            finalizers: vec![
+                "example.com/cleanup".to_string(),
+                "kubernetes".to_string(),
+                "example.com/cleanup".to_string(),
+            ],
+            ..Default::default()
+        };
+
+        assert!(!meta.remove_finalizer("example.com/missing"));
+        assert_eq!(meta.finalizers.len(), 3);
+
+        assert!(meta.remove_finalizer("example.com/cleanup"));
+        assert_eq!(meta.finalizers, vec!["kubernetes".to_string()]);
+        assert!(!meta.remove_finalizer("example.com/cleanup"));
+    }
+
+    #[test]
+    fn test_merge_for_update_preserves_resource_version() {
+        let mut stored = ObjectMeta {
+            name: Some("web".to_string()),
+            uid: Some("6f1c1b7e-0a4d-4a3f-9c1e-2b5d8f0e7a11".to_string()),
+            resource_version: Some("42".to_string()),
+            labels: BTreeMap::from([("app".to_string(), "web".to_string())]),
+            ..Default::default()
+        };
+        let incoming = ObjectMeta {
+            name: Some("web".to_string()),
+            resource_version: Some("7".to_string()),
+            labels: BTreeMap::from([
+                ("app".to_string(), "web".to_string()),
+                ("tier".to_string(), "frontend".to_string()),
+            ]),
+            ..Default::default()
+        };
+
+        stored.merge_for_update(&incoming);
+        assert_eq!(stored.labels, incoming.labels);
+        assert_eq!(stored.resource_version(), "42");
+        assert_eq!(stored.uid(), "6f1c1b7e-0a4d-4a3f-9c1e-2b5d8f0e7a11");
+    }
+
+    #[test]
+    fn test_strip_last_applied() {
+        let mut metadata = meta(&[
+            (LAST_APPLIED_CONFIG_ANNOTATION, r#"{"kind":"ConfigMap"}"#),
+            ("team", "a"),
+        ]);
+        assert_eq!(
+            metadata.last_applied_configuration(),
+            Some(r#"{"kind":"ConfigMap"}"#)
+        );
+
+        metadata.strip_last_applied();
+        assert_eq!(metadata.last_applied_configuration(), None);
+        assert_eq!(metadata.annotations, meta(&[("team", "a")]).annotations);
+    }
+
+    #[test]
+    fn test_three_way_merge_meta_removes_dropped_annotation() {
+        let last_applied = meta(&[("team", "a"), ("owner", "alice")]);
+        let original = meta(&[("team", "b")]);
+        let live = meta(&[("team", "a"), ("owner", "alice")]);
+
+        let merged = three_way_merge_meta(&original, &last_applied, &live);
+        assert_eq!(merged.annotations, meta(&[("team", "b")]).annotations);
+    }
+
+    #[test]
+    fn test_three_way_merge_meta_preserves_server_added_annotation() {
+        let last_applied = meta(&[("team", "a")]);
+        let original = meta(&[("team", "a"), ("tier", "web")]);
+        let mut live = meta(&[("team", "a"), ("deployment.kubernetes.io/revision", "3")]);
+        live.resource_version = Some("42".to_string());
+        live.labels = BTreeMap::from([("app".to_string(), "web".to_string())]);
+
+        let merged = three_way_merge_meta(&original, &last_applied, &live);
+        assert_eq!(
+            merged.annotations,
+            meta(&[
+                ("deployment.kubernetes.io/revision", "3"),
+                ("team", "a"),
+                ("tier", "web"),
+            ])
+            .annotations
+        );
+        assert_eq!(merged.labels, live.labels);
+        assert_eq!(merged.resource_version.as_deref(), Some("42"));
+    }
+
+    #[test]
+    fn test_delete_options_foreground_with_uid_precondition() {
+        let options = DeleteOptions {
+            grace_period_seconds: Some(0),
+            preconditions: Some(Preconditions {
+                uid: Some("5b0a8b3c-3f1e-4a52-9c1d-7f2e6b8d4a10".to_string()),
+                ..Default::default()
+            }),
+            propagation_policy: Some(DeletionPropagation::Foreground),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&options).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "gracePeriodSeconds": 0,
+                "preconditions": {"uid": "5b0a8b3c-3f1e-4a52-9c1d-7f2e6b8d4a10"},
+                "propagationPolicy": deletion_propagation::FOREGROUND
+            })
+        );
+        let decoded: DeleteOptions = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, options);
+    }
+}
@@ -4,7 +4,15 @@
 //! different Kubernetes API versions and groups.
 
 pub mod compat;
+pub mod decode;
+pub mod hash;
+pub mod image;
 pub mod meta;
+pub mod patch;
+mod proto;
+pub mod quota;
+pub mod reference;
+pub mod table;
 #[cfg(test)]
 pub mod test_fixtures;
 #[cfg(test)]
@@ -14,16 +22,26 @@ pub mod traits;
 pub mod util;
 pub mod validation;
 pub mod volume;
+pub mod watch;
 
+pub use decode::decode_and_default;
+pub use hash::spec_hash;
+pub use image::{ImageRef, parse_image_ref};
 pub use meta::{
-    Condition, FieldSelectorRequirement, GroupResource, GroupVersionKind, GroupVersionResource,
-    LabelSelector, LabelSelectorRequirement, ListMeta, ManagedFieldsEntry, ObjectMeta,
-    OwnerReference, Status, StatusCause, StatusDetails, TypeMeta,
+    Condition, DeleteOptions, DeletionPropagation, FieldSelectorRequirement, GroupResource,
+    GroupVersionKind, GroupVersionResource, LabelSelector, LabelSelectorRequirement, ListMeta,
+    ManagedFieldsEntry, ObjectMeta, OwnerReference, Preconditions, Status, StatusCause,
+    StatusDetails, TypeMeta, deletion_propagation, three_way_merge_meta,
 };
+pub use patch::{FieldManager, HasStatus, status_patch};
+pub use reference::object_reference_to;
+pub use table::{Table, TableColumnDefinition, TableRow, TableRowCondition};
 pub use time::{MicroTime, Timestamp};
 pub use traits::*;
 pub use util::{IntOrString, Quantity, is_false, is_zero_i32};
+pub use validation::names;
 pub use volume::{
     PersistentVolumeReclaimPolicy, PersistentVolumeSpec, TopologySelectorLabelRequirement,
     TopologySelectorTerm,
 };
+pub use watch::{ResourceVersionTracker, WatchEvent, watch_event_type};
@@ -0,0 +1,143 @@
+//! Patch helpers
+//!
+//! Builds JSON merge patches (RFC 7386) for use with `PATCH` requests, and the
+//! query parameters of server-side apply requests.
+
+use serde::Serialize;
+
+/// Exposes the status subobject of a resource that has a `status` subresource.
+pub trait HasStatus {
+    /// The status type of the resource.
+    type Status: Serialize;
+
+    /// Returns the status, if set.
+    fn status(&self) -> Option<&Self::Status>;
+}
+
+/// Returns a merge patch for the status subresource that carries only the
+/// `status` of `obj`.
+///
+/// An unset status is sent as an empty object rather than `null`, since a
+/// `null` member in a merge patch deletes the field. Returns an error if the
+/// status cannot be serialized to JSON.
+pub fn status_patch<T: Serialize + HasStatus>(
+    obj: &T,
+) -> Result<serde_json::Value, serde_json::Error> {
+    let status = match obj.status() {
+        Some(status) => serde_json::to_value(status)?,
+        None => serde_json::Value::Object(Default::default()),
+    };
+    Ok(serde_json::json!({ "status": status }))
+}
+
+/// Identifies the actor that owns the fields set by a server-side apply request.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct FieldManager {
+    /// Name of the field manager, e.g. the controller name.
+    pub name: String,
+    /// Force takes ownership of fields that conflict with other managers.
+    pub force: bool,
+}
+
+impl FieldManager {
+    /// Creates a field manager that does not force conflicting fields.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            force: false,
+        }
+    }
+
+    /// Returns the query string for an apply request (`fieldManager=...`,
+    /// plus `force=true` when forcing). The name is percent-encoded.
+    pub fn query(&self) -> String {
+        let name = encode_query_component(&self.name);
+        if self.force {
+            format!("fieldManager={}&force=true", name)
+        } else {
+            format!("fieldManager={}", name)
+        }
+    }
+}
+
+/// Percent-encodes every byte outside the RFC 3986 unreserved set.
+fn encode_query_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apps::v1::{Deployment, DeploymentSpec, DeploymentStatus};
+    use crate::common::ObjectMeta;
+    use crate::core::v1::{Node, Pod};
+
+    #[test]
+    fn test_status_patch_contains_only_status() {
+        let deployment = Deployment {
+            metadata: Some(ObjectMeta {
+                name: Some("web".to_string()),
+                ..Default::default()
+            }),
+            spec: Some(DeploymentSpec {
+                replicas: Some(3),
+                ..Default::default()
+            }),
+            status: Some(DeploymentStatus {
+                observed_generation: Some(2),
+                ready_replicas: Some(3),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let patch = status_patch(&deployment).unwrap();
+        assert_eq!(
+            patch,
+            serde_json::json!({"status": {"observedGeneration": 2, "readyReplicas": 3}})
+        );
+    }
+
+    #[test]
+    fn test_status_patch_pod_and_node() {
+        let pod: Pod = serde_json::from_value(serde_json::json!({
+            "metadata": {"name": "web-0"},
+            "spec": {"containers": [{"name": "app", "image": "nginx"}]},
+            "status": {"phase": "Running"}
+        }))
+        .unwrap();
+        assert_eq!(
+            status_patch(&pod).unwrap(),
+            serde_json::json!({"status": {"phase": "Running"}})
+        );
+
+        let node: Node = serde_json::from_value(serde_json::json!({
+            "metadata": {"name": "node-1"},
+            "spec": {"unschedulable": true}
+        }))
+        .unwrap();
+        let patch = status_patch(&node).unwrap();
+        assert_eq!(patch, serde_json::json!({"status": {}}));
+        assert_eq!(patch.as_object().map(|obj| obj.len()), Some(1));
+    }
+
+    #[test]
+    fn test_field_manager_query_encodes_name() {
+        assert_eq!(
+            FieldManager::new("kube-controller_v1.2~x").query(),
+            "fieldManager=kube-controller_v1.2~x"
+        );
+        assert_eq!(
+            FieldManager::new("my manager&force=true/é").query(),
+            "fieldManager=my%20manager%26force%3Dtrue%2F%C3%A9"
+        );
+    }
+}
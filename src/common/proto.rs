@@ -0,0 +1,921 @@
+//! Protobuf encodings for shared metadata types
+//!
+//! Field numbers follow k8s.io/apimachinery/pkg/apis/meta/v1/generated.proto,
+//! and pkg/api/resource and pkg/util/intstr for Quantity and IntOrString.
+//! Optional fields are written only when set, so presence survives a round trip.
+
+use chrono::DateTime;
+use prost::bytes::{Buf, BufMut};
+use prost::encoding::{
+    DecodeContext, WireType, bool, btree_map, int32, int64, message, skip_field, string,
+};
+use prost::{DecodeError, Message};
+
+use super::meta::{
+    LabelSelector, LabelSelectorRequirement, ListMeta, ManagedFieldsEntry, ObjectMeta,
+    OwnerReference, Preconditions,
+};
+use super::time::{MicroTime, Timestamp};
+use super::util::{IntOrString, Quantity};
+
+// ============================================================================
+// Timestamp
+// ============================================================================
+
+impl Message for Timestamp {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        int64::encode(1, &self.0.timestamp(), buf);
+        int32::encode(2, &(self.0.timestamp_subsec_nanos() as i32), buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        let mut seconds = self.0.timestamp();
+        let mut nanos = self.0.timestamp_subsec_nanos() as i32;
+        match tag {
+            1 => int64::merge(wire_type, &mut seconds, buf, ctx)?,
+            2 => int32::merge(wire_type, &mut nanos, buf, ctx)?,
+            _ => return skip_field(wire_type, tag, buf, ctx),
+        }
+        let nanos = u32::try_from(nanos).map_err(|_| DecodeError::new("negative nanos"))?;
+        self.0 = DateTime::from_timestamp(seconds, nanos)
+            .ok_or_else(|| DecodeError::new("timestamp out of range"))?;
+        Ok(())
+    }
+
+    fn encoded_len(&self) -> usize {
+        int64::encoded_len(1, &self.0.timestamp())
+            + int32::encoded_len(2, &(self.0.timestamp_subsec_nanos() as i32))
+    }
+
+    fn clear(&mut self) {
+        *self = Timestamp::default();
+    }
+}
+
+// ============================================================================
+// MicroTime
+// ============================================================================
+
+/// Encoded like Timestamp; the nanos field carries the sub-second precision.
+impl Message for MicroTime {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        int64::encode(1, &self.0.timestamp(), buf);
+        int32::encode(2, &(self.0.timestamp_subsec_nanos() as i32), buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        let mut seconds = self.0.timestamp();
+        let mut nanos = self.0.timestamp_subsec_nanos() as i32;
+        match tag {
+            1 => int64::merge(wire_type, &mut seconds, buf, ctx)?,
+            2 => int32::merge(wire_type, &mut nanos, buf, ctx)?,
+            _ => return skip_field(wire_type, tag, buf, ctx),
+        }
+        let nanos = u32::try_from(nanos).map_err(|_| DecodeError::new("negative nanos"))?;
+        self.0 = DateTime::from_timestamp(seconds, nanos)
+            .ok_or_else(|| DecodeError::new("timestamp out of range"))?;
+        Ok(())
+    }
+
+    fn encoded_len(&self) -> usize {
+        int64::encoded_len(1, &self.0.timestamp())
+            + int32::encoded_len(2, &(self.0.timestamp_subsec_nanos() as i32))
+    }
+
+    fn clear(&mut self) {
+        *self = MicroTime::default();
+    }
+}
+
+// ============================================================================
+// ObjectMeta
+// ============================================================================
+
+impl Message for ObjectMeta {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.name {
+            string::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.generate_name {
+            string::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.namespace {
+            string::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.self_link {
+            string::encode(4, value, buf);
+        }
+        if let Some(ref value) = self.uid {
+            string::encode(5, value, buf);
+        }
+        if let Some(ref value) = self.resource_version {
+            string::encode(6, value, buf);
+        }
+        if let Some(ref value) = self.generation {
+            int64::encode(7, value, buf);
+        }
+        if let Some(ref value) = self.creation_timestamp {
+            message::encode(8, value, buf);
+        }
+        if let Some(ref value) = self.deletion_timestamp {
+            message::encode(9, value, buf);
+        }
+        if let Some(ref value) = self.deletion_grace_period_seconds {
+            int64::encode(10, value, buf);
+        }
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            string::encode,
+            string::encoded_len,
+            11,
+            &self.labels,
+            buf,
+        );
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            string::encode,
+            string::encoded_len,
+            12,
+            &self.annotations,
+            buf,
+        );
+        message::encode_repeated(13, &self.owner_references, buf);
+        string::encode_repeated(14, &self.finalizers, buf);
+        message::encode_repeated(17, &self.managed_fields, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, self.name.get_or_insert_default(), buf, ctx),
+            2 => string::merge(
+                wire_type,
+                self.generate_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            3 => string::merge(wire_type, self.namespace.get_or_insert_default(), buf, ctx),
+            4 => string::merge(wire_type, self.self_link.get_or_insert_default(), buf, ctx),
+            5 => string::merge(wire_type, self.uid.get_or_insert_default(), buf, ctx),
+            6 => string::merge(
+                wire_type,
+                self.resource_version.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            7 => int64::merge(wire_type, self.generation.get_or_insert_default(), buf, ctx),
+            8 => message::merge(
+                wire_type,
+                self.creation_timestamp.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            9 => message::merge(
+                wire_type,
+                self.deletion_timestamp.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            10 => int64::merge(
+                wire_type,
+                self.deletion_grace_period_seconds.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            11 => btree_map::merge(string::merge, string::merge, &mut self.labels, buf, ctx),
+            12 => btree_map::merge(
+                string::merge,
+                string::merge,
+                &mut self.annotations,
+                buf,
+                ctx,
+            ),
+            13 => message::merge_repeated(wire_type, &mut self.owner_references, buf, ctx),
+            14 => string::merge_repeated(wire_type, &mut self.finalizers, buf, ctx),
+            17 => message::merge_repeated(wire_type, &mut self.managed_fields, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.name.as_ref().map_or(0, |v| string::encoded_len(1, v))
+            + self
+                .generate_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(2, v))
+            + self
+                .namespace
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(3, v))
+            + self
+                .self_link
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(4, v))
+            + self.uid.as_ref().map_or(0, |v| string::encoded_len(5, v))
+            + self
+                .resource_version
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(6, v))
+            + self
+                .generation
+                .as_ref()
+                .map_or(0, |v| int64::encoded_len(7, v))
+            + self
+                .creation_timestamp
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(8, v))
+            + self
+                .deletion_timestamp
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(9, v))
+            + self
+                .deletion_grace_period_seconds
+                .as_ref()
+                .map_or(0, |v| int64::encoded_len(10, v))
+            + btree_map::encoded_len(string::encoded_len, string::encoded_len, 11, &self.labels)
+            + btree_map::encoded_len(
+                string::encoded_len,
+                string::encoded_len,
+                12,
+                &self.annotations,
+            )
+            + message::encoded_len_repeated(13, &self.owner_references)
+            + string::encoded_len_repeated(14, &self.finalizers)
+            + message::encoded_len_repeated(17, &self.managed_fields)
+    }
+
+    fn clear(&mut self) {
+        *self = ObjectMeta::default();
+    }
+}
+
+// ============================================================================
+// ListMeta
+// ============================================================================
+
+impl Message for ListMeta {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.self_link {
+            string::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.resource_version {
+            string::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.continue_ {
+            string::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.remaining_item_count {
+            int64::encode(4, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, self.self_link.get_or_insert_default(), buf, ctx),
+            2 => string::merge(
+                wire_type,
+                self.resource_version.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            3 => string::merge(wire_type, self.continue_.get_or_insert_default(), buf, ctx),
+            4 => int64::merge(
+                wire_type,
+                self.remaining_item_count.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.self_link
+            .as_ref()
+            .map_or(0, |v| string::encoded_len(1, v))
+            + self
+                .resource_version
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(2, v))
+            + self
+                .continue_
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(3, v))
+            + self
+                .remaining_item_count
+                .as_ref()
+                .map_or(0, |v| int64::encoded_len(4, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ListMeta::default();
+    }
+}
+
+// ============================================================================
+// OwnerReference
+// ============================================================================
+
+impl Message for OwnerReference {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.kind, buf);
+        string::encode(3, &self.name, buf);
+        string::encode(4, &self.uid, buf);
+        string::encode(5, &self.api_version, buf);
+        if let Some(ref value) = self.controller {
+            bool::encode(6, value, buf);
+        }
+        if let Some(ref value) = self.block_owner_deletion {
+            bool::encode(7, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.kind, buf, ctx),
+            3 => string::merge(wire_type, &mut self.name, buf, ctx),
+            4 => string::merge(wire_type, &mut self.uid, buf, ctx),
+            5 => string::merge(wire_type, &mut self.api_version, buf, ctx),
+            6 => bool::merge(wire_type, self.controller.get_or_insert_default(), buf, ctx),
+            7 => bool::merge(
+                wire_type,
+                self.block_owner_deletion.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.kind)
+            + string::encoded_len(3, &self.name)
+            + string::encoded_len(4, &self.uid)
+            + string::encoded_len(5, &self.api_version)
+            + self
+                .controller
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(6, v))
+            + self
+                .block_owner_deletion
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(7, v))
+    }
+
+    fn clear(&mut self) {
+        *self = OwnerReference::default();
+    }
+}
+
+// ============================================================================
+// Preconditions
+// ============================================================================
+
+impl Message for Preconditions {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.uid {
+            string::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.resource_version {
+            string::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, self.uid.get_or_insert_default(), buf, ctx),
+            2 => string::merge(
+                wire_type,
+                self.resource_version.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.uid.as_ref().map_or(0, |v| string::encoded_len(1, v))
+            + self
+                .resource_version
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = Preconditions::default();
+    }
+}
+
+// ============================================================================
+// ManagedFieldsEntry
+// ============================================================================
+
+/// FieldsV1 wire form: the raw JSON of the managed field set.
+#[derive(Clone, PartialEq, prost::Message)]
+struct FieldsV1 {
+    #[prost(bytes = "vec", tag = "1")]
+    raw: Vec<u8>,
+}
+
+impl FieldsV1 {
+    fn from_value(value: &serde_json::Value) -> Self {
+        Self {
+            raw: serde_json::to_vec(value).unwrap_or_default(),
+        }
+    }
+}
+
+impl Message for ManagedFieldsEntry {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.manager {
+            string::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.operation {
+            string::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.api_version {
+            string::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.time {
+            message::encode(4, value, buf);
+        }
+        if let Some(ref value) = self.fields_type {
+            string::encode(6, value, buf);
+        }
+        if let Some(ref value) = self.fields_v1 {
+            message::encode(7, &FieldsV1::from_value(value), buf);
+        }
+        if let Some(ref value) = self.subresource {
+            string::encode(8, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, self.manager.get_or_insert_default(), buf, ctx),
+            2 => string::merge(wire_type, self.operation.get_or_insert_default(), buf, ctx),
+            3 => string::merge(
+                wire_type,
+                self.api_version.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => message::merge(wire_type, self.time.get_or_insert_default(), buf, ctx),
+            6 => string::merge(
+                wire_type,
+                self.fields_type.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            7 => {
+                let mut fields = FieldsV1::default();
+                message::merge(wire_type, &mut fields, buf, ctx)?;
+                let value = serde_json::from_slice(&fields.raw)
+                    .map_err(|_| DecodeError::new("invalid fieldsV1 JSON"))?;
+                self.fields_v1 = Some(value);
+                Ok(())
+            }
+            8 => string::merge(
+                wire_type,
+                self.subresource.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.manager
+            .as_ref()
+            .map_or(0, |v| string::encoded_len(1, v))
+            + self
+                .operation
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(2, v))
+            + self
+                .api_version
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(3, v))
+            + self.time.as_ref().map_or(0, |v| message::encoded_len(4, v))
+            + self
+                .fields_type
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(6, v))
+            + self
+                .fields_v1
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(7, &FieldsV1::from_value(v)))
+            + self
+                .subresource
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(8, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ManagedFieldsEntry::default();
+    }
+}
+
+// ============================================================================
+// LabelSelector
+// ============================================================================
+
+impl Message for LabelSelector {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            string::encode,
+            string::encoded_len,
+            1,
+            &self.match_labels,
+            buf,
+        );
+        message::encode_repeated(2, &self.match_expressions, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => btree_map::merge(
+                string::merge,
+                string::merge,
+                &mut self.match_labels,
+                buf,
+                ctx,
+            ),
+            2 => message::merge_repeated(wire_type, &mut self.match_expressions, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        btree_map::encoded_len(
+            string::encoded_len,
+            string::encoded_len,
+            1,
+            &self.match_labels,
+        ) + message::encoded_len_repeated(2, &self.match_expressions)
+    }
+
+    fn clear(&mut self) {
+        *self = LabelSelector::default();
+    }
+}
+
+// ============================================================================
+// LabelSelectorRequirement
+// ============================================================================
+
+impl Message for LabelSelectorRequirement {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.key, buf);
+        string::encode(2, &self.operator, buf);
+        string::encode_repeated(3, &self.values, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.key, buf, ctx),
+            2 => string::merge(wire_type, &mut self.operator, buf, ctx),
+            3 => string::merge_repeated(wire_type, &mut self.values, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.key)
+            + string::encoded_len(2, &self.operator)
+            + string::encoded_len_repeated(3, &self.values)
+    }
+
+    fn clear(&mut self) {
+        *self = LabelSelectorRequirement::default();
+    }
+}
+
+// ============================================================================
+// Quantity
+// ============================================================================
+
+impl Message for Quantity {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.0, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.0, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.0)
+    }
+
+    fn clear(&mut self) {
+        *self = Quantity::default();
+    }
+}
+
+// ============================================================================
+// IntOrString
+// ============================================================================
+//
+// The wire message carries the type discriminator (0 = int, 1 = string) next
+// to both values. Go writes the discriminator first, so the variant is known
+// by the time a value arrives; a value for the other variant is ignored.
+
+impl Message for IntOrString {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        match self {
+            IntOrString::Int(value) => {
+                int64::encode(1, &0, buf);
+                int32::encode(2, value, buf);
+                string::encode(3, &String::new(), buf);
+            }
+            IntOrString::String(value) => {
+                int64::encode(1, &1, buf);
+                int32::encode(2, &0, buf);
+                string::encode(3, value, buf);
+            }
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => {
+                let mut kind = 0i64;
+                int64::merge(wire_type, &mut kind, buf, ctx)?;
+                match (kind, &*self) {
+                    (1, IntOrString::Int(_)) => *self = IntOrString::String(String::new()),
+                    (1, IntOrString::String(_)) => {}
+                    (_, IntOrString::String(_)) => *self = IntOrString::Int(0),
+                    (_, IntOrString::Int(_)) => {}
+                }
+                Ok(())
+            }
+            2 => {
+                let mut value = 0i32;
+                int32::merge(wire_type, &mut value, buf, ctx)?;
+                if let IntOrString::Int(current) = self {
+                    *current = value;
+                }
+                Ok(())
+            }
+            3 => {
+                let mut value = String::new();
+                string::merge(wire_type, &mut value, buf, ctx)?;
+                if let IntOrString::String(current) = self {
+                    *current = value;
+                }
+                Ok(())
+            }
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        match self {
+            IntOrString::Int(value) => {
+                int64::encoded_len(1, &0)
+                    + int32::encoded_len(2, value)
+                    + string::encoded_len(3, &String::new())
+            }
+            IntOrString::String(value) => {
+                int64::encoded_len(1, &1)
+                    + int32::encoded_len(2, &0)
+                    + string::encoded_len(3, value)
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        *self = IntOrString::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_object_meta_proto_roundtrip() {
+        let meta = ObjectMeta {
+            name: Some("web".to_string()),
+            namespace: Some("default".to_string()),
+            uid: Some("1234".to_string()),
+            generation: Some(3),
+            labels: BTreeMap::from([("app".to_string(), "web".to_string())]),
+            annotations: BTreeMap::from([("note".to_string(), String::new())]),
+            owner_references: vec![OwnerReference {
+                api_version: "apps/v1".to_string(),
+                kind: "ReplicaSet".to_string(),
+                name: "web-abc".to_string(),
+                uid: "5678".to_string(),
+                controller: Some(true),
+                block_owner_deletion: Some(false),
+            }],
+            finalizers: vec!["example.com/cleanup".to_string()],
+            creation_timestamp: Some(Timestamp::from_str("2024-01-15T10:00:00Z").unwrap()),
+            deletion_grace_period_seconds: Some(0),
+            ..Default::default()
+        };
+
+        let decoded = ObjectMeta::decode(meta.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, meta);
+    }
+
+    #[test]
+    fn test_micro_time_proto_roundtrip() {
+        let time = MicroTime::from_str("2024-01-15T10:00:00.123456Z").unwrap();
+        let bytes = time.encode_to_vec();
+        assert_eq!(bytes.len(), time.encoded_len());
+
+        let decoded = MicroTime::decode(bytes.as_slice()).unwrap();
+        assert_eq!(decoded, time);
+        assert_eq!(decoded.timestamp_subsec_micros(), 123456);
+    }
+
+    #[test]
+    fn test_preconditions_proto_roundtrip() {
+        let preconditions = Preconditions {
+            uid: Some("pod-uid".to_string()),
+            resource_version: Some("42".to_string()),
+        };
+        let decoded = Preconditions::decode(preconditions.encode_to_vec().as_slice()).unwrap();
+        assert_eq!(decoded, preconditions);
+
+        assert!(Preconditions::default().encode_to_vec().is_empty());
+    }
+
+    #[test]
+    fn test_int_or_string_proto_roundtrip() {
+        let port = IntOrString::Int(8080);
+        let bytes = port.encode_to_vec();
+        assert_eq!(bytes, [0x08, 0x00, 0x10, 0x90, 0x3f, 0x1a, 0x00]);
+        assert_eq!(IntOrString::decode(bytes.as_slice()).unwrap(), port);
+
+        let named = IntOrString::String("http".to_string());
+        let bytes = named.encode_to_vec();
+        assert_eq!(bytes.len(), named.encoded_len());
+        assert_eq!(IntOrString::decode(bytes.as_slice()).unwrap(), named);
+    }
+
+    #[test]
+    fn test_label_selector_proto_roundtrip() {
+        let selector = LabelSelector {
+            match_labels: BTreeMap::from([("app".to_string(), "web".to_string())]),
+            match_expressions: vec![LabelSelectorRequirement {
+                key: "tier".to_string(),
+                operator: "NotIn".to_string(),
+                values: vec!["cache".to_string()],
+            }],
+        };
+        let bytes = selector.encode_to_vec();
+        assert_eq!(bytes.len(), selector.encoded_len());
+        assert_eq!(LabelSelector::decode(bytes.as_slice()).unwrap(), selector);
+
+        let quantity = Quantity("500Mi".to_string());
+        assert_eq!(
+            Quantity::decode(quantity.encode_to_vec().as_slice()).unwrap(),
+            quantity
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Resource quota usage helpers
+//!
+//! Based on k8s.io/kubernetes/pkg/quota/v1/evaluator/core/pods.go
+
+use crate::common::Quantity;
+use crate::core::v1::pod::PodSpec;
+use crate::core::v1::resource::{ResourceList, is_extended_resource_name, resource_name};
+
+/// Object count name for pods, as used by `count/pods` quotas.
+const COUNT_PODS: &str = "count/pods";
+
+/// Standard resources tracked as `<name>`, `requests.<name>` and `limits.<name>`.
+const COMPUTE_RESOURCES: [(&str, &str, &str); 3] = [
+    (
+        resource_name::CPU,
+        resource_name::REQUESTS_CPU,
+        resource_name::LIMITS_CPU,
+    ),
+    (
+        resource_name::MEMORY,
+        resource_name::REQUESTS_MEMORY,
+        resource_name::LIMITS_MEMORY,
+    ),
+    (
+        resource_name::EPHEMERAL_STORAGE,
+        resource_name::REQUESTS_EPHEMERAL_STORAGE,
+        resource_name::LIMITS_EPHEMERAL_STORAGE,
+    ),
+];
+
+/// Returns the quota usage charged for a pod with the given spec.
+///
+/// Mirrors `PodUsageFunc` for a non-terminal pod: the pod counts once towards
+/// `pods` and `count/pods`; CPU, memory and ephemeral storage requests are
+/// charged as both `<name>` and `requests.<name>` and limits as `limits.<name>`;
+/// hugepages requests are charged as `hugepages-*` and `requests.hugepages-*`,
+/// and extended resources only as `requests.<name>`. Fails if the pod's
+/// quantities cannot be summed.
+pub fn resource_names_for(pod: &PodSpec) -> Result<ResourceList, String> {
+    let requests = pod.effective_requests()?;
+    let limits = pod.effective_limits()?;
+
+    let mut usage = ResourceList::new();
+    usage.insert(resource_name::PODS.to_string(), Quantity::from("1"));
+    usage.insert(COUNT_PODS.to_string(), Quantity::from("1"));
+
+    for (name, requests_name, limits_name) in COMPUTE_RESOURCES {
+        if let Some(request) = requests.get(name) {
+            usage.insert(name.to_string(), request.clone());
+            usage.insert(requests_name.to_string(), request.clone());
+        }
+        if let Some(limit) = limits.get(name) {
+            usage.insert(limits_name.to_string(), limit.clone());
+        }
+    }
+
+    for (name, request) in &requests {
+        let requests_name = format!("{}{}", resource_name::DEFAULT_REQUESTS_PREFIX, name);
+        if name.starts_with(resource_name::HUGE_PAGES_PREFIX) {
+            usage.insert(name.clone(), request.clone());
+            usage.insert(requests_name, request.clone());
+        } else if is_extended_resource_name(name) {
+            usage.insert(requests_name, request.clone());
+        }
+    }
+
+    Ok(usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::v1::pod::Container;
+    use crate::core::v1::resource::ResourceRequirements;
+
+    fn list(entries: &[(&str, &str)]) -> ResourceList {
+        entries
+            .iter()
+            .map(|(name, quantity)| (name.to_string(), Quantity::from(*quantity)))
+            .collect()
+    }
+
+    #[test]
+    fn test_resource_names_for_requests_and_limits() {
+        let container = |name: &str| Container {
+            name: name.to_string(),
+            resources: Some(ResourceRequirements {
+                requests: list(&[
+                    ("cpu", "250m"),
+                    ("memory", "128Mi"),
+                    ("example.com/foo", "1"),
+                ]),
+                limits: list(&[("cpu", "500m"), ("memory", "256Mi")]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let spec = PodSpec {
+            containers: vec![container("app"), container("proxy")],
+            ..Default::default()
+        };
+
+        let usage: Vec<(String, String)> = resource_names_for(&spec)
+            .unwrap()
+            .iter()
+            .map(|(name, quantity)| (name.clone(), quantity.canonicalized().to_string()))
+            .collect();
+        let expected: Vec<(String, String)> = [
+            ("count/pods", "1"),
+            ("cpu", "500m"),
+            ("limits.cpu", "1"),
+            ("limits.memory", "512Mi"),
+            ("memory", "256Mi"),
+            ("pods", "1"),
+            ("requests.cpu", "500m"),
+            ("requests.example.com/foo", "2"),
+            ("requests.memory", "256Mi"),
+        ]
+        .iter()
+        .map(|(name, quantity)| (name.to_string(), quantity.to_string()))
+        .collect();
+        assert_eq!(usage, expected);
+    }
+}
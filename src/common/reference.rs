@@ -0,0 +1,95 @@
+//! Object reference construction
+//!
+//! Based on k8s.io/client-go/tools/reference/ref.go
+
+use crate::common::{HasTypeMeta, ResourceSchema, VersionedObject};
+use crate::core::v1::ObjectReference;
+
+/// Builds an `ObjectReference` pointing at `obj`, as used for an Event's
+/// `involvedObject` or a Binding's `target`.
+///
+/// `apiVersion` and `kind` come from the object's TypeMeta when set and
+/// otherwise from its `ResourceSchema`; namespace, name, uid and
+/// resourceVersion are copied from its metadata.
+pub fn object_reference_to<T>(obj: &T) -> ObjectReference
+where
+    T: ResourceSchema + VersionedObject + HasTypeMeta,
+{
+    let schema = T::Meta::default();
+    let type_meta = obj.type_meta();
+    let api_version = if type_meta.api_version.is_empty() {
+        match T::group(&schema) {
+            "" => T::version(&schema).to_string(),
+            group => format!("{}/{}", group, T::version(&schema)),
+        }
+    } else {
+        type_meta.api_version.clone()
+    };
+    let kind = if type_meta.kind.is_empty() {
+        T::kind(&schema).to_string()
+    } else {
+        type_meta.kind.clone()
+    };
+
+    let meta = obj.metadata();
+    ObjectReference {
+        kind: Some(kind),
+        namespace: meta.namespace.clone(),
+        name: meta.name.clone(),
+        uid: meta.uid.clone(),
+        api_version: Some(api_version),
+        resource_version: meta.resource_version.clone(),
+        field_path: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::apps::v1::Deployment;
+    use crate::common::ObjectMeta;
+    use crate::core::v1::Pod;
+
+    #[test]
+    fn test_object_reference_to_pod() {
+        let pod = Pod {
+            metadata: Some(ObjectMeta {
+                name: Some("web-0".to_string()),
+                namespace: Some("prod".to_string()),
+                uid: Some("3f1c2a9e-1b2c-4d5e-8f90-0a1b2c3d4e5f".to_string()),
+                resource_version: Some("12345".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            object_reference_to(&pod),
+            ObjectReference {
+                kind: Some("Pod".to_string()),
+                namespace: Some("prod".to_string()),
+                name: Some("web-0".to_string()),
+                uid: Some("3f1c2a9e-1b2c-4d5e-8f90-0a1b2c3d4e5f".to_string()),
+                api_version: Some("v1".to_string()),
+                resource_version: Some("12345".to_string()),
+                field_path: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_object_reference_to_grouped_resource() {
+        let deployment = Deployment {
+            metadata: Some(ObjectMeta {
+                name: Some("web".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let reference = object_reference_to(&deployment);
+        assert_eq!(reference.api_version.as_deref(), Some("apps/v1"));
+        assert_eq!(reference.kind.as_deref(), Some("Deployment"));
+        assert_eq!(reference.namespace, None);
+    }
+}
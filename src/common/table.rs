@@ -0,0 +1,184 @@
+//! Server-side printing types
+//!
+//! A `Table` is returned instead of a list when a client asks for
+//! `application/json;as=Table;v=v1;g=meta.k8s.io`, as `kubectl get` does.
+//!
+//! Corresponds to [Kubernetes Table](https://github.com/kubernetes/apimachinery/blob/master/pkg/apis/meta/v1/types.go#L1370)
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::meta::{ListMeta, TypeMeta};
+
+/// Table is a tabular representation of a set of API resources.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Table {
+    /// TypeMeta for this resource
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+
+    /// Standard list metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMeta>,
+
+    /// columnDefinitions describes each column in the returned items array.
+    #[serde(default)]
+    pub column_definitions: Vec<TableColumnDefinition>,
+
+    /// rows is the list of items in the table.
+    #[serde(default)]
+    pub rows: Vec<TableRow>,
+}
+
+/// TableColumnDefinition contains information about a column returned in the Table.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TableColumnDefinition {
+    /// name is a human readable name for the column.
+    pub name: String,
+
+    /// type is an OpenAPI type definition for this column, such as number,
+    /// integer, string, or array.
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// format is an optional OpenAPI type modifier for this column.
+    #[serde(default)]
+    pub format: String,
+
+    /// description is a human readable description of this column.
+    #[serde(default)]
+    pub description: String,
+
+    /// priority is an integer defining the relative importance of this column
+    /// compared to others. Lower numbers are considered higher priority.
+    #[serde(default)]
+    pub priority: i32,
+}
+
+/// TableRow is an individual row in a table.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TableRow {
+    /// cells will be as wide as the column definitions array and may contain
+    /// strings, numbers, booleans, simple maps, lists, or null.
+    #[serde(default)]
+    pub cells: Vec<Value>,
+
+    /// conditions describe additional status of a row that are relevant for a
+    /// human user.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<TableRowCondition>,
+
+    /// This field contains the requested additional information about each
+    /// object based on the includeObject policy when requesting the Table.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub object: Option<Value>,
+}
+
+/// TableRowCondition allows a row to be marked with additional information.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TableRowCondition {
+    /// Type of row condition. The only defined value is 'Completed'.
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// Status of the condition, one of True, False, Unknown.
+    pub status: String,
+
+    /// (brief) machine readable reason for the condition's last transition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// Human readable message indicating details about last transition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Row condition type constants
+pub mod row_condition_type {
+    /// RowCompleted means the underlying resource has reached completion and
+    /// may be given less visual priority than other resources.
+    pub const COMPLETED: &str = "Completed";
+}
+
+impl Table {
+    /// Decodes the objects embedded in each row.
+    ///
+    /// Rows without an object (`includeObject=None`) are skipped. Use
+    /// `PartialObjectMetadata`-shaped types for `includeObject=Metadata`.
+    pub fn objects<T: DeserializeOwned>(&self) -> serde_json::Result<Vec<T>> {
+        self.rows
+            .iter()
+            .filter_map(|row| row.object.clone())
+            .map(serde_json::from_value)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::v1::Pod;
+
+    const POD_TABLE: &str = r#"{
+        "kind": "Table",
+        "apiVersion": "meta.k8s.io/v1",
+        "metadata": {"resourceVersion": "1203"},
+        "columnDefinitions": [
+            {"name": "Name", "type": "string", "format": "name", "description": "Name must be unique within a namespace.", "priority": 0},
+            {"name": "Ready", "type": "string", "format": "", "description": "The aggregate readiness state of this pod for accepting traffic.", "priority": 0},
+            {"name": "Restarts", "type": "string", "format": "", "description": "The number of times the containers in this pod have been restarted.", "priority": 0},
+            {"name": "IP", "type": "string", "format": "", "description": "IP address allocated to the pod.", "priority": 1}
+        ],
+        "rows": [
+            {
+                "cells": ["web-0", "1/1", 0, "10.0.0.5"],
+                "object": {"kind": "Pod", "apiVersion": "v1", "metadata": {"name": "web-0", "namespace": "default"}}
+            },
+            {
+                "cells": ["migrate-x7k", "0/1", 0, null],
+                "conditions": [{"type": "Completed", "status": "True"}],
+                "object": {"kind": "Pod", "apiVersion": "v1", "metadata": {"name": "migrate-x7k", "namespace": "default"}}
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_decode_table_with_two_rows() {
+        let table: Table = serde_json::from_str(POD_TABLE).unwrap();
+        assert_eq!(table.type_meta.kind, "Table");
+        assert_eq!(table.column_definitions.len(), 4);
+        assert_eq!(table.column_definitions[3].priority, 1);
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.rows[0].cells[1], Value::from("1/1"));
+        assert_eq!(table.rows[1].cells[3], Value::Null);
+        assert_eq!(
+            table.rows[1].conditions[0].type_,
+            row_condition_type::COMPLETED
+        );
+
+        let round_trip: Table =
+            serde_json::from_value(serde_json::to_value(&table).unwrap()).unwrap();
+        assert_eq!(round_trip, table);
+    }
+
+    #[test]
+    fn test_table_objects() {
+        let mut table: Table = serde_json::from_str(POD_TABLE).unwrap();
+        table.rows.push(TableRow {
+            cells: vec![Value::from("no-object")],
+            ..Default::default()
+        });
+
+        let pods: Vec<Pod> = table.objects().unwrap();
+        let names: Vec<_> = pods
+            .iter()
+            .map(|pod| pod.metadata.as_ref().unwrap().name.as_deref().unwrap())
+            .collect();
+        assert_eq!(names, vec!["web-0", "migrate-x7k"]);
+    }
+}
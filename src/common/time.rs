@@ -2,7 +2,6 @@
 //!
 //! This module contains time-related types used across Kubernetes API objects.
 
-use crate::impl_unimplemented_prost_message;
 #[allow(unused_imports)]
 use chrono::{DateTime, TimeDelta, TimeZone, Utc};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -103,8 +102,6 @@ impl Timestamp {
     }
 }
 
-impl_unimplemented_prost_message!(Timestamp);
-
 impl Deref for Timestamp {
     type Target = DateTime<Utc>;
 
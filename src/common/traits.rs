@@ -3,6 +3,7 @@
 //! 映射 Kubernetes API 核心语义：静态身份、外部传输态、内部业务态
 
 use crate::common::{ListMeta, ObjectMeta, TypeMeta};
+use std::collections::BTreeMap;
 
 // ============================================================================
 // 1. 静态身份 (Resource Schema)
@@ -205,6 +206,39 @@ pub trait VersionedObject: Send + Sync {
     ///
     /// 如果 metadata 为 None，自动插入默认 ObjectMeta
     fn metadata_mut(&mut self) -> &mut ObjectMeta;
+
+    /// 获取对象名称，未设置时返回空字符串
+    fn name(&self) -> &str {
+        self.metadata().name.as_deref().unwrap_or_default()
+    }
+
+    /// 获取对象所在命名空间，未设置时返回空字符串
+    fn namespace(&self) -> &str {
+        self.metadata().namespace.as_deref().unwrap_or_default()
+    }
+
+    /// 获取对象 UID，未设置时返回空字符串
+    fn uid(&self) -> &str {
+        self.metadata().uid.as_deref().unwrap_or_default()
+    }
+
+    /// 获取对象 resourceVersion，未设置时返回空字符串
+    fn resource_version(&self) -> &str {
+        self.metadata()
+            .resource_version
+            .as_deref()
+            .unwrap_or_default()
+    }
+
+    /// 获取对象标签
+    fn labels(&self) -> &BTreeMap<String, String> {
+        &self.metadata().labels
+    }
+
+    /// 获取对象注解
+    fn annotations(&self) -> &BTreeMap<String, String> {
+        &self.metadata().annotations
+    }
 }
 
 /// 为所有 `VersionedObject` 实现者自动实现 `HasObjectMeta`。
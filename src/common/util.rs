@@ -100,6 +100,8 @@ pub struct Quantity(pub String);
 struct ParsedQuantity {
     value: f64,
     unit: QuantityUnit,
+    /// Exact value as a count of nano units, rounded up past nano precision.
+    nanos: i128,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -191,22 +193,16 @@ impl ParsedQuantity {
         let value: f64 = num_str
             .parse()
             .map_err(|_| format!("Invalid quantity value: {}", num_str))?;
+        let nanos =
+            exact_nanos(num_str, &unit).ok_or_else(|| format!("Quantity out of range: {}", s))?;
 
-        Ok(ParsedQuantity { value, unit })
+        Ok(ParsedQuantity { value, unit, nanos })
     }
 
     /// Converts to a base value (multiplied by unit multiplier)
     fn to_base_value(&self) -> f64 {
         self.value * self.unit.multiplier()
     }
-
-    /// Creates from a base value and target unit
-    fn from_base_value(base: f64, unit: QuantityUnit) -> Self {
-        ParsedQuantity {
-            value: base / unit.multiplier(),
-            unit,
-        }
-    }
 }
 
 impl Quantity {
@@ -232,12 +228,18 @@ impl Quantity {
         &self.0
     }
 
-    /// Checks if the quantity is zero
+    /// Checks if the quantity is zero, whatever its suffix (`0`, `0Gi`, `0m`).
+    ///
+    /// Unparseable quantities are not zero.
     pub fn is_zero(&self) -> bool {
-        match ParsedQuantity::parse(&self.0) {
-            Ok(pq) => pq.to_base_value() == 0.0,
-            Err(_) => false,
-        }
+        self.parse().is_ok_and(|q| q.to_nanos() == 0)
+    }
+
+    /// Checks if the quantity is below zero.
+    ///
+    /// Unparseable quantities are not negative.
+    pub fn is_negative(&self) -> bool {
+        self.parse().is_ok_and(|q| q.to_nanos() < 0)
     }
 
     /// Parses this quantity
@@ -252,13 +254,13 @@ impl Quantity {
         let q2 = other.parse()?;
 
         let base_sum = q1.to_base_value() + q2.to_base_value();
-        let result = ParsedQuantity::from_base_value(base_sum, q1.unit.clone());
+        let value = base_sum / q1.unit.multiplier();
 
-        let suffix = result.unit.suffix();
-        let value_str = if result.value.fract() == 0.0 && result.value.abs() < 1e9 {
-            format!("{}", result.value as i64)
+        let suffix = q1.unit.suffix();
+        let value_str = if value.fract() == 0.0 && value.abs() < 1e9 {
+            format!("{}", value as i64)
         } else {
-            format!("{:.6}", result.value)
+            format!("{:.6}", value)
                 .trim_end_matches('0')
                 .trim_end_matches('.')
                 .to_string()
@@ -277,13 +279,13 @@ impl Quantity {
             return Err("Subtraction would result in negative value".to_string());
         }
 
-        let result = ParsedQuantity::from_base_value(base_diff, q1.unit.clone());
+        let value = base_diff / q1.unit.multiplier();
 
-        let suffix = result.unit.suffix();
-        let value_str = if result.value.fract() == 0.0 && result.value.abs() < 1e9 {
-            format!("{}", result.value as i64)
+        let suffix = q1.unit.suffix();
+        let value_str = if value.fract() == 0.0 && value.abs() < 1e9 {
+            format!("{}", value as i64)
         } else {
-            format!("{:.6}", result.value)
+            format!("{:.6}", value)
                 .trim_end_matches('0')
                 .trim_end_matches('.')
                 .to_string()
@@ -345,16 +347,13 @@ impl Quantity {
             return Ok(Quantity("0".to_string()));
         }
 
-        let result = ParsedQuantity {
-            value: new_value as f64,
-            unit: q.unit.clone(),
-        };
+        let value = new_value as f64;
 
-        let suffix = result.unit.suffix();
-        let value_str = if result.value.fract() == 0.0 && result.value.abs() < 1e9 {
-            format!("{}", result.value as i64)
+        let suffix = q.unit.suffix();
+        let value_str = if value.fract() == 0.0 && value.abs() < 1e9 {
+            format!("{}", value as i64)
         } else {
-            format!("{:.6}", result.value)
+            format!("{:.6}", value)
                 .trim_end_matches('0')
                 .trim_end_matches('.')
                 .to_string()
@@ -376,16 +375,13 @@ impl Quantity {
     /// ```
     pub fn checked_neg(&self) -> Result<Quantity, String> {
         let q = self.parse()?;
-        let negated = ParsedQuantity {
-            value: -q.value,
-            unit: q.unit.clone(),
-        };
+        let negated = -q.value;
 
-        let suffix = negated.unit.suffix();
-        let value_str = if negated.value.fract() == 0.0 && negated.value.abs() < 1e9 {
-            format!("{}", negated.value as i64)
+        let suffix = q.unit.suffix();
+        let value_str = if negated.fract() == 0.0 && negated.abs() < 1e9 {
+            format!("{}", negated as i64)
         } else {
-            format!("{:.6}", negated.value)
+            format!("{:.6}", negated)
                 .trim_end_matches('0')
                 .trim_end_matches('.')
                 .to_string()
@@ -394,7 +390,7 @@ impl Quantity {
         Ok(Quantity(value_str + suffix))
     }
 
-    /// Returns the sign of the quantity.
+    /// Returns the sign of the quantity, computed on its canonical nano-unit value.
     ///
     /// Uses `Ordering` for a Rust-idiomatic approach:
     /// - `Less`: negative quantity
@@ -407,15 +403,7 @@ impl Quantity {
     /// assert_eq!(Quantity::from_str("0").sign(), Ok(Ordering::Equal));
     /// ```
     pub fn sign(&self) -> Result<std::cmp::Ordering, String> {
-        let q = self.parse()?;
-        const EPSILON: f64 = 1e-9;
-        if q.value.abs() < EPSILON {
-            Ok(std::cmp::Ordering::Equal)
-        } else if q.value < 0.0 {
-            Ok(std::cmp::Ordering::Less)
-        } else {
-            Ok(std::cmp::Ordering::Greater)
-        }
+        Ok(self.parse()?.to_nanos().cmp(&0))
     }
 
     /// Attempts to convert the quantity to an i64 value.
@@ -458,6 +446,276 @@ impl Quantity {
 
         Ok(q.value as i64)
     }
+
+    /// Rounds the quantity up to the nearest multiple of `multiple`.
+    ///
+    /// The result is expressed with the suffix of `multiple`, so rounding
+    /// `1500Mi` up to `1Gi` yields `2Gi`. Unparseable or non-positive inputs
+    /// leave the quantity unchanged.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let q = Quantity::from_str("1500Mi");
+    /// assert_eq!(q.round_up_to(&Quantity::from_str("1Gi")).as_str(), "2Gi");
+    /// ```
+    pub fn round_up_to(&self, multiple: &Quantity) -> Quantity {
+        let (Ok(q), Ok(m)) = (self.parse(), multiple.parse()) else {
+            return self.clone();
+        };
+        let step = m.to_nanos();
+        if step <= 0 {
+            return self.clone();
+        }
+        // ceil(n / step) * step, valid for negative values as well.
+        let rounded = -(-q.to_nanos()).div_euclid(step) * step;
+        Quantity::from_nanos(rounded, m.unit)
+    }
+
+    /// Divides the quantity by `divisor`, rounding the quotient up.
+    ///
+    /// Returns `Err` if either quantity fails to parse, the divisor is not
+    /// positive, or the quotient overflows i64.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let q = Quantity::from_str("1500Mi");
+    /// assert_eq!(q.div_ceil(&Quantity::from_str("1Gi")), Ok(2));
+    /// ```
+    pub fn div_ceil(&self, divisor: &Quantity) -> Result<i64, String> {
+        let q = self.parse()?;
+        let d = divisor.parse()?.to_nanos();
+        if d <= 0 {
+            return Err(format!("Divisor must be positive: {}", divisor.0));
+        }
+        let quotient = -(-q.to_nanos()).div_euclid(d);
+        i64::try_from(quotient)
+            .map_err(|_| format!("Division overflow: {} / {}", self.0, divisor.0))
+    }
+
+    /// Multiplies the quantity by a floating point factor.
+    ///
+    /// The factor is taken at its shortest decimal representation (`0.1` is
+    /// exactly one tenth) and the product is computed in integer nano units,
+    /// rounded to the nearest one. The result keeps the original suffix.
+    /// Unparseable quantities, non-finite factors and products out of range
+    /// leave the quantity unchanged.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let q = Quantity::from_str("1Gi");
+    /// assert_eq!(q.scaled(1.5).as_str(), "1.5Gi");
+    /// ```
+    pub fn scaled(&self, factor: f64) -> Quantity {
+        let Ok(q) = self.parse() else {
+            return self.clone();
+        };
+        match scale_nanos(q.to_nanos(), factor) {
+            Some(nanos) => Quantity::from_nanos(nanos, q.unit),
+            None => self.clone(),
+        }
+    }
+
+    /// Returns the canonical form of the quantity.
+    ///
+    /// Binary quantities of at least `1Ki` use the largest binary suffix that
+    /// keeps the value an integer; everything else uses the largest decimal
+    /// suffix that does. For example `1024Mi` becomes `1Gi` and `1000m`
+    /// becomes `1`. Unparseable quantities are returned unchanged.
+    pub fn canonicalized(&self) -> Quantity {
+        let Ok(q) = self.parse() else {
+            return self.clone();
+        };
+        let nanos = q.to_nanos();
+        if nanos == 0 {
+            return Quantity("0".to_string());
+        }
+
+        const BINARY_UNITS: [QuantityUnit; 6] = [
+            QuantityUnit::Ei,
+            QuantityUnit::Pi,
+            QuantityUnit::Ti,
+            QuantityUnit::Gi,
+            QuantityUnit::Mi,
+            QuantityUnit::Ki,
+        ];
+        const DECIMAL_UNITS: [QuantityUnit; 10] = [
+            QuantityUnit::E,
+            QuantityUnit::P,
+            QuantityUnit::T,
+            QuantityUnit::G,
+            QuantityUnit::M,
+            QuantityUnit::K,
+            QuantityUnit::None,
+            QuantityUnit::Milli,
+            QuantityUnit::Micro,
+            QuantityUnit::Nano,
+        ];
+
+        let candidates: &[QuantityUnit] = if BINARY_UNITS.contains(&q.unit)
+            && nanos.abs() >= QuantityUnit::Ki.nano_multiplier()
+            && nanos % QuantityUnit::None.nano_multiplier() == 0
+        {
+            &BINARY_UNITS
+        } else {
+            &DECIMAL_UNITS
+        };
+        let unit = candidates
+            .iter()
+            .find(|unit| nanos % unit.nano_multiplier() == 0)
+            .cloned()
+            .unwrap_or(QuantityUnit::Nano);
+        Quantity::from_nanos(nanos, unit)
+    }
+
+    /// Formats an integer number of nano units using the given unit suffix.
+    ///
+    /// The value is written out exactly. Every nano multiplier is a product
+    /// of powers of two and ten, so the decimal expansion always terminates.
+    fn from_nanos(nanos: i128, unit: QuantityUnit) -> Quantity {
+        if nanos == 0 {
+            return Quantity("0".to_string());
+        }
+        let multiplier = unit.nano_multiplier().unsigned_abs();
+        let magnitude = nanos.unsigned_abs();
+        let mut value_str = String::new();
+        if nanos < 0 {
+            value_str.push('-');
+        }
+        value_str.push_str(&(magnitude / multiplier).to_string());
+        let mut remainder = magnitude % multiplier;
+        if remainder != 0 {
+            value_str.push('.');
+            while remainder != 0 {
+                remainder *= 10;
+                value_str.push_str(&(remainder / multiplier).to_string());
+                remainder %= multiplier;
+            }
+        }
+        Quantity(value_str + unit.suffix())
+    }
+}
+
+impl ParsedQuantity {
+    /// Converts to the canonical integer form: a count of nano units.
+    fn to_nanos(&self) -> i128 {
+        self.nanos
+    }
+}
+
+/// Parses a decimal number (`[+-]digits[.digits]`) into an integer mantissa and
+/// the number of fractional digits.
+fn parse_decimal(num: &str) -> Option<(i128, u32)> {
+    let (negative, digits) = match num.as_bytes().first()? {
+        b'-' => (true, &num[1..]),
+        b'+' => (false, &num[1..]),
+        _ => (false, num),
+    };
+    let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+    if int_part.is_empty() && frac_part.is_empty() {
+        return None;
+    }
+    let mut mantissa: i128 = 0;
+    for c in int_part.chars().chain(frac_part.chars()) {
+        let digit = c.to_digit(10)?;
+        mantissa = mantissa.checked_mul(10)?.checked_add(digit as i128)?;
+    }
+    let scale = u32::try_from(frac_part.len()).ok()?;
+    Some((if negative { -mantissa } else { mantissa }, scale))
+}
+
+/// Converts a decimal number with a unit suffix to nano units without going
+/// through floating point. Precision finer than a nano unit is rounded up, as
+/// the API server does. Returns `None` if the result does not fit in an i128.
+fn exact_nanos(num: &str, unit: &QuantityUnit) -> Option<i128> {
+    let (mantissa, scale) = parse_decimal(num)?;
+    let (pow2, pow10) = unit.nano_factors();
+    let value = mantissa.checked_mul(1i128.checked_shl(pow2)?)?;
+    if pow10 >= scale {
+        return value.checked_mul(10i128.checked_pow(pow10 - scale)?);
+    }
+    match 10i128.checked_pow(scale - pow10) {
+        Some(divisor) => Some(-(-value).div_euclid(divisor)),
+        // No i128 mantissa reaches this divisor, so only the sign survives the ceiling.
+        None => Some(i128::from(value > 0)),
+    }
+}
+
+/// Multiplies a nano count by `factor`, using the shortest decimal
+/// representation of the factor and rounding half away from zero.
+///
+/// Returns `None` for non-finite factors or when the product overflows.
+fn scale_nanos(nanos: i128, factor: f64) -> Option<i128> {
+    if !factor.is_finite() {
+        return None;
+    }
+    let repr = format!("{:e}", factor);
+    let (mantissa, exponent) = repr.split_once('e')?;
+    let (mantissa, scale) = parse_decimal(mantissa)?;
+    let exponent = exponent.parse::<i32>().ok()? - i32::try_from(scale).ok()?;
+    if exponent >= 0 {
+        return nanos
+            .checked_mul(mantissa)?
+            .checked_mul(10i128.checked_pow(exponent.unsigned_abs())?);
+    }
+    let divisor = 10i128.checked_pow(exponent.unsigned_abs())?;
+    // Split nanos into quotient and remainder first to keep the products small.
+    let whole = (nanos / divisor).checked_mul(mantissa)?;
+    let part = (nanos % divisor).checked_mul(mantissa)?;
+    let (quotient, remainder) = (part / divisor, part % divisor);
+    let rounded = if remainder.abs() >= divisor - remainder.abs() {
+        quotient + part.signum()
+    } else {
+        quotient
+    };
+    whole.checked_add(rounded)
+}
+
+impl QuantityUnit {
+    /// Returns the nano multiplier of this unit as `(p, q)`, the multiplier
+    /// being `2^p * 10^q`.
+    fn nano_factors(&self) -> (u32, u32) {
+        match self {
+            QuantityUnit::Nano => (0, 0),
+            QuantityUnit::Micro => (0, 3),
+            QuantityUnit::Milli => (0, 6),
+            QuantityUnit::None => (0, 9),
+            QuantityUnit::Ki => (10, 9),
+            QuantityUnit::Mi => (20, 9),
+            QuantityUnit::Gi => (30, 9),
+            QuantityUnit::Ti => (40, 9),
+            QuantityUnit::Pi => (50, 9),
+            QuantityUnit::Ei => (60, 9),
+            QuantityUnit::K => (0, 12),
+            QuantityUnit::M => (0, 15),
+            QuantityUnit::G => (0, 18),
+            QuantityUnit::T => (0, 21),
+            QuantityUnit::P => (0, 24),
+            QuantityUnit::E => (0, 27),
+        }
+    }
+
+    /// Returns the multiplier for this unit expressed in nano units.
+    fn nano_multiplier(&self) -> i128 {
+        const NANOS_PER_UNIT: i128 = 1_000_000_000;
+        match self {
+            QuantityUnit::Nano => 1,
+            QuantityUnit::Micro => 1_000,
+            QuantityUnit::Milli => 1_000_000,
+            QuantityUnit::None => NANOS_PER_UNIT,
+            QuantityUnit::Ki => NANOS_PER_UNIT << 10,
+            QuantityUnit::Mi => NANOS_PER_UNIT << 20,
+            QuantityUnit::Gi => NANOS_PER_UNIT << 30,
+            QuantityUnit::Ti => NANOS_PER_UNIT << 40,
+            QuantityUnit::Pi => NANOS_PER_UNIT << 50,
+            QuantityUnit::Ei => NANOS_PER_UNIT << 60,
+            QuantityUnit::K => NANOS_PER_UNIT * 1_000,
+            QuantityUnit::M => NANOS_PER_UNIT * 1_000_000,
+            QuantityUnit::G => NANOS_PER_UNIT * 1_000_000_000,
+            QuantityUnit::T => NANOS_PER_UNIT * 1_000_000_000_000,
+            QuantityUnit::P => NANOS_PER_UNIT * 1_000_000_000_000_000,
+            QuantityUnit::E => NANOS_PER_UNIT * 1_000_000_000_000_000_000,
+        }
+    }
 }
 
 impl QuantityUnit {
@@ -512,7 +770,172 @@ impl std::fmt::Display for Quantity {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantity_round_up_to_larger_unit() {
+        let q = Quantity::from_str("1500Mi");
+        assert_eq!(q.round_up_to(&Quantity::from_str("1Gi")).as_str(), "2Gi");
+    }
+
+    #[test]
+    fn test_quantity_round_up_to_exact_multiple() {
+        let q = Quantity::from_str("2Gi");
+        assert_eq!(q.round_up_to(&Quantity::from_str("1Gi")).as_str(), "2Gi");
+
+        let cpu = Quantity::from_str("250m");
+        assert_eq!(
+            cpu.round_up_to(&Quantity::from_str("100m")).as_str(),
+            "300m"
+        );
+    }
+
+    #[test]
+    fn test_quantity_div_ceil_is_exact() {
+        let one = Quantity::from_str("1");
+        assert_eq!(
+            Quantity::from_str("999999999999").div_ceil(&one),
+            Ok(999999999999)
+        );
+        assert_eq!(
+            Quantity::from_str("123456789012345678").div_ceil(&one),
+            Ok(123456789012345678)
+        );
+        assert_eq!(
+            Quantity::from_str("0.1").div_ceil(&Quantity::from_str("1m")),
+            Ok(100)
+        );
+        assert_eq!(
+            Quantity::from_str("1500Mi").div_ceil(&Quantity::from_str("1Gi")),
+            Ok(2)
+        );
+        assert_eq!(
+            Quantity::from_str("0.5n").div_ceil(&Quantity::from_str("1n")),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn test_quantity_round_up_to_large_value() {
+        let q = Quantity::from_str("1234567890123");
+        assert_eq!(
+            q.round_up_to(&Quantity::from_str("1")).as_str(),
+            "1234567890123"
+        );
+        assert_eq!(
+            q.round_up_to(&Quantity::from_str("1k")).as_str(),
+            "1234567891k"
+        );
+    }
+
+    #[test]
+    fn test_quantity_exact_nanos() {
+        let one_nano = Quantity::from_str("1n");
+        // Beyond f64's 53-bit mantissa every digit is kept.
+        assert_eq!(
+            Quantity::from_str("9007199254740993n").div_ceil(&one_nano),
+            Ok(9007199254740993)
+        );
+        assert_eq!(
+            Quantity::from_str("1.000000001").div_ceil(&one_nano),
+            Ok(1000000001)
+        );
+        // Precision finer than a nano unit rounds up rather than to zero.
+        assert!(!Quantity::from_str("0.0000000001").is_zero());
+        assert_eq!(
+            Quantity::from_str("0.0000000001").sign(),
+            Ok(std::cmp::Ordering::Greater)
+        );
+    }
+
+    #[test]
+    fn test_quantity_out_of_range() {
+        // 10^12 E is 10^39 nano units, past i128.
+        let err = Quantity::from_str_validated("1000000000000E").unwrap_err();
+        assert!(err.starts_with("Quantity out of range"), "{err}");
+        assert!(!Quantity::from_str("1000000000000E").is_zero());
+
+        assert!(Quantity::from_str_validated("100000000000E").is_ok());
+    }
+
+    #[test]
+    fn test_quantity_arithmetic_unchanged_by_exact_nanos() {
+        let q = Quantity::from_str;
+        assert_eq!(q("1Gi").add(&q("512Mi")).unwrap().as_str(), "1.5Gi");
+        assert_eq!(q("1").sub(&q("250m")).unwrap().as_str(), "0.75");
+        assert_eq!(q("100Mi").mul(3).unwrap().as_str(), "300Mi");
+        assert_eq!(q("100m").checked_neg().unwrap().as_str(), "-100m");
+    }
+
+    #[test]
+    fn test_quantity_canonicalized() {
+        let cases = [
+            ("1024Mi", "1Gi"),
+            ("1000m", "1"),
+            ("1500m", "1500m"),
+            ("2048", "2048"),
+            ("1536Ki", "1536Ki"),
+            ("512Ki", "512Ki"),
+            ("0.5Gi", "512Mi"),
+            ("1000k", "1M"),
+            ("0Gi", "0"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(Quantity::from_str(input).canonicalized().as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn test_quantity_is_zero_ignores_suffix() {
+        for zero in ["0", "0Mi", "0Gi", "0m", "-0", "0.0k"] {
+            let q = Quantity::from_str(zero);
+            assert!(q.is_zero(), "{zero} should be zero");
+            assert!(!q.is_negative(), "{zero} should not be negative");
+            assert_eq!(q.sign(), Ok(std::cmp::Ordering::Equal));
+        }
+        assert!(!Quantity::from_str("1n").is_zero());
+        assert!(!Quantity::from_str("bogus").is_zero());
+    }
+
+    #[test]
+    fn test_quantity_sign() {
+        let negative = Quantity::from_str("-250m");
+        assert!(negative.is_negative());
+        assert!(!negative.is_zero());
+        assert_eq!(negative.sign(), Ok(std::cmp::Ordering::Less));
+
+        assert_eq!(
+            Quantity::from_str("1Ki").sign(),
+            Ok(std::cmp::Ordering::Greater)
+        );
+        assert!(Quantity::from_str("bogus").sign().is_err());
+        assert!(!Quantity::from_str("bogus").is_negative());
+    }
+
+    #[test]
+    fn test_quantity_scaled_preserves_suffix() {
+        assert_eq!(Quantity::from_str("1Gi").scaled(1.5).as_str(), "1.5Gi");
+        assert_eq!(Quantity::from_str("200m").scaled(2.0).as_str(), "400m");
+        assert_eq!(Quantity::from_str("100Mi").scaled(0.0).as_str(), "0");
+    }
+
+    #[test]
+    fn test_quantity_scaled_is_exact() {
+        assert_eq!(
+            Quantity::from_str("999999999999").scaled(1.0).as_str(),
+            "999999999999"
+        );
+        assert_eq!(Quantity::from_str("1Gi").scaled(0.1).as_str(), "0.1Gi");
+        assert_eq!(
+            Quantity::from_str("1Ki").scaled(0.0009765625).as_str(),
+            "0.0009765625Ki"
+        );
+        assert_eq!(Quantity::from_str("-3").scaled(0.5).as_str(), "-1.5");
+        assert_eq!(Quantity::from_str("1n").scaled(0.5).as_str(), "1n");
+        assert_eq!(Quantity::from_str("1Gi").scaled(f64::NAN).as_str(), "1Gi");
+    }
+}
 
 // ============================================================================
 // Helper functions for serde
@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+
+use super::qualified_name::is_qualified_name;
+use super::{BadValue, ErrorList, Path, invalid, too_long};
+
+/// Maximum total size in bytes of all annotation keys and values on an object.
+///
+/// Corresponds to upstream `TotalAnnotationSizeLimitB`.
+pub const TOTAL_ANNOTATION_SIZE_LIMIT_B: usize = 256 * (1 << 10); // 256 KiB
+
+/// Validates annotation keys and the total annotation size.
+///
+/// Keys must be qualified names (compared case-insensitively), and the
+/// combined length of all keys and values may not exceed
+/// [`TOTAL_ANNOTATION_SIZE_LIMIT_B`].
+pub fn validate_annotations(annotations: &BTreeMap<String, String>, fld_path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    for key in annotations.keys() {
+        for msg in is_qualified_name(&key.to_lowercase()) {
+            all_errs.push(invalid(fld_path, BadValue::String(key.to_string()), &msg));
+        }
+    }
+    all_errs.extend(validate_annotations_size(annotations, fld_path));
+    all_errs
+}
+
+fn validate_annotations_size(annotations: &BTreeMap<String, String>, fld_path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    let total_size: usize = annotations.iter().map(|(k, v)| k.len() + v.len()).sum();
+    if total_size > TOTAL_ANNOTATION_SIZE_LIMIT_B {
+        all_errs.push(too_long(fld_path, TOTAL_ANNOTATION_SIZE_LIMIT_B));
+    }
+    all_errs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::validation::ErrorType;
+
+    #[test]
+    fn test_validate_annotations_valid() {
+        let annotations = BTreeMap::from([
+            ("example.com/Owner".to_string(), "team-a".to_string()),
+            (
+                "description".to_string(),
+                "any value is allowed".to_string(),
+            ),
+        ]);
+        assert!(validate_annotations(&annotations, &Path::new("annotations")).is_empty());
+    }
+
+    #[test]
+    fn test_validate_annotations_invalid_key() {
+        let annotations = BTreeMap::from([("-bad key".to_string(), "value".to_string())]);
+        let errs = validate_annotations(&annotations, &Path::new("annotations"));
+        assert!(!errs.is_empty());
+        assert!(
+            errs.errors
+                .iter()
+                .all(|e| e.error_type == ErrorType::Invalid && e.field == "annotations")
+        );
+    }
+
+    #[test]
+    fn test_validate_annotations_over_size_limit() {
+        let annotations = BTreeMap::from([(
+            "payload".to_string(),
+            "x".repeat(TOTAL_ANNOTATION_SIZE_LIMIT_B),
+        )]);
+        let errs = validate_annotations(&annotations, &Path::new("annotations"));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::TooLong);
+    }
+}
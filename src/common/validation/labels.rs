@@ -1,33 +1,10 @@
-use regex::Regex;
 use std::collections::BTreeMap;
-use std::sync::OnceLock;
 
-use super::qualified_name::is_qualified_name;
-use super::{BadValue, ErrorList, Path, invalid};
+use super::qualified_name::{is_qualified_name, validate_qualified_name};
+use super::{BadValue, ErrorList, Path, invalid, not_supported, required};
+use crate::common::meta::{LabelSelector, LabelSelectorRequirement, label_selector_operator};
 
-const LABEL_VALUE_FMT: &str = "(([A-Za-z0-9][-A-Za-z0-9_.]*)?[A-Za-z0-9])?";
-const LABEL_VALUE_ERR_MSG: &str = "a valid label must be an empty string or consist of alphanumeric characters, '-', '_' or '.', and must start and end with an alphanumeric character";
-const LABEL_VALUE_MAX_LENGTH: usize = 63;
-
-static LABEL_VALUE_RE: OnceLock<Regex> = OnceLock::new();
-
-pub fn is_valid_label_value(value: &str) -> Vec<String> {
-    let mut errs = Vec::new();
-    if value.len() > LABEL_VALUE_MAX_LENGTH {
-        errs.push(format!(
-            "must be no more than {} characters",
-            LABEL_VALUE_MAX_LENGTH
-        ));
-    }
-    let re = LABEL_VALUE_RE.get_or_init(|| Regex::new(&format!("^{}$", LABEL_VALUE_FMT)).unwrap());
-    if !re.is_match(value) {
-        errs.push(format!(
-            "{} (regex used for validation is '{}')",
-            LABEL_VALUE_ERR_MSG, LABEL_VALUE_FMT
-        ));
-    }
-    errs
-}
+pub use super::names::is_valid_label_value;
 
 pub fn validate_label_name(name: &str, fld_path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
@@ -51,3 +28,225 @@ pub fn validate_labels(labels: &BTreeMap<String, String>, fld_path: &Path) -> Er
     }
     all_errs
 }
+
+/// Read access to a label selector, so the validators below accept both the
+/// meta type and the core internal copy of it.
+pub trait LabelSelectorFields {
+    type Requirement: LabelSelectorRequirementFields;
+
+    fn match_labels(&self) -> &BTreeMap<String, String>;
+    fn match_expressions(&self) -> &[Self::Requirement];
+}
+
+/// Read access to a label selector requirement. See [`LabelSelectorFields`].
+pub trait LabelSelectorRequirementFields {
+    fn key(&self) -> &str;
+    fn operator(&self) -> &str;
+    fn values(&self) -> &[String];
+}
+
+impl LabelSelectorFields for LabelSelector {
+    type Requirement = LabelSelectorRequirement;
+
+    fn match_labels(&self) -> &BTreeMap<String, String> {
+        &self.match_labels
+    }
+
+    fn match_expressions(&self) -> &[LabelSelectorRequirement] {
+        &self.match_expressions
+    }
+}
+
+impl LabelSelectorRequirementFields for LabelSelectorRequirement {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    fn operator(&self) -> &str {
+        &self.operator
+    }
+
+    fn values(&self) -> &[String] {
+        &self.values
+    }
+}
+
+/// Options for [`validate_label_selector_with_options`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LabelSelectorValidationOptions {
+    /// Skips the label value check on matchExpressions values, for objects
+    /// stored before that check existed.
+    pub allow_invalid_label_value_in_selector: bool,
+}
+
+/// Validates a label selector: its matchLabels and, for every matchExpressions
+/// requirement, the key, the operator and the values.
+///
+/// `In`/`NotIn` need at least one value, `Exists`/`DoesNotExist` must have none,
+/// and every value must be a valid label value.
+pub fn validate_label_selector<S: LabelSelectorFields>(selector: &S, fld_path: &Path) -> ErrorList {
+    validate_label_selector_with_options(
+        selector,
+        LabelSelectorValidationOptions::default(),
+        fld_path,
+    )
+}
+
+/// Like [`validate_label_selector`], with the checks adjusted by `opts`.
+pub fn validate_label_selector_with_options<S: LabelSelectorFields>(
+    selector: &S,
+    opts: LabelSelectorValidationOptions,
+    fld_path: &Path,
+) -> ErrorList {
+    let mut all_errs = validate_labels(selector.match_labels(), &fld_path.child("matchLabels"));
+
+    for (i, requirement) in selector.match_expressions().iter().enumerate() {
+        let req_path = fld_path.child("matchExpressions").index(i);
+        if requirement.key().is_empty() {
+            all_errs.push(required(&req_path.child("key"), "key is required"));
+        } else {
+            all_errs.extend(validate_qualified_name(
+                requirement.key(),
+                &req_path.child("key"),
+            ));
+        }
+
+        match requirement.operator() {
+            label_selector_operator::IN | label_selector_operator::NOT_IN => {
+                if requirement.values().is_empty() {
+                    all_errs.push(required(
+                        &req_path.child("values"),
+                        "values must be non-empty for In/NotIn operators",
+                    ));
+                }
+            }
+            label_selector_operator::EXISTS | label_selector_operator::DOES_NOT_EXIST => {
+                if !requirement.values().is_empty() {
+                    all_errs.push(invalid(
+                        &req_path.child("values"),
+                        BadValue::String(format!("{:?}", requirement.values())),
+                        "values must be empty for Exists/DoesNotExist operators",
+                    ));
+                }
+            }
+            _ => {
+                all_errs.push(not_supported(
+                    &req_path.child("operator"),
+                    BadValue::String(requirement.operator().to_string()),
+                    &[
+                        label_selector_operator::IN,
+                        label_selector_operator::NOT_IN,
+                        label_selector_operator::EXISTS,
+                        label_selector_operator::DOES_NOT_EXIST,
+                    ],
+                ));
+            }
+        }
+
+        if opts.allow_invalid_label_value_in_selector {
+            continue;
+        }
+        for (j, value) in requirement.values().iter().enumerate() {
+            for msg in is_valid_label_value(value) {
+                all_errs.push(invalid(
+                    &req_path.child("values").index(j),
+                    BadValue::String(value.clone()),
+                    &msg,
+                ));
+            }
+        }
+    }
+
+    all_errs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::validation::ErrorType;
+
+    fn selector(operator: &str, values: &[&str]) -> LabelSelector {
+        LabelSelector {
+            match_expressions: vec![LabelSelectorRequirement {
+                key: "app.kubernetes.io/name".to_string(),
+                operator: operator.to_string(),
+                values: values.iter().map(|value| value.to_string()).collect(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn errors(selector: &LabelSelector) -> Vec<(String, ErrorType)> {
+        validate_label_selector(selector, &Path::new("selector"))
+            .errors
+            .into_iter()
+            .map(|err| (err.field, err.error_type))
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_label_selector_in_without_values() {
+        assert!(errors(&selector(label_selector_operator::IN, &["web"])).is_empty());
+        assert_eq!(
+            errors(&selector(label_selector_operator::IN, &[])),
+            vec![(
+                "selector.matchExpressions[0].values".to_string(),
+                ErrorType::Required
+            )]
+        );
+    }
+
+    #[test]
+    fn test_validate_label_selector_exists_with_values() {
+        assert!(errors(&selector(label_selector_operator::EXISTS, &[])).is_empty());
+        assert_eq!(
+            errors(&selector(label_selector_operator::EXISTS, &["web"])),
+            vec![(
+                "selector.matchExpressions[0].values".to_string(),
+                ErrorType::Invalid
+            )]
+        );
+    }
+
+    #[test]
+    fn test_validate_label_selector_allow_invalid_label_value() {
+        let bad_value = selector(label_selector_operator::IN, &["not a label value"]);
+        assert_eq!(
+            errors(&bad_value),
+            vec![(
+                "selector.matchExpressions[0].values[0]".to_string(),
+                ErrorType::Invalid
+            )]
+        );
+
+        let opts = LabelSelectorValidationOptions {
+            allow_invalid_label_value_in_selector: true,
+        };
+        assert!(
+            validate_label_selector_with_options(&bad_value, opts, &Path::new("selector"))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_validate_label_selector_core_internal_selector() {
+        use crate::core::internal::selector as core;
+
+        let internal = core::LabelSelector {
+            match_expressions: vec![core::LabelSelectorRequirement {
+                key: "app.kubernetes.io/name".to_string(),
+                operator: label_selector_operator::IN.to_string(),
+                values: vec![],
+            }],
+            ..Default::default()
+        };
+        let errs = validate_label_selector(&internal, &Path::new("selector"));
+        assert_eq!(
+            errs.errors
+                .into_iter()
+                .map(|err| (err.field, err.error_type))
+                .collect::<Vec<_>>(),
+            errors(&selector(label_selector_operator::IN, &[]))
+        );
+    }
+}
@@ -4,14 +4,18 @@
 //! - `Path`: Represents a path from root to a field (similar to Go's field.Path)
 //! - `Error` / `ErrorList`: Validation error types
 //! - DNS validation functions: `is_dns1123_label`, `is_dns1123_subdomain`, `is_dns1035_label`
+//! - `names`: shared name and label value checks with apimachinery error messages
 
+pub mod annotations;
 pub mod dns;
 pub mod errors;
 pub mod labels;
+pub mod names;
 pub mod object_meta;
 pub mod path;
 pub mod qualified_name;
 
+pub use annotations::{TOTAL_ANNOTATION_SIZE_LIMIT_B, validate_annotations};
 pub use dns::{
     DNS1035_LABEL_ERROR_MSG, DNS1123_LABEL_ERROR_MSG, DNS1123_SUBDOMAIN_ERROR_MSG,
     is_dns1035_label, is_dns1123_label, is_dns1123_subdomain, is_dns1123_subdomain_with_underscore,
@@ -20,7 +24,11 @@ pub use errors::{
     BadValue, Error, ErrorList, ErrorType, duplicate, forbidden, internal_error, invalid,
     not_found, not_supported, required, too_long, too_many, type_invalid,
 };
-pub use labels::{is_valid_label_value, validate_label_name, validate_labels};
+pub use labels::{
+    LabelSelectorFields, LabelSelectorRequirementFields, LabelSelectorValidationOptions,
+    is_valid_label_value, validate_label_name, validate_label_selector,
+    validate_label_selector_with_options, validate_labels,
+};
 pub use object_meta::{
     ValidateNameFunc, name_is_dns_label, name_is_dns_subdomain, validate_object_meta,
     validate_object_meta_update,
@@ -0,0 +1,356 @@
+//! Shared name and label value checks.
+//!
+//! Ported from k8s.io/apimachinery/pkg/util/validation/validation.go
+//!
+//! Every function returns the list of error messages produced by its Go
+//! counterpart, word for word, so callers only need to wrap them in field errors.
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+pub(crate) const QUALIFIED_NAME_FMT: &str = "([A-Za-z0-9][-A-Za-z0-9_.]*)?[A-Za-z0-9]";
+const QUALIFIED_NAME_ERR_MSG: &str = "must consist of alphanumeric characters, '-', '_' or '.', and must start and end with an alphanumeric character";
+const QUALIFIED_NAME_MAX_LENGTH: usize = 63;
+
+pub(crate) const LABEL_VALUE_FMT: &str = "(([A-Za-z0-9][-A-Za-z0-9_.]*)?[A-Za-z0-9])?";
+const LABEL_VALUE_ERR_MSG: &str = "a valid label must be an empty string or consist of alphanumeric characters, '-', '_' or '.', and must start and end with an alphanumeric character";
+/// LabelValueMaxLength is a label's max length.
+pub const LABEL_VALUE_MAX_LENGTH: usize = 63;
+
+pub(crate) const DNS1123_LABEL_FMT: &str = "[a-z0-9]([-a-z0-9]*[a-z0-9])?";
+/// DNS1123LabelErrorMessage is error message for invalid DNS1123 labels.
+pub const DNS1123_LABEL_ERROR_MSG: &str = "a lowercase RFC 1123 label must consist of lower case alphanumeric characters or '-', and must start and end with an alphanumeric character";
+/// DNS1123LabelMaxLength is a label's max length in DNS (RFC 1123).
+pub const DNS1123_LABEL_MAX_LENGTH: usize = 63;
+
+pub(crate) const DNS1123_SUBDOMAIN_FMT: &str =
+    r"[a-z0-9]([-a-z0-9]*[a-z0-9])?(\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*";
+/// DNS1123SubdomainErrorMessage is error message for invalid DNS1123 subdomains.
+pub const DNS1123_SUBDOMAIN_ERROR_MSG: &str = "a lowercase RFC 1123 subdomain must consist of lower case alphanumeric characters, '-' or '.', and must start and end with an alphanumeric character";
+/// DNS1123SubdomainMaxLength is a subdomain's max length in DNS (RFC 1123).
+pub const DNS1123_SUBDOMAIN_MAX_LENGTH: usize = 253;
+
+const PORT_NAME_MAX_LENGTH: usize = 15;
+
+static QUALIFIED_NAME_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!("^{QUALIFIED_NAME_FMT}$")).unwrap());
+static LABEL_VALUE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!("^{LABEL_VALUE_FMT}$")).unwrap());
+static DNS1123_LABEL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!("^{DNS1123_LABEL_FMT}$")).unwrap());
+static DNS1123_SUBDOMAIN_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!("^{DNS1123_SUBDOMAIN_FMT}$")).unwrap());
+static PORT_NAME_CHARSET_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new("^[-a-z0-9]+$").unwrap());
+static PORT_NAME_ONE_LETTER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new("[a-z]").unwrap());
+
+/// IsQualifiedName tests whether the value passed is what Kubernetes calls a
+/// "qualified name": an optional DNS subdomain prefix and '/', followed by a name.
+///
+/// # Examples
+/// ```
+/// # use taibai_api::common::validation::names::is_qualified_name;
+/// assert!(is_qualified_name("example.com/MyName").is_empty());
+/// assert!(!is_qualified_name("a/b/c").is_empty());
+/// ```
+pub fn is_qualified_name(value: &str) -> Vec<String> {
+    let mut errs = Vec::new();
+    let (prefix, name) = split_qualified(value);
+    match prefix {
+        _ if name.contains('/') => {
+            errs.push(format!(
+                "a qualified name {} with an optional DNS subdomain prefix and '/' (e.g. 'example.com/MyName')",
+                regex_error(
+                    QUALIFIED_NAME_ERR_MSG,
+                    QUALIFIED_NAME_FMT,
+                    &["MyName", "my.name", "123-abc"],
+                )
+            ));
+            return errs;
+        }
+        Some("") => errs.push(format!("prefix part {}", empty_error())),
+        Some(prefix) => errs.extend(prefix_each(is_dns1123_subdomain(prefix), "prefix part ")),
+        None => {}
+    }
+
+    if name.is_empty() {
+        errs.push(format!("name part {}", empty_error()));
+    } else if name.len() > QUALIFIED_NAME_MAX_LENGTH {
+        errs.push(format!(
+            "name part {}",
+            max_len_error(QUALIFIED_NAME_MAX_LENGTH)
+        ));
+    }
+    if !QUALIFIED_NAME_RE.is_match(name) {
+        errs.push(format!(
+            "name part {}",
+            regex_error(
+                QUALIFIED_NAME_ERR_MSG,
+                QUALIFIED_NAME_FMT,
+                &["MyName", "my.name", "123-abc"],
+            )
+        ));
+    }
+    errs
+}
+
+/// Splits a qualified name into its optional prefix and its name part.
+///
+/// The split happens at the first '/', so the name part of a malformed value
+/// such as `a/b/c` still contains a '/'.
+///
+/// # Examples
+/// ```
+/// # use taibai_api::common::names::split_qualified;
+/// assert_eq!(split_qualified("app.kubernetes.io/name"), (Some("app.kubernetes.io"), "name"));
+/// assert_eq!(split_qualified("name"), (None, "name"));
+/// ```
+pub fn split_qualified(value: &str) -> (Option<&str>, &str) {
+    match value.split_once('/') {
+        Some((prefix, name)) => (Some(prefix), name),
+        None => (None, value),
+    }
+}
+
+/// Joins an optional prefix and a name into a qualified name; the inverse of
+/// [`split_qualified`].
+pub fn join_qualified(prefix: Option<&str>, name: &str) -> String {
+    match prefix {
+        Some(prefix) => format!("{prefix}/{name}"),
+        None => name.to_string(),
+    }
+}
+
+/// IsValidLabelValue tests whether the value passed is a valid label value.
+///
+/// # Examples
+/// ```
+/// # use taibai_api::common::validation::names::is_valid_label_value;
+/// assert!(is_valid_label_value("").is_empty());
+/// assert!(is_valid_label_value("my_value").is_empty());
+/// assert!(!is_valid_label_value("-bad").is_empty());
+/// ```
+pub fn is_valid_label_value(value: &str) -> Vec<String> {
+    let mut errs = Vec::new();
+    if value.len() > LABEL_VALUE_MAX_LENGTH {
+        errs.push(max_len_error(LABEL_VALUE_MAX_LENGTH));
+    }
+    if !LABEL_VALUE_RE.is_match(value) {
+        errs.push(regex_error(
+            LABEL_VALUE_ERR_MSG,
+            LABEL_VALUE_FMT,
+            &["MyValue", "my_value", "12345"],
+        ));
+    }
+    errs
+}
+
+/// IsDNS1123Label tests for a string that conforms to the definition of a label in DNS (RFC 1123).
+///
+/// # Examples
+/// ```
+/// # use taibai_api::common::validation::names::is_dns1123_label;
+/// assert!(is_dns1123_label("my-name").is_empty());
+/// assert_eq!(is_dns1123_label("my.name"), vec!["must not contain dots"]);
+/// ```
+pub fn is_dns1123_label(value: &str) -> Vec<String> {
+    let mut errs = Vec::new();
+    if value.len() > DNS1123_LABEL_MAX_LENGTH {
+        errs.push(max_len_error(DNS1123_LABEL_MAX_LENGTH));
+    }
+    if !DNS1123_LABEL_RE.is_match(value) {
+        if DNS1123_SUBDOMAIN_RE.is_match(value) {
+            // It was a valid subdomain and not a valid label. Since we already
+            // checked length, it must be dots.
+            errs.push("must not contain dots".to_string());
+        } else {
+            errs.push(regex_error(
+                DNS1123_LABEL_ERROR_MSG,
+                DNS1123_LABEL_FMT,
+                &["my-name", "123-abc"],
+            ));
+        }
+    }
+    errs
+}
+
+/// IsDNS1123Subdomain tests for a string that conforms to the definition of a subdomain in DNS (RFC 1123).
+///
+/// # Examples
+/// ```
+/// # use taibai_api::common::validation::names::is_dns1123_subdomain;
+/// assert!(is_dns1123_subdomain("example.com").is_empty());
+/// assert!(!is_dns1123_subdomain("Example.com").is_empty());
+/// ```
+pub fn is_dns1123_subdomain(value: &str) -> Vec<String> {
+    let mut errs = Vec::new();
+    if value.len() > DNS1123_SUBDOMAIN_MAX_LENGTH {
+        errs.push(max_len_error(DNS1123_SUBDOMAIN_MAX_LENGTH));
+    }
+    if !DNS1123_SUBDOMAIN_RE.is_match(value) {
+        errs.push(regex_error(
+            DNS1123_SUBDOMAIN_ERROR_MSG,
+            DNS1123_SUBDOMAIN_FMT,
+            &["example.com"],
+        ));
+    }
+    errs
+}
+
+/// IsValidPortNum tests that the argument is a valid, non-zero port number.
+pub fn is_valid_port_num(port: i32) -> Vec<String> {
+    if (1..=65535).contains(&port) {
+        return Vec::new();
+    }
+    vec![inclusive_range_error(1, 65535)]
+}
+
+/// IsValidPortName checks that the argument is valid syntax. It must be
+/// non-empty and no more than 15 characters long. It may contain only [-a-z0-9]
+/// and must contain at least one letter [a-z]. It must not start or end with a
+/// hyphen, nor contain adjacent hyphens.
+///
+/// # Examples
+/// ```
+/// # use taibai_api::common::validation::names::is_valid_port_name;
+/// assert!(is_valid_port_name("http").is_empty());
+/// assert_eq!(is_valid_port_name("8080"), vec!["must contain at least one letter (a-z)"]);
+/// ```
+pub fn is_valid_port_name(port: &str) -> Vec<String> {
+    let mut errs = Vec::new();
+    if port.len() > PORT_NAME_MAX_LENGTH {
+        errs.push(max_len_error(PORT_NAME_MAX_LENGTH));
+    }
+    if !PORT_NAME_CHARSET_RE.is_match(port) {
+        errs.push(
+            "must contain only alpha-numeric characters (a-z, 0-9), and hyphens (-)".to_string(),
+        );
+    }
+    if !PORT_NAME_ONE_LETTER_RE.is_match(port) {
+        errs.push("must contain at least one letter (a-z)".to_string());
+    }
+    if port.contains("--") {
+        errs.push("must not contain consecutive hyphens".to_string());
+    }
+    if port.starts_with('-') || port.ends_with('-') {
+        errs.push("must not begin or end with a hyphen".to_string());
+    }
+    errs
+}
+
+/// InclusiveRangeError returns a string explanation of a numeric "must be
+/// between" validation failure.
+pub fn inclusive_range_error(lo: i64, hi: i64) -> String {
+    format!("must be between {lo} and {hi}, inclusive")
+}
+
+/// MaxLenError returns a string explanation of a "string too long" validation failure.
+pub fn max_len_error(length: usize) -> String {
+    format!("must be no more than {length} characters")
+}
+
+/// RegexError returns a string explanation of a regex validation failure.
+pub fn regex_error(msg: &str, fmt: &str, examples: &[&str]) -> String {
+    if examples.is_empty() {
+        return format!("{msg} (regex used for validation is '{fmt}')");
+    }
+
+    let mut out = format!("{msg} (e.g. ");
+    for (i, example) in examples.iter().enumerate() {
+        if i > 0 {
+            out.push_str(" or ");
+        }
+        out.push_str(&format!("'{example}', "));
+    }
+    out.push_str(&format!("regex used for validation is '{fmt}')"));
+    out
+}
+
+/// EmptyError returns a string explanation of a "must not be empty" validation failure.
+pub fn empty_error() -> &'static str {
+    "must be non-empty"
+}
+
+/// Prepends `prefix` to every message.
+pub fn prefix_each(msgs: Vec<String>, prefix: &str) -> Vec<String> {
+    msgs.into_iter()
+        .map(|msg| format!("{prefix}{msg}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_and_join_qualified() {
+        let (prefix, name) = split_qualified("app.kubernetes.io/name");
+        assert_eq!((prefix, name), (Some("app.kubernetes.io"), "name"));
+        assert_eq!(join_qualified(prefix, name), "app.kubernetes.io/name");
+        assert!(is_dns1123_subdomain(prefix.unwrap()).is_empty());
+        assert!(is_qualified_name("app.kubernetes.io/name").is_empty());
+
+        let (prefix, name) = split_qualified("name");
+        assert_eq!((prefix, name), (None, "name"));
+        assert_eq!(join_qualified(prefix, name), "name");
+        assert!(is_dns1123_label(name).is_empty());
+        assert!(is_qualified_name("name").is_empty());
+
+        assert_eq!(split_qualified("/name"), (Some(""), "name"));
+        assert_eq!(
+            is_qualified_name("/name"),
+            vec!["prefix part must be non-empty"]
+        );
+    }
+
+    #[test]
+    fn test_is_valid_label_value_go_message() {
+        assert_eq!(
+            is_valid_label_value("-bad-"),
+            vec![
+                "a valid label must be an empty string or consist of alphanumeric characters, '-', '_' or '.', \
+                 and must start and end with an alphanumeric character (e.g. 'MyValue',  or 'my_value',  or '12345', \
+                 regex used for validation is '(([A-Za-z0-9][-A-Za-z0-9_.]*)?[A-Za-z0-9])?')"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_dns1123_subdomain_too_long_go_message() {
+        let value = format!("{}.com", "a".repeat(250));
+        assert_eq!(
+            is_dns1123_subdomain(&value),
+            vec!["must be no more than 253 characters"]
+        );
+
+        assert_eq!(
+            is_dns1123_subdomain("Example.com"),
+            vec![
+                "a lowercase RFC 1123 subdomain must consist of lower case alphanumeric characters, '-' or '.', \
+                 and must start and end with an alphanumeric character (e.g. 'example.com', \
+                 regex used for validation is '[a-z0-9]([-a-z0-9]*[a-z0-9])?(\\.[a-z0-9]([-a-z0-9]*[a-z0-9])?)*')"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_dns1123_label_go_message() {
+        assert_eq!(
+            is_dns1123_label("My-Name"),
+            vec![
+                "a lowercase RFC 1123 label must consist of lower case alphanumeric characters or '-', \
+                 and must start and end with an alphanumeric character (e.g. 'my-name',  or '123-abc', \
+                 regex used for validation is '[a-z0-9]([-a-z0-9]*[a-z0-9])?')"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_qualified_name_prefix_errors() {
+        assert_eq!(
+            is_qualified_name("/name"),
+            vec!["prefix part must be non-empty"]
+        );
+        assert!(is_qualified_name("Example.com/name")[0].starts_with("prefix part a lowercase"));
+    }
+}
@@ -1,6 +1,6 @@
 use super::{
     BadValue, ErrorList, Path, forbidden, invalid, is_dns1123_label, is_dns1123_subdomain,
-    required, validate_labels,
+    required, validate_annotations, validate_labels,
 };
 use crate::common::ObjectMeta;
 use std::collections::BTreeSet;
@@ -98,8 +98,12 @@ pub fn validate_object_meta(
         ));
     }
 
-    // Validate labels (matches upstream ValidateObjectMetaAccessor)
+    // Validate labels and annotations (matches upstream ValidateObjectMetaAccessor)
     all_errs.extend(validate_labels(&meta.labels, &fld_path.child("labels")));
+    all_errs.extend(validate_annotations(
+        &meta.annotations,
+        &fld_path.child("annotations"),
+    ));
 
     all_errs
 }
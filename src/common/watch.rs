@@ -0,0 +1,112 @@
+//! Watch event types
+//!
+//! Corresponds to [Kubernetes WatchEvent](https://github.com/kubernetes/apimachinery/blob/master/pkg/apis/meta/v1/watch.go)
+
+use serde::{Deserialize, Serialize};
+
+use super::traits::VersionedObject;
+
+/// Event type constants
+pub mod watch_event_type {
+    /// The object was added.
+    pub const ADDED: &str = "ADDED";
+    /// The object was modified.
+    pub const MODIFIED: &str = "MODIFIED";
+    /// The object was deleted.
+    pub const DELETED: &str = "DELETED";
+    /// A bookmark carrying only the current resourceVersion of the collection.
+    pub const BOOKMARK: &str = "BOOKMARK";
+    /// The watch failed; the object is a `Status`.
+    pub const ERROR: &str = "ERROR";
+}
+
+/// WatchEvent is a single event of a watch stream.
+///
+/// `T` is the watched object type. `ERROR` events carry a `Status` instead and
+/// should be decoded as `WatchEvent<Status>`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchEvent<T> {
+    /// Type is one of ADDED, MODIFIED, DELETED, BOOKMARK or ERROR.
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    /// Object is the object that changed, the bookmark object, or the error status.
+    pub object: T,
+}
+
+/// Tracks the resourceVersion to resume a watch from.
+///
+/// Every non-error event, including `BOOKMARK`, advances the tracked version to
+/// the resourceVersion of its object.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct ResourceVersionTracker {
+    resource_version: Option<String>,
+}
+
+impl ResourceVersionTracker {
+    /// Creates a tracker starting from the resourceVersion of an initial list.
+    pub fn new(resource_version: impl Into<String>) -> Self {
+        let resource_version = resource_version.into();
+        Self {
+            resource_version: (!resource_version.is_empty()).then_some(resource_version),
+        }
+    }
+
+    /// Updates the tracked version from `event`. `ERROR` events and objects
+    /// without a resourceVersion leave it unchanged.
+    pub fn observe<T: VersionedObject>(&mut self, event: &WatchEvent<T>) {
+        if event.type_ == watch_event_type::ERROR {
+            return;
+        }
+        let resource_version = event.object.metadata().resource_version();
+        if !resource_version.is_empty() {
+            self.resource_version = Some(resource_version.to_string());
+        }
+    }
+
+    /// Returns the resourceVersion to pass when restarting the watch.
+    pub fn resource_version(&self) -> Option<&str> {
+        self.resource_version.as_deref()
+    }
+
+    /// Forgets the tracked version, e.g. after a `410 Gone` forces a relist.
+    pub fn reset(&mut self) {
+        self.resource_version = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::v1::Pod;
+
+    #[test]
+    fn test_tracker_follows_added_and_bookmark_events() {
+        let added: WatchEvent<Pod> = serde_json::from_value(serde_json::json!({
+            "type": "ADDED",
+            "object": {
+                "kind": "Pod",
+                "apiVersion": "v1",
+                "metadata": {"name": "web-0", "namespace": "default", "resourceVersion": "1201"}
+            }
+        }))
+        .unwrap();
+        let bookmark: WatchEvent<Pod> = serde_json::from_value(serde_json::json!({
+            "type": "BOOKMARK",
+            "object": {"kind": "Pod", "apiVersion": "v1", "metadata": {"resourceVersion": "1250"}}
+        }))
+        .unwrap();
+
+        let mut tracker = ResourceVersionTracker::new("1100");
+        tracker.observe(&added);
+        assert_eq!(tracker.resource_version(), Some("1201"));
+
+        tracker.observe(&bookmark);
+        assert_eq!(bookmark.type_, watch_event_type::BOOKMARK);
+        assert_eq!(tracker.resource_version(), Some("1250"));
+
+        tracker.reset();
+        assert_eq!(tracker.resource_version(), None);
+    }
+}
@@ -94,7 +94,7 @@ pub struct LeaseList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// items is a list of schema objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Lease>,
 }
 
@@ -165,7 +165,7 @@ pub struct LeaseCandidateList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// items is a list of schema objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<LeaseCandidate>,
 }
 
@@ -2,6 +2,7 @@
 //!
 //! Ported from k8s.io/kubernetes/pkg/apis/coordination/validation/validation.go
 
+use crate::common::validation::names::{max_len_error, regex_error};
 use crate::common::validation::{
     BadValue, ErrorList, Path, forbidden, invalid, name_is_dns_subdomain, not_supported, required,
     validate_object_meta, validate_object_meta_update,
@@ -59,6 +60,16 @@ pub fn validate_lease_spec(spec: &internal::LeaseSpec, fld_path: &Path) -> Error
         }
     }
 
+    if let (Some(acquire_time), Some(renew_time)) = (&spec.acquire_time, &spec.renew_time)
+        && renew_time < acquire_time
+    {
+        all_errs.push(invalid(
+            &fld_path.child("renewTime"),
+            BadValue::String(renew_time.to_string()),
+            "must not be earlier than acquireTime",
+        ));
+    }
+
     if let Some(value) = spec.lease_transitions {
         if value < 0 {
             all_errs.push(invalid(
@@ -277,28 +288,6 @@ fn has_ch_dir_prefix(value: &str) -> Vec<String> {
     }
 }
 
-fn max_len_error(length: usize) -> String {
-    format!("must be no more than {} characters", length)
-}
-
-fn regex_error(msg: &str, fmt: &str, examples: &[&str]) -> String {
-    if examples.is_empty() {
-        return format!("{} (regex used for validation is '{}')", msg, fmt);
-    }
-
-    let mut out = format!("{} (e.g. ", msg);
-    for (i, example) in examples.iter().enumerate() {
-        if i > 0 {
-            out.push_str(" or ");
-        }
-        out.push('\'');
-        out.push_str(example);
-        out.push_str("', ");
-    }
-    out.push_str(&format!("regex used for validation is '{}')", fmt));
-    out
-}
-
 // ============================================================================
 // Tests
 // ============================================================================
@@ -307,7 +296,7 @@ fn regex_error(msg: &str, fmt: &str, examples: &[&str]) -> String {
 mod tests {
     use super::*;
     use crate::common::validation::ErrorType;
-    use crate::common::{ObjectMeta, TypeMeta};
+    use crate::common::{MicroTime, ObjectMeta, TypeMeta};
 
     fn lease_meta(name: &str) -> ObjectMeta {
         ObjectMeta {
@@ -351,6 +340,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_lease_renew_time_before_acquire_time() {
+        let obj = internal::Lease {
+            type_meta: TypeMeta::default(),
+            metadata: lease_meta("lease-a"),
+            spec: internal::LeaseSpec {
+                acquire_time: Some(MicroTime::from_str("2024-01-15T10:00:00.500000Z").unwrap()),
+                renew_time: Some(MicroTime::from_str("2024-01-15T10:00:00.250000Z").unwrap()),
+                ..Default::default()
+            },
+        };
+
+        let errs = validate_lease(&obj);
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].field, "spec.renewTime");
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+    }
+
+    #[test]
+    fn test_validate_lease_renew_time_after_acquire_time() {
+        let acquire_time = MicroTime::from_str("2024-01-15T10:00:00.250000Z").unwrap();
+        let obj = internal::Lease {
+            type_meta: TypeMeta::default(),
+            metadata: lease_meta("lease-a"),
+            spec: internal::LeaseSpec {
+                acquire_time: Some(acquire_time.clone()),
+                renew_time: Some(acquire_time),
+                lease_transitions: Some(0),
+                ..Default::default()
+            },
+        };
+        assert!(validate_lease(&obj).is_empty());
+
+        let mut renewed = obj.clone();
+        renewed.spec.renew_time = Some(MicroTime::from_str("2024-01-15T10:00:10Z").unwrap());
+        assert!(validate_lease(&renewed).is_empty());
+    }
+
     #[test]
     fn test_validate_lease_preferred_holder_requires_strategy() {
         let obj = internal::Lease {
@@ -8,7 +8,6 @@ use crate::common::{
     ApplyDefault, HasTypeMeta, ListMeta, MicroTime, ObjectMeta, ResourceSchema, TypeMeta,
     VersionedObject,
 };
-use crate::impl_unimplemented_prost_message;
 use serde::{Deserialize, Serialize};
 use std::sync::OnceLock;
 
@@ -101,7 +100,7 @@ pub struct LeaseList {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<ListMeta>,
     /// items is a list of schema objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Lease>,
 }
 
@@ -248,12 +247,7 @@ impl ApplyDefault for LeaseList {
     }
 }
 
-// ----------------------------------------------------------------------------
-// Protobuf Placeholder (using macro)
-// ----------------------------------------------------------------------------
-
-impl_unimplemented_prost_message!(Lease);
-impl_unimplemented_prost_message!(LeaseList);
+// Protobuf: see proto.rs
 
 // ============================================================================
 // Tests
@@ -270,3 +264,8 @@ mod serde_roundtrip_tests;
 
 #[cfg(test)]
 mod conversion_roundtrip_tests;
+
+mod proto;
+
+#[cfg(test)]
+mod proto_roundtrip_tests;
@@ -0,0 +1,210 @@
+//! Protobuf encodings for coordination/v1 types
+//!
+//! Field numbers follow k8s.io/api/coordination/v1/generated.proto. TypeMeta is
+//! carried by the runtime envelope, not by the messages themselves.
+
+use prost::bytes::{Buf, BufMut};
+use prost::encoding::{DecodeContext, WireType, int32, message, skip_field, string};
+use prost::{DecodeError, Message};
+
+use super::{Lease, LeaseList, LeaseSpec};
+
+// ============================================================================
+// Lease
+// ============================================================================
+
+impl Message for Lease {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref metadata) = self.metadata {
+            message::encode(1, metadata, buf);
+        }
+        if let Some(ref spec) = self.spec {
+            message::encode(2, spec, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.metadata.get_or_insert_default(), buf, ctx),
+            2 => message::merge(wire_type, self.spec.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.metadata
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + self.spec.as_ref().map_or(0, |v| message::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = Lease::default();
+    }
+}
+
+impl Message for LeaseList {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref metadata) = self.metadata {
+            message::encode(1, metadata, buf);
+        }
+        message::encode_repeated(2, &self.items, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.metadata.get_or_insert_default(), buf, ctx),
+            2 => message::merge_repeated(wire_type, &mut self.items, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.metadata
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + message::encoded_len_repeated(2, &self.items)
+    }
+
+    fn clear(&mut self) {
+        *self = LeaseList::default();
+    }
+}
+
+// ============================================================================
+// LeaseSpec
+// ============================================================================
+
+impl Message for LeaseSpec {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.holder_identity {
+            string::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.lease_duration_seconds {
+            int32::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.acquire_time {
+            message::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.renew_time {
+            message::encode(4, value, buf);
+        }
+        if let Some(ref value) = self.lease_transitions {
+            int32::encode(5, value, buf);
+        }
+        if let Some(ref value) = self.strategy {
+            string::encode(6, value, buf);
+        }
+        if let Some(ref value) = self.preferred_holder {
+            string::encode(7, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(
+                wire_type,
+                self.holder_identity.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            2 => int32::merge(
+                wire_type,
+                self.lease_duration_seconds.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            3 => message::merge(
+                wire_type,
+                self.acquire_time.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => message::merge(wire_type, self.renew_time.get_or_insert_default(), buf, ctx),
+            5 => int32::merge(
+                wire_type,
+                self.lease_transitions.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            6 => string::merge(wire_type, self.strategy.get_or_insert_default(), buf, ctx),
+            7 => string::merge(
+                wire_type,
+                self.preferred_holder.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.holder_identity
+            .as_ref()
+            .map_or(0, |v| string::encoded_len(1, v))
+            + self
+                .lease_duration_seconds
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(2, v))
+            + self
+                .acquire_time
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+            + self
+                .renew_time
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(4, v))
+            + self
+                .lease_transitions
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(5, v))
+            + self
+                .strategy
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(6, v))
+            + self
+                .preferred_holder
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(7, v))
+    }
+
+    fn clear(&mut self) {
+        *self = LeaseSpec::default();
+    }
+}
@@ -0,0 +1,60 @@
+use super::{Lease, LeaseList, LeaseSpec, coordinated_lease_strategy};
+use crate::common::{ListMeta, MicroTime, ObjectMeta};
+use prost::Message;
+
+fn lease_full() -> Lease {
+    Lease {
+        metadata: Some(ObjectMeta {
+            name: Some("kube-controller-manager".to_string()),
+            namespace: Some("kube-system".to_string()),
+            resource_version: Some("42".to_string()),
+            ..Default::default()
+        }),
+        spec: Some(LeaseSpec {
+            holder_identity: Some("node-a_0f3c".to_string()),
+            lease_duration_seconds: Some(15),
+            acquire_time: Some(
+                MicroTime::from_str("2024-01-15T10:00:00.123456Z").expect("parse microtime"),
+            ),
+            renew_time: Some(
+                MicroTime::from_str("2024-01-15T10:05:30.000001Z").expect("parse microtime"),
+            ),
+            lease_transitions: Some(0),
+            strategy: Some(coordinated_lease_strategy::OLDEST_EMULATION_VERSION.to_string()),
+            preferred_holder: Some("node-b_9a1e".to_string()),
+        }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn proto_roundtrip_lease_full() {
+    let lease = lease_full();
+    let bytes = lease.encode_to_vec();
+    assert_eq!(bytes.len(), lease.encoded_len());
+
+    let decoded = Lease::decode(bytes.as_slice()).expect("decode Lease");
+    assert_eq!(decoded, lease);
+}
+
+#[test]
+fn proto_lease_spec_preserves_presence() {
+    let empty = LeaseSpec::default();
+    let decoded = LeaseSpec::decode(empty.encode_to_vec().as_slice()).unwrap();
+    assert_eq!(decoded, empty);
+}
+
+#[test]
+fn proto_roundtrip_lease_list() {
+    let list = LeaseList {
+        metadata: Some(ListMeta {
+            resource_version: Some("7".to_string()),
+            ..Default::default()
+        }),
+        items: vec![lease_full(), Lease::default()],
+        ..Default::default()
+    };
+
+    let decoded = LeaseList::decode(list.encode_to_vec().as_slice()).expect("decode LeaseList");
+    assert_eq!(decoded, list);
+}
@@ -89,7 +89,7 @@ pub struct LeaseList {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<ListMeta>,
     /// items is a list of schema objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Lease>,
 }
 
@@ -161,7 +161,7 @@ pub struct LeaseCandidateList {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<ListMeta>,
     /// items is a list of schema objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<LeaseCandidate>,
 }
 
@@ -44,7 +44,7 @@ pub struct ComponentStatusList {
     pub type_meta: TypeMeta,
     pub metadata: ListMeta,
     /// List of ComponentStatus objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ComponentStatus>,
 }
 
@@ -48,7 +48,7 @@ pub struct ConfigMapList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// Items is the list of ConfigMaps.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ConfigMap>,
 }
 
@@ -97,7 +97,7 @@ pub struct SecretList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// Items is the list of Secret objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Secret>,
 }
 
@@ -138,7 +138,7 @@ pub struct ServiceAccountList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// Items is the list of ServiceAccounts.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ServiceAccount>,
 }
 
@@ -40,7 +40,7 @@ pub struct EndpointsList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// List of endpoints.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Endpoints>,
 }
 
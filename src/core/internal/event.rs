@@ -100,7 +100,7 @@ pub struct EventList {
     pub type_meta: TypeMeta,
     pub metadata: ListMeta,
     /// List of events.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Event>,
 }
 
@@ -122,6 +122,9 @@ pub struct Preconditions {
     /// Specifies the target UID.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub uid: Option<String>,
+    /// Specifies the target ResourceVersion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_version: Option<String>,
 }
 
 // ============================================================================
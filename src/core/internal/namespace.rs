@@ -41,7 +41,7 @@ pub struct NamespaceList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// Items is the list of Namespaces.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Namespace>,
 }
 
@@ -422,7 +422,7 @@ pub struct NodeList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// List of nodes.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Node>,
 }
 
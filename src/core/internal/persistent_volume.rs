@@ -566,7 +566,7 @@ pub struct PersistentVolumeList {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<ListMeta>,
     /// List of persistent volumes.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<PersistentVolume>,
 }
 
@@ -675,7 +675,7 @@ pub struct PersistentVolumeClaimList {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<ListMeta>,
     /// List of persistent volume claims.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<PersistentVolumeClaim>,
 }
 
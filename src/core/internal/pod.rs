@@ -184,10 +184,10 @@ pub struct PodStatus {
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub host_ip: String,
     /// HostIPs holds the IP addresses allocated to the host.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "hostIPs")]
     pub host_ips: Vec<HostIP>,
     /// PodIPs holds all of the known IP addresses allocated to the pod.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "podIPs")]
     pub pod_ips: Vec<PodIP>,
     /// StartTime is the time when the pod was acknowledged by the Kubelet.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -226,7 +226,7 @@ pub struct PodList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// List of pods.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Pod>,
 }
 
@@ -273,7 +273,7 @@ pub struct PodTemplateList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// List of PodTemplates.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<PodTemplate>,
 }
 
@@ -398,4 +398,21 @@ pub struct ContainerExtendedResourceRequest {
 // ============================================================================
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pod_status_ip_lists_use_upstream_json_keys() {
+        let json = serde_json::json!({
+            "hostIPs": [{"ip": "10.0.0.1"}],
+            "podIPs": [{"ip": "10.244.0.5"}, {"ip": "fd00:10:244::5"}]
+        });
+        let status: PodStatus = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(status.host_ips[0].ip, "10.0.0.1");
+        assert_eq!(status.pod_ips[1].ip, "fd00:10:244::5");
+
+        let value = serde_json::to_value(&status).unwrap();
+        assert_eq!(value["hostIPs"], json["hostIPs"]);
+        assert_eq!(value["podIPs"], json["podIPs"]);
+    }
+}
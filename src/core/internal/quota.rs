@@ -83,7 +83,7 @@ pub struct ResourceQuotaList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// Items is a list of ResourceQuota objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ResourceQuota>,
 }
 
@@ -228,7 +228,7 @@ pub struct LimitRangeList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// Items is a list of LimitRange objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<LimitRange>,
 }
 
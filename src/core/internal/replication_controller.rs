@@ -88,7 +88,7 @@ pub struct ReplicationControllerList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// List of replication controllers.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ReplicationController>,
 }
 
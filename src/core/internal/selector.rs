@@ -4,7 +4,9 @@
 //! These are shared types used across different Kubernetes API versions.
 
 use crate::common::util::Quantity;
+use crate::common::validation::{LabelSelectorFields, LabelSelectorRequirementFields};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// LabelSelector is a label query over a set of resources.
 ///
@@ -39,6 +41,32 @@ pub struct LabelSelectorRequirement {
     pub values: Vec<String>,
 }
 
+impl LabelSelectorFields for LabelSelector {
+    type Requirement = LabelSelectorRequirement;
+
+    fn match_labels(&self) -> &BTreeMap<String, String> {
+        &self.match_labels
+    }
+
+    fn match_expressions(&self) -> &[LabelSelectorRequirement] {
+        &self.match_expressions
+    }
+}
+
+impl LabelSelectorRequirementFields for LabelSelectorRequirement {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    fn operator(&self) -> &str {
+        &self.operator
+    }
+
+    fn values(&self) -> &[String] {
+        &self.values
+    }
+}
+
 /// LabelSelectorOperator constants
 pub mod label_selector_operator {
     pub const IN: &str = "In";
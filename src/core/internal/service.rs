@@ -132,7 +132,7 @@ pub struct ServiceList {
     #[serde(default)]
     pub metadata: ListMeta,
     /// List of services.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Service>,
 }
 
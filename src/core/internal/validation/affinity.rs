@@ -3,14 +3,12 @@
 //! Ported from k8s.io/kubernetes/pkg/apis/core/validation/validation.go
 
 use crate::common::validation::{
-    BadValue, ErrorList, Path, duplicate, invalid, is_dns1123_label, is_valid_label_value,
-    not_supported, required, validate_label_name,
+    BadValue, ErrorList, Path, invalid, is_dns1123_label, not_supported, required,
+    validate_label_name, validate_label_selector,
 };
-use crate::core::internal::selector::LabelSelector as InternalLabelSelector;
 use crate::core::internal::{
     Affinity, NodeAffinity, PodAffinity, PodAffinityTerm, PodAntiAffinity, WeightedPodAffinityTerm,
 };
-use std::collections::HashSet;
 
 // ============================================================================
 // Affinity Validation
@@ -135,7 +133,9 @@ fn validate_node_selector_term(
     all_errs
 }
 
-fn validate_node_selector_requirement(
+/// Validates a node selector requirement. `is_label` selects label-name rules
+/// for the key (matchExpressions) over qualified-name rules (matchFields).
+pub fn validate_node_selector_requirement(
     req: &crate::core::internal::selector::NodeSelectorRequirement,
     path: &Path,
     is_label: bool,
@@ -209,7 +209,8 @@ fn validate_node_selector_requirement(
     all_errs
 }
 
-fn validate_pod_affinity(affinity: &PodAffinity, path: &Path) -> ErrorList {
+/// Validates the required and preferred terms of a PodAffinity.
+pub fn validate_pod_affinity(affinity: &PodAffinity, path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
     for (i, term) in affinity
@@ -241,7 +242,8 @@ fn validate_pod_affinity(affinity: &PodAffinity, path: &Path) -> ErrorList {
     all_errs
 }
 
-fn validate_pod_anti_affinity(affinity: &PodAntiAffinity, path: &Path) -> ErrorList {
+/// Validates the required and preferred terms of a PodAntiAffinity.
+pub fn validate_pod_anti_affinity(affinity: &PodAntiAffinity, path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
     for (i, term) in affinity
@@ -326,75 +328,6 @@ fn validate_weighted_pod_affinity_term(term: &WeightedPodAffinityTerm, path: &Pa
     all_errs
 }
 
-pub fn validate_label_selector(selector: &InternalLabelSelector, path: &Path) -> ErrorList {
-    let mut all_errs = ErrorList::new();
-
-    if !selector.match_labels.is_empty() {
-        all_errs.extend(crate::common::validation::validate_labels(
-            &selector.match_labels,
-            &path.child("matchLabels"),
-        ));
-    }
-
-    for (i, expr) in selector.match_expressions.iter().enumerate() {
-        let expr_path = path.child("matchExpressions").index(i);
-        all_errs.extend(validate_label_name(&expr.key, &expr_path.child("key")));
-        match expr.operator.as_str() {
-            crate::core::internal::selector::label_selector_operator::IN
-            | crate::core::internal::selector::label_selector_operator::NOT_IN => {
-                if expr.values.is_empty() {
-                    all_errs.push(required(
-                        &expr_path.child("values"),
-                        "values are required for In/NotIn",
-                    ));
-                }
-            }
-            crate::core::internal::selector::label_selector_operator::EXISTS
-            | crate::core::internal::selector::label_selector_operator::DOES_NOT_EXIST => {
-                if !expr.values.is_empty() {
-                    all_errs.push(invalid(
-                        &expr_path.child("values"),
-                        BadValue::String(format!("{:?}", expr.values)),
-                        "values must be empty for Exists/DoesNotExist",
-                    ));
-                }
-            }
-            _ => {
-                let valid = vec![
-                    crate::core::internal::selector::label_selector_operator::IN,
-                    crate::core::internal::selector::label_selector_operator::NOT_IN,
-                    crate::core::internal::selector::label_selector_operator::EXISTS,
-                    crate::core::internal::selector::label_selector_operator::DOES_NOT_EXIST,
-                ];
-                all_errs.push(not_supported(
-                    &expr_path.child("operator"),
-                    BadValue::String(expr.operator.clone()),
-                    &valid,
-                ));
-            }
-        }
-
-        let mut seen = HashSet::new();
-        for (j, value) in expr.values.iter().enumerate() {
-            if !seen.insert(value) {
-                all_errs.push(duplicate(
-                    &expr_path.child("values").index(j),
-                    BadValue::String(value.clone()),
-                ));
-            }
-            for msg in is_valid_label_value(value) {
-                all_errs.push(invalid(
-                    &expr_path.child("values").index(j),
-                    BadValue::String(value.clone()),
-                    &msg,
-                ));
-            }
-        }
-    }
-
-    all_errs
-}
-
 fn validate_dns1123_label(value: &str, path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
     for msg in is_dns1123_label(value) {
@@ -0,0 +1,48 @@
+//! Binding validation for Kubernetes core internal API
+//!
+//! Ported from k8s.io/kubernetes/pkg/registry/core/pod/storage/storage.go
+
+use crate::common::validation::{
+    BadValue, ErrorList, Path, name_is_dns_subdomain, not_supported, required,
+};
+use crate::core::internal::Binding;
+
+/// The only kind a pod may be bound to.
+const BINDING_TARGET_KIND_NODE: &str = "Node";
+
+/// Validates a Binding used to assign a pod to a node.
+pub fn validate_binding(binding: &Binding) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+
+    all_errs.extend(crate::common::validation::validate_object_meta(
+        &binding.metadata,
+        true,
+        name_is_dns_subdomain,
+        &Path::new("metadata"),
+    ));
+
+    let target_path = Path::new("target");
+    if binding
+        .target
+        .name
+        .as_deref()
+        .unwrap_or_default()
+        .is_empty()
+    {
+        all_errs.push(required(
+            &target_path.child("name"),
+            "target name is required",
+        ));
+    }
+
+    let kind = binding.target.kind.as_deref().unwrap_or_default();
+    if kind != BINDING_TARGET_KIND_NODE {
+        all_errs.push(not_supported(
+            &target_path.child("kind"),
+            BadValue::String(kind.to_string()),
+            &[BINDING_TARGET_KIND_NODE],
+        ));
+    }
+
+    all_errs
+}
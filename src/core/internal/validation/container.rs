@@ -18,7 +18,9 @@ use crate::core::internal::validation::probe::{
 };
 use crate::core::internal::validation::resources::validate_container_resource_requirements;
 use crate::core::internal::validation::volume::{validate_volume_devices, validate_volume_mounts};
-use crate::core::internal::{ContainerPort, EnvFromSource, EnvVar, VolumeSource};
+use crate::core::internal::{
+    ContainerPort, EnvFromSource, EnvVar, VolumeSource, container_restart_policy,
+};
 use std::collections::{HashMap, HashSet};
 use std::sync::LazyLock;
 
@@ -316,16 +318,11 @@ pub fn validate_containers(
         ));
 
         // Check for duplicate container names
-        if !container.name.is_empty() {
-            if all_names.contains(&container.name) {
-                all_errs.push(crate::common::validation::duplicate(
-                    &idx_path.child("name"),
-                    BadValue::String(container.name.clone()),
-                ));
-            } else {
-                all_names.insert(container.name.clone());
-            }
-        }
+        all_errs.extend(validate_container_name_unique(
+            &container.name,
+            &mut all_names,
+            &idx_path.child("name"),
+        ));
     }
 
     // Check for host port conflicts across all containers
@@ -346,6 +343,25 @@ pub fn validate_containers(
     all_errs
 }
 
+/// Records `name` in `all_names`, reporting a duplicate at `path` if an earlier
+/// container in the pod already uses it.
+///
+/// Empty names are skipped; they are reported by the per-container validators.
+pub fn validate_container_name_unique(
+    name: &str,
+    all_names: &mut HashSet<String>,
+    path: &Path,
+) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    if !name.is_empty() && !all_names.insert(name.to_string()) {
+        all_errs.push(crate::common::validation::duplicate(
+            path,
+            BadValue::String(name.to_string()),
+        ));
+    }
+    all_errs
+}
+
 /// Validates a list of init containers.
 ///
 /// Init containers have similar validation to regular containers, but:
@@ -378,16 +394,11 @@ pub fn validate_init_containers(
         ));
 
         // Check for duplicate names (must be unique across regular + init containers)
-        if !container.name.is_empty() {
-            if all_names.contains(&container.name) {
-                all_errs.push(crate::common::validation::duplicate(
-                    &idx_path.child("name"),
-                    BadValue::String(container.name.clone()),
-                ));
-            } else {
-                all_names.insert(container.name.clone());
-            }
-        }
+        all_errs.extend(validate_container_name_unique(
+            &container.name,
+            &mut all_names,
+            &idx_path.child("name"),
+        ));
 
         // Init containers run one-by-one, so check host port conflicts individually
         let port_sets: Vec<Vec<ContainerPort>> = vec![
@@ -402,31 +413,33 @@ pub fn validate_init_containers(
             port_sets.iter().map(|ports| ports.as_slice()).collect();
         all_errs.extend(accumulate_unique_host_ports(&port_slices, &path));
 
-        // For now, disallow lifecycle and probes in init containers
-        // TODO: Add support for restartPolicy=Always init containers in later phase
-        if container.lifecycle.is_some() {
-            all_errs.push(forbidden(
-                &idx_path.child("lifecycle"),
-                "may not be set for init containers without restartPolicy=Always",
-            ));
-        }
-        if container.liveness_probe.is_some() {
-            all_errs.push(forbidden(
-                &idx_path.child("livenessProbe"),
-                "may not be set for init containers without restartPolicy=Always",
-            ));
-        }
-        if container.readiness_probe.is_some() {
-            all_errs.push(forbidden(
-                &idx_path.child("readinessProbe"),
-                "may not be set for init containers without restartPolicy=Always",
-            ));
-        }
-        if container.startup_probe.is_some() {
-            all_errs.push(forbidden(
-                &idx_path.child("startupProbe"),
-                "may not be set for init containers without restartPolicy=Always",
-            ));
+        // Lifecycle hooks and probes are only meaningful for sidecars
+        // (restartPolicy=Always), which keep running alongside the main containers.
+        if container.restart_policy.as_deref() != Some(container_restart_policy::ALWAYS) {
+            if container.lifecycle.is_some() {
+                all_errs.push(forbidden(
+                    &idx_path.child("lifecycle"),
+                    "may not be set for init containers without restartPolicy=Always",
+                ));
+            }
+            if container.liveness_probe.is_some() {
+                all_errs.push(forbidden(
+                    &idx_path.child("livenessProbe"),
+                    "may not be set for init containers without restartPolicy=Always",
+                ));
+            }
+            if container.readiness_probe.is_some() {
+                all_errs.push(forbidden(
+                    &idx_path.child("readinessProbe"),
+                    "may not be set for init containers without restartPolicy=Always",
+                ));
+            }
+            if container.startup_probe.is_some() {
+                all_errs.push(forbidden(
+                    &idx_path.child("startupProbe"),
+                    "may not be set for init containers without restartPolicy=Always",
+                ));
+            }
         }
     }
 
@@ -17,24 +17,31 @@ pub fn validate_env(vars: &[EnvVar], path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
     for (i, ev) in vars.iter().enumerate() {
-        let idx_path = path.index(i);
+        all_errs.extend(validate_env_var(ev, &path.index(i)));
+    }
 
-        if ev.name.is_empty() {
-            all_errs.push(required(&idx_path.child("name"), "name is required"));
-        } else if !is_valid_env_var_name(&ev.name) {
-            all_errs.push(invalid(
-                &idx_path.child("name"),
-                BadValue::String(ev.name.clone()),
-                "invalid environment variable name",
-            ));
-        }
+    all_errs
+}
+
+/// Validates a single environment variable.
+///
+/// The name must be a C identifier, and `value` and `valueFrom` are mutually
+/// exclusive.
+pub fn validate_env_var(ev: &EnvVar, path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
 
-        all_errs.extend(validate_env_var_value_from(
-            ev,
-            &idx_path.child("valueFrom"),
+    if ev.name.is_empty() {
+        all_errs.push(required(&path.child("name"), "name is required"));
+    } else if !is_valid_env_var_name(&ev.name) {
+        all_errs.push(invalid(
+            &path.child("name"),
+            BadValue::String(ev.name.clone()),
+            "invalid environment variable name",
         ));
     }
 
+    all_errs.extend(validate_env_var_value_from(ev, &path.child("valueFrom")));
+
     all_errs
 }
 
@@ -1,5 +1,7 @@
 //! Helper validation wrappers for internal core API types.
 
+use crate::common::IntOrString;
+use crate::common::validation::names::{is_valid_port_name, is_valid_port_num};
 use crate::common::validation::{BadValue, ErrorList, Path, invalid, is_dns1123_label};
 
 pub fn validate_container_name(name: &str, path: &Path) -> ErrorList {
@@ -10,6 +12,24 @@ pub fn validate_container_name(name: &str, path: &Path) -> ErrorList {
     all_errs
 }
 
+/// Validates a port that can be either a number or an IANA service name (IntOrString)
+pub fn validate_port_num_or_name(port: &IntOrString, path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    match port {
+        IntOrString::Int(num) => {
+            for msg in is_valid_port_num(*num) {
+                all_errs.push(invalid(path, BadValue::Int((*num).into()), &msg));
+            }
+        }
+        IntOrString::String(name) => {
+            for msg in is_valid_port_name(name) {
+                all_errs.push(invalid(path, BadValue::String(name.clone()), &msg));
+            }
+        }
+    }
+    all_errs
+}
+
 /// Validates a ConfigMap or Secret data key.
 ///
 /// Keys must follow these rules:
@@ -1,6 +1,7 @@
 //! Internal validation entrypoints for core API types.
 
 pub mod affinity;
+pub mod binding;
 pub mod config;
 pub mod container;
 pub mod container_ports;
@@ -146,8 +146,11 @@ fn validate_finalizer_name(finalizer: &str, path: &Path) -> ErrorList {
 }
 
 /// Checks if a finalizer name is a standard Kubernetes finalizer
+///
+/// Besides the namespace `kubernetes` finalizer, the garbage collector's
+/// `orphan` and `foregroundDeletion` finalizers are standard.
 fn is_standard_finalizer(name: &str) -> bool {
-    matches!(name, "kubernetes")
+    matches!(name, "kubernetes" | "orphan" | "foregroundDeletion")
 }
 
 fn namespace_phase_to_str(value: &NamespacePhase) -> &'static str {
@@ -4,7 +4,7 @@
 
 use super::helpers::is_config_map_key;
 use crate::common::validation::{
-    BadValue, ErrorList, Path, forbidden, invalid, required, validate_label_name,
+    BadValue, ErrorList, Path, duplicate, forbidden, invalid, required, validate_label_name,
 };
 use crate::core::internal::{
     AvoidPods, Node, NodeConfigSource, NodeConfigStatus, NodeSwapStatus, Taint,
@@ -185,32 +185,46 @@ fn validate_pod_cidrs(values: &[String], path: &Path) -> ErrorList {
     all_errs
 }
 
-/// Validates a taint
-fn validate_node_taints(taints: &[Taint], path: &Path) -> ErrorList {
+/// Validates a list of node taints.
+pub fn validate_node_taints(taints: &[Taint], path: &Path) -> ErrorList {
+    validate_taint_fields(
+        taints.iter().map(|taint| {
+            (
+                taint.key.as_str(),
+                taint.value.as_str(),
+                taint_effect_to_str(&taint.effect),
+            )
+        }),
+        path,
+    )
+}
+
+/// Validates taints given as `(key, value, effect)` strings.
+///
+/// Shared with core/v1, where the effect is a free-form string that can be
+/// empty or unknown and would not survive conversion to the internal enum.
+pub(crate) fn validate_taint_fields<'a>(
+    taints: impl IntoIterator<Item = (&'a str, &'a str, &'a str)>,
+    path: &Path,
+) -> ErrorList {
     let mut all_errs = ErrorList::new();
     let mut unique = HashSet::new();
-    for (i, taint) in taints.iter().enumerate() {
+    for (i, (key, value, effect)) in taints.into_iter().enumerate() {
         let idx_path = path.index(i);
-        all_errs.extend(validate_label_name(&taint.key, &idx_path.child("key")));
-        for msg in crate::common::validation::is_valid_label_value(&taint.value) {
+        all_errs.extend(validate_label_name(key, &idx_path.child("key")));
+        for msg in crate::common::validation::is_valid_label_value(value) {
             all_errs.push(invalid(
                 &idx_path.child("value"),
-                BadValue::String(taint.value.clone()),
+                BadValue::String(value.to_string()),
                 &msg,
             ));
         }
-        all_errs.extend(validate_taint_effect(
-            &taint.effect,
-            &idx_path.child("effect"),
-        ));
+        all_errs.extend(validate_taint_effect(effect, &idx_path.child("effect")));
 
-        let effect = taint_effect_to_str(&taint.effect);
-        let key = (taint.key.clone(), effect.to_string());
-        if !unique.insert(key) {
-            all_errs.push(invalid(
+        if !unique.insert((key, effect)) {
+            all_errs.push(duplicate(
                 &idx_path,
-                BadValue::String(format!("{}:{}", taint.key, effect)),
-                "taints must be unique by key and effect pair",
+                BadValue::String(format!("{}:{}", key, effect)),
             ));
         }
     }
@@ -218,14 +232,15 @@ fn validate_node_taints(taints: &[Taint], path: &Path) -> ErrorList {
     all_errs
 }
 
-fn validate_taint_effect(effect: &crate::core::internal::TaintEffect, path: &Path) -> ErrorList {
+fn validate_taint_effect(effect: &str, path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
     let valid_effects = ["NoSchedule", "PreferNoSchedule", "NoExecute"];
-    let effect_value = taint_effect_to_str(effect);
-    if !valid_effects.contains(&effect_value) {
+    if effect.is_empty() {
+        all_errs.push(required(path, ""));
+    } else if !valid_effects.contains(&effect) {
         all_errs.push(crate::common::validation::not_supported(
             path,
-            BadValue::String(effect_value.to_string()),
+            BadValue::String(effect.to_string()),
             &valid_effects,
         ));
     }
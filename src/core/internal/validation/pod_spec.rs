@@ -2,19 +2,23 @@
 
 use crate::common::validation::{
     BadValue, ErrorList, Path, duplicate, forbidden, invalid, is_dns1123_label,
-    is_valid_label_value, not_supported, required, validate_label_name,
+    is_valid_label_value, not_supported, required, validate_label_name, validate_label_selector,
+};
+use crate::core::internal::validation::affinity::validate_affinity;
+use crate::core::internal::validation::container::{
+    validate_container_name_unique, validate_containers, validate_init_containers,
 };
-use crate::core::internal::validation::affinity::{validate_affinity, validate_label_selector};
-use crate::core::internal::validation::container::{validate_containers, validate_init_containers};
 use crate::core::internal::validation::dns::{
     DnsValidationOptions, validate_dns_policy, validate_pod_dns_config,
 };
-use crate::core::internal::validation::resources::validate_pod_resource_requirements;
+use crate::core::internal::validation::resources::{
+    validate_pod_overhead, validate_pod_resource_requirements,
+};
 use crate::core::internal::validation::security::validate_pod_security_context;
 use crate::core::internal::validation::volume::validate_volumes;
 use crate::core::internal::{
-    HostAlias, InternalContainer, InternalPodReadinessGate, PodOS, PodSchedulingGate, PodSpec,
-    TaintEffect, Toleration, TolerationOperator,
+    HostAlias, InternalContainer, InternalPodReadinessGate, PodOS, PodResourceClaim,
+    PodSchedulingGate, PodSpec, TaintEffect, Toleration, TolerationOperator,
 };
 use crate::core::v1::EphemeralContainer;
 use std::collections::HashSet;
@@ -31,11 +35,17 @@ pub fn validate_pod_spec(spec: &PodSpec, path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
     // Validate termination grace period (required)
-    if spec.termination_grace_period_seconds.is_none() {
-        all_errs.push(required(
+    match spec.termination_grace_period_seconds {
+        None => all_errs.push(required(
             &path.child("terminationGracePeriodSeconds"),
             "terminationGracePeriodSeconds is required",
-        ));
+        )),
+        Some(value) if value < 0 => all_errs.push(invalid(
+            &path.child("terminationGracePeriodSeconds"),
+            BadValue::Int(value),
+            "must be greater than or equal to 0",
+        )),
+        Some(_) => {}
     }
 
     let grace_period = &spec.termination_grace_period_seconds;
@@ -64,6 +74,12 @@ pub fn validate_pod_spec(spec: &PodSpec, path: &Path) -> ErrorList {
     let (volumes_by_source, volume_errs) = validate_volumes(&spec.volumes, &path.child("volumes"));
     all_errs.extend(volume_errs);
 
+    // Validate pod resource claims
+    all_errs.extend(validate_pod_resource_claims(
+        &spec.resource_claims,
+        &path.child("resourceClaims"),
+    ));
+
     // Gather pod resource claim names
     let pod_claim_names: HashSet<String> = spec
         .resource_claims
@@ -200,18 +216,10 @@ pub fn validate_pod_spec(spec: &PodSpec, path: &Path) -> ErrorList {
         ));
     }
 
-    // Validate overhead quantities (non-negative)
-    if !spec.overhead.is_empty() {
-        for (name, qty) in &spec.overhead {
-            if qty.sign().unwrap_or(std::cmp::Ordering::Equal).is_lt() {
-                all_errs.push(invalid(
-                    &path.child("overhead").key(name),
-                    BadValue::String(qty.to_string()),
-                    "must be non-negative",
-                ));
-            }
-        }
-    }
+    all_errs.extend(validate_pod_overhead(
+        &spec.overhead,
+        &path.child("overhead"),
+    ));
 
     // Validate active deadline seconds
     if let Some(value) = spec.active_deadline_seconds {
@@ -336,6 +344,63 @@ fn validate_restart_policy(
     ErrorList::new()
 }
 
+fn validate_pod_resource_claims(claims: &[PodResourceClaim], path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    let mut seen = HashSet::new();
+
+    for (i, claim) in claims.iter().enumerate() {
+        let idx_path = path.index(i);
+
+        if claim.name.is_empty() {
+            all_errs.push(required(&idx_path.child("name"), ""));
+        } else if !seen.insert(claim.name.as_str()) {
+            all_errs.push(duplicate(
+                &idx_path.child("name"),
+                BadValue::String(claim.name.clone()),
+            ));
+        } else {
+            all_errs.extend(validate_dns1123_label(&claim.name, &idx_path.child("name")));
+        }
+
+        match (
+            &claim.resource_claim_name,
+            &claim.resource_claim_template_name,
+        ) {
+            (Some(_), Some(_)) => all_errs.push(invalid(
+                &idx_path,
+                BadValue::String(claim.name.clone()),
+                "at most one of `resourceClaimName` or `resourceClaimTemplateName` may be specified",
+            )),
+            (None, None) => all_errs.push(invalid(
+                &idx_path,
+                BadValue::String(claim.name.clone()),
+                "must specify one of: `resourceClaimName`, `resourceClaimTemplateName`",
+            )),
+            _ => {}
+        }
+
+        for (field, name) in [
+            ("resourceClaimName", &claim.resource_claim_name),
+            (
+                "resourceClaimTemplateName",
+                &claim.resource_claim_template_name,
+            ),
+        ] {
+            if let Some(name) = name {
+                for msg in crate::common::validation::is_dns1123_subdomain(name) {
+                    all_errs.push(invalid(
+                        &idx_path.child(field),
+                        BadValue::String(name.clone()),
+                        &msg,
+                    ));
+                }
+            }
+        }
+    }
+
+    all_errs
+}
+
 fn validate_readiness_gates(gates: &[InternalPodReadinessGate], path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
@@ -350,7 +415,8 @@ fn validate_readiness_gates(gates: &[InternalPodReadinessGate], path: &Path) ->
     all_errs
 }
 
-fn validate_scheduling_gates(gates: &[PodSchedulingGate], path: &Path) -> ErrorList {
+/// Validates `spec.schedulingGates`: each name must be a unique qualified name.
+pub fn validate_scheduling_gates(gates: &[PodSchedulingGate], path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
     let mut seen = HashSet::new();
 
@@ -476,18 +542,31 @@ fn validate_tolerations(tolerations: &[Toleration], path: &Path) -> ErrorList {
     all_errs
 }
 
-fn validate_host_aliases(aliases: &[HostAlias], path: &Path) -> ErrorList {
+/// Validates `spec.hostAliases`.
+///
+/// Each alias needs a valid IP and at least one hostname, and every hostname
+/// must be a DNS-1123 subdomain.
+pub fn validate_host_aliases(aliases: &[HostAlias], path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
     for (i, alias) in aliases.iter().enumerate() {
         let idx_path = path.index(i);
         all_errs.extend(validate_host_alias_ip(&alias.ip, &idx_path.child("ip")));
-        for (j, hostname) in alias.hostnames.iter().enumerate() {
-            all_errs.extend(validate_dns1123_label(
-                hostname,
-                &idx_path.child("hostnames").index(j),
+        if alias.hostnames.is_empty() {
+            all_errs.push(required(
+                &idx_path.child("hostnames"),
+                "must specify at least one hostname",
             ));
         }
+        for (j, hostname) in alias.hostnames.iter().enumerate() {
+            for msg in crate::common::validation::is_dns1123_subdomain(hostname) {
+                all_errs.push(invalid(
+                    &idx_path.child("hostnames").index(j),
+                    BadValue::String(hostname.clone()),
+                    &msg,
+                ));
+            }
+        }
     }
 
     all_errs
@@ -512,7 +591,11 @@ fn validate_pod_os(_os: &PodOS, _path: &Path) -> ErrorList {
     ErrorList::new()
 }
 
-fn validate_topology_spread_constraints(
+/// Validates `spec.topologySpreadConstraints`.
+///
+/// Each constraint is validated on its own, and no two constraints may share
+/// the same `topologyKey` and `whenUnsatisfiable`.
+pub fn validate_topology_spread_constraints(
     constraints: &[crate::core::internal::InternalTopologySpreadConstraint],
     path: &Path,
 ) -> ErrorList {
@@ -521,32 +604,7 @@ fn validate_topology_spread_constraints(
 
     for (i, constraint) in constraints.iter().enumerate() {
         let idx_path = path.index(i);
-        all_errs.extend(validate_positive_field(
-            constraint.max_skew as i64,
-            &idx_path.child("maxSkew"),
-        ));
-
-        if constraint.topology_key.is_empty() {
-            all_errs.push(required(
-                &idx_path.child("topologyKey"),
-                "topologyKey is required",
-            ));
-        }
-
-        if !matches!(
-            constraint.when_unsatisfiable.as_str(),
-            WHEN_UNSATISFIABLE_DO_NOT_SCHEDULE | WHEN_UNSATISFIABLE_SCHEDULE_ANYWAY
-        ) {
-            let valid = vec![
-                WHEN_UNSATISFIABLE_DO_NOT_SCHEDULE,
-                WHEN_UNSATISFIABLE_SCHEDULE_ANYWAY,
-            ];
-            all_errs.push(not_supported(
-                &idx_path.child("whenUnsatisfiable"),
-                BadValue::String(constraint.when_unsatisfiable.clone()),
-                &valid,
-            ));
-        }
+        all_errs.extend(validate_topology_spread_constraint(constraint, &idx_path));
 
         let pair_key = format!(
             "{}:{}",
@@ -561,59 +619,99 @@ fn validate_topology_spread_constraints(
                 BadValue::String(pair_key),
             ));
         }
+    }
+
+    all_errs
+}
 
-        if let Some(min_domains) = constraint.min_domains {
-            all_errs.extend(validate_positive_field(
-                min_domains as i64,
+fn validate_topology_spread_constraint(
+    constraint: &crate::core::internal::InternalTopologySpreadConstraint,
+    idx_path: &Path,
+) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    all_errs.extend(validate_positive_field(
+        constraint.max_skew as i64,
+        &idx_path.child("maxSkew"),
+    ));
+
+    if constraint.topology_key.is_empty() {
+        all_errs.push(required(
+            &idx_path.child("topologyKey"),
+            "topologyKey is required",
+        ));
+    } else {
+        all_errs.extend(validate_label_name(
+            &constraint.topology_key,
+            &idx_path.child("topologyKey"),
+        ));
+    }
+
+    if !matches!(
+        constraint.when_unsatisfiable.as_str(),
+        WHEN_UNSATISFIABLE_DO_NOT_SCHEDULE | WHEN_UNSATISFIABLE_SCHEDULE_ANYWAY
+    ) {
+        let valid = vec![
+            WHEN_UNSATISFIABLE_DO_NOT_SCHEDULE,
+            WHEN_UNSATISFIABLE_SCHEDULE_ANYWAY,
+        ];
+        all_errs.push(not_supported(
+            &idx_path.child("whenUnsatisfiable"),
+            BadValue::String(constraint.when_unsatisfiable.clone()),
+            &valid,
+        ));
+    }
+
+    if let Some(min_domains) = constraint.min_domains {
+        all_errs.extend(validate_positive_field(
+            min_domains as i64,
+            &idx_path.child("minDomains"),
+        ));
+        if constraint.when_unsatisfiable != WHEN_UNSATISFIABLE_DO_NOT_SCHEDULE {
+            all_errs.push(invalid(
                 &idx_path.child("minDomains"),
+                BadValue::Int(min_domains as i64),
+                "can only use minDomains when whenUnsatisfiable=DoNotSchedule",
             ));
-            if constraint.when_unsatisfiable != WHEN_UNSATISFIABLE_DO_NOT_SCHEDULE {
-                all_errs.push(invalid(
-                    &idx_path.child("minDomains"),
-                    BadValue::Int(min_domains as i64),
-                    "can only use minDomains when whenUnsatisfiable=DoNotSchedule",
-                ));
-            }
         }
+    }
 
-        if !constraint.node_affinity_policy.is_empty()
-            && !matches!(
-                constraint.node_affinity_policy.as_str(),
-                NODE_AFFINITY_POLICY_IGNORE | NODE_AFFINITY_POLICY_HONOR
-            )
-        {
-            let valid = vec![NODE_AFFINITY_POLICY_IGNORE, NODE_AFFINITY_POLICY_HONOR];
-            all_errs.push(not_supported(
-                &idx_path.child("nodeAffinityPolicy"),
-                BadValue::String(constraint.node_affinity_policy.clone()),
-                &valid,
-            ));
-        }
+    if !constraint.node_affinity_policy.is_empty()
+        && !matches!(
+            constraint.node_affinity_policy.as_str(),
+            NODE_AFFINITY_POLICY_IGNORE | NODE_AFFINITY_POLICY_HONOR
+        )
+    {
+        let valid = vec![NODE_AFFINITY_POLICY_IGNORE, NODE_AFFINITY_POLICY_HONOR];
+        all_errs.push(not_supported(
+            &idx_path.child("nodeAffinityPolicy"),
+            BadValue::String(constraint.node_affinity_policy.clone()),
+            &valid,
+        ));
+    }
 
-        if !constraint.match_label_keys.is_empty() {
-            let mut seen = HashSet::new();
-            for (j, key) in constraint.match_label_keys.iter().enumerate() {
-                if !seen.insert(key) {
-                    all_errs.push(duplicate(
-                        &idx_path.child("matchLabelKeys").index(j),
-                        BadValue::String(key.clone()),
-                    ));
-                }
-                all_errs.extend(validate_label_name(
-                    key,
+    if !constraint.match_label_keys.is_empty() {
+        let mut seen = HashSet::new();
+        for (j, key) in constraint.match_label_keys.iter().enumerate() {
+            if !seen.insert(key) {
+                all_errs.push(duplicate(
                     &idx_path.child("matchLabelKeys").index(j),
+                    BadValue::String(key.clone()),
                 ));
             }
-        }
-
-        if let Some(ref selector) = constraint.label_selector {
-            all_errs.extend(validate_label_selector(
-                selector,
-                &idx_path.child("labelSelector"),
+            all_errs.extend(validate_label_name(
+                key,
+                &idx_path.child("matchLabelKeys").index(j),
             ));
         }
     }
 
+    if let Some(ref selector) = constraint.label_selector {
+        all_errs.extend(validate_label_selector(
+            selector,
+            &idx_path.child("labelSelector"),
+        ));
+    }
+
     all_errs
 }
 
@@ -621,6 +719,58 @@ fn validate_topology_spread_constraints(
 // Ephemeral Container Validation
 // ============================================================================
 
+/// Validates `spec.ephemeralContainers` against the rest of the pod spec.
+///
+/// `path` is the path of the ephemeral container list. Volume errors are not
+/// reported here; they belong to [`validate_pod_spec`].
+pub fn validate_pod_spec_ephemeral_containers(spec: &PodSpec, path: &Path) -> ErrorList {
+    let (volumes_by_source, _) = validate_volumes(&spec.volumes, &Path::new("volumes"));
+    validate_ephemeral_containers(
+        &spec.ephemeral_containers,
+        &spec.containers,
+        &spec.init_containers,
+        &volumes_by_source,
+        path,
+    )
+}
+
+/// Validates that container names are unique across `containers`,
+/// `initContainers` and `ephemeralContainers`, rooted at the pod spec `path`.
+///
+/// This is the name check [`validate_pod_spec`] runs while walking the three
+/// lists, so a collision is reported on the later container.
+pub fn validate_unique_container_names(spec: &PodSpec, path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    let mut all_names = HashSet::new();
+    let containers = spec
+        .containers
+        .iter()
+        .enumerate()
+        .map(|(i, c)| ("containers", i, c.name.as_str()));
+    let init_containers = spec
+        .init_containers
+        .iter()
+        .enumerate()
+        .map(|(i, c)| ("initContainers", i, c.name.as_str()));
+    let ephemeral_containers = spec
+        .ephemeral_containers
+        .iter()
+        .enumerate()
+        .map(|(i, c)| ("ephemeralContainers", i, c.name.as_str()));
+
+    for (field, i, name) in containers
+        .chain(init_containers)
+        .chain(ephemeral_containers)
+    {
+        all_errs.extend(validate_container_name_unique(
+            name,
+            &mut all_names,
+            &path.child(field).index(i).child("name"),
+        ));
+    }
+    all_errs
+}
+
 /// Validates ephemeral containers.
 ///
 /// Corresponds to [upstream validateEphemeralContainers](https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/core/validation/validation.go)
@@ -671,14 +821,11 @@ fn validate_ephemeral_containers(
             }
 
             // Name must be unique across all container types
-            if all_names.contains(&ec.name) {
-                all_errs.push(duplicate(
-                    &idx_path.child("name"),
-                    BadValue::String(ec.name.clone()),
-                ));
-            } else {
-                all_names.insert(ec.name.clone());
-            }
+            all_errs.extend(validate_container_name_unique(
+                &ec.name,
+                &mut all_names,
+                &idx_path.child("name"),
+            ));
         }
 
         // Validate image
@@ -1,6 +1,6 @@
 //! Probe and Lifecycle validation for Kubernetes core internal API.
 
-use crate::common::util::IntOrString;
+use super::helpers::validate_port_num_or_name;
 use crate::common::validation::{
     BadValue, ErrorList, Path, forbidden, invalid, not_supported, required,
 };
@@ -93,7 +93,8 @@ pub fn validate_startup_probe(
     all_errs
 }
 
-fn validate_probe(probe: &Probe, grace_period: &Option<i64>, path: &Path) -> ErrorList {
+/// Validates the handler and timing fields common to all probe types.
+pub fn validate_probe(probe: &Probe, grace_period: &Option<i64>, path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
     all_errs.extend(validate_probe_handler(
@@ -437,51 +438,6 @@ fn validate_sleep_action(seconds: i64, grace_period: &Option<i64>, path: &Path)
 // Helper Functions
 // ============================================================================
 
-fn validate_port_num_or_name(port: &IntOrString, path: &Path) -> ErrorList {
-    match port {
-        IntOrString::Int(num) => validate_port_number(*num, path),
-        IntOrString::String(name) => validate_port_name(name, path),
-    }
-}
-
-fn validate_port_number(port: i32, path: &Path) -> ErrorList {
-    let mut all_errs = ErrorList::new();
-    if !(1..=65535).contains(&port) {
-        all_errs.push(invalid(
-            path,
-            BadValue::Int(port as i64),
-            "must be in the range 1-65535",
-        ));
-    }
-    all_errs
-}
-
-fn validate_port_name(name: &str, path: &Path) -> ErrorList {
-    let mut all_errs = ErrorList::new();
-    if name.is_empty() {
-        all_errs.push(required(path, "port name must not be empty"));
-        return all_errs;
-    }
-
-    let is_valid = name.len() <= 15
-        && name
-            .chars()
-            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
-        && name.chars().any(|c| c.is_ascii_lowercase())
-        && !name.starts_with('-')
-        && !name.ends_with('-')
-        && !name.contains("--");
-
-    if !is_valid {
-        all_errs.push(invalid(
-            path,
-            BadValue::String(name.to_string()),
-            "must be a valid port name (lowercase alphanumeric or '-', 1-15 chars, must contain at least one letter, no consecutive hyphens)",
-        ));
-    }
-    all_errs
-}
-
 fn is_valid_http_header_name(name: &str) -> bool {
     if name.is_empty() {
         return false;
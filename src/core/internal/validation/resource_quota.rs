@@ -852,7 +852,7 @@ fn validate_resource_quota_quantity_value(
         return all_errs;
     }
 
-    if quantity.sign().unwrap_or(std::cmp::Ordering::Equal).is_lt() {
+    if quantity.is_negative() {
         all_errs.push(invalid(
             path,
             BadValue::String(quantity.to_string()),
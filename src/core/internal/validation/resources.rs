@@ -2,7 +2,7 @@
 
 use crate::common::Quantity;
 use crate::common::validation::{BadValue, ErrorList, Path, forbidden, invalid, required};
-use crate::core::internal::{ResourceClaim, ResourceRequirements};
+use crate::core::internal::{ResourceClaim, ResourceList, ResourceRequirements};
 use std::collections::HashSet;
 use std::sync::LazyLock;
 
@@ -214,7 +214,7 @@ pub(crate) fn validate_resource_quantity_value(
 ) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
-    if quantity.sign().unwrap_or(std::cmp::Ordering::Equal).is_lt() {
+    if quantity.is_negative() {
         all_errs.push(invalid(
             path,
             BadValue::String(quantity.to_string()),
@@ -225,6 +225,24 @@ pub(crate) fn validate_resource_quantity_value(
     all_errs
 }
 
+/// Validates a pod overhead: every entry needs a valid container resource name
+/// and a non-negative quantity.
+pub fn validate_pod_overhead(overhead: &ResourceList, path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+
+    for (name, quantity) in overhead {
+        let entry_path = path.key(name);
+        all_errs.extend(validate_container_resource_name(name, &entry_path));
+        all_errs.extend(validate_resource_quantity_value(
+            name,
+            quantity,
+            &entry_path,
+        ));
+    }
+
+    all_errs
+}
+
 #[allow(dead_code)]
 pub(crate) fn validate_resource_name_for_node(name: &str, path: &Path) -> ErrorList {
     validate_container_resource_name(name, path)
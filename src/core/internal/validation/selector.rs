@@ -3,7 +3,9 @@
 //! Ported from k8s.io/kubernetes/pkg/apis/core/validation/validation.go
 
 use crate::common::Quantity;
-use crate::common::validation::{BadValue, ErrorList, Path, invalid, not_supported, required};
+use crate::common::validation::{
+    BadValue, ErrorList, Path, invalid, not_supported, required, validate_qualified_name,
+};
 use crate::core::internal::selector::{
     ConfigMapKeySelector, FileKeySelector, ObjectFieldSelector, ResourceFieldSelector,
     SecretKeySelector,
@@ -62,12 +64,28 @@ static VALID_MEMORY_DIVISORS: LazyLock<HashSet<&'static str>> = LazyLock::new(||
 // Selector Validation
 // ============================================================================
 
-/// Validates an ObjectFieldSelector.
+/// Validates an ObjectFieldSelector used by an env var.
 ///
 /// Validates:
 /// - apiVersion is required
 /// - fieldPath is required and supported
+/// - subscripted paths are only allowed on metadata.labels/metadata.annotations
 pub fn validate_object_field_selector(selector: &ObjectFieldSelector, path: &Path) -> ErrorList {
+    validate_object_field_selector_with_expressions(
+        selector,
+        &VALID_ENV_DOWNWARD_API_FIELD_PATHS,
+        path,
+    )
+}
+
+/// Validates an ObjectFieldSelector against a set of supported field paths.
+///
+/// Corresponds to [upstream validateObjectFieldSelector](https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/core/validation/validation.go)
+pub(crate) fn validate_object_field_selector_with_expressions(
+    selector: &ObjectFieldSelector,
+    expressions: &HashSet<&'static str>,
+    path: &Path,
+) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
     if selector.api_version.is_empty() {
@@ -83,30 +101,49 @@ pub fn validate_object_field_selector(selector: &ObjectFieldSelector, path: &Pat
         return all_errs;
     }
 
-    let field_path = &selector.field_path;
+    let field_path = selector.field_path.as_str();
 
-    let base_path = if let Some(bracket_pos) = field_path.find('[') {
-        &field_path[..bracket_pos]
-    } else {
-        field_path.as_str()
-    };
-
-    if !VALID_ENV_DOWNWARD_API_FIELD_PATHS.contains(base_path) {
-        if !base_path.starts_with("metadata.labels")
-            && !base_path.starts_with("metadata.annotations")
-        {
-            let valid: Vec<&str> = VALID_ENV_DOWNWARD_API_FIELD_PATHS.iter().copied().collect();
-            all_errs.push(not_supported(
-                &path.child("fieldPath"),
-                BadValue::String(field_path.clone()),
-                &valid,
-            ));
+    if let Some((base_path, subscript)) = split_maybe_subscripted_path(field_path) {
+        match base_path {
+            "metadata.annotations" => {
+                all_errs.extend(validate_qualified_name(&subscript.to_lowercase(), path));
+            }
+            "metadata.labels" => {
+                all_errs.extend(validate_qualified_name(subscript, path));
+            }
+            _ => {
+                all_errs.push(invalid(
+                    path,
+                    BadValue::String(base_path.to_string()),
+                    "does not support subscript",
+                ));
+            }
         }
+    } else if !expressions.contains(field_path) {
+        let mut valid: Vec<&str> = expressions.iter().copied().collect();
+        valid.sort_unstable();
+        all_errs.push(not_supported(
+            &path.child("fieldPath"),
+            BadValue::String(field_path.to_string()),
+            &valid,
+        ));
     }
 
     all_errs
 }
 
+/// Splits a field path of the form `path['subscript']` into its parts.
+///
+/// Returns `None` when the field path is not subscripted.
+pub(crate) fn split_maybe_subscripted_path(field_path: &str) -> Option<(&str, &str)> {
+    let trimmed = field_path.strip_suffix("']")?;
+    let (base_path, subscript) = trimmed.split_once("['")?;
+    if base_path.is_empty() {
+        return None;
+    }
+    Some((base_path, subscript))
+}
+
 /// Validates a ResourceFieldSelector.
 ///
 /// Validates:
@@ -344,8 +381,8 @@ pub fn validate_file_key_selector(selector: &FileKeySelector, path: &Path) -> Er
 
 /// Validates an environment variable name.
 ///
-/// A valid env var name:
-/// - Starts with a letter or underscore
+/// A valid env var name is a C identifier:
+/// - Starts with an ASCII letter or underscore
 /// - Contains only alphanumeric characters and underscores
 /// - Does not contain '='
 pub(crate) fn is_valid_env_var_name(name: &str) -> bool {
@@ -354,12 +391,12 @@ pub(crate) fn is_valid_env_var_name(name: &str) -> bool {
     }
 
     let first_char = name.chars().next().unwrap();
-    if !first_char.is_alphabetic() && first_char != '_' {
+    if !first_char.is_ascii_alphabetic() && first_char != '_' {
         return false;
     }
 
     for c in name.chars() {
-        if !c.is_alphanumeric() && c != '_' {
+        if !c.is_ascii_alphanumeric() && c != '_' {
             return false;
         }
     }
@@ -384,3 +421,68 @@ pub(crate) fn is_valid_config_map_key(key: &str) -> bool {
 
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::validation::ErrorType;
+
+    fn field_selector(field_path: &str) -> ObjectFieldSelector {
+        ObjectFieldSelector {
+            api_version: "v1".to_string(),
+            field_path: field_path.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_object_field_selector_supported_paths() {
+        let path = Path::new("fieldRef");
+        for field_path in [
+            "metadata.name",
+            "metadata.namespace",
+            "status.podIP",
+            "spec.nodeName",
+            "metadata.labels['app']",
+            "metadata.annotations['example.com/Owner']",
+        ] {
+            let errs = validate_object_field_selector(&field_selector(field_path), &path);
+            assert!(errs.is_empty(), "{field_path}: {errs:?}");
+        }
+    }
+
+    #[test]
+    fn test_validate_object_field_selector_unsupported_path() {
+        let path = Path::new("fieldRef");
+
+        let errs = validate_object_field_selector(&field_selector("spec.containers"), &path);
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::NotSupported);
+        assert_eq!(errs.errors[0].field, "fieldRef.fieldPath");
+
+        // Whole label maps are only exposed through downward API volumes.
+        let errs = validate_object_field_selector(&field_selector("metadata.labels"), &path);
+        assert_eq!(errs.errors[0].error_type, ErrorType::NotSupported);
+
+        let errs = validate_object_field_selector(&field_selector("metadata.name['x']"), &path);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+
+        let errs =
+            validate_object_field_selector(&field_selector("metadata.labels['-bad']"), &path);
+        assert!(!errs.is_empty());
+    }
+
+    #[test]
+    fn test_validate_container_resource_field_selector_resource_names() {
+        let path = Path::new("resourceFieldRef");
+        let mut selector = ResourceFieldSelector {
+            container_name: String::new(),
+            resource: "limits.memory".to_string(),
+            divisor: None,
+        };
+        assert!(validate_container_resource_field_selector(&selector, &path, false).is_empty());
+
+        selector.resource = "limits.gpu".to_string();
+        let errs = validate_container_resource_field_selector(&selector, &path, false);
+        assert_eq!(errs.errors[0].error_type, ErrorType::NotSupported);
+    }
+}
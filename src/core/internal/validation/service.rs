@@ -2,6 +2,7 @@
 //!
 //! Ported from k8s.io/kubernetes/pkg/apis/core/validation/validation.go
 
+use super::helpers::validate_port_num_or_name;
 use crate::common::validation::{
     BadValue, Error, ErrorList, ErrorType, Path, forbidden, invalid, not_supported, required,
 };
@@ -599,29 +600,11 @@ pub fn validate_service_port(
         ));
     }
 
-    // Validate target port (simplified - just check it's set if provided)
     if let Some(ref target_port) = port.target_port {
-        match target_port {
-            crate::common::IntOrString::Int(val) => {
-                if *val < MIN_PORT || *val > MAX_PORT {
-                    all_errs.push(invalid(
-                        &path.child("targetPort"),
-                        BadValue::Int((*val).into()),
-                        &format!("must be between {} and {}", MIN_PORT, MAX_PORT),
-                    ));
-                }
-            }
-            crate::common::IntOrString::String(name) => {
-                if name.is_empty() {
-                    all_errs.push(invalid(
-                        &path.child("targetPort"),
-                        BadValue::String(name.clone()),
-                        "must not be empty",
-                    ));
-                }
-                // Named ports are validated when referenced - accept any non-empty string here
-            }
-        }
+        all_errs.extend(validate_port_num_or_name(
+            target_port,
+            &path.child("targetPort"),
+        ));
     }
 
     // Validate appProtocol (if present, must be a qualified name)
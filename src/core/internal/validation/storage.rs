@@ -4,13 +4,11 @@
 
 use crate::common::validation::{
     BadValue, ErrorList, Path, forbidden, invalid, is_dns1123_label, is_dns1123_subdomain,
-    is_valid_label_value, not_supported, required, validate_labels, validate_qualified_name,
+    not_supported, required, validate_label_selector, validate_qualified_name,
 };
-use crate::core::internal::LabelSelector;
 use crate::core::internal::host_path_type;
 use crate::core::internal::node_selector_operator;
 use crate::core::internal::persistent_volume as internal_pv;
-use crate::core::internal::selector::label_selector_operator;
 use crate::core::internal::validation::resources::validate_resource_quantity_value;
 use crate::core::internal::{
     NodeSelector, NodeSelectorRequirement, NodeSelectorTerm, PersistentVolume,
@@ -736,77 +734,6 @@ fn validate_positive_quantity_value(quantity: &crate::common::Quantity, path: &P
     all_errs
 }
 
-fn validate_label_selector(selector: &LabelSelector, path: &Path) -> ErrorList {
-    let mut all_errs = ErrorList::new();
-    all_errs.extend(validate_labels(
-        &selector.match_labels,
-        &path.child("matchLabels"),
-    ));
-
-    for (i, requirement) in selector.match_expressions.iter().enumerate() {
-        let req_path = path.child("matchExpressions").index(i);
-        if requirement.key.is_empty() {
-            all_errs.push(required(&req_path.child("key"), "key is required"));
-        } else {
-            all_errs.extend(validate_qualified_name(
-                &requirement.key,
-                &req_path.child("key"),
-            ));
-        }
-
-        match requirement.operator.as_str() {
-            label_selector_operator::IN | label_selector_operator::NOT_IN => {
-                if requirement.values.is_empty() {
-                    all_errs.push(required(
-                        &req_path.child("values"),
-                        "values must be non-empty for In/NotIn operators",
-                    ));
-                }
-            }
-            label_selector_operator::EXISTS | label_selector_operator::DOES_NOT_EXIST => {
-                if !requirement.values.is_empty() {
-                    all_errs.push(invalid(
-                        &req_path.child("values"),
-                        BadValue::String(format!("{:?}", requirement.values)),
-                        "values must be empty for Exists/DoesNotExist operators",
-                    ));
-                }
-            }
-            _ => {
-                all_errs.push(not_supported(
-                    &req_path.child("operator"),
-                    BadValue::String(requirement.operator.clone()),
-                    &[
-                        label_selector_operator::IN,
-                        label_selector_operator::NOT_IN,
-                        label_selector_operator::EXISTS,
-                        label_selector_operator::DOES_NOT_EXIST,
-                    ],
-                ));
-            }
-        }
-
-        for (j, value) in requirement.values.iter().enumerate() {
-            if value.is_empty() {
-                all_errs.push(required(
-                    &req_path.child("values").index(j),
-                    "value must be non-empty",
-                ));
-                continue;
-            }
-            for msg in is_valid_label_value(value) {
-                all_errs.push(invalid(
-                    &req_path.child("values").index(j),
-                    BadValue::String(value.clone()),
-                    &msg,
-                ));
-            }
-        }
-    }
-
-    all_errs
-}
-
 fn validate_volume_node_affinity(node_affinity: &VolumeNodeAffinity, path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
     if let Some(ref required) = node_affinity.required {
@@ -2,7 +2,7 @@
 
 use crate::common::validation::{
     BadValue, ErrorList, Path, forbidden, invalid, is_dns1123_label, is_dns1123_subdomain,
-    not_found, not_supported, required,
+    not_found, required,
 };
 use crate::core::internal::InternalContainer as Container;
 use crate::core::internal::{
@@ -584,11 +584,7 @@ fn validate_empty_dir_volume_source(
     // Validate size limit if specified
     if let Some(ref size_limit) = empty_dir.size_limit {
         // Size limit must be non-negative
-        if size_limit
-            .sign()
-            .unwrap_or(std::cmp::Ordering::Equal)
-            .is_lt()
-        {
+        if size_limit.is_negative() {
             all_errs.push(invalid(
                 &path.child("sizeLimit"),
                 BadValue::String(size_limit.to_string()),
@@ -934,7 +930,14 @@ fn validate_downward_api_volume_file(file: &DownwardAPIVolumeFile, path: &Path)
     all_errs
 }
 
-fn validate_projected_volume_source(projected: &ProjectedVolumeSource, path: &Path) -> ErrorList {
+/// Validates a projected volume source.
+///
+/// Every projected file must use a relative path without `..` segments, and
+/// no two projections may write the same path.
+pub fn validate_projected_volume_source(
+    projected: &ProjectedVolumeSource,
+    path: &Path,
+) -> ErrorList {
     let mut all_errs = ErrorList::new();
     let mut all_paths = HashSet::new();
 
@@ -1429,10 +1432,7 @@ pub fn validate_volume_mounts(
     for (i, mnt) in mounts.iter().enumerate() {
         let idx_path = path.index(i);
 
-        // name is required
-        if mnt.name.is_empty() {
-            all_errs.push(required(&idx_path.child("name"), "name is required"));
-        }
+        all_errs.extend(validate_volume_mount(mnt, container, &idx_path));
 
         // name must exist in volumes
         if !volumes.contains_key(&mnt.name) {
@@ -1442,14 +1442,6 @@ pub fn validate_volume_mounts(
             ));
         }
 
-        // mountPath is required
-        if mnt.mount_path.is_empty() {
-            all_errs.push(required(
-                &idx_path.child("mountPath"),
-                "mountPath is required",
-            ));
-        }
-
         // mountPath must be unique
         if mount_points.contains(&mnt.mount_path) {
             all_errs.push(invalid(
@@ -1475,48 +1467,103 @@ pub fn validate_volume_mounts(
                 "must not already exist as a path in volumeDevices",
             ));
         }
+    }
 
-        // Validate subPath if specified
-        if !mnt.sub_path.is_empty() {
-            all_errs.extend(validate_local_descending_path(
-                &mnt.sub_path,
-                &idx_path.child("subPath"),
-            ));
-        }
+    all_errs
+}
 
-        // Validate subPathExpr if specified
-        if !mnt.sub_path_expr.is_empty() {
-            // subPath and subPathExpr are mutually exclusive
-            if !mnt.sub_path.is_empty() {
-                all_errs.push(invalid(
-                    &idx_path.child("subPathExpr"),
-                    BadValue::String(mnt.sub_path_expr.clone()),
-                    "subPathExpr and subPath are mutually exclusive",
-                ));
-            }
+/// Validates a single volume mount of `container`, independent of the other
+/// mounts and of the pod's volumes.
+///
+/// `mountPath` must be absolute and may not contain `:` other than after a
+/// Windows drive letter. `subPath` and `subPathExpr` are mutually exclusive
+/// and must be relative paths without `..`. `Bidirectional` propagation
+/// requires a privileged container and a writable mount.
+pub fn validate_volume_mount(mnt: &VolumeMount, container: &Container, path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
 
-            all_errs.extend(validate_local_descending_path(
-                &mnt.sub_path_expr,
-                &idx_path.child("subPathExpr"),
-            ));
-        }
+    // name is required
+    if mnt.name.is_empty() {
+        all_errs.push(required(&path.child("name"), "name is required"));
+    }
 
-        if let Some(ref propagation) = mnt.mount_propagation {
-            all_errs.extend(validate_mount_propagation(
-                propagation,
-                container,
-                &idx_path.child("mountPropagation"),
+    // mountPath is required and must be absolute
+    if mnt.mount_path.is_empty() {
+        all_errs.push(required(&path.child("mountPath"), "mountPath is required"));
+    } else if !is_absolute_mount_path(&mnt.mount_path) {
+        all_errs.push(invalid(
+            &path.child("mountPath"),
+            BadValue::String(mnt.mount_path.clone()),
+            "must be an absolute path",
+        ));
+    } else if mount_path_without_drive(&mnt.mount_path).contains(':') {
+        all_errs.push(invalid(
+            &path.child("mountPath"),
+            BadValue::String(mnt.mount_path.clone()),
+            "must not contain ':'",
+        ));
+    }
+
+    // Validate subPath if specified
+    if !mnt.sub_path.is_empty() {
+        all_errs.extend(validate_local_descending_path(
+            &mnt.sub_path,
+            &path.child("subPath"),
+        ));
+    }
+
+    // Validate subPathExpr if specified
+    if !mnt.sub_path_expr.is_empty() {
+        // subPath and subPathExpr are mutually exclusive
+        if !mnt.sub_path.is_empty() {
+            all_errs.push(invalid(
+                &path.child("subPathExpr"),
+                BadValue::String(mnt.sub_path_expr.clone()),
+                "subPathExpr and subPath are mutually exclusive",
             ));
         }
-        all_errs.extend(validate_mount_recursive_read_only(
-            mnt,
-            &idx_path.child("recursiveReadOnly"),
+
+        all_errs.extend(validate_local_descending_path(
+            &mnt.sub_path_expr,
+            &path.child("subPathExpr"),
+        ));
+    }
+
+    if let Some(ref propagation) = mnt.mount_propagation {
+        all_errs.extend(validate_mount_propagation(
+            propagation,
+            mnt.read_only,
+            container,
+            &path.child("mountPropagation"),
         ));
     }
+    all_errs.extend(validate_mount_recursive_read_only(
+        mnt,
+        &path.child("recursiveReadOnly"),
+    ));
 
     all_errs
 }
 
+/// Returns true for absolute container paths. Windows containers may also
+/// use drive-letter (`C:\data`) or UNC (`\\server\share`) paths.
+fn is_absolute_mount_path(mount_path: &str) -> bool {
+    let bytes = mount_path.as_bytes();
+    let is_drive_path = bytes.len() >= 3
+        && bytes[0].is_ascii_alphabetic()
+        && bytes[1] == b':'
+        && (bytes[2] == b'\\' || bytes[2] == b'/');
+    mount_path.starts_with('/') || mount_path.starts_with('\\') || is_drive_path
+}
+
+/// Strips a leading Windows drive letter (`C:`) from an absolute mount path.
+fn mount_path_without_drive(mount_path: &str) -> &str {
+    match mount_path.as_bytes() {
+        [drive, b':', ..] if drive.is_ascii_alphabetic() => &mount_path[2..],
+        _ => mount_path,
+    }
+}
+
 /// Validates volume devices
 pub fn validate_volume_devices(
     devices: &[VolumeDevice],
@@ -1668,45 +1715,11 @@ fn validate_volume_object_field_selector(
     selector: &crate::core::internal::ObjectFieldSelector,
     path: &Path,
 ) -> ErrorList {
-    let mut all_errs = ErrorList::new();
-
-    if selector.api_version.is_empty() {
-        all_errs.push(required(
-            &path.child("apiVersion"),
-            "apiVersion is required",
-        ));
-        return all_errs;
-    }
-
-    if selector.field_path.is_empty() {
-        all_errs.push(required(&path.child("fieldPath"), "fieldPath is required"));
-        return all_errs;
-    }
-
-    let field_path = &selector.field_path;
-    let base_path = if let Some(bracket_pos) = field_path.find('[') {
-        &field_path[..bracket_pos]
-    } else {
-        field_path.as_str()
-    };
-
-    if !VALID_VOLUME_DOWNWARD_API_FIELD_PATH_EXPRESSIONS.contains(base_path) {
-        if !base_path.starts_with("metadata.labels")
-            && !base_path.starts_with("metadata.annotations")
-        {
-            let valid: Vec<&str> = VALID_VOLUME_DOWNWARD_API_FIELD_PATH_EXPRESSIONS
-                .iter()
-                .copied()
-                .collect();
-            all_errs.push(not_supported(
-                &path.child("fieldPath"),
-                BadValue::String(field_path.clone()),
-                &valid,
-            ));
-        }
-    }
-
-    all_errs
+    crate::core::internal::validation::selector::validate_object_field_selector_with_expressions(
+        selector,
+        &VALID_VOLUME_DOWNWARD_API_FIELD_PATH_EXPRESSIONS,
+        path,
+    )
 }
 
 fn validate_container_resource_field_selector(
@@ -1747,26 +1760,35 @@ fn validate_csi_driver_name(driver: &str, path: &Path) -> ErrorList {
 
 fn validate_mount_propagation(
     mount_propagation: &crate::core::internal::MountPropagationMode,
+    read_only: bool,
     container: &Container,
     path: &Path,
 ) -> ErrorList {
     let mut all_errs = ErrorList::new();
+    if !matches!(
+        mount_propagation,
+        crate::core::internal::MountPropagationMode::Bidirectional
+    ) {
+        return all_errs;
+    }
 
     let privileged = container
         .security_context
         .as_ref()
         .and_then(|ctx| ctx.privileged)
         .unwrap_or(false);
-    if matches!(
-        mount_propagation,
-        crate::core::internal::MountPropagationMode::Bidirectional
-    ) && !privileged
-    {
+    if !privileged {
         all_errs.push(forbidden(
             path,
             "Bidirectional mount propagation is available only to privileged containers",
         ));
     }
+    if read_only {
+        all_errs.push(forbidden(
+            path,
+            "Bidirectional mount propagation may not be used with a readOnly mount",
+        ));
+    }
 
     all_errs
 }
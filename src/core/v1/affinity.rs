@@ -173,6 +173,45 @@ pub struct PodAffinityTerm {
     pub topology_key: String,
 }
 
+/// EffectiveNamespaces is the set of namespaces a [`PodAffinityTerm`] applies to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EffectiveNamespaces {
+    /// `namespaceSelector` is set and empty, so every namespace matches.
+    All,
+    /// The listed `namespaces`, plus any namespace matched by a non-empty selector.
+    Selected {
+        namespaces: Vec<String>,
+        selector: Option<LabelSelector>,
+    },
+    /// Neither `namespaces` nor `namespaceSelector` is set; only the pod's own namespace matches.
+    PodNamespace(String),
+}
+
+impl PodAffinityTerm {
+    /// Resolves the namespaces this term applies to, as the scheduler does.
+    ///
+    /// An empty `namespaceSelector` matches all namespaces. When neither
+    /// `namespaces` nor `namespaceSelector` is set the term applies to
+    /// `pod_namespace`. Otherwise the listed namespaces are unioned with those
+    /// matched by the selector.
+    pub fn effective_namespaces(&self, pod_namespace: &str) -> EffectiveNamespaces {
+        match &self.namespace_selector {
+            Some(selector)
+                if selector.match_labels.is_empty() && selector.match_expressions.is_empty() =>
+            {
+                EffectiveNamespaces::All
+            }
+            None if self.namespaces.is_empty() => {
+                EffectiveNamespaces::PodNamespace(pod_namespace.to_string())
+            }
+            selector => EffectiveNamespaces::Selected {
+                namespaces: self.namespaces.clone(),
+                selector: selector.clone(),
+            },
+        }
+    }
+}
+
 /// WeightedPodAffinityTerm represents a weighted pod affinity/anti-affinity term.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
@@ -189,4 +228,59 @@ pub struct WeightedPodAffinityTerm {
 pub type NodeSelectorSimple = BTreeMap<String, String>;
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_namespaces_empty_selector_matches_all() {
+        let term = PodAffinityTerm {
+            namespace_selector: Some(LabelSelector::default()),
+            namespaces: vec!["ignored".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            term.effective_namespaces("default"),
+            EffectiveNamespaces::All
+        );
+    }
+
+    #[test]
+    fn test_effective_namespaces_static_list() {
+        let term = PodAffinityTerm {
+            namespaces: vec!["team-a".to_string(), "team-b".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            term.effective_namespaces("default"),
+            EffectiveNamespaces::Selected {
+                namespaces: vec!["team-a".to_string(), "team-b".to_string()],
+                selector: None,
+            }
+        );
+
+        let selector = LabelSelector {
+            match_labels: BTreeMap::from([("env".to_string(), "prod".to_string())]),
+            ..Default::default()
+        };
+        let term = PodAffinityTerm {
+            namespace_selector: Some(selector.clone()),
+            ..Default::default()
+        };
+        assert_eq!(
+            term.effective_namespaces("default"),
+            EffectiveNamespaces::Selected {
+                namespaces: vec![],
+                selector: Some(selector),
+            }
+        );
+    }
+
+    #[test]
+    fn test_effective_namespaces_defaults_to_pod_namespace() {
+        let term = PodAffinityTerm::default();
+        assert_eq!(
+            term.effective_namespaces("default"),
+            EffectiveNamespaces::PodNamespace("default".to_string())
+        );
+    }
+}
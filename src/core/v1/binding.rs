@@ -2,9 +2,10 @@
 //!
 //! This module contains types for binding objects to other objects.
 
+pub use crate::common::Preconditions;
 use crate::common::{ApplyDefault, HasTypeMeta, ObjectMeta, ResourceSchema, TypeMeta};
 use crate::core::v1::reference::ObjectReference;
-use crate::{impl_unimplemented_prost_message, impl_versioned_object};
+use crate::impl_versioned_object;
 use serde::{Deserialize, Serialize};
 
 /// Binding binds one object to another.
@@ -28,15 +29,28 @@ pub struct Binding {
 
 impl_versioned_object!(Binding);
 
-/// Preconditions must be fulfilled before an operation (update, delete, etc.) is carried out.
-///
-/// Corresponds to [Kubernetes Preconditions](https://github.com/kubernetes/api/blob/master/core/v1/types.go#L7172)
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
-#[serde(rename_all = "camelCase")]
-pub struct Preconditions {
-    /// Specifies the target UID.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub uid: Option<String>,
+impl Binding {
+    /// Builds a Binding that assigns the named pod to a node.
+    ///
+    /// This is the object a scheduler posts to `pods/binding` in its bind step.
+    pub fn for_pod(pod_namespace: &str, pod_name: &str, node_name: &str) -> Binding {
+        let mut binding = Binding {
+            type_meta: TypeMeta::default(),
+            metadata: Some(ObjectMeta {
+                name: Some(pod_name.to_string()),
+                namespace: Some(pod_namespace.to_string()),
+                ..Default::default()
+            }),
+            target: ObjectReference {
+                api_version: Some("v1".to_string()),
+                kind: Some("Node".to_string()),
+                name: Some(node_name.to_string()),
+                ..Default::default()
+            },
+        };
+        binding.apply_default();
+        binding
+    }
 }
 
 // ============================================================================
@@ -107,12 +121,52 @@ impl ApplyDefault for Binding {
     }
 }
 
-// ----------------------------------------------------------------------------
-// Protobuf Placeholder
-// ----------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::v1::validation::validate_binding;
+    use prost::Message;
+    use serde_json::json;
+
+    #[test]
+    fn test_binding_for_pod() {
+        let binding = Binding::for_pod("default", "web-0", "node-a");
+        assert!(validate_binding(&binding).is_empty());
+
+        assert_eq!(
+            serde_json::to_value(&binding).unwrap(),
+            json!({
+                "apiVersion": "v1",
+                "kind": "Binding",
+                "metadata": {"name": "web-0", "namespace": "default"},
+                "target": {"apiVersion": "v1", "kind": "Node", "name": "node-a"}
+            })
+        );
+    }
 
-impl_unimplemented_prost_message!(Binding);
-impl_unimplemented_prost_message!(Preconditions);
+    #[test]
+    fn test_validate_binding_rejects_invalid_target() {
+        let mut binding = Binding::for_pod("default", "web-0", "");
+        binding.target.kind = Some("Pod".to_string());
 
-#[cfg(test)]
-mod tests {}
+        let errs = validate_binding(&binding);
+        assert_eq!(errs.len(), 2);
+        assert!(errs.errors.iter().any(|e| e.field == "target.name"));
+        assert!(errs.errors.iter().any(|e| e.field == "target.kind"));
+    }
+
+    #[test]
+    fn test_binding_proto_roundtrip() {
+        let mut binding = Binding::for_pod("default", "web-0", "node-a");
+        binding.metadata.as_mut().unwrap().uid = Some("pod-uid".to_string());
+
+        let bytes = binding.encode_to_vec();
+        assert_eq!(bytes.len(), binding.encoded_len());
+
+        let decoded = Binding::decode(bytes.as_slice()).expect("decode Binding");
+        assert_eq!(decoded.metadata, binding.metadata);
+        assert_eq!(decoded.target, binding.target);
+        // TypeMeta travels in the runtime envelope, not in the message.
+        assert_eq!(decoded.type_meta, TypeMeta::default());
+    }
+}
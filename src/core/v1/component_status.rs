@@ -47,7 +47,7 @@ pub struct ComponentStatusList {
     #[serde(default)]
     pub metadata: Option<ListMeta>,
     /// List of ComponentStatus objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ComponentStatus>,
 }
 
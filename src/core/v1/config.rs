@@ -52,7 +52,7 @@ pub struct ConfigMapList {
     pub metadata: Option<ListMeta>,
 
     /// Items is the list of ConfigMaps.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ConfigMap>,
 }
 
@@ -104,7 +104,7 @@ pub struct SecretList {
     pub metadata: Option<ListMeta>,
 
     /// Items is a list of secret objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Secret>,
 }
 
@@ -184,10 +184,47 @@ pub struct ServiceAccountList {
     pub metadata: Option<ListMeta>,
 
     /// Items is the list of ServiceAccounts.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ServiceAccount>,
 }
 
+// ============================================================================
+// Data Accessors
+// ============================================================================
+
+impl ConfigMap {
+    /// Returns the UTF-8 value stored under `key` in `data`.
+    ///
+    /// `binaryData` is not consulted.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.data.get(key).map(String::as_str)
+    }
+}
+
+impl Secret {
+    /// Returns the decoded bytes stored under `key`.
+    ///
+    /// `data` is base64-decoded when the secret is deserialized. A `stringData`
+    /// entry is returned as-is and takes precedence, as the API server merges
+    /// `stringData` over `data` on write.
+    pub fn get_decoded(&self, key: &str) -> Option<Vec<u8>> {
+        self.string_data
+            .get(key)
+            .map(|value| value.as_bytes().to_vec())
+            .or_else(|| self.data.get(key).map(|value| value.0.clone()))
+    }
+
+    /// Stores `value` under `key` in `data`, base64-encoded on serialization.
+    ///
+    /// Any `stringData` entry for `key` is removed so it does not override the
+    /// new value.
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<Vec<u8>>) {
+        let key = key.into();
+        self.string_data.remove(&key);
+        self.data.insert(key, ByteString(value.into()));
+    }
+}
+
 // ============================================================================
 // Trait Implementations for ConfigMap, ConfigMapList, Secret, and SecretList
 // ============================================================================
@@ -582,4 +619,37 @@ mod tests {
 
         assert_eq!(secret.type_, Some(secret_type::OPAQUE.to_string()));
     }
+
+    #[test]
+    fn test_config_map_get() {
+        let config_map: ConfigMap = serde_json::from_value(serde_json::json!({
+            "metadata": {"name": "app-config"},
+            "data": {"log.level": "debug"},
+            "binaryData": {"logo.png": "iVBORw=="}
+        }))
+        .unwrap();
+
+        assert_eq!(config_map.get("log.level"), Some("debug"));
+        assert_eq!(config_map.get("logo.png"), None);
+    }
+
+    #[test]
+    fn test_secret_get_decoded_and_set() {
+        let mut secret: Secret = serde_json::from_value(serde_json::json!({
+            "metadata": {"name": "db-credentials"},
+            "data": {"password": "czNjcjN0"},
+            "stringData": {"username": "admin"}
+        }))
+        .unwrap();
+
+        assert_eq!(secret.get_decoded("password"), Some(b"s3cr3t".to_vec()));
+        assert_eq!(secret.get_decoded("username"), Some(b"admin".to_vec()));
+        assert_eq!(secret.get_decoded("token"), None);
+
+        secret.set("username", b"root".to_vec());
+        assert_eq!(secret.get_decoded("username"), Some(b"root".to_vec()));
+        let value = serde_json::to_value(&secret).unwrap();
+        assert_eq!(value["data"]["username"], "cm9vdA==");
+        assert!(value.get("stringData").is_none());
+    }
 }
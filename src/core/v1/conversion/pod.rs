@@ -734,8 +734,10 @@ impl ToInternal<internal::PodSpec> for pod::PodSpec {
             context.host_users = self.host_users;
         }
 
+        // DeprecatedServiceAccount is only honored when ServiceAccountName is unset.
         let service_account_name = self
             .service_account_name
+            .filter(|name| !name.is_empty())
             .or(self.deprecated_service_account)
             .unwrap_or_default();
 
@@ -1615,6 +1617,25 @@ mod tests {
         assert_eq!(v1_spec.hostname, roundtrip.hostname);
     }
 
+    #[test]
+    fn test_pod_spec_deprecated_service_account_alias() {
+        let v1_spec = pod::PodSpec {
+            service_account_name: Some(String::new()),
+            deprecated_service_account: Some("legacy".to_string()),
+            ..Default::default()
+        };
+
+        let internal_spec = v1_spec.to_internal();
+        assert_eq!(internal_spec.service_account_name, "legacy");
+
+        let roundtrip = pod::PodSpec::from_internal(internal_spec);
+        assert_eq!(roundtrip.service_account_name.as_deref(), Some("legacy"));
+        assert_eq!(
+            roundtrip.deprecated_service_account.as_deref(),
+            Some("legacy")
+        );
+    }
+
     #[test]
     fn test_pod_roundtrip() {
         use crate::common::ObjectMeta;
@@ -12,13 +12,19 @@ use crate::core::v1::binding;
 
 impl ToInternal<internal::Preconditions> for binding::Preconditions {
     fn to_internal(self) -> internal::Preconditions {
-        internal::Preconditions { uid: self.uid }
+        internal::Preconditions {
+            uid: self.uid,
+            resource_version: self.resource_version,
+        }
     }
 }
 
 impl FromInternal<internal::Preconditions> for binding::Preconditions {
     fn from_internal(value: internal::Preconditions) -> Self {
-        Self { uid: value.uid }
+        Self {
+            uid: value.uid,
+            resource_version: value.resource_version,
+        }
     }
 }
 
@@ -30,10 +36,12 @@ mod tests {
     fn test_preconditions_roundtrip() {
         let v1 = binding::Preconditions {
             uid: Some("abc-123".to_string()),
+            resource_version: Some("42".to_string()),
         };
 
         let internal = v1.clone().to_internal();
         assert_eq!(internal.uid.as_deref(), Some("abc-123"));
+        assert_eq!(internal.resource_version.as_deref(), Some("42"));
 
         let roundtrip = binding::Preconditions::from_internal(internal);
         assert_eq!(v1, roundtrip);
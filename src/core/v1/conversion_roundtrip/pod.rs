@@ -1,7 +1,10 @@
 use crate::common::test_utils::assert_conversion_roundtrip;
 use crate::common::{ApplyDefault, ListMeta, ObjectMeta, TypeMeta};
 use crate::core::internal;
-use crate::core::v1::{Container, ContainerPort, Pod, PodList, PodSpec, PodStatus};
+use crate::core::v1::{
+    Container, ContainerPort, ContainerResizePolicy, EphemeralContainer, Pod, PodList,
+    PodResourceClaim, PodSchedulingGate, PodSpec, PodStatus,
+};
 use std::collections::BTreeMap;
 
 fn pod_basic() -> Pod {
@@ -37,6 +40,74 @@ fn pod_basic() -> Pod {
     }
 }
 
+fn pod_with_ephemeral_container_and_scheduling_gates() -> Pod {
+    Pod {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta {
+            name: Some("debug-pod".to_string()),
+            namespace: Some("default".to_string()),
+            ..Default::default()
+        }),
+        spec: Some(PodSpec {
+            init_containers: vec![Container {
+                name: "log-shipper".to_string(),
+                image: Some("fluent-bit:3.0".to_string()),
+                restart_policy: Some("Always".to_string()),
+                ..Default::default()
+            }],
+            containers: vec![Container {
+                name: "app".to_string(),
+                image: Some("nginx:1.25".to_string()),
+                resize_policy: vec![
+                    ContainerResizePolicy {
+                        resource_name: "cpu".to_string(),
+                        restart_policy: "NotRequired".to_string(),
+                    },
+                    ContainerResizePolicy {
+                        resource_name: "memory".to_string(),
+                        restart_policy: "RestartContainer".to_string(),
+                    },
+                ],
+                ..Default::default()
+            }],
+            ephemeral_containers: vec![EphemeralContainer {
+                name: "debugger".to_string(),
+                image: "busybox:1.36".to_string(),
+                image_pull_policy: "IfNotPresent".to_string(),
+                command: vec!["sh".to_string()],
+                target_container_name: "app".to_string(),
+                ..Default::default()
+            }],
+            service_account_name: Some("builder".to_string()),
+            deprecated_service_account: Some("builder".to_string()),
+            hostname: Some("debug".to_string()),
+            subdomain: Some("pods".to_string()),
+            set_hostname_as_fqdn: Some(true),
+            priority: Some(1000),
+            preemption_policy: Some("Never".to_string()),
+            host_users: Some(false),
+            scheduling_gates: vec![
+                PodSchedulingGate {
+                    name: "example.com/quota".to_string(),
+                },
+                PodSchedulingGate {
+                    name: "example.com/approval".to_string(),
+                },
+            ],
+            resource_claims: vec![PodResourceClaim {
+                name: "gpu".to_string(),
+                resource_claim_name: None,
+                resource_claim_template_name: Some("gpu-template".to_string()),
+            }],
+            ..Default::default()
+        }),
+        status: Some(PodStatus {
+            phase: Some("Pending".to_string()),
+            ..Default::default()
+        }),
+    }
+}
+
 fn pod_list_basic() -> PodList {
     let mut item = pod_basic();
     item.apply_default();
@@ -55,6 +126,13 @@ fn conversion_roundtrip_pod() {
     assert_conversion_roundtrip::<Pod, internal::pod::Pod>(pod_basic());
 }
 
+#[test]
+fn conversion_roundtrip_pod_with_ephemeral_container_and_scheduling_gates() {
+    assert_conversion_roundtrip::<Pod, internal::pod::Pod>(
+        pod_with_ephemeral_container_and_scheduling_gates(),
+    );
+}
+
 #[test]
 fn conversion_roundtrip_pod_list() {
     assert_conversion_roundtrip::<PodList, internal::pod::PodList>(pod_list_basic());
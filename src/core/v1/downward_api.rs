@@ -0,0 +1,142 @@
+//! Downward API field resolution for Kubernetes Core v1 Pods
+//!
+//! Ported from k8s.io/kubernetes/pkg/api/v1/resource and pkg/fieldpath.
+
+use crate::common::validation::{BadValue, Error, Path, not_supported};
+use crate::core::internal::validation::selector::split_maybe_subscripted_path;
+use crate::core::v1::pod::Pod;
+
+/// Field paths that [`resolve_field_path`] can evaluate without a subscript.
+const SUPPORTED_FIELD_PATHS: [&str; 9] = [
+    "metadata.name",
+    "metadata.namespace",
+    "metadata.uid",
+    "spec.nodeName",
+    "spec.serviceAccountName",
+    "status.hostIP",
+    "status.hostIPs",
+    "status.podIP",
+    "status.podIPs",
+];
+
+/// Resolves a downward API field path against a pod.
+///
+/// Supports the paths accepted by `fieldRef`, including the subscripted
+/// `metadata.labels['key']` and `metadata.annotations['key']` forms. Missing
+/// labels, annotations and unset fields resolve to an empty string; list
+/// fields such as `status.podIPs` are joined with commas.
+///
+/// # Example
+/// ```ignore
+/// let value = resolve_field_path(&pod, "metadata.labels['app']")?;
+/// ```
+pub fn resolve_field_path(pod: &Pod, field_path: &str) -> Result<String, Error> {
+    let metadata = pod.metadata.as_ref();
+
+    if let Some((path, subscript)) = split_maybe_subscripted_path(field_path) {
+        let map = match path {
+            "metadata.labels" => metadata.map(|meta| &meta.labels),
+            "metadata.annotations" => metadata.map(|meta| &meta.annotations),
+            _ => return Err(unsupported(field_path)),
+        };
+        return Ok(map
+            .and_then(|map| map.get(subscript))
+            .cloned()
+            .unwrap_or_default());
+    }
+
+    let spec = pod.spec.as_ref();
+    let status = pod.status.as_ref();
+    let value = match field_path {
+        "metadata.name" => metadata.and_then(|meta| meta.name.clone()),
+        "metadata.namespace" => metadata.and_then(|meta| meta.namespace.clone()),
+        "metadata.uid" => metadata.and_then(|meta| meta.uid.clone()),
+        "spec.nodeName" => spec.and_then(|spec| spec.node_name.clone()),
+        "spec.serviceAccountName" => spec.and_then(|spec| spec.service_account_name.clone()),
+        "status.hostIP" => status.and_then(|status| status.host_ip.clone()),
+        "status.hostIPs" => status.map(|status| {
+            let ips: Vec<&str> = status.host_ips.iter().map(|ip| ip.ip.as_str()).collect();
+            ips.join(",")
+        }),
+        "status.podIP" => status.and_then(|status| status.pod_ip.clone()),
+        "status.podIPs" => status.map(|status| {
+            let ips: Vec<&str> = status.pod_ips.iter().map(|ip| ip.ip.as_str()).collect();
+            ips.join(",")
+        }),
+        _ => return Err(unsupported(field_path)),
+    };
+    Ok(value.unwrap_or_default())
+}
+
+fn unsupported(field_path: &str) -> Error {
+    not_supported(
+        &Path::new("fieldPath"),
+        BadValue::String(field_path.to_string()),
+        &SUPPORTED_FIELD_PATHS,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::validation::ErrorType;
+    use serde_json::json;
+
+    fn pod() -> Pod {
+        serde_json::from_value(json!({
+            "metadata": {
+                "name": "web-0",
+                "namespace": "prod",
+                "labels": {"app": "web"},
+                "annotations": {"example.com/owner": "team-a"}
+            },
+            "spec": {"nodeName": "node-a", "containers": []},
+            "status": {"podIP": "10.0.0.7", "podIPs": [{"ip": "10.0.0.7"}, {"ip": "fd00::7"}]}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_resolve_field_path_label_subscript() {
+        let pod = pod();
+        assert_eq!(
+            resolve_field_path(&pod, "metadata.labels['app']").unwrap(),
+            "web"
+        );
+        assert_eq!(
+            resolve_field_path(&pod, "metadata.annotations['example.com/owner']").unwrap(),
+            "team-a"
+        );
+        assert_eq!(
+            resolve_field_path(&pod, "metadata.labels['missing']").unwrap(),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_resolve_field_path_pod_fields() {
+        let pod = pod();
+        assert_eq!(
+            resolve_field_path(&pod, "status.podIP").unwrap(),
+            "10.0.0.7"
+        );
+        assert_eq!(
+            resolve_field_path(&pod, "status.podIPs").unwrap(),
+            "10.0.0.7,fd00::7"
+        );
+        assert_eq!(resolve_field_path(&pod, "spec.nodeName").unwrap(), "node-a");
+        assert_eq!(
+            resolve_field_path(&pod, "metadata.namespace").unwrap(),
+            "prod"
+        );
+    }
+
+    #[test]
+    fn test_resolve_field_path_unsupported() {
+        let err = resolve_field_path(&pod(), "spec.containers").unwrap_err();
+        assert_eq!(err.error_type, ErrorType::NotSupported);
+
+        let err = resolve_field_path(&pod(), "metadata.name['x']").unwrap_err();
+        assert_eq!(err.error_type, ErrorType::NotSupported);
+    }
+}
@@ -6,6 +6,8 @@
 use crate::common::ApplyDefault;
 use crate::core::v1::ContainerPort;
 use crate::core::v1::env::{EnvFromSource, EnvVar};
+use crate::core::v1::pod::ContainerRestartRule;
+use crate::core::v1::pod_resources::ContainerResizePolicy;
 use crate::core::v1::probe::{Lifecycle, Probe};
 use crate::core::v1::resource::ResourceRequirements;
 use crate::core::v1::security::SecurityContext;
@@ -72,9 +74,15 @@ pub struct EphemeralContainer {
     /// Whether the ephemeral container's filesystem should be read-only.
     #[serde(default)]
     pub read_only_root_filesystem: bool,
-    /// Path at which the ephemeral container is restarted.
+    /// Resources resize policy for the ephemeral container.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resize_policy: Vec<ContainerResizePolicy>,
+    /// Restart policy for the ephemeral container. Not allowed for ephemeral containers.
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub restart_policy: String,
+    /// Restart rules for the ephemeral container. Not allowed for ephemeral containers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub restart_policy_rules: Vec<ContainerRestartRule>,
     /// Target container name for the ephemeral container.
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub target_container_name: String,
@@ -139,6 +147,15 @@ pub struct EphemeralContainerCommon {
     /// Whether this container has a read-only root filesystem.
     #[serde(default)]
     pub read_only_root_filesystem: bool,
+    /// Resources resize policy for the container.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resize_policy: Vec<ContainerResizePolicy>,
+    /// Restart policy for the container.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub restart_policy: String,
+    /// Restart rules for the container.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub restart_policy_rules: Vec<ContainerRestartRule>,
 }
 
 /// Constants for image pull policy
@@ -169,13 +186,7 @@ impl ApplyDefault for EphemeralContainer {
         if self.image_pull_policy.is_empty() {
             if !self.image.is_empty() {
                 // Check if the image tag is "latest" or missing (implies latest)
-                let is_latest = if let Some(tag_start) = self.image.rfind(':') {
-                    let tag = &self.image[tag_start + 1..];
-                    tag == "latest" || tag.is_empty()
-                } else {
-                    // No tag specified, defaults to latest
-                    true
-                };
+                let is_latest = crate::common::parse_image_ref(&self.image).is_latest();
 
                 self.image_pull_policy = if is_latest {
                     "Always".to_string()
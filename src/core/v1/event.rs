@@ -138,7 +138,7 @@ pub struct EventList {
     pub metadata: Option<ListMeta>,
 
     /// List of events.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Event>,
 }
 
@@ -7,6 +7,7 @@ pub mod binding;
 pub mod component_status;
 pub mod config;
 pub mod conversion;
+pub mod downward_api;
 pub mod env;
 pub mod ephemeral;
 pub mod event;
@@ -15,12 +16,15 @@ pub mod namespace;
 pub mod node;
 pub mod persistent_volume;
 pub mod pod;
+pub mod pod_references;
 pub mod pod_resources;
 pub mod pod_status_result;
 pub mod probe;
 pub mod reference;
 pub mod replication_controller;
 pub mod resource;
+pub mod resource_helper;
+pub mod resource_list;
 pub mod security;
 pub mod selector;
 pub mod service;
@@ -35,12 +39,13 @@ pub use pod::{
     ContainerStateRunning, ContainerStateTerminated, ContainerStateWaiting, ContainerStatus,
     HostAlias, HostIP, Pod, PodCondition, PodDNSConfig, PodDNSConfigOption,
     PodExtendedResourceClaimStatus, PodIP, PodList, PodOS, PodReadinessGate, PodSchedulingGate,
-    PodSpec, PodStatus, dns_policy, os_name, pod_phase, restart_policy,
+    PodSpec, PodStatus, dns_policy, os_name, pod_condition_type, pod_phase, pod_reason,
+    restart_policy,
 };
 
 pub use pod_resources::{
     ContainerResizePolicy, ContainerUser, LinuxContainerUser, PodResourceClaim,
-    PodResourceClaimStatus,
+    PodResourceClaimStatus, resource_resize_restart_policy,
 };
 
 pub use namespace::{
@@ -57,11 +62,19 @@ pub use replication_controller::{
 
 pub use binding::{Binding, Preconditions};
 
+pub use downward_api::resolve_field_path;
 pub use helper::{
     ByteString, NodeProxyOptions, PodAttachOptions, PodExecOptions, PodLogOptions,
     PodPortForwardOptions, PodProxyOptions, RangeAllocation, SerializedReference,
     ServiceProxyOptions,
 };
+pub use pod_references::validate_pod_references;
+pub use resource_helper::{node_allocatable_remaining, sum_pod_requests};
+pub use validation::affinity::{validate_node_selector_requirement, validate_pod_affinity};
+pub use validation::{
+    validate_pod_overhead, validate_pod_spec, validate_pod_status_resize, validate_secret,
+    validate_taints, validate_unique_container_names, validate_volume_mount,
+};
 
 pub use event::{Event, EventList, EventSeries, EventSource, event_type};
 
@@ -84,9 +97,10 @@ pub use config::{
 
 pub use resource::{
     LimitRange, LimitRangeItem, LimitRangeList, LimitRangeSpec, ResourceClaim, ResourceList,
-    ResourceQuota, ResourceQuotaList, ResourceQuotaScope, ResourceQuotaSpec, ResourceQuotaStatus,
-    ResourceRequirements, ScopeSelector, ScopedResourceSelectorRequirement, limit_type,
-    resource_name, resource_quota_scope, scope_selector_operator,
+    ResourceListExt, ResourceQuota, ResourceQuotaList, ResourceQuotaScope, ResourceQuotaSpec,
+    ResourceQuotaStatus, ResourceRequirements, ScopeSelector, ScopedResourceSelectorRequirement,
+    is_extended_resource_name, limit_type, resource_name, resource_quota_scope,
+    scope_selector_operator,
 };
 
 pub use node::{
@@ -154,16 +168,19 @@ pub use security::{
 };
 
 pub use affinity::{
-    Affinity, NodeAffinity, NodeSelector, NodeSelectorOperator, NodeSelectorRequirement,
-    NodeSelectorSimple, NodeSelectorTerm, PodAffinity, PodAffinityTerm, PodAntiAffinity,
-    PreferredSchedulingTerm, WeightedPodAffinityTerm,
+    Affinity, EffectiveNamespaces, NodeAffinity, NodeSelector, NodeSelectorOperator,
+    NodeSelectorRequirement, NodeSelectorSimple, NodeSelectorTerm, PodAffinity, PodAffinityTerm,
+    PodAntiAffinity, PreferredSchedulingTerm, WeightedPodAffinityTerm,
 };
 
 pub use affinity::node_selector_operator;
 
 pub use toleration::{Toleration, TolerationOperator};
 
-pub use toleration::{toleration_effect, toleration_operator};
+pub use toleration::{
+    DEFAULT_TOLERATION_SECONDS, add_default_toleration_seconds, taint_key, toleration_effect,
+    toleration_operator,
+};
 
 pub use template::{
     PodTemplate, PodTemplateList, PodTemplateSpec, apply_pod_template_spec_defaults,
@@ -197,4 +214,9 @@ mod conversion_roundtrip;
 #[cfg(test)]
 mod serde_roundtrip;
 
+mod proto;
+
+#[cfg(test)]
+mod proto_roundtrip_tests;
+
 // Tests moved to core/tests/ directory
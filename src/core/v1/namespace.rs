@@ -47,7 +47,7 @@ pub struct NamespaceList {
     pub metadata: Option<ListMeta>,
 
     /// Items is the list of Namespace objects in the list.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Namespace>,
 }
 
@@ -3,8 +3,8 @@
 //! This module contains types for Kubernetes nodes.
 
 use crate::common::{
-    ApplyDefault, HasTypeMeta, ListMeta, ObjectMeta, Quantity, ResourceSchema, Timestamp, TypeMeta,
-    VersionedObject,
+    ApplyDefault, HasStatus, HasTypeMeta, ListMeta, ObjectMeta, Quantity, ResourceSchema,
+    Timestamp, TypeMeta, VersionedObject,
 };
 use crate::impl_unimplemented_prost_message;
 use serde::{Deserialize, Serialize};
@@ -52,7 +52,7 @@ pub struct NodeList {
     pub metadata: Option<ListMeta>,
 
     /// List of nodes.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Node>,
 }
 
@@ -686,6 +686,14 @@ impl VersionedObject for Node {
     }
 }
 
+impl HasStatus for Node {
+    type Status = NodeStatus;
+
+    fn status(&self) -> Option<&NodeStatus> {
+        self.status.as_ref()
+    }
+}
+
 // Note: NodeList does not implement VersionedObject because its metadata is ListMeta
 
 // ----------------------------------------------------------------------------
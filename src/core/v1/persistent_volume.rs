@@ -56,7 +56,7 @@ pub struct PersistentVolumeList {
     pub metadata: Option<ListMeta>,
 
     /// List of persistent volumes.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<PersistentVolume>,
 }
 
@@ -223,7 +223,7 @@ pub struct PersistentVolumeClaimList {
     pub metadata: Option<ListMeta>,
 
     /// List of persistent volume claims.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<PersistentVolumeClaim>,
 }
 
@@ -486,7 +486,7 @@ pub struct VolumeResourceRequirements {
 /// TypedObjectReference is a reference to an object with a specific type.
 ///
 /// Corresponds to [Kubernetes TypedObjectReference](https://github.com/kubernetes/api/blob/master/core/v1/types.go#L629)
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct TypedObjectReference {
     /// APIGroup is the group for the resource.
@@ -3,20 +3,25 @@
 //! This module contains the Pod type and its associated spec and status types.
 
 use crate::common::{
-    ApplyDefault, HasTypeMeta, ListMeta, ObjectMeta, ResourceSchema, Timestamp, TypeMeta,
-    VersionedObject,
+    ApplyDefault, HasStatus, HasTypeMeta, IntOrString, ListMeta, ObjectMeta, ResourceSchema,
+    Timestamp, TypeMeta, VersionedObject,
 };
 use crate::core::v1::affinity::Affinity;
 use crate::core::v1::env::{EnvFromSource, EnvVar};
 use crate::core::v1::ephemeral::EphemeralContainer;
-use crate::core::v1::pod_resources::{PodResourceClaim, PodResourceClaimStatus};
+use crate::core::v1::pod_resources::{
+    ContainerResizePolicy, PodResourceClaim, PodResourceClaimStatus,
+};
 use crate::core::v1::probe::{Lifecycle, Probe};
 use crate::core::v1::reference::LocalObjectReference;
 use crate::core::v1::resource::{ResourceList, ResourceRequirements};
 use crate::core::v1::security::{PodSecurityContext, SecurityContext};
+use crate::core::v1::service::protocol;
 use crate::core::v1::toleration::Toleration;
 use crate::core::v1::topology::TopologySpreadConstraint;
-use crate::core::v1::volume::{Volume, VolumeDevice, VolumeMount, apply_volume_defaults};
+use crate::core::v1::volume::{
+    PullPolicy, Volume, VolumeDevice, VolumeMount, apply_volume_defaults, pull_policy,
+};
 use crate::impl_unimplemented_prost_message;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -57,7 +62,7 @@ pub struct PodList {
     pub metadata: Option<ListMeta>,
 
     /// List of pods.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Pod>,
 }
 
@@ -275,7 +280,7 @@ pub struct PodStatus {
     pub pod_ip: Option<String>,
 
     /// hostIPs holds the IP addresses of the host.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "hostIPs")]
     pub host_ips: Vec<HostIP>,
 
     /// Current service state of pod.
@@ -298,8 +303,8 @@ pub struct PodStatus {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub start_time: Option<Timestamp>,
 
-    /// IP address of the pod for this host (only for host network pods).
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    /// podIPs holds the IP addresses allocated to the pod.
+    #[serde(default, skip_serializing_if = "Vec::is_empty", rename = "podIPs")]
     pub pod_ips: Vec<PodIP>,
 
     /// Reason for the current pod status.
@@ -369,7 +374,7 @@ pub struct PodCondition {
 }
 
 /// PodDNSConfig defines the DNS parameters of a pod.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct PodDNSConfig {
     /// A list of DNS name server IP addresses.
@@ -386,7 +391,7 @@ pub struct PodDNSConfig {
 }
 
 /// PodDNSConfigOption defines DNS resolver options of a pod.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct PodDNSConfigOption {
     /// Name of the option.
@@ -398,7 +403,7 @@ pub struct PodDNSConfigOption {
 }
 
 /// PodOS defines the operating system of the containers in a pod.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct PodOS {
     /// Name is the name of the operating system (e.g., "linux" or "windows").
@@ -406,7 +411,7 @@ pub struct PodOS {
 }
 
 /// PodReadinessGate contains the reference to a pod condition.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct PodReadinessGate {
     /// ConditionType refers to a condition in the pod's condition list.
@@ -414,7 +419,7 @@ pub struct PodReadinessGate {
 }
 
 /// PodSchedulingGate is associated to a Pod to guard its scheduling.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct PodSchedulingGate {
     /// Name of the scheduling gate.
@@ -422,7 +427,7 @@ pub struct PodSchedulingGate {
 }
 
 /// HostAlias holds the mapping between IP and hostnames.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct HostAlias {
     /// IP address of the host file entry.
@@ -527,6 +532,19 @@ pub struct Container {
     /// Whether this container should be allocated a read-only root filesystem.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub read_only_root_filesystem: Option<bool>,
+
+    /// Resources resize policy for the container.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resize_policy: Vec<ContainerResizePolicy>,
+
+    /// RestartPolicy defines the restart behavior of individual containers in a pod.
+    /// Only "Always" is allowed, and only for init containers (sidecars).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restart_policy: Option<String>,
+
+    /// Rules that determine whether a container is restarted when it exits.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub restart_policy_rules: Vec<ContainerRestartRule>,
 }
 
 /// ContainerStatus contains details for the current status of this container.
@@ -669,7 +687,7 @@ pub struct ContainerStateWaiting {
 }
 
 /// ContainerPort represents a network port in a single container.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ContainerPort {
     /// If specified, this must be an IANA_SVC_NAME and unique within the pod.
@@ -707,6 +725,36 @@ pub mod pod_phase {
     pub const UNKNOWN: &str = "Unknown";
 }
 
+/// Pod condition type constants.
+pub mod pod_condition_type {
+    pub const POD_SCHEDULED: &str = "PodScheduled";
+    pub const READY: &str = "Ready";
+    pub const INITIALIZED: &str = "Initialized";
+    pub const CONTAINERS_READY: &str = "ContainersReady";
+    pub const DISRUPTION_TARGET: &str = "DisruptionTarget";
+    pub const POD_RESIZE_PENDING: &str = "PodResizePending";
+    pub const POD_RESIZE_IN_PROGRESS: &str = "PodResizeInProgress";
+}
+
+/// Pod resize status constants.
+pub mod pod_resize_status {
+    pub const IN_PROGRESS: &str = "InProgress";
+    pub const DEFERRED: &str = "Deferred";
+    pub const INFEASIBLE: &str = "Infeasible";
+}
+
+/// Pod and container reason constants.
+pub mod pod_reason {
+    /// Set on the PodScheduled condition when the pod has scheduling gates.
+    pub const SCHEDULING_GATED: &str = "SchedulingGated";
+    /// Set on the pod status when the node hosting the pod became unreachable.
+    pub const NODE_LOST: &str = "NodeLost";
+    /// Waiting reason reported for containers while init containers are running.
+    pub const POD_INITIALIZING: &str = "PodInitializing";
+    /// Terminated reason reported for containers that exited successfully.
+    pub const COMPLETED: &str = "Completed";
+}
+
 /// Restart policy constants.
 pub mod restart_policy {
     pub const ALWAYS: &str = "Always";
@@ -979,6 +1027,14 @@ impl VersionedObject for Pod {
     }
 }
 
+impl HasStatus for Pod {
+    type Status = PodStatus;
+
+    fn status(&self) -> Option<&PodStatus> {
+        self.status.as_ref()
+    }
+}
+
 // Helper function for static default ObjectMeta
 fn static_default_object_meta() -> &'static ObjectMeta {
     use std::sync::OnceLock;
@@ -1085,19 +1141,7 @@ impl ApplyDefault for Container {
 
         // Set default image pull policy based on image tag if not specified
         if self.image_pull_policy.is_none() {
-            if let Some(ref image) = self.image {
-                // Align with upstream parser semantics (handle registry ports and digests)
-                let is_latest = image_tag_or_latest(image) == "latest";
-
-                self.image_pull_policy = Some(if is_latest {
-                    "Always".to_string()
-                } else {
-                    "IfNotPresent".to_string()
-                });
-            } else {
-                // No image specified, default to IfNotPresent
-                self.image_pull_policy = Some("IfNotPresent".to_string());
-            }
+            self.image_pull_policy = Some(self.effective_image_pull_policy());
         }
 
         // Apply defaults to container ports - protocol defaults to TCP
@@ -1128,20 +1172,6 @@ impl ApplyDefault for Container {
     }
 }
 
-fn image_tag_or_latest(image: &str) -> &str {
-    let (name, _) = image.split_once('@').unwrap_or((image, ""));
-    let last_slash = name.rfind('/');
-    if let Some(colon) = name.rfind(':') {
-        if last_slash.map(|slash| colon > slash).unwrap_or(true) {
-            let tag = &name[colon + 1..];
-            if !tag.is_empty() {
-                return tag;
-            }
-        }
-    }
-    "latest"
-}
-
 fn apply_pod_requests_from_limits(spec: &mut PodSpec) {
     for container in &mut spec.containers {
         apply_container_requests_from_limits(container);
@@ -1174,6 +1204,328 @@ fn default_host_network_ports(containers: &mut [Container]) {
     }
 }
 
+// ----------------------------------------------------------------------------
+// Display Helpers
+// ----------------------------------------------------------------------------
+
+impl Pod {
+    /// Returns the STATUS column value shown by `kubectl get pods`.
+    ///
+    /// Mirrors `printPod` in kubectl: the pod phase (or status reason) is
+    /// overridden by init container progress (`Init:0/2`, `Init:Error`, ...),
+    /// then by the waiting/terminated reasons of regular containers
+    /// (`CrashLoopBackOff`, `Error`, `Completed`, ...), and finally by
+    /// `Terminating` once a deletion timestamp is set on a non-terminal pod.
+    pub fn display_status(&self) -> String {
+        let default_spec = PodSpec::default();
+        let default_status = PodStatus::default();
+        let spec = self.spec.as_ref().unwrap_or(&default_spec);
+        let status = self.status.as_ref().unwrap_or(&default_status);
+
+        let phase = status.phase.as_deref().unwrap_or("");
+        let mut reason = match status.reason.as_deref() {
+            Some(r) if !r.is_empty() => r.to_string(),
+            _ => phase.to_string(),
+        };
+
+        if status.conditions.iter().any(|c| {
+            c.type_ == pod_condition_type::POD_SCHEDULED
+                && c.reason.as_deref() == Some(pod_reason::SCHEDULING_GATED)
+        }) {
+            reason = pod_reason::SCHEDULING_GATED.to_string();
+        }
+
+        let mut initializing = false;
+        for (i, container) in status.init_container_statuses.iter().enumerate() {
+            let state = container.state.as_ref();
+            let terminated = state.and_then(|s| s.terminated.as_ref());
+            let waiting = state.and_then(|s| s.waiting.as_ref());
+
+            if terminated.is_some_and(|t| t.exit_code == 0) {
+                continue;
+            }
+
+            // Started sidecar (restartable init) containers do not block initialization.
+            if started_sidecar(spec, container) {
+                continue;
+            }
+
+            reason = if let Some(terminated) = terminated {
+                match terminated.reason.as_deref() {
+                    Some(r) if !r.is_empty() => format!("Init:{}", r),
+                    _ => match terminated.signal {
+                        Some(signal) if signal != 0 => format!("Init:Signal:{}", signal),
+                        _ => format!("Init:ExitCode:{}", terminated.exit_code),
+                    },
+                }
+            } else if let Some(r) = waiting
+                .and_then(|w| w.reason.as_deref())
+                .filter(|r| !r.is_empty() && *r != pod_reason::POD_INITIALIZING)
+            {
+                format!("Init:{}", r)
+            } else {
+                format!("Init:{}/{}", i, spec.init_containers.len())
+            };
+            initializing = true;
+            break;
+        }
+
+        if !initializing || has_true_condition(status, pod_condition_type::INITIALIZED) {
+            let mut has_running = false;
+            for container in status.container_statuses.iter().rev() {
+                let state = container.state.as_ref();
+                let terminated = state.and_then(|s| s.terminated.as_ref());
+                let waiting_reason = state
+                    .and_then(|s| s.waiting.as_ref())
+                    .and_then(|w| w.reason.as_deref())
+                    .filter(|r| !r.is_empty());
+
+                if let Some(r) = waiting_reason {
+                    reason = r.to_string();
+                } else if let Some(terminated) = terminated {
+                    reason = match terminated.reason.as_deref() {
+                        Some(r) if !r.is_empty() => r.to_string(),
+                        _ => match terminated.signal {
+                            Some(signal) if signal != 0 => format!("Signal:{}", signal),
+                            _ => format!("ExitCode:{}", terminated.exit_code),
+                        },
+                    };
+                } else if container.ready && state.is_some_and(|s| s.running.is_some()) {
+                    has_running = true;
+                }
+            }
+
+            // A completed container does not hide other containers that are still running.
+            if reason == pod_reason::COMPLETED && has_running {
+                reason = if has_true_condition(status, pod_condition_type::READY) {
+                    pod_phase::RUNNING.to_string()
+                } else {
+                    "NotReady".to_string()
+                };
+            }
+        }
+
+        let deleting = self
+            .metadata
+            .as_ref()
+            .is_some_and(|m| m.deletion_timestamp.is_some());
+        if deleting && status.reason.as_deref() == Some(pod_reason::NODE_LOST) {
+            reason = pod_phase::UNKNOWN.to_string();
+        } else if deleting && phase != pod_phase::SUCCEEDED && phase != pod_phase::FAILED {
+            reason = "Terminating".to_string();
+        }
+
+        reason
+    }
+}
+
+fn has_true_condition(status: &PodStatus, condition_type: &str) -> bool {
+    status
+        .conditions
+        .iter()
+        .any(|c| c.type_ == condition_type && c.status == "True")
+}
+
+// ----------------------------------------------------------------------------
+// Init Container Helpers
+// ----------------------------------------------------------------------------
+
+impl PodStatus {
+    /// Returns the name of the init container that initialization is waiting on.
+    ///
+    /// Init containers run one at a time in order, so this is the first entry of
+    /// `initContainerStatuses` that has not terminated with exit code 0. The
+    /// status does not record restart policies, so `spec` is used to recognise
+    /// sidecar (restartable init) containers, which count as done once started.
+    pub fn current_init_container(&self, spec: &PodSpec) -> Option<&str> {
+        self.init_container_statuses
+            .iter()
+            .find(|status| !terminated_successfully(status) && !started_sidecar(spec, status))
+            .map(|status| status.name.as_str())
+    }
+
+    /// Returns true once every reported init container has completed successfully,
+    /// or, for sidecars, has started.
+    pub fn init_containers_complete(&self, spec: &PodSpec) -> bool {
+        self.current_init_container(spec).is_none()
+    }
+}
+
+/// Returns true if `status` belongs to a sidecar (restartable init) container
+/// of `spec` that has started.
+fn started_sidecar(spec: &PodSpec, status: &ContainerStatus) -> bool {
+    status.started == Some(true)
+        && spec.init_containers.iter().any(|c| {
+            c.name == status.name && c.restart_policy.as_deref() == Some(restart_policy::ALWAYS)
+        })
+}
+
+fn terminated_successfully(status: &ContainerStatus) -> bool {
+    status
+        .state
+        .as_ref()
+        .and_then(|state| state.terminated.as_ref())
+        .is_some_and(|terminated| terminated.exit_code == 0)
+}
+
+// ----------------------------------------------------------------------------
+// Condition Helpers
+// ----------------------------------------------------------------------------
+
+impl PodStatus {
+    /// Returns true if the `PodScheduled` condition is `True`.
+    pub fn is_scheduled(&self) -> bool {
+        has_true_condition(self, pod_condition_type::POD_SCHEDULED)
+    }
+
+    /// Returns true if the `Initialized` condition is `True`.
+    pub fn is_initialized(&self) -> bool {
+        has_true_condition(self, pod_condition_type::INITIALIZED)
+    }
+
+    /// Returns true if the `ContainersReady` condition is `True`.
+    pub fn is_containers_ready(&self) -> bool {
+        has_true_condition(self, pod_condition_type::CONTAINERS_READY)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Node Assignment Helpers
+// ----------------------------------------------------------------------------
+
+impl Pod {
+    /// Returns the node the pod is bound to (`spec.nodeName`), if any.
+    pub fn assigned_node(&self) -> Option<&str> {
+        self.spec
+            .as_ref()
+            .and_then(|spec| spec.node_name.as_deref())
+            .filter(|name| !name.is_empty())
+    }
+
+    /// Returns the node nominated by preemption (`status.nominatedNodeName`), if any.
+    pub fn nominated_node(&self) -> Option<&str> {
+        self.status
+            .as_ref()
+            .and_then(|status| status.nominated_node_name.as_deref())
+            .filter(|name| !name.is_empty())
+    }
+
+    /// Returns true if the pod has been bound to a node.
+    pub fn is_assigned(&self) -> bool {
+        self.assigned_node().is_some()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Port Helpers
+// ----------------------------------------------------------------------------
+
+impl PodSpec {
+    /// Returns the first container port named `name`, whatever its protocol.
+    ///
+    /// Regular containers are scanned first, then sidecar (restartable init)
+    /// containers, and the first port with a matching name wins.
+    pub fn named_port(&self, name: &str) -> Option<&ContainerPort> {
+        self.container_ports()
+            .find(|port| port.name.as_deref() == Some(name))
+    }
+
+    /// Returns the first container port named `name` that uses `protocol`.
+    ///
+    /// Mirrors `FindPort` in k8s.io/kubernetes/pkg/api/v1/pod: containers are
+    /// scanned in the same order as [`PodSpec::named_port`], and the first port
+    /// matching both name and protocol wins. A port without a protocol is TCP.
+    pub fn named_port_with_protocol(&self, name: &str, protocol: &str) -> Option<&ContainerPort> {
+        self.container_ports().find(|port| {
+            port.name.as_deref() == Some(name)
+                && port.protocol.as_deref().unwrap_or(protocol::TCP) == protocol
+        })
+    }
+
+    /// Resolves a service `targetPort` to a container port number.
+    ///
+    /// Numeric ports are returned as-is; named ports are looked up with
+    /// [`PodSpec::named_port_with_protocol`] using the service port's `protocol`.
+    pub fn resolve_port(&self, port: &IntOrString, protocol: &str) -> Option<i32> {
+        match port {
+            IntOrString::Int(number) => Some(*number),
+            IntOrString::String(name) => self
+                .named_port_with_protocol(name, protocol)
+                .map(|p| p.container_port),
+        }
+    }
+
+    fn container_ports(&self) -> impl Iterator<Item = &ContainerPort> {
+        let sidecars = self
+            .init_containers
+            .iter()
+            .filter(|c| c.restart_policy.as_deref() == Some(restart_policy::ALWAYS));
+        self.containers
+            .iter()
+            .chain(sidecars)
+            .flat_map(|c| c.ports.iter())
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Scheduling Gate Helpers
+// ----------------------------------------------------------------------------
+
+impl PodSpec {
+    /// Returns true if `schedulingGates` contains a gate named `name`.
+    pub fn has_scheduling_gate(&self, name: &str) -> bool {
+        self.scheduling_gates.iter().any(|gate| gate.name == name)
+    }
+
+    /// Appends a scheduling gate named `name` unless one is already present.
+    ///
+    /// The name is not checked here; gate names must be qualified names, see
+    /// [`validate_scheduling_gates`](crate::core::v1::validation::pod_spec::validate_scheduling_gates).
+    pub fn add_scheduling_gate(&mut self, name: &str) {
+        if !self.has_scheduling_gate(name) {
+            self.scheduling_gates.push(PodSchedulingGate {
+                name: name.to_string(),
+            });
+        }
+    }
+
+    /// Removes the scheduling gate named `name`, returning true if it was present.
+    pub fn remove_scheduling_gate(&mut self, name: &str) -> bool {
+        let len = self.scheduling_gates.len();
+        self.scheduling_gates.retain(|gate| gate.name != name);
+        self.scheduling_gates.len() != len
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Image Pull Policy Helpers
+// ----------------------------------------------------------------------------
+
+impl Container {
+    /// Returns the image pull policy the API server applies to this container.
+    ///
+    /// An explicitly set policy is returned unchanged. Otherwise images tagged
+    /// `:latest` or without a tag or digest resolve to `Always`, and all other
+    /// images (and containers without an image) to `IfNotPresent`.
+    pub fn effective_image_pull_policy(&self) -> PullPolicy {
+        if let Some(policy) = self.image_pull_policy.as_ref()
+            && !policy.is_empty()
+        {
+            return policy.clone();
+        }
+        // Align with upstream parser semantics (handle registry ports and digests)
+        let is_latest = self
+            .image
+            .as_deref()
+            .is_some_and(|image| crate::common::parse_image_ref(image).is_latest());
+        if is_latest {
+            pull_policy::ALWAYS.to_string()
+        } else {
+            pull_policy::IF_NOT_PRESENT.to_string()
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Version Conversion Placeholder (using UnimplementedConversion)
 // ----------------------------------------------------------------------------
@@ -1188,3 +1540,297 @@ fn default_host_network_ports(containers: &mut [Container]) {
 // Conversion implementations in src/core/v1/conversion/pod.rs
 impl_unimplemented_prost_message!(Pod);
 impl_unimplemented_prost_message!(PodList);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_status_crash_loop_back_off() {
+        let pod: Pod = serde_json::from_value(serde_json::json!({
+            "metadata": {"name": "web"},
+            "spec": {"containers": [{"name": "app", "image": "nginx"}]},
+            "status": {
+                "phase": "Running",
+                "containerStatuses": [{
+                    "name": "app",
+                    "ready": false,
+                    "restartCount": 5,
+                    "state": {"waiting": {"reason": "CrashLoopBackOff"}},
+                    "lastState": {"terminated": {"exitCode": 1, "reason": "Error"}}
+                }]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(pod.display_status(), "CrashLoopBackOff");
+    }
+
+    #[test]
+    fn test_display_status_terminating() {
+        let pod: Pod = serde_json::from_value(serde_json::json!({
+            "metadata": {"name": "web", "deletionTimestamp": "2024-01-15T10:00:00Z"},
+            "spec": {"containers": [{"name": "app", "image": "nginx"}]},
+            "status": {
+                "phase": "Running",
+                "containerStatuses": [{
+                    "name": "app",
+                    "ready": true,
+                    "restartCount": 0,
+                    "state": {"running": {"startedAt": "2024-01-15T09:00:00Z"}}
+                }]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(pod.display_status(), "Terminating");
+    }
+
+    #[test]
+    fn test_display_status_init_progress() {
+        let pod: Pod = serde_json::from_value(serde_json::json!({
+            "spec": {
+                "initContainers": [{"name": "init-a"}, {"name": "init-b"}],
+                "containers": [{"name": "app"}]
+            },
+            "status": {
+                "phase": "Pending",
+                "initContainerStatuses": [
+                    {"name": "init-a", "ready": false, "restartCount": 0,
+                     "state": {"waiting": {"reason": "PodInitializing"}}},
+                    {"name": "init-b", "ready": false, "restartCount": 0,
+                     "state": {"waiting": {"reason": "PodInitializing"}}}
+                ]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(pod.display_status(), "Init:0/2");
+    }
+
+    #[test]
+    fn test_current_init_container_stuck_on_second() {
+        let status: PodStatus = serde_json::from_value(serde_json::json!({
+            "phase": "Pending",
+            "initContainerStatuses": [
+                {"name": "init-config", "ready": false, "restartCount": 0,
+                 "state": {"terminated": {"exitCode": 0, "reason": "Completed"}}},
+                {"name": "wait-for-db", "ready": false, "restartCount": 3,
+                 "state": {"running": {"startedAt": "2024-01-15T10:00:00Z"}}},
+                {"name": "migrate", "ready": false, "restartCount": 0,
+                 "state": {"waiting": {"reason": "PodInitializing"}}}
+            ]
+        }))
+        .unwrap();
+
+        let spec = PodSpec::default();
+        assert_eq!(status.current_init_container(&spec), Some("wait-for-db"));
+        assert!(!status.init_containers_complete(&spec));
+        assert!(PodStatus::default().init_containers_complete(&spec));
+    }
+
+    #[test]
+    fn test_current_init_container_skips_started_sidecar() {
+        let spec: PodSpec = serde_json::from_value(serde_json::json!({
+            "initContainers": [
+                {"name": "init-config", "image": "busybox"},
+                {"name": "log-shipper", "image": "fluent-bit", "restartPolicy": "Always"},
+                {"name": "migrate", "image": "migrate"}
+            ],
+            "containers": [{"name": "app", "image": "nginx"}]
+        }))
+        .unwrap();
+        let mut status: PodStatus = serde_json::from_value(serde_json::json!({
+            "phase": "Pending",
+            "initContainerStatuses": [
+                {"name": "init-config", "ready": false, "restartCount": 0,
+                 "state": {"terminated": {"exitCode": 0, "reason": "Completed"}}},
+                {"name": "log-shipper", "ready": true, "restartCount": 0, "started": true,
+                 "state": {"running": {"startedAt": "2024-01-15T10:00:00Z"}}},
+                {"name": "migrate", "ready": false, "restartCount": 0,
+                 "state": {"terminated": {"exitCode": 0, "reason": "Completed"}}}
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(status.current_init_container(&spec), None);
+        assert!(status.init_containers_complete(&spec));
+        // Without the spec the running sidecar looks like a stuck init container.
+        assert_eq!(
+            status.current_init_container(&PodSpec::default()),
+            Some("log-shipper")
+        );
+
+        status.init_container_statuses[1].started = Some(false);
+        assert_eq!(status.current_init_container(&spec), Some("log-shipper"));
+    }
+
+    #[test]
+    fn test_condition_predicates_initialized_not_ready() {
+        let status: PodStatus = serde_json::from_value(serde_json::json!({
+            "phase": "Running",
+            "conditions": [
+                {"type": "PodScheduled", "status": "True"},
+                {"type": "Initialized", "status": "True"},
+                {"type": "ContainersReady", "status": "False", "reason": "ContainersNotReady"},
+                {"type": "Ready", "status": "False", "reason": "ContainersNotReady"}
+            ]
+        }))
+        .unwrap();
+
+        assert!(status.is_scheduled());
+        assert!(status.is_initialized());
+        assert!(!status.is_containers_ready());
+
+        let pending = PodStatus::default();
+        assert!(!pending.is_scheduled());
+        assert!(!pending.is_initialized());
+    }
+
+    #[test]
+    fn test_node_assignment_accessors() {
+        let assigned = Pod {
+            spec: Some(PodSpec {
+                node_name: Some("worker-1".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(assigned.assigned_node(), Some("worker-1"));
+        assert_eq!(assigned.nominated_node(), None);
+        assert!(assigned.is_assigned());
+
+        let unscheduled = Pod {
+            spec: Some(PodSpec::default()),
+            status: Some(PodStatus {
+                nominated_node_name: Some("worker-2".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(unscheduled.assigned_node(), None);
+        assert_eq!(unscheduled.nominated_node(), Some("worker-2"));
+        assert!(!unscheduled.is_assigned());
+    }
+
+    #[test]
+    fn test_named_port_on_second_container() {
+        let spec: PodSpec = serde_json::from_value(serde_json::json!({
+            "initContainers": [
+                {"name": "setup", "ports": [{"name": "metrics", "containerPort": 7000}]},
+                {"name": "proxy", "restartPolicy": "Always",
+                 "ports": [{"name": "admin", "containerPort": 15000}]}
+            ],
+            "containers": [
+                {"name": "app", "ports": [{"name": "http", "containerPort": 8080}]},
+                {"name": "exporter", "ports": [
+                    {"name": "metrics", "containerPort": 9100},
+                    {"name": "http", "containerPort": 9000},
+                    {"name": "dns", "containerPort": 5353, "protocol": "UDP"}
+                ]}
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            spec.named_port("metrics").map(|p| p.container_port),
+            Some(9100)
+        );
+        // The first container declaring the name wins.
+        assert_eq!(
+            spec.named_port("http").map(|p| p.container_port),
+            Some(8080)
+        );
+        assert_eq!(
+            spec.named_port("admin").map(|p| p.container_port),
+            Some(15000)
+        );
+        assert_eq!(spec.named_port("dns").map(|p| p.container_port), Some(5353));
+        assert!(spec.named_port("grpc").is_none());
+
+        // The protocol-aware lookup must match the protocol too.
+        let tcp = protocol::TCP;
+        assert_eq!(
+            spec.named_port_with_protocol("metrics", tcp)
+                .map(|p| p.container_port),
+            Some(9100)
+        );
+        assert!(
+            spec.named_port_with_protocol("metrics", protocol::UDP)
+                .is_none()
+        );
+        assert!(spec.named_port_with_protocol("dns", tcp).is_none());
+        assert_eq!(
+            spec.named_port_with_protocol("dns", protocol::UDP)
+                .map(|p| p.container_port),
+            Some(5353)
+        );
+
+        assert_eq!(
+            spec.resolve_port(&IntOrString::String("metrics".to_string()), tcp),
+            Some(9100)
+        );
+        assert_eq!(spec.resolve_port(&IntOrString::Int(443), tcp), Some(443));
+    }
+
+    #[test]
+    fn test_add_and_remove_scheduling_gate() {
+        let mut spec = PodSpec::default();
+        spec.add_scheduling_gate("example.com/quota");
+        spec.add_scheduling_gate("example.com/provisioning");
+        spec.add_scheduling_gate("example.com/quota");
+
+        assert_eq!(spec.scheduling_gates.len(), 2);
+        assert!(spec.has_scheduling_gate("example.com/quota"));
+
+        assert!(spec.remove_scheduling_gate("example.com/quota"));
+        assert!(!spec.remove_scheduling_gate("example.com/quota"));
+        assert!(!spec.has_scheduling_gate("example.com/quota"));
+        assert_eq!(
+            spec.scheduling_gates,
+            vec![PodSchedulingGate {
+                name: "example.com/provisioning".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_effective_image_pull_policy() {
+        let container = |image: &str| Container {
+            name: "app".to_string(),
+            image: Some(image.to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            container("nginx:latest").effective_image_pull_policy(),
+            pull_policy::ALWAYS
+        );
+        assert_eq!(
+            container("nginx:1.2").effective_image_pull_policy(),
+            pull_policy::IF_NOT_PRESENT
+        );
+        assert_eq!(
+            container("nginx").effective_image_pull_policy(),
+            pull_policy::ALWAYS
+        );
+
+        let pinned = Container {
+            image_pull_policy: Some(pull_policy::NEVER.to_string()),
+            ..container("nginx:latest")
+        };
+        assert_eq!(pinned.effective_image_pull_policy(), pull_policy::NEVER);
+    }
+
+    #[test]
+    fn test_pod_status_ip_lists_use_upstream_json_keys() {
+        let json = serde_json::json!({
+            "hostIPs": [{"ip": "10.0.0.1"}, {"ip": "fd00::1"}],
+            "podIPs": [{"ip": "10.244.0.5"}, {"ip": "fd00:10:244::5"}]
+        });
+        let status: PodStatus = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(status.host_ips[1].ip, "fd00::1");
+        assert_eq!(status.pod_ips[0].ip, "10.244.0.5");
+        assert_eq!(serde_json::to_value(&status).unwrap(), json);
+    }
+}
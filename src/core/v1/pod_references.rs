@@ -0,0 +1,428 @@
+//! ConfigMap and Secret reference checks for Kubernetes Core v1 Pods
+//!
+//! Used for dry-run validation: reports env and volume sources that point at
+//! ConfigMaps, Secrets or keys that do not exist, the same references the
+//! kubelet would fail to resolve when starting the pod.
+
+use std::collections::HashMap;
+
+use crate::common::validation::{BadValue, ErrorList, Path, not_found};
+use crate::core::v1::config::{ConfigMap, Secret};
+use crate::core::v1::env::{EnvFromSource, EnvVar};
+use crate::core::v1::pod::Pod;
+use crate::core::v1::volume::{KeyToPath, Volume};
+
+/// Checks that every ConfigMap and Secret referenced by a pod exists and
+/// carries the referenced keys.
+///
+/// Covers `env[].valueFrom`, `envFrom` of all containers (including init and
+/// ephemeral containers) and `configMap`, `secret` and `projected` volumes.
+/// References marked `optional: true` are skipped. Maps are keyed by object
+/// name.
+pub fn validate_pod_references(
+    pod: &Pod,
+    configmaps: &HashMap<String, ConfigMap>,
+    secrets: &HashMap<String, Secret>,
+) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    let Some(spec) = pod.spec.as_ref() else {
+        return all_errs;
+    };
+    let refs = References {
+        configmaps,
+        secrets,
+    };
+    let spec_path = Path::new("spec");
+
+    for (i, container) in spec.init_containers.iter().enumerate() {
+        let path = spec_path.child("initContainers").index(i);
+        refs.check_env(&container.env, &path.child("env"), &mut all_errs);
+        refs.check_env_from(&container.env_from, &path.child("envFrom"), &mut all_errs);
+    }
+    for (i, container) in spec.containers.iter().enumerate() {
+        let path = spec_path.child("containers").index(i);
+        refs.check_env(&container.env, &path.child("env"), &mut all_errs);
+        refs.check_env_from(&container.env_from, &path.child("envFrom"), &mut all_errs);
+    }
+    for (i, container) in spec.ephemeral_containers.iter().enumerate() {
+        let path = spec_path.child("ephemeralContainers").index(i);
+        refs.check_env(&container.env, &path.child("env"), &mut all_errs);
+        refs.check_env_from(&container.env_from, &path.child("envFrom"), &mut all_errs);
+    }
+    for (i, volume) in spec.volumes.iter().enumerate() {
+        refs.check_volume(volume, &spec_path.child("volumes").index(i), &mut all_errs);
+    }
+
+    all_errs
+}
+
+struct References<'a> {
+    configmaps: &'a HashMap<String, ConfigMap>,
+    secrets: &'a HashMap<String, Secret>,
+}
+
+/// The kind of object a reference points at.
+#[derive(Clone, Copy)]
+enum RefKind {
+    ConfigMap,
+    Secret,
+}
+
+impl References<'_> {
+    fn check_env(&self, env: &[EnvVar], fld_path: &Path, all_errs: &mut ErrorList) {
+        for (i, var) in env.iter().enumerate() {
+            let Some(source) = var.value_from.as_ref() else {
+                continue;
+            };
+            let path = fld_path.index(i).child("valueFrom");
+            if let Some(selector) = source.config_map_key_ref.as_ref() {
+                let path = path.child("configMapKeyRef");
+                self.check(
+                    RefKind::ConfigMap,
+                    selector.name.as_deref(),
+                    selector.optional,
+                    &path.child("name"),
+                    &[(path.child("key"), selector.key.as_str())],
+                    all_errs,
+                );
+            }
+            if let Some(selector) = source.secret_key_ref.as_ref() {
+                let path = path.child("secretKeyRef");
+                self.check(
+                    RefKind::Secret,
+                    selector.name.as_deref(),
+                    selector.optional,
+                    &path.child("name"),
+                    &[(path.child("key"), selector.key.as_str())],
+                    all_errs,
+                );
+            }
+        }
+    }
+
+    fn check_env_from(
+        &self,
+        env_from: &[EnvFromSource],
+        fld_path: &Path,
+        all_errs: &mut ErrorList,
+    ) {
+        for (i, source) in env_from.iter().enumerate() {
+            let path = fld_path.index(i);
+            if let Some(config_map) = source.config_map_ref.as_ref() {
+                self.check(
+                    RefKind::ConfigMap,
+                    config_map.local_object_reference.name.as_deref(),
+                    config_map.optional,
+                    &path.child("configMapRef").child("name"),
+                    &[],
+                    all_errs,
+                );
+            }
+            if let Some(secret) = source.secret_ref.as_ref() {
+                self.check(
+                    RefKind::Secret,
+                    secret.local_object_reference.name.as_deref(),
+                    secret.optional,
+                    &path.child("secretRef").child("name"),
+                    &[],
+                    all_errs,
+                );
+            }
+        }
+    }
+
+    fn check_volume(&self, volume: &Volume, fld_path: &Path, all_errs: &mut ErrorList) {
+        let source = &volume.volume_source;
+        if let Some(config_map) = source.config_map.as_ref() {
+            let path = fld_path.child("configMap");
+            self.check(
+                RefKind::ConfigMap,
+                config_map.name.as_deref(),
+                config_map.optional,
+                &path.child("name"),
+                &item_keys(&config_map.items, &path),
+                all_errs,
+            );
+        }
+        if let Some(secret) = source.secret.as_ref() {
+            let path = fld_path.child("secret");
+            self.check(
+                RefKind::Secret,
+                secret.secret_name.as_deref(),
+                secret.optional,
+                &path.child("secretName"),
+                &item_keys(&secret.items, &path),
+                all_errs,
+            );
+        }
+        if let Some(projected) = source.projected.as_ref() {
+            for (i, projection) in projected.sources.iter().enumerate() {
+                let path = fld_path.child("projected").child("sources").index(i);
+                if let Some(config_map) = projection.config_map.as_ref() {
+                    let path = path.child("configMap");
+                    self.check(
+                        RefKind::ConfigMap,
+                        config_map.name.as_deref(),
+                        config_map.optional,
+                        &path.child("name"),
+                        &item_keys(&config_map.items, &path),
+                        all_errs,
+                    );
+                }
+                if let Some(secret) = projection.secret.as_ref() {
+                    let path = path.child("secret");
+                    self.check(
+                        RefKind::Secret,
+                        secret.name.as_deref(),
+                        secret.optional,
+                        &path.child("name"),
+                        &item_keys(&secret.items, &path),
+                        all_errs,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Checks one reference to a ConfigMap or Secret, reporting a missing
+    /// object under `name_path` and each missing key under its own path.
+    fn check(
+        &self,
+        kind: RefKind,
+        name: Option<&str>,
+        optional: Option<bool>,
+        name_path: &Path,
+        keys: &[(Path, &str)],
+        all_errs: &mut ErrorList,
+    ) {
+        if optional.unwrap_or(false) {
+            return;
+        }
+        let name = name.unwrap_or_default();
+        let exists = match kind {
+            RefKind::ConfigMap => self.configmaps.contains_key(name),
+            RefKind::Secret => self.secrets.contains_key(name),
+        };
+        if !exists {
+            all_errs.push(not_found(name_path, BadValue::String(name.to_string())));
+            return;
+        }
+        for (path, key) in keys {
+            if !self.has_key(kind, name, key) {
+                all_errs.push(not_found(path, BadValue::String(key.to_string())));
+            }
+        }
+    }
+
+    fn has_key(&self, kind: RefKind, name: &str, key: &str) -> bool {
+        match kind {
+            RefKind::ConfigMap => self.configmaps.get(name).is_some_and(|found| {
+                found.data.contains_key(key) || found.binary_data.contains_key(key)
+            }),
+            RefKind::Secret => self.secrets.get(name).is_some_and(|found| {
+                found.data.contains_key(key) || found.string_data.contains_key(key)
+            }),
+        }
+    }
+}
+
+/// Pairs each projected item key with the path it is reported under.
+fn item_keys<'k>(items: &'k [KeyToPath], fld_path: &Path) -> Vec<(Path, &'k str)> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            (
+                fld_path.child("items").index(i).child("key"),
+                item.key.as_str(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ObjectMeta;
+    use crate::common::validation::ErrorType;
+    use crate::core::internal::ByteString;
+    use crate::core::v1::env::EnvVarSource;
+    use crate::core::v1::pod::{Container, PodSpec};
+    use crate::core::v1::selector::{ConfigMapKeySelector, SecretKeySelector};
+    use crate::core::v1::volume::{ConfigMapVolumeSource, SecretVolumeSource, VolumeSource};
+    use std::collections::BTreeMap;
+
+    fn secret(name: &str, keys: &[&str]) -> Secret {
+        Secret {
+            metadata: Some(ObjectMeta {
+                name: Some(name.to_string()),
+                ..Default::default()
+            }),
+            data: keys
+                .iter()
+                .map(|key| (key.to_string(), ByteString(b"value".to_vec())))
+                .collect::<BTreeMap<_, _>>(),
+            ..Default::default()
+        }
+    }
+
+    fn pod_with(container: Container, volumes: Vec<Volume>) -> Pod {
+        Pod {
+            spec: Some(PodSpec {
+                containers: vec![container],
+                volumes,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn env_from_secret(name: &str, key: &str) -> EnvVar {
+        EnvVar {
+            name: "PASSWORD".to_string(),
+            value: String::new(),
+            value_from: Some(EnvVarSource {
+                secret_key_ref: Some(SecretKeySelector {
+                    name: Some(name.to_string()),
+                    key: key.to_string(),
+                    optional: None,
+                }),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_validate_pod_references_present() {
+        let pod = pod_with(
+            Container {
+                name: "app".to_string(),
+                env: vec![env_from_secret("db", "password")],
+                ..Default::default()
+            },
+            vec![],
+        );
+        let secrets = HashMap::from([("db".to_string(), secret("db", &["password"]))]);
+
+        let errs = validate_pod_references(&pod, &HashMap::new(), &secrets);
+        assert!(errs.is_empty(), "expected no errors, got: {errs:?}");
+    }
+
+    #[test]
+    fn test_validate_pod_references_missing_secret_key() {
+        let pod = pod_with(
+            Container {
+                name: "app".to_string(),
+                env: vec![env_from_secret("db", "password")],
+                ..Default::default()
+            },
+            vec![],
+        );
+        let secrets = HashMap::from([("db".to_string(), secret("db", &["username"]))]);
+
+        let errs = validate_pod_references(&pod, &HashMap::new(), &secrets);
+        assert_eq!(errs.len(), 1, "unexpected errors: {errs:?}");
+        assert_eq!(errs.errors[0].error_type, ErrorType::NotFound);
+        assert_eq!(
+            errs.errors[0].field,
+            "spec.containers[0].env[0].valueFrom.secretKeyRef.key"
+        );
+    }
+
+    #[test]
+    fn test_validate_pod_references_optional_missing_config_map() {
+        let pod = pod_with(
+            Container {
+                name: "app".to_string(),
+                env: vec![EnvVar {
+                    name: "MODE".to_string(),
+                    value: String::new(),
+                    value_from: Some(EnvVarSource {
+                        config_map_key_ref: Some(ConfigMapKeySelector {
+                            name: Some("settings".to_string()),
+                            key: "mode".to_string(),
+                            optional: Some(true),
+                        }),
+                        ..Default::default()
+                    }),
+                }],
+                ..Default::default()
+            },
+            vec![Volume {
+                name: "settings".to_string(),
+                volume_source: VolumeSource {
+                    config_map: Some(ConfigMapVolumeSource {
+                        name: Some("settings".to_string()),
+                        optional: Some(true),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            }],
+        );
+
+        let errs = validate_pod_references(&pod, &HashMap::new(), &HashMap::new());
+        assert!(errs.is_empty(), "expected no errors, got: {errs:?}");
+    }
+
+    #[test]
+    fn test_validate_pod_references_missing_volume_config_map() {
+        let pod = pod_with(
+            Container {
+                name: "app".to_string(),
+                ..Default::default()
+            },
+            vec![Volume {
+                name: "settings".to_string(),
+                volume_source: VolumeSource {
+                    config_map: Some(ConfigMapVolumeSource {
+                        name: Some("settings".to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            }],
+        );
+
+        let errs = validate_pod_references(&pod, &HashMap::new(), &HashMap::new());
+        assert_eq!(errs.len(), 1, "unexpected errors: {errs:?}");
+        assert_eq!(errs.errors[0].field, "spec.volumes[0].configMap.name");
+    }
+
+    #[test]
+    fn test_validate_pod_references_missing_volume_secret_item() {
+        let pod = pod_with(
+            Container {
+                name: "app".to_string(),
+                ..Default::default()
+            },
+            vec![Volume {
+                name: "tls".to_string(),
+                volume_source: VolumeSource {
+                    secret: Some(SecretVolumeSource {
+                        secret_name: Some("tls".to_string()),
+                        items: vec![
+                            KeyToPath {
+                                key: "tls.crt".to_string(),
+                                path: "tls.crt".to_string(),
+                                mode: None,
+                            },
+                            KeyToPath {
+                                key: "tls.key".to_string(),
+                                path: "tls.key".to_string(),
+                                mode: None,
+                            },
+                        ],
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            }],
+        );
+        let secrets = HashMap::from([("tls".to_string(), secret("tls", &["tls.crt"]))]);
+
+        let errs = validate_pod_references(&pod, &HashMap::new(), &secrets);
+        assert_eq!(errs.len(), 1, "unexpected errors: {errs:?}");
+        assert_eq!(errs.errors[0].field, "spec.volumes[0].secret.items[1].key");
+    }
+}
@@ -47,19 +47,25 @@ pub struct PodResourceClaimStatus {
 // Container Resize Policy
 // ============================================================================
 
-/// ContainerResizePolicy represents the resize policy for a container.
+/// ContainerResizePolicy represents resource resize policy for the container.
 ///
-/// Corresponds to [Kubernetes ContainerResizePolicy](https://github.com/kubernetes/api/blob/master/core/v1/types.go#L7667)
+/// Corresponds to [Kubernetes ContainerResizePolicy](https://github.com/kubernetes/api/blob/master/core/v1/types.go#L2843)
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ContainerResizePolicy {
-    /// Name of the container to apply the resize policy to.
-    #[serde(default, skip_serializing_if = "String::is_empty")]
-    pub container_name: String,
+    /// Name of the resource to which this resource resize policy applies.
+    #[serde(default)]
+    pub resource_name: String,
 
-    /// The policy for resizing the container.
+    /// Restart policy to apply when specified resource is resized.
     #[serde(default)]
-    pub policy: String,
+    pub restart_policy: String,
+}
+
+/// ResourceResizeRestartPolicy constants.
+pub mod resource_resize_restart_policy {
+    pub const NOT_REQUIRED: &str = "NotRequired";
+    pub const RESTART_CONTAINER: &str = "RestartContainer";
 }
 
 // ============================================================================
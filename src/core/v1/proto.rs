@@ -0,0 +1,6419 @@
+//! Protobuf encodings for core/v1 types
+//!
+//! Field numbers follow k8s.io/api/core/v1/generated.proto. TypeMeta is
+//! carried by the runtime envelope, not by the messages themselves.
+//!
+//! The legacy in-tree volume sources that are still modelled as raw JSON
+//! (gcePersistentDisk, awsElasticBlockStore, rbd, cinder and the like) are
+//! encoded from per-message field tables; JSON keys outside those tables are
+//! dropped. The container-level readOnlyRootFilesystem shortcut has no
+//! upstream field and is not encoded. Plain string fields that are pointers
+//! upstream are written only when non-empty.
+
+use prost::bytes::{Buf, BufMut};
+use std::collections::BTreeMap;
+
+use prost::encoding::{
+    DecodeContext, WireType, bool, btree_map, check_wire_type, decode_key, encode_key,
+    encode_varint, encoded_len_varint, int32, int64, key_len, merge_loop, message, skip_field,
+    string,
+};
+use prost::{DecodeError, Message};
+
+use crate::core::internal::selector as internal_selector;
+
+use super::affinity::{
+    Affinity, NodeAffinity, NodeSelector, NodeSelectorRequirement, NodeSelectorTerm, PodAffinity,
+    PodAffinityTerm, PodAntiAffinity, PreferredSchedulingTerm, WeightedPodAffinityTerm,
+};
+use super::binding::Binding;
+use super::env::{ConfigMapEnvSource, EnvFromSource, EnvVar, EnvVarSource, SecretEnvSource};
+use super::ephemeral::EphemeralContainer;
+use super::persistent_volume::{
+    PersistentVolumeClaimSpec, TypedObjectReference, VolumeResourceRequirements,
+};
+use super::pod::{
+    Container, ContainerPort, ContainerRestartRule, ContainerRestartRuleOnExitCodes, HostAlias,
+    PodDNSConfig, PodDNSConfigOption, PodOS, PodReadinessGate, PodSchedulingGate, PodSpec,
+};
+use super::pod_resources::{ContainerResizePolicy, PodResourceClaim};
+use super::probe::{
+    ExecAction, GRPCAction, HTTPGetAction, HTTPHeader, Lifecycle, LifecycleHandler, Probe,
+    ProbeHandler, SleepAction, TCPSocketAction,
+};
+use super::reference::{LocalObjectReference, ObjectReference, TypedLocalObjectReference};
+use super::resource::{ResourceClaim, ResourceRequirements};
+use super::security::{
+    AppArmorProfile, Capabilities, PodSecurityContext, SELinuxOptions, SeccompProfile,
+    SecurityContext, Sysctl, WindowsSecurityContextOptions,
+};
+use super::selector::{
+    ConfigMapKeySelector, FileKeySelector, ObjectFieldSelector, ResourceFieldSelector,
+    SecretKeySelector,
+};
+use super::template::{PodTemplate, PodTemplateList, PodTemplateSpec};
+use super::toleration::Toleration;
+use super::topology::TopologySpreadConstraint;
+use super::volume::{
+    CSIVolumeSource, ClusterTrustBundleProjection, ConfigMapProjection, ConfigMapVolumeSource,
+    DownwardAPIProjection, DownwardAPIVolumeFile, DownwardAPIVolumeSource, EmptyDirVolumeSource,
+    EphemeralVolumeSource, GlusterfsVolumeSource, HostPathVolumeSource, ISCSIVolumeSource,
+    ImageVolumeSource, KeyToPath, NFSVolumeSource, PersistentVolumeClaimTemplate,
+    PersistentVolumeClaimVolumeSource, PodCertificateProjection, ProjectedVolumeSource,
+    SecretProjection, SecretVolumeSource, ServiceAccountTokenProjection, Volume, VolumeDevice,
+    VolumeMount, VolumeProjection, VolumeSource,
+};
+
+// ============================================================================
+// ObjectReference
+// ============================================================================
+
+impl Message for ObjectReference {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        for (tag, value) in self.string_fields() {
+            if let Some(value) = value {
+                string::encode(tag, value, buf);
+            }
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        let field = match tag {
+            1 => &mut self.kind,
+            2 => &mut self.namespace,
+            3 => &mut self.name,
+            4 => &mut self.uid,
+            5 => &mut self.api_version,
+            6 => &mut self.resource_version,
+            7 => &mut self.field_path,
+            _ => return skip_field(wire_type, tag, buf, ctx),
+        };
+        string::merge(wire_type, field.get_or_insert_default(), buf, ctx)
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.string_fields()
+            .into_iter()
+            .filter_map(|(tag, value)| value.as_ref().map(|v| string::encoded_len(tag, v)))
+            .sum()
+    }
+
+    fn clear(&mut self) {
+        *self = ObjectReference::default();
+    }
+}
+
+impl ObjectReference {
+    fn string_fields(&self) -> [(u32, &Option<String>); 7] {
+        [
+            (1, &self.kind),
+            (2, &self.namespace),
+            (3, &self.name),
+            (4, &self.uid),
+            (5, &self.api_version),
+            (6, &self.resource_version),
+            (7, &self.field_path),
+        ]
+    }
+}
+
+// ============================================================================
+// PodSpec and pod-level types
+// ============================================================================
+
+impl Message for PodSpec {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode_repeated(1, &self.volumes, buf);
+        message::encode_repeated(2, &self.containers, buf);
+        if let Some(ref value) = self.restart_policy {
+            string::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.termination_grace_period_seconds {
+            int64::encode(4, value, buf);
+        }
+        if let Some(ref value) = self.active_deadline_seconds {
+            int64::encode(5, value, buf);
+        }
+        if let Some(ref value) = self.dns_policy {
+            string::encode(6, value, buf);
+        }
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            string::encode,
+            string::encoded_len,
+            7,
+            &self.node_selector,
+            buf,
+        );
+        if let Some(ref value) = self.service_account_name {
+            string::encode(8, value, buf);
+        }
+        if let Some(ref value) = self.deprecated_service_account {
+            string::encode(9, value, buf);
+        }
+        if let Some(ref value) = self.node_name {
+            string::encode(10, value, buf);
+        }
+        bool::encode(11, &self.host_network, buf);
+        bool::encode(12, &self.host_pid, buf);
+        bool::encode(13, &self.host_ipc, buf);
+        if let Some(ref value) = self.security_context {
+            message::encode(14, value, buf);
+        }
+        message::encode_repeated(15, &self.image_pull_secrets, buf);
+        if let Some(ref value) = self.hostname {
+            string::encode(16, value, buf);
+        }
+        if let Some(ref value) = self.subdomain {
+            string::encode(17, value, buf);
+        }
+        if let Some(ref value) = self.affinity {
+            message::encode(18, value, buf);
+        }
+        if let Some(ref value) = self.scheduler_name {
+            string::encode(19, value, buf);
+        }
+        message::encode_repeated(20, &self.init_containers, buf);
+        if let Some(ref value) = self.automount_service_account_token {
+            bool::encode(21, value, buf);
+        }
+        message::encode_repeated(22, &self.tolerations, buf);
+        message::encode_repeated(23, &self.host_aliases, buf);
+        if let Some(ref value) = self.priority_class_name {
+            string::encode(24, value, buf);
+        }
+        if let Some(ref value) = self.priority {
+            int32::encode(25, value, buf);
+        }
+        if let Some(ref value) = self.dns_config {
+            message::encode(26, value, buf);
+        }
+        if let Some(ref value) = self.share_process_namespace {
+            bool::encode(27, value, buf);
+        }
+        message::encode_repeated(28, &self.readiness_gates, buf);
+        if let Some(ref value) = self.runtime_class_name {
+            string::encode(29, value, buf);
+        }
+        if let Some(ref value) = self.enable_service_links {
+            bool::encode(30, value, buf);
+        }
+        if let Some(ref value) = self.preemption_policy {
+            string::encode(31, value, buf);
+        }
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            message::encode,
+            message::encoded_len,
+            32,
+            &self.overhead,
+            buf,
+        );
+        message::encode_repeated(33, &self.topology_spread_constraints, buf);
+        message::encode_repeated(34, &self.ephemeral_containers, buf);
+        if let Some(ref value) = self.set_hostname_as_fqdn {
+            bool::encode(35, value, buf);
+        }
+        if let Some(ref value) = self.os {
+            message::encode(36, value, buf);
+        }
+        if let Some(ref value) = self.host_users {
+            bool::encode(37, value, buf);
+        }
+        message::encode_repeated(38, &self.scheduling_gates, buf);
+        message::encode_repeated(39, &self.resource_claims, buf);
+        if let Some(ref value) = self.resources {
+            message::encode(40, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge_repeated(wire_type, &mut self.volumes, buf, ctx),
+            2 => message::merge_repeated(wire_type, &mut self.containers, buf, ctx),
+            3 => string::merge(
+                wire_type,
+                self.restart_policy.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => int64::merge(
+                wire_type,
+                self.termination_grace_period_seconds
+                    .get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            5 => int64::merge(
+                wire_type,
+                self.active_deadline_seconds.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            6 => string::merge(wire_type, self.dns_policy.get_or_insert_default(), buf, ctx),
+            7 => btree_map::merge(
+                string::merge,
+                string::merge,
+                &mut self.node_selector,
+                buf,
+                ctx,
+            ),
+            8 => string::merge(
+                wire_type,
+                self.service_account_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            9 => string::merge(
+                wire_type,
+                self.deprecated_service_account.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            10 => string::merge(wire_type, self.node_name.get_or_insert_default(), buf, ctx),
+            11 => bool::merge(wire_type, &mut self.host_network, buf, ctx),
+            12 => bool::merge(wire_type, &mut self.host_pid, buf, ctx),
+            13 => bool::merge(wire_type, &mut self.host_ipc, buf, ctx),
+            14 => message::merge(
+                wire_type,
+                self.security_context.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            15 => message::merge_repeated(wire_type, &mut self.image_pull_secrets, buf, ctx),
+            16 => string::merge(wire_type, self.hostname.get_or_insert_default(), buf, ctx),
+            17 => string::merge(wire_type, self.subdomain.get_or_insert_default(), buf, ctx),
+            18 => message::merge(wire_type, self.affinity.get_or_insert_default(), buf, ctx),
+            19 => string::merge(
+                wire_type,
+                self.scheduler_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            20 => message::merge_repeated(wire_type, &mut self.init_containers, buf, ctx),
+            21 => bool::merge(
+                wire_type,
+                self.automount_service_account_token.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            22 => message::merge_repeated(wire_type, &mut self.tolerations, buf, ctx),
+            23 => message::merge_repeated(wire_type, &mut self.host_aliases, buf, ctx),
+            24 => string::merge(
+                wire_type,
+                self.priority_class_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            25 => int32::merge(wire_type, self.priority.get_or_insert_default(), buf, ctx),
+            26 => message::merge(wire_type, self.dns_config.get_or_insert_default(), buf, ctx),
+            27 => bool::merge(
+                wire_type,
+                self.share_process_namespace.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            28 => message::merge_repeated(wire_type, &mut self.readiness_gates, buf, ctx),
+            29 => string::merge(
+                wire_type,
+                self.runtime_class_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            30 => bool::merge(
+                wire_type,
+                self.enable_service_links.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            31 => string::merge(
+                wire_type,
+                self.preemption_policy.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            32 => btree_map::merge(string::merge, message::merge, &mut self.overhead, buf, ctx),
+            33 => {
+                message::merge_repeated(wire_type, &mut self.topology_spread_constraints, buf, ctx)
+            }
+            34 => message::merge_repeated(wire_type, &mut self.ephemeral_containers, buf, ctx),
+            35 => bool::merge(
+                wire_type,
+                self.set_hostname_as_fqdn.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            36 => message::merge(wire_type, self.os.get_or_insert_default(), buf, ctx),
+            37 => bool::merge(wire_type, self.host_users.get_or_insert_default(), buf, ctx),
+            38 => message::merge_repeated(wire_type, &mut self.scheduling_gates, buf, ctx),
+            39 => message::merge_repeated(wire_type, &mut self.resource_claims, buf, ctx),
+            40 => message::merge(wire_type, self.resources.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len_repeated(1, &self.volumes)
+            + message::encoded_len_repeated(2, &self.containers)
+            + self
+                .restart_policy
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(3, v))
+            + self
+                .termination_grace_period_seconds
+                .as_ref()
+                .map_or(0, |v| int64::encoded_len(4, v))
+            + self
+                .active_deadline_seconds
+                .as_ref()
+                .map_or(0, |v| int64::encoded_len(5, v))
+            + self
+                .dns_policy
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(6, v))
+            + btree_map::encoded_len(
+                string::encoded_len,
+                string::encoded_len,
+                7,
+                &self.node_selector,
+            )
+            + self
+                .service_account_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(8, v))
+            + self
+                .deprecated_service_account
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(9, v))
+            + self
+                .node_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(10, v))
+            + bool::encoded_len(11, &self.host_network)
+            + bool::encoded_len(12, &self.host_pid)
+            + bool::encoded_len(13, &self.host_ipc)
+            + self
+                .security_context
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(14, v))
+            + message::encoded_len_repeated(15, &self.image_pull_secrets)
+            + self
+                .hostname
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(16, v))
+            + self
+                .subdomain
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(17, v))
+            + self
+                .affinity
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(18, v))
+            + self
+                .scheduler_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(19, v))
+            + message::encoded_len_repeated(20, &self.init_containers)
+            + self
+                .automount_service_account_token
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(21, v))
+            + message::encoded_len_repeated(22, &self.tolerations)
+            + message::encoded_len_repeated(23, &self.host_aliases)
+            + self
+                .priority_class_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(24, v))
+            + self
+                .priority
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(25, v))
+            + self
+                .dns_config
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(26, v))
+            + self
+                .share_process_namespace
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(27, v))
+            + message::encoded_len_repeated(28, &self.readiness_gates)
+            + self
+                .runtime_class_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(29, v))
+            + self
+                .enable_service_links
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(30, v))
+            + self
+                .preemption_policy
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(31, v))
+            + btree_map::encoded_len(
+                string::encoded_len,
+                message::encoded_len,
+                32,
+                &self.overhead,
+            )
+            + message::encoded_len_repeated(33, &self.topology_spread_constraints)
+            + message::encoded_len_repeated(34, &self.ephemeral_containers)
+            + self
+                .set_hostname_as_fqdn
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(35, v))
+            + self.os.as_ref().map_or(0, |v| message::encoded_len(36, v))
+            + self
+                .host_users
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(37, v))
+            + message::encoded_len_repeated(38, &self.scheduling_gates)
+            + message::encoded_len_repeated(39, &self.resource_claims)
+            + self
+                .resources
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(40, v))
+    }
+
+    fn clear(&mut self) {
+        *self = PodSpec::default();
+    }
+}
+
+impl Message for Container {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+        if let Some(ref value) = self.image {
+            string::encode(2, value, buf);
+        }
+        string::encode_repeated(3, &self.command, buf);
+        string::encode_repeated(4, &self.args, buf);
+        if let Some(ref value) = self.working_dir {
+            string::encode(5, value, buf);
+        }
+        message::encode_repeated(6, &self.ports, buf);
+        message::encode_repeated(7, &self.env, buf);
+        if let Some(ref value) = self.resources {
+            message::encode(8, value, buf);
+        }
+        message::encode_repeated(9, &self.volume_mounts, buf);
+        if let Some(ref value) = self.liveness_probe {
+            message::encode(10, value, buf);
+        }
+        if let Some(ref value) = self.readiness_probe {
+            message::encode(11, value, buf);
+        }
+        if let Some(ref value) = self.lifecycle {
+            message::encode(12, value, buf);
+        }
+        if let Some(ref value) = self.termination_message_path {
+            string::encode(13, value, buf);
+        }
+        if let Some(ref value) = self.image_pull_policy {
+            string::encode(14, value, buf);
+        }
+        if let Some(ref value) = self.security_context {
+            message::encode(15, value, buf);
+        }
+        bool::encode(16, &self.stdin, buf);
+        bool::encode(17, &self.stdin_once, buf);
+        bool::encode(18, &self.tty, buf);
+        message::encode_repeated(19, &self.env_from, buf);
+        if let Some(ref value) = self.termination_message_policy {
+            string::encode(20, value, buf);
+        }
+        message::encode_repeated(21, &self.volume_devices, buf);
+        if let Some(ref value) = self.startup_probe {
+            message::encode(22, value, buf);
+        }
+        message::encode_repeated(23, &self.resize_policy, buf);
+        if let Some(ref value) = self.restart_policy {
+            string::encode(24, value, buf);
+        }
+        message::encode_repeated(25, &self.restart_policy_rules, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            2 => string::merge(wire_type, self.image.get_or_insert_default(), buf, ctx),
+            3 => string::merge_repeated(wire_type, &mut self.command, buf, ctx),
+            4 => string::merge_repeated(wire_type, &mut self.args, buf, ctx),
+            5 => string::merge(
+                wire_type,
+                self.working_dir.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            6 => message::merge_repeated(wire_type, &mut self.ports, buf, ctx),
+            7 => message::merge_repeated(wire_type, &mut self.env, buf, ctx),
+            8 => message::merge(wire_type, self.resources.get_or_insert_default(), buf, ctx),
+            9 => message::merge_repeated(wire_type, &mut self.volume_mounts, buf, ctx),
+            10 => message::merge(
+                wire_type,
+                self.liveness_probe.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            11 => message::merge(
+                wire_type,
+                self.readiness_probe.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            12 => message::merge(wire_type, self.lifecycle.get_or_insert_default(), buf, ctx),
+            13 => string::merge(
+                wire_type,
+                self.termination_message_path.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            14 => string::merge(
+                wire_type,
+                self.image_pull_policy.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            15 => message::merge(
+                wire_type,
+                self.security_context.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            16 => bool::merge(wire_type, &mut self.stdin, buf, ctx),
+            17 => bool::merge(wire_type, &mut self.stdin_once, buf, ctx),
+            18 => bool::merge(wire_type, &mut self.tty, buf, ctx),
+            19 => message::merge_repeated(wire_type, &mut self.env_from, buf, ctx),
+            20 => string::merge(
+                wire_type,
+                self.termination_message_policy.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            21 => message::merge_repeated(wire_type, &mut self.volume_devices, buf, ctx),
+            22 => message::merge(
+                wire_type,
+                self.startup_probe.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            23 => message::merge_repeated(wire_type, &mut self.resize_policy, buf, ctx),
+            24 => string::merge(
+                wire_type,
+                self.restart_policy.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            25 => message::merge_repeated(wire_type, &mut self.restart_policy_rules, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name)
+            + self.image.as_ref().map_or(0, |v| string::encoded_len(2, v))
+            + string::encoded_len_repeated(3, &self.command)
+            + string::encoded_len_repeated(4, &self.args)
+            + self
+                .working_dir
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(5, v))
+            + message::encoded_len_repeated(6, &self.ports)
+            + message::encoded_len_repeated(7, &self.env)
+            + self
+                .resources
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(8, v))
+            + message::encoded_len_repeated(9, &self.volume_mounts)
+            + self
+                .liveness_probe
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(10, v))
+            + self
+                .readiness_probe
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(11, v))
+            + self
+                .lifecycle
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(12, v))
+            + self
+                .termination_message_path
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(13, v))
+            + self
+                .image_pull_policy
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(14, v))
+            + self
+                .security_context
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(15, v))
+            + bool::encoded_len(16, &self.stdin)
+            + bool::encoded_len(17, &self.stdin_once)
+            + bool::encoded_len(18, &self.tty)
+            + message::encoded_len_repeated(19, &self.env_from)
+            + self
+                .termination_message_policy
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(20, v))
+            + message::encoded_len_repeated(21, &self.volume_devices)
+            + self
+                .startup_probe
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(22, v))
+            + message::encoded_len_repeated(23, &self.resize_policy)
+            + self
+                .restart_policy
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(24, v))
+            + message::encoded_len_repeated(25, &self.restart_policy_rules)
+    }
+
+    fn clear(&mut self) {
+        *self = Container::default();
+    }
+}
+
+impl Message for ContainerPort {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.name {
+            string::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.host_port {
+            int32::encode(2, value, buf);
+        }
+        int32::encode(3, &self.container_port, buf);
+        if let Some(ref value) = self.protocol {
+            string::encode(4, value, buf);
+        }
+        if let Some(ref value) = self.host_ip {
+            string::encode(5, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, self.name.get_or_insert_default(), buf, ctx),
+            2 => int32::merge(wire_type, self.host_port.get_or_insert_default(), buf, ctx),
+            3 => int32::merge(wire_type, &mut self.container_port, buf, ctx),
+            4 => string::merge(wire_type, self.protocol.get_or_insert_default(), buf, ctx),
+            5 => string::merge(wire_type, self.host_ip.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.name.as_ref().map_or(0, |v| string::encoded_len(1, v))
+            + self
+                .host_port
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(2, v))
+            + int32::encoded_len(3, &self.container_port)
+            + self
+                .protocol
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(4, v))
+            + self
+                .host_ip
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(5, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ContainerPort::default();
+    }
+}
+
+impl Message for ContainerRestartRule {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.action {
+            string::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.exit_codes {
+            message::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, self.action.get_or_insert_default(), buf, ctx),
+            2 => message::merge(wire_type, self.exit_codes.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.action
+            .as_ref()
+            .map_or(0, |v| string::encoded_len(1, v))
+            + self
+                .exit_codes
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ContainerRestartRule::default();
+    }
+}
+
+impl Message for ContainerRestartRuleOnExitCodes {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.operator {
+            string::encode(1, value, buf);
+        }
+        int32::encode_repeated(2, &self.values, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, self.operator.get_or_insert_default(), buf, ctx),
+            2 => int32::merge_repeated(wire_type, &mut self.values, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.operator
+            .as_ref()
+            .map_or(0, |v| string::encoded_len(1, v))
+            + int32::encoded_len_repeated(2, &self.values)
+    }
+
+    fn clear(&mut self) {
+        *self = ContainerRestartRuleOnExitCodes::default();
+    }
+}
+
+impl Message for PodDNSConfig {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode_repeated(1, &self.nameservers, buf);
+        string::encode_repeated(2, &self.searches, buf);
+        message::encode_repeated(3, &self.options, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge_repeated(wire_type, &mut self.nameservers, buf, ctx),
+            2 => string::merge_repeated(wire_type, &mut self.searches, buf, ctx),
+            3 => message::merge_repeated(wire_type, &mut self.options, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len_repeated(1, &self.nameservers)
+            + string::encoded_len_repeated(2, &self.searches)
+            + message::encoded_len_repeated(3, &self.options)
+    }
+
+    fn clear(&mut self) {
+        *self = PodDNSConfig::default();
+    }
+}
+
+impl Message for PodDNSConfigOption {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+        if let Some(ref value) = self.value {
+            string::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            2 => string::merge(wire_type, self.value.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name)
+            + self.value.as_ref().map_or(0, |v| string::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = PodDNSConfigOption::default();
+    }
+}
+
+impl Message for HostAlias {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.ip, buf);
+        string::encode_repeated(2, &self.hostnames, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.ip, buf, ctx),
+            2 => string::merge_repeated(wire_type, &mut self.hostnames, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.ip) + string::encoded_len_repeated(2, &self.hostnames)
+    }
+
+    fn clear(&mut self) {
+        *self = HostAlias::default();
+    }
+}
+
+impl Message for PodReadinessGate {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.condition_type, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.condition_type, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.condition_type)
+    }
+
+    fn clear(&mut self) {
+        *self = PodReadinessGate::default();
+    }
+}
+
+impl Message for PodOS {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name)
+    }
+
+    fn clear(&mut self) {
+        *self = PodOS::default();
+    }
+}
+
+impl Message for PodSchedulingGate {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name)
+    }
+
+    fn clear(&mut self) {
+        *self = PodSchedulingGate::default();
+    }
+}
+
+// ============================================================================
+// EphemeralContainer
+// ============================================================================
+//
+// Upstream nests the container fields in an embedded EphemeralContainerCommon
+// message (field 1), next to targetContainerName (field 2). The Rust type
+// keeps them flat, so the embedded message is written and read in place.
+
+impl Message for EphemeralContainer {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        encode_key(1, WireType::LengthDelimited, buf);
+        encode_varint(self.common_fields_encoded_len() as u64, buf);
+        self.encode_common_fields(buf);
+        string::encode(2, &self.target_container_name, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => {
+                let mut common = EphemeralContainerFields(std::mem::take(self));
+                let result = message::merge(wire_type, &mut common, buf, ctx);
+                *self = common.0;
+                result
+            }
+            2 => string::merge(wire_type, &mut self.target_container_name, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        let common_len = self.common_fields_encoded_len();
+        key_len(1)
+            + encoded_len_varint(common_len as u64)
+            + common_len
+            + string::encoded_len(2, &self.target_container_name)
+    }
+
+    fn clear(&mut self) {
+        *self = EphemeralContainer::default();
+    }
+}
+
+/// Decodes the embedded EphemeralContainerCommon message into an
+/// EphemeralContainer's flat fields.
+#[derive(Debug, Default)]
+struct EphemeralContainerFields(EphemeralContainer);
+
+impl Message for EphemeralContainerFields {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        self.0.encode_common_fields(buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        self.0.merge_common_field(tag, wire_type, buf, ctx)
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.0.common_fields_encoded_len()
+    }
+
+    fn clear(&mut self) {
+        *self = EphemeralContainerFields::default();
+    }
+}
+
+impl EphemeralContainer {
+    /// Writes the fields of the embedded `EphemeralContainerCommon` message.
+    fn encode_common_fields<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+        string::encode(2, &self.image, buf);
+        string::encode_repeated(3, &self.command, buf);
+        string::encode_repeated(4, &self.args, buf);
+        string::encode(5, &self.working_dir, buf);
+        message::encode_repeated(6, &self.ports, buf);
+        message::encode_repeated(7, &self.env, buf);
+        if let Some(ref value) = self.resources {
+            message::encode(8, value, buf);
+        }
+        message::encode_repeated(9, &self.volume_mounts, buf);
+        if let Some(ref value) = self.liveness_probe {
+            message::encode(10, value, buf);
+        }
+        if let Some(ref value) = self.readiness_probe {
+            message::encode(11, value, buf);
+        }
+        if let Some(ref value) = self.lifecycle {
+            message::encode(12, value, buf);
+        }
+        string::encode(14, &self.image_pull_policy, buf);
+        if let Some(ref value) = self.security_context {
+            message::encode(15, value, buf);
+        }
+        message::encode_repeated(19, &self.env_from, buf);
+        message::encode_repeated(21, &self.volume_devices, buf);
+        if let Some(ref value) = self.startup_probe {
+            message::encode(22, value, buf);
+        }
+        message::encode_repeated(23, &self.resize_policy, buf);
+        if !self.restart_policy.is_empty() {
+            string::encode(24, &self.restart_policy, buf);
+        }
+        message::encode_repeated(25, &self.restart_policy_rules, buf);
+    }
+
+    fn merge_common_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            2 => string::merge(wire_type, &mut self.image, buf, ctx),
+            3 => string::merge_repeated(wire_type, &mut self.command, buf, ctx),
+            4 => string::merge_repeated(wire_type, &mut self.args, buf, ctx),
+            5 => string::merge(wire_type, &mut self.working_dir, buf, ctx),
+            6 => message::merge_repeated(wire_type, &mut self.ports, buf, ctx),
+            7 => message::merge_repeated(wire_type, &mut self.env, buf, ctx),
+            8 => message::merge(wire_type, self.resources.get_or_insert_default(), buf, ctx),
+            9 => message::merge_repeated(wire_type, &mut self.volume_mounts, buf, ctx),
+            10 => message::merge(
+                wire_type,
+                self.liveness_probe.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            11 => message::merge(
+                wire_type,
+                self.readiness_probe.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            12 => message::merge(wire_type, self.lifecycle.get_or_insert_default(), buf, ctx),
+            14 => string::merge(wire_type, &mut self.image_pull_policy, buf, ctx),
+            15 => message::merge(
+                wire_type,
+                self.security_context.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            19 => message::merge_repeated(wire_type, &mut self.env_from, buf, ctx),
+            21 => message::merge_repeated(wire_type, &mut self.volume_devices, buf, ctx),
+            22 => message::merge(
+                wire_type,
+                self.startup_probe.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            23 => message::merge_repeated(wire_type, &mut self.resize_policy, buf, ctx),
+            24 => string::merge(wire_type, &mut self.restart_policy, buf, ctx),
+            25 => message::merge_repeated(wire_type, &mut self.restart_policy_rules, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn common_fields_encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name)
+            + string::encoded_len(2, &self.image)
+            + string::encoded_len_repeated(3, &self.command)
+            + string::encoded_len_repeated(4, &self.args)
+            + string::encoded_len(5, &self.working_dir)
+            + message::encoded_len_repeated(6, &self.ports)
+            + message::encoded_len_repeated(7, &self.env)
+            + self
+                .resources
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(8, v))
+            + message::encoded_len_repeated(9, &self.volume_mounts)
+            + self
+                .liveness_probe
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(10, v))
+            + self
+                .readiness_probe
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(11, v))
+            + self
+                .lifecycle
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(12, v))
+            + string::encoded_len(14, &self.image_pull_policy)
+            + self
+                .security_context
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(15, v))
+            + message::encoded_len_repeated(19, &self.env_from)
+            + message::encoded_len_repeated(21, &self.volume_devices)
+            + self
+                .startup_probe
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(22, v))
+            + message::encoded_len_repeated(23, &self.resize_policy)
+            + if self.restart_policy.is_empty() {
+                0
+            } else {
+                string::encoded_len(24, &self.restart_policy)
+            }
+            + message::encoded_len_repeated(25, &self.restart_policy_rules)
+    }
+}
+
+// ============================================================================
+// Pod resources
+// ============================================================================
+
+impl Message for ContainerResizePolicy {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.resource_name, buf);
+        string::encode(2, &self.restart_policy, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.resource_name, buf, ctx),
+            2 => string::merge(wire_type, &mut self.restart_policy, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.resource_name) + string::encoded_len(2, &self.restart_policy)
+    }
+
+    fn clear(&mut self) {
+        *self = ContainerResizePolicy::default();
+    }
+}
+
+impl Message for PodResourceClaim {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+        if let Some(ref value) = self.resource_claim_name {
+            string::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.resource_claim_template_name {
+            string::encode(4, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            3 => string::merge(
+                wire_type,
+                self.resource_claim_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => string::merge(
+                wire_type,
+                self.resource_claim_template_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name)
+            + self
+                .resource_claim_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(3, v))
+            + self
+                .resource_claim_template_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(4, v))
+    }
+
+    fn clear(&mut self) {
+        *self = PodResourceClaim::default();
+    }
+}
+
+// ============================================================================
+// ResourceRequirements
+// ============================================================================
+
+impl Message for ResourceRequirements {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            message::encode,
+            message::encoded_len,
+            1,
+            &self.limits,
+            buf,
+        );
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            message::encode,
+            message::encoded_len,
+            2,
+            &self.requests,
+            buf,
+        );
+        message::encode_repeated(3, &self.claims, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => btree_map::merge(string::merge, message::merge, &mut self.limits, buf, ctx),
+            2 => btree_map::merge(string::merge, message::merge, &mut self.requests, buf, ctx),
+            3 => message::merge_repeated(wire_type, &mut self.claims, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        btree_map::encoded_len(string::encoded_len, message::encoded_len, 1, &self.limits)
+            + btree_map::encoded_len(string::encoded_len, message::encoded_len, 2, &self.requests)
+            + message::encoded_len_repeated(3, &self.claims)
+    }
+
+    fn clear(&mut self) {
+        *self = ResourceRequirements::default();
+    }
+}
+
+impl Message for ResourceClaim {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+        string::encode(2, &self.request, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            2 => string::merge(wire_type, &mut self.request, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name) + string::encoded_len(2, &self.request)
+    }
+
+    fn clear(&mut self) {
+        *self = ResourceClaim::default();
+    }
+}
+
+// ============================================================================
+// Environment
+// ============================================================================
+
+impl Message for EnvVar {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+        string::encode(2, &self.value, buf);
+        if let Some(ref value) = self.value_from {
+            message::encode(3, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            2 => string::merge(wire_type, &mut self.value, buf, ctx),
+            3 => message::merge(wire_type, self.value_from.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name)
+            + string::encoded_len(2, &self.value)
+            + self
+                .value_from
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+    }
+
+    fn clear(&mut self) {
+        *self = EnvVar::default();
+    }
+}
+
+impl Message for EnvVarSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.field_ref {
+            message::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.resource_field_ref {
+            message::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.config_map_key_ref {
+            message::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.secret_key_ref {
+            message::encode(4, value, buf);
+        }
+        if let Some(ref value) = self.file_key_ref {
+            message::encode(5, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.field_ref.get_or_insert_default(), buf, ctx),
+            2 => message::merge(
+                wire_type,
+                self.resource_field_ref.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            3 => message::merge(
+                wire_type,
+                self.config_map_key_ref.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => message::merge(
+                wire_type,
+                self.secret_key_ref.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            5 => message::merge(
+                wire_type,
+                self.file_key_ref.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.field_ref
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + self
+                .resource_field_ref
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+            + self
+                .config_map_key_ref
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+            + self
+                .secret_key_ref
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(4, v))
+            + self
+                .file_key_ref
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(5, v))
+    }
+
+    fn clear(&mut self) {
+        *self = EnvVarSource::default();
+    }
+}
+
+impl Message for EnvFromSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.prefix, buf);
+        if let Some(ref value) = self.config_map_ref {
+            message::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.secret_ref {
+            message::encode(3, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.prefix, buf, ctx),
+            2 => message::merge(
+                wire_type,
+                self.config_map_ref.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            3 => message::merge(wire_type, self.secret_ref.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.prefix)
+            + self
+                .config_map_ref
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+            + self
+                .secret_ref
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+    }
+
+    fn clear(&mut self) {
+        *self = EnvFromSource::default();
+    }
+}
+
+impl Message for ConfigMapEnvSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode(1, &self.local_object_reference, buf);
+        if let Some(ref value) = self.optional {
+            bool::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, &mut self.local_object_reference, buf, ctx),
+            2 => bool::merge(wire_type, self.optional.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len(1, &self.local_object_reference)
+            + self
+                .optional
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ConfigMapEnvSource::default();
+    }
+}
+
+impl Message for SecretEnvSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode(1, &self.local_object_reference, buf);
+        if let Some(ref value) = self.optional {
+            bool::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, &mut self.local_object_reference, buf, ctx),
+            2 => bool::merge(wire_type, self.optional.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len(1, &self.local_object_reference)
+            + self
+                .optional
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = SecretEnvSource::default();
+    }
+}
+
+// ============================================================================
+// Selectors
+// ============================================================================
+
+impl Message for ObjectFieldSelector {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.api_version, buf);
+        string::encode(2, &self.field_path, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.api_version, buf, ctx),
+            2 => string::merge(wire_type, &mut self.field_path, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.api_version) + string::encoded_len(2, &self.field_path)
+    }
+
+    fn clear(&mut self) {
+        *self = ObjectFieldSelector::default();
+    }
+}
+
+impl Message for ResourceFieldSelector {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.container_name, buf);
+        string::encode(2, &self.resource, buf);
+        if let Some(ref value) = self.divisor {
+            message::encode(3, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.container_name, buf, ctx),
+            2 => string::merge(wire_type, &mut self.resource, buf, ctx),
+            3 => message::merge(wire_type, self.divisor.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.container_name)
+            + string::encoded_len(2, &self.resource)
+            + self
+                .divisor
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ResourceFieldSelector::default();
+    }
+}
+
+impl Message for ConfigMapKeySelector {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        encode_local_object_reference(1, &self.name, buf);
+        string::encode(2, &self.key, buf);
+        if let Some(ref value) = self.optional {
+            bool::encode(3, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => merge_local_object_reference(wire_type, &mut self.name, buf, ctx),
+            2 => string::merge(wire_type, &mut self.key, buf, ctx),
+            3 => bool::merge(wire_type, self.optional.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        local_object_reference_encoded_len(1, &self.name)
+            + string::encoded_len(2, &self.key)
+            + self
+                .optional
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(3, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ConfigMapKeySelector::default();
+    }
+}
+
+impl Message for SecretKeySelector {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        encode_local_object_reference(1, &self.name, buf);
+        string::encode(2, &self.key, buf);
+        if let Some(ref value) = self.optional {
+            bool::encode(3, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => merge_local_object_reference(wire_type, &mut self.name, buf, ctx),
+            2 => string::merge(wire_type, &mut self.key, buf, ctx),
+            3 => bool::merge(wire_type, self.optional.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        local_object_reference_encoded_len(1, &self.name)
+            + string::encoded_len(2, &self.key)
+            + self
+                .optional
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(3, v))
+    }
+
+    fn clear(&mut self) {
+        *self = SecretKeySelector::default();
+    }
+}
+
+impl Message for FileKeySelector {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.volume_name, buf);
+        string::encode(2, &self.path, buf);
+        string::encode(3, &self.key, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.volume_name, buf, ctx),
+            2 => string::merge(wire_type, &mut self.path, buf, ctx),
+            3 => string::merge(wire_type, &mut self.key, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.volume_name)
+            + string::encoded_len(2, &self.path)
+            + string::encoded_len(3, &self.key)
+    }
+
+    fn clear(&mut self) {
+        *self = FileKeySelector::default();
+    }
+}
+
+// ============================================================================
+// Probes and lifecycle handlers
+// ============================================================================
+
+impl Message for Probe {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode(1, &self.probe_handler, buf);
+        if let Some(ref value) = self.initial_delay_seconds {
+            int32::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.timeout_seconds {
+            int32::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.period_seconds {
+            int32::encode(4, value, buf);
+        }
+        if let Some(ref value) = self.success_threshold {
+            int32::encode(5, value, buf);
+        }
+        if let Some(ref value) = self.failure_threshold {
+            int32::encode(6, value, buf);
+        }
+        if let Some(ref value) = self.termination_grace_period_seconds {
+            int64::encode(7, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, &mut self.probe_handler, buf, ctx),
+            2 => int32::merge(
+                wire_type,
+                self.initial_delay_seconds.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            3 => int32::merge(
+                wire_type,
+                self.timeout_seconds.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => int32::merge(
+                wire_type,
+                self.period_seconds.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            5 => int32::merge(
+                wire_type,
+                self.success_threshold.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            6 => int32::merge(
+                wire_type,
+                self.failure_threshold.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            7 => int64::merge(
+                wire_type,
+                self.termination_grace_period_seconds
+                    .get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len(1, &self.probe_handler)
+            + self
+                .initial_delay_seconds
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(2, v))
+            + self
+                .timeout_seconds
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(3, v))
+            + self
+                .period_seconds
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(4, v))
+            + self
+                .success_threshold
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(5, v))
+            + self
+                .failure_threshold
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(6, v))
+            + self
+                .termination_grace_period_seconds
+                .as_ref()
+                .map_or(0, |v| int64::encoded_len(7, v))
+    }
+
+    fn clear(&mut self) {
+        *self = Probe::default();
+    }
+}
+
+impl Message for ProbeHandler {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.exec {
+            message::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.http_get {
+            message::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.tcp_socket {
+            message::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.grpc {
+            message::encode(4, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.exec.get_or_insert_default(), buf, ctx),
+            2 => message::merge(wire_type, self.http_get.get_or_insert_default(), buf, ctx),
+            3 => message::merge(wire_type, self.tcp_socket.get_or_insert_default(), buf, ctx),
+            4 => message::merge(wire_type, self.grpc.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.exec.as_ref().map_or(0, |v| message::encoded_len(1, v))
+            + self
+                .http_get
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+            + self
+                .tcp_socket
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+            + self.grpc.as_ref().map_or(0, |v| message::encoded_len(4, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ProbeHandler::default();
+    }
+}
+
+impl Message for ExecAction {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode_repeated(1, &self.command, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge_repeated(wire_type, &mut self.command, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len_repeated(1, &self.command)
+    }
+
+    fn clear(&mut self) {
+        *self = ExecAction::default();
+    }
+}
+
+impl Message for HTTPGetAction {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.path, buf);
+        message::encode(2, &self.port, buf);
+        string::encode(3, &self.host, buf);
+        if let Some(ref value) = self.scheme {
+            string::encode(4, value, buf);
+        }
+        message::encode_repeated(5, &self.http_headers, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.path, buf, ctx),
+            2 => message::merge(wire_type, &mut self.port, buf, ctx),
+            3 => string::merge(wire_type, &mut self.host, buf, ctx),
+            4 => string::merge(wire_type, self.scheme.get_or_insert_default(), buf, ctx),
+            5 => message::merge_repeated(wire_type, &mut self.http_headers, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.path)
+            + message::encoded_len(2, &self.port)
+            + string::encoded_len(3, &self.host)
+            + self
+                .scheme
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(4, v))
+            + message::encoded_len_repeated(5, &self.http_headers)
+    }
+
+    fn clear(&mut self) {
+        *self = HTTPGetAction::default();
+    }
+}
+
+impl Message for HTTPHeader {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+        string::encode(2, &self.value, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            2 => string::merge(wire_type, &mut self.value, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name) + string::encoded_len(2, &self.value)
+    }
+
+    fn clear(&mut self) {
+        *self = HTTPHeader::default();
+    }
+}
+
+impl Message for TCPSocketAction {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode(1, &self.port, buf);
+        string::encode(2, &self.host, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, &mut self.port, buf, ctx),
+            2 => string::merge(wire_type, &mut self.host, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len(1, &self.port) + string::encoded_len(2, &self.host)
+    }
+
+    fn clear(&mut self) {
+        *self = TCPSocketAction::default();
+    }
+}
+
+impl Message for GRPCAction {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        int32::encode(1, &self.port, buf);
+        if let Some(ref value) = self.service {
+            string::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => int32::merge(wire_type, &mut self.port, buf, ctx),
+            2 => string::merge(wire_type, self.service.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        int32::encoded_len(1, &self.port)
+            + self
+                .service
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = GRPCAction::default();
+    }
+}
+
+impl Message for Lifecycle {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.post_start {
+            message::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.pre_stop {
+            message::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.post_start.get_or_insert_default(), buf, ctx),
+            2 => message::merge(wire_type, self.pre_stop.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.post_start
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + self
+                .pre_stop
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = Lifecycle::default();
+    }
+}
+
+impl Message for LifecycleHandler {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.exec {
+            message::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.http_get {
+            message::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.tcp_socket {
+            message::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.sleep {
+            message::encode(4, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.exec.get_or_insert_default(), buf, ctx),
+            2 => message::merge(wire_type, self.http_get.get_or_insert_default(), buf, ctx),
+            3 => message::merge(wire_type, self.tcp_socket.get_or_insert_default(), buf, ctx),
+            4 => message::merge(wire_type, self.sleep.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.exec.as_ref().map_or(0, |v| message::encoded_len(1, v))
+            + self
+                .http_get
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+            + self
+                .tcp_socket
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+            + self
+                .sleep
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(4, v))
+    }
+
+    fn clear(&mut self) {
+        *self = LifecycleHandler::default();
+    }
+}
+
+impl Message for SleepAction {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        int64::encode(1, &self.seconds, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => int64::merge(wire_type, &mut self.seconds, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        int64::encoded_len(1, &self.seconds)
+    }
+
+    fn clear(&mut self) {
+        *self = SleepAction::default();
+    }
+}
+
+// ============================================================================
+// Security contexts
+// ============================================================================
+
+impl Message for SecurityContext {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.capabilities {
+            message::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.privileged {
+            bool::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.se_linux_options {
+            message::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.run_as_user {
+            int64::encode(4, value, buf);
+        }
+        if let Some(ref value) = self.run_as_non_root {
+            bool::encode(5, value, buf);
+        }
+        if let Some(ref value) = self.read_only_root_filesystem {
+            bool::encode(6, value, buf);
+        }
+        if let Some(ref value) = self.allow_privilege_escalation {
+            bool::encode(7, value, buf);
+        }
+        if let Some(ref value) = self.run_as_group {
+            int64::encode(8, value, buf);
+        }
+        if let Some(ref value) = self.proc_mount {
+            string::encode(9, value, buf);
+        }
+        if let Some(ref value) = self.windows_options {
+            message::encode(10, value, buf);
+        }
+        if let Some(ref value) = self.seccomp_profile {
+            message::encode(11, value, buf);
+        }
+        if let Some(ref value) = self.app_armor_profile {
+            message::encode(12, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(
+                wire_type,
+                self.capabilities.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            2 => bool::merge(wire_type, self.privileged.get_or_insert_default(), buf, ctx),
+            3 => message::merge(
+                wire_type,
+                self.se_linux_options.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => int64::merge(
+                wire_type,
+                self.run_as_user.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            5 => bool::merge(
+                wire_type,
+                self.run_as_non_root.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            6 => bool::merge(
+                wire_type,
+                self.read_only_root_filesystem.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            7 => bool::merge(
+                wire_type,
+                self.allow_privilege_escalation.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            8 => int64::merge(
+                wire_type,
+                self.run_as_group.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            9 => string::merge(wire_type, self.proc_mount.get_or_insert_default(), buf, ctx),
+            10 => message::merge(
+                wire_type,
+                self.windows_options.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            11 => message::merge(
+                wire_type,
+                self.seccomp_profile.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            12 => message::merge(
+                wire_type,
+                self.app_armor_profile.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.capabilities
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + self
+                .privileged
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(2, v))
+            + self
+                .se_linux_options
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+            + self
+                .run_as_user
+                .as_ref()
+                .map_or(0, |v| int64::encoded_len(4, v))
+            + self
+                .run_as_non_root
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(5, v))
+            + self
+                .read_only_root_filesystem
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(6, v))
+            + self
+                .allow_privilege_escalation
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(7, v))
+            + self
+                .run_as_group
+                .as_ref()
+                .map_or(0, |v| int64::encoded_len(8, v))
+            + self
+                .proc_mount
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(9, v))
+            + self
+                .windows_options
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(10, v))
+            + self
+                .seccomp_profile
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(11, v))
+            + self
+                .app_armor_profile
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(12, v))
+    }
+
+    fn clear(&mut self) {
+        *self = SecurityContext::default();
+    }
+}
+
+impl Message for Capabilities {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode_repeated(1, &self.add, buf);
+        string::encode_repeated(2, &self.drop, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge_repeated(wire_type, &mut self.add, buf, ctx),
+            2 => string::merge_repeated(wire_type, &mut self.drop, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len_repeated(1, &self.add) + string::encoded_len_repeated(2, &self.drop)
+    }
+
+    fn clear(&mut self) {
+        *self = Capabilities::default();
+    }
+}
+
+impl Message for SELinuxOptions {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.user, buf);
+        string::encode(2, &self.role, buf);
+        string::encode(3, &self.type_, buf);
+        string::encode(4, &self.level, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.user, buf, ctx),
+            2 => string::merge(wire_type, &mut self.role, buf, ctx),
+            3 => string::merge(wire_type, &mut self.type_, buf, ctx),
+            4 => string::merge(wire_type, &mut self.level, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.user)
+            + string::encoded_len(2, &self.role)
+            + string::encoded_len(3, &self.type_)
+            + string::encoded_len(4, &self.level)
+    }
+
+    fn clear(&mut self) {
+        *self = SELinuxOptions::default();
+    }
+}
+
+impl Message for WindowsSecurityContextOptions {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.gmsa_credential_spec_name {
+            string::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.gmsa_credential_spec {
+            string::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.run_as_user_name {
+            string::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.host_process {
+            bool::encode(4, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(
+                wire_type,
+                self.gmsa_credential_spec_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            2 => string::merge(
+                wire_type,
+                self.gmsa_credential_spec.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            3 => string::merge(
+                wire_type,
+                self.run_as_user_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => bool::merge(
+                wire_type,
+                self.host_process.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.gmsa_credential_spec_name
+            .as_ref()
+            .map_or(0, |v| string::encoded_len(1, v))
+            + self
+                .gmsa_credential_spec
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(2, v))
+            + self
+                .run_as_user_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(3, v))
+            + self
+                .host_process
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(4, v))
+    }
+
+    fn clear(&mut self) {
+        *self = WindowsSecurityContextOptions::default();
+    }
+}
+
+impl Message for SeccompProfile {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.type_, buf);
+        if let Some(ref value) = self.localhost_profile {
+            string::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.type_, buf, ctx),
+            2 => string::merge(
+                wire_type,
+                self.localhost_profile.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.type_)
+            + self
+                .localhost_profile
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = SeccompProfile::default();
+    }
+}
+
+impl Message for AppArmorProfile {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.type_, buf);
+        if let Some(ref value) = self.localhost_profile {
+            string::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.type_, buf, ctx),
+            2 => string::merge(
+                wire_type,
+                self.localhost_profile.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.type_)
+            + self
+                .localhost_profile
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = AppArmorProfile::default();
+    }
+}
+
+impl Message for PodSecurityContext {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.se_linux_options {
+            message::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.run_as_user {
+            int64::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.run_as_non_root {
+            bool::encode(3, value, buf);
+        }
+        int64::encode_repeated(4, &self.supplemental_groups, buf);
+        if let Some(ref value) = self.fs_group {
+            int64::encode(5, value, buf);
+        }
+        if let Some(ref value) = self.run_as_group {
+            int64::encode(6, value, buf);
+        }
+        message::encode_repeated(7, &self.sysctls, buf);
+        if let Some(ref value) = self.windows_options {
+            message::encode(8, value, buf);
+        }
+        if let Some(ref value) = self.fs_group_change_policy {
+            string::encode(9, value, buf);
+        }
+        if let Some(ref value) = self.seccomp_profile {
+            message::encode(10, value, buf);
+        }
+        if let Some(ref value) = self.app_armor_profile {
+            message::encode(11, value, buf);
+        }
+        if let Some(ref value) = self.supplemental_groups_policy {
+            string::encode(12, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(
+                wire_type,
+                self.se_linux_options.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            2 => int64::merge(
+                wire_type,
+                self.run_as_user.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            3 => bool::merge(
+                wire_type,
+                self.run_as_non_root.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => int64::merge_repeated(wire_type, &mut self.supplemental_groups, buf, ctx),
+            5 => int64::merge(wire_type, self.fs_group.get_or_insert_default(), buf, ctx),
+            6 => int64::merge(
+                wire_type,
+                self.run_as_group.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            7 => message::merge_repeated(wire_type, &mut self.sysctls, buf, ctx),
+            8 => message::merge(
+                wire_type,
+                self.windows_options.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            9 => string::merge(
+                wire_type,
+                self.fs_group_change_policy.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            10 => message::merge(
+                wire_type,
+                self.seccomp_profile.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            11 => message::merge(
+                wire_type,
+                self.app_armor_profile.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            12 => string::merge(
+                wire_type,
+                self.supplemental_groups_policy.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.se_linux_options
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + self
+                .run_as_user
+                .as_ref()
+                .map_or(0, |v| int64::encoded_len(2, v))
+            + self
+                .run_as_non_root
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(3, v))
+            + int64::encoded_len_repeated(4, &self.supplemental_groups)
+            + self
+                .fs_group
+                .as_ref()
+                .map_or(0, |v| int64::encoded_len(5, v))
+            + self
+                .run_as_group
+                .as_ref()
+                .map_or(0, |v| int64::encoded_len(6, v))
+            + message::encoded_len_repeated(7, &self.sysctls)
+            + self
+                .windows_options
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(8, v))
+            + self
+                .fs_group_change_policy
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(9, v))
+            + self
+                .seccomp_profile
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(10, v))
+            + self
+                .app_armor_profile
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(11, v))
+            + self
+                .supplemental_groups_policy
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(12, v))
+    }
+
+    fn clear(&mut self) {
+        *self = PodSecurityContext::default();
+    }
+}
+
+impl Message for Sysctl {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+        string::encode(2, &self.value, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            2 => string::merge(wire_type, &mut self.value, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name) + string::encoded_len(2, &self.value)
+    }
+
+    fn clear(&mut self) {
+        *self = Sysctl::default();
+    }
+}
+
+// ============================================================================
+// Affinity
+// ============================================================================
+
+impl Message for Affinity {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.node_affinity {
+            message::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.pod_affinity {
+            message::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.pod_anti_affinity {
+            message::encode(3, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(
+                wire_type,
+                self.node_affinity.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            2 => message::merge(
+                wire_type,
+                self.pod_affinity.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            3 => message::merge(
+                wire_type,
+                self.pod_anti_affinity.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.node_affinity
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + self
+                .pod_affinity
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+            + self
+                .pod_anti_affinity
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+    }
+
+    fn clear(&mut self) {
+        *self = Affinity::default();
+    }
+}
+
+impl Message for NodeAffinity {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.required_during_scheduling_ignored_during_execution {
+            message::encode(1, value, buf);
+        }
+        message::encode_repeated(
+            2,
+            &self.preferred_during_scheduling_ignored_during_execution,
+            buf,
+        );
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(
+                wire_type,
+                self.required_during_scheduling_ignored_during_execution
+                    .get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            2 => message::merge_repeated(
+                wire_type,
+                &mut self.preferred_during_scheduling_ignored_during_execution,
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.required_during_scheduling_ignored_during_execution
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + message::encoded_len_repeated(
+                2,
+                &self.preferred_during_scheduling_ignored_during_execution,
+            )
+    }
+
+    fn clear(&mut self) {
+        *self = NodeAffinity::default();
+    }
+}
+
+impl Message for NodeSelector {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode_repeated(1, &self.node_selector_terms, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge_repeated(wire_type, &mut self.node_selector_terms, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len_repeated(1, &self.node_selector_terms)
+    }
+
+    fn clear(&mut self) {
+        *self = NodeSelector::default();
+    }
+}
+
+impl Message for NodeSelectorTerm {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode_repeated(1, &self.match_expressions, buf);
+        message::encode_repeated(2, &self.match_fields, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge_repeated(wire_type, &mut self.match_expressions, buf, ctx),
+            2 => message::merge_repeated(wire_type, &mut self.match_fields, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len_repeated(1, &self.match_expressions)
+            + message::encoded_len_repeated(2, &self.match_fields)
+    }
+
+    fn clear(&mut self) {
+        *self = NodeSelectorTerm::default();
+    }
+}
+
+impl Message for NodeSelectorRequirement {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.key, buf);
+        string::encode(2, &self.operator, buf);
+        string::encode_repeated(3, &self.values, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.key, buf, ctx),
+            2 => string::merge(wire_type, &mut self.operator, buf, ctx),
+            3 => string::merge_repeated(wire_type, &mut self.values, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.key)
+            + string::encoded_len(2, &self.operator)
+            + string::encoded_len_repeated(3, &self.values)
+    }
+
+    fn clear(&mut self) {
+        *self = NodeSelectorRequirement::default();
+    }
+}
+
+impl Message for PreferredSchedulingTerm {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        int32::encode(1, &self.weight, buf);
+        if let Some(ref value) = self.preference {
+            message::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => int32::merge(wire_type, &mut self.weight, buf, ctx),
+            2 => message::merge(wire_type, self.preference.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        int32::encoded_len(1, &self.weight)
+            + self
+                .preference
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = PreferredSchedulingTerm::default();
+    }
+}
+
+impl Message for PodAffinity {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode_repeated(
+            1,
+            &self.required_during_scheduling_ignored_during_execution,
+            buf,
+        );
+        message::encode_repeated(
+            2,
+            &self.preferred_during_scheduling_ignored_during_execution,
+            buf,
+        );
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge_repeated(
+                wire_type,
+                &mut self.required_during_scheduling_ignored_during_execution,
+                buf,
+                ctx,
+            ),
+            2 => message::merge_repeated(
+                wire_type,
+                &mut self.preferred_during_scheduling_ignored_during_execution,
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len_repeated(1, &self.required_during_scheduling_ignored_during_execution)
+            + message::encoded_len_repeated(
+                2,
+                &self.preferred_during_scheduling_ignored_during_execution,
+            )
+    }
+
+    fn clear(&mut self) {
+        *self = PodAffinity::default();
+    }
+}
+
+impl Message for PodAntiAffinity {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode_repeated(
+            1,
+            &self.required_during_scheduling_ignored_during_execution,
+            buf,
+        );
+        message::encode_repeated(
+            2,
+            &self.preferred_during_scheduling_ignored_during_execution,
+            buf,
+        );
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge_repeated(
+                wire_type,
+                &mut self.required_during_scheduling_ignored_during_execution,
+                buf,
+                ctx,
+            ),
+            2 => message::merge_repeated(
+                wire_type,
+                &mut self.preferred_during_scheduling_ignored_during_execution,
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len_repeated(1, &self.required_during_scheduling_ignored_during_execution)
+            + message::encoded_len_repeated(
+                2,
+                &self.preferred_during_scheduling_ignored_during_execution,
+            )
+    }
+
+    fn clear(&mut self) {
+        *self = PodAntiAffinity::default();
+    }
+}
+
+impl Message for PodAffinityTerm {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.label_selector {
+            message::encode(1, value, buf);
+        }
+        string::encode(2, &self.topology_key, buf);
+        string::encode_repeated(3, &self.namespaces, buf);
+        if let Some(ref value) = self.namespace_selector {
+            message::encode(4, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(
+                wire_type,
+                self.label_selector.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            2 => string::merge(wire_type, &mut self.topology_key, buf, ctx),
+            3 => string::merge_repeated(wire_type, &mut self.namespaces, buf, ctx),
+            4 => message::merge(
+                wire_type,
+                self.namespace_selector.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.label_selector
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + string::encoded_len(2, &self.topology_key)
+            + string::encoded_len_repeated(3, &self.namespaces)
+            + self
+                .namespace_selector
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(4, v))
+    }
+
+    fn clear(&mut self) {
+        *self = PodAffinityTerm::default();
+    }
+}
+
+impl Message for WeightedPodAffinityTerm {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        int32::encode(1, &self.weight, buf);
+        if let Some(ref value) = self.pod_affinity_term {
+            message::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => int32::merge(wire_type, &mut self.weight, buf, ctx),
+            2 => message::merge(
+                wire_type,
+                self.pod_affinity_term.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        int32::encoded_len(1, &self.weight)
+            + self
+                .pod_affinity_term
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = WeightedPodAffinityTerm::default();
+    }
+}
+
+// ============================================================================
+// Toleration
+// ============================================================================
+
+impl Message for Toleration {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.key, buf);
+        string::encode(2, &self.operator, buf);
+        string::encode(3, &self.value, buf);
+        string::encode(4, &self.effect, buf);
+        if let Some(ref value) = self.toleration_seconds {
+            int64::encode(5, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.key, buf, ctx),
+            2 => string::merge(wire_type, &mut self.operator, buf, ctx),
+            3 => string::merge(wire_type, &mut self.value, buf, ctx),
+            4 => string::merge(wire_type, &mut self.effect, buf, ctx),
+            5 => int64::merge(
+                wire_type,
+                self.toleration_seconds.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.key)
+            + string::encoded_len(2, &self.operator)
+            + string::encoded_len(3, &self.value)
+            + string::encoded_len(4, &self.effect)
+            + self
+                .toleration_seconds
+                .as_ref()
+                .map_or(0, |v| int64::encoded_len(5, v))
+    }
+
+    fn clear(&mut self) {
+        *self = Toleration::default();
+    }
+}
+
+// ============================================================================
+// TopologySpreadConstraint
+// ============================================================================
+
+impl Message for TopologySpreadConstraint {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        int32::encode(1, &self.max_skew, buf);
+        string::encode(2, &self.topology_key, buf);
+        string::encode(3, &self.when_unsatisfiable, buf);
+        if let Some(ref value) = self.label_selector {
+            message::encode(4, value, buf);
+        }
+        if let Some(ref value) = self.min_domains {
+            int32::encode(5, value, buf);
+        }
+        if !self.node_affinity_policy.is_empty() {
+            string::encode(6, &self.node_affinity_policy, buf);
+        }
+        string::encode_repeated(8, &self.match_label_keys, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => int32::merge(wire_type, &mut self.max_skew, buf, ctx),
+            2 => string::merge(wire_type, &mut self.topology_key, buf, ctx),
+            3 => string::merge(wire_type, &mut self.when_unsatisfiable, buf, ctx),
+            4 => message::merge(
+                wire_type,
+                self.label_selector.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            5 => int32::merge(
+                wire_type,
+                self.min_domains.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            6 => string::merge(wire_type, &mut self.node_affinity_policy, buf, ctx),
+            8 => string::merge_repeated(wire_type, &mut self.match_label_keys, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        int32::encoded_len(1, &self.max_skew)
+            + string::encoded_len(2, &self.topology_key)
+            + string::encoded_len(3, &self.when_unsatisfiable)
+            + self
+                .label_selector
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(4, v))
+            + self
+                .min_domains
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(5, v))
+            + if self.node_affinity_policy.is_empty() {
+                0
+            } else {
+                string::encoded_len(6, &self.node_affinity_policy)
+            }
+            + string::encoded_len_repeated(8, &self.match_label_keys)
+    }
+
+    fn clear(&mut self) {
+        *self = TopologySpreadConstraint::default();
+    }
+}
+
+// ============================================================================
+// Object references
+// ============================================================================
+
+impl Message for LocalObjectReference {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.name {
+            string::encode(1, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, self.name.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.name.as_ref().map_or(0, |v| string::encoded_len(1, v))
+    }
+
+    fn clear(&mut self) {
+        *self = LocalObjectReference::default();
+    }
+}
+
+impl Message for TypedLocalObjectReference {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.api_group {
+            string::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.kind {
+            string::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.name {
+            string::encode(3, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, self.api_group.get_or_insert_default(), buf, ctx),
+            2 => string::merge(wire_type, self.kind.get_or_insert_default(), buf, ctx),
+            3 => string::merge(wire_type, self.name.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.api_group
+            .as_ref()
+            .map_or(0, |v| string::encoded_len(1, v))
+            + self.kind.as_ref().map_or(0, |v| string::encoded_len(2, v))
+            + self.name.as_ref().map_or(0, |v| string::encoded_len(3, v))
+    }
+
+    fn clear(&mut self) {
+        *self = TypedLocalObjectReference::default();
+    }
+}
+
+// ============================================================================
+// Binding and PodTemplate
+// ============================================================================
+
+impl Message for Binding {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref metadata) = self.metadata {
+            message::encode(1, metadata, buf);
+        }
+        message::encode(2, &self.target, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.metadata.get_or_insert_default(), buf, ctx),
+            2 => message::merge(wire_type, &mut self.target, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.metadata
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + message::encoded_len(2, &self.target)
+    }
+
+    fn clear(&mut self) {
+        *self = Binding::default();
+    }
+}
+
+impl Message for PodTemplate {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref metadata) = self.metadata {
+            message::encode(1, metadata, buf);
+        }
+        if let Some(ref template) = self.template {
+            message::encode(2, template, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.metadata.get_or_insert_default(), buf, ctx),
+            2 => message::merge(wire_type, self.template.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.metadata
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + self
+                .template
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = PodTemplate::default();
+    }
+}
+
+impl Message for PodTemplateSpec {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref metadata) = self.metadata {
+            message::encode(1, metadata, buf);
+        }
+        if let Some(ref spec) = self.spec {
+            message::encode(2, spec, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.metadata.get_or_insert_default(), buf, ctx),
+            2 => message::merge(wire_type, self.spec.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.metadata
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + self.spec.as_ref().map_or(0, |v| message::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = PodTemplateSpec::default();
+    }
+}
+
+impl Message for PodTemplateList {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref metadata) = self.metadata {
+            message::encode(1, metadata, buf);
+        }
+        message::encode_repeated(2, &self.items, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.metadata.get_or_insert_default(), buf, ctx),
+            2 => message::merge_repeated(wire_type, &mut self.items, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.metadata
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + message::encoded_len_repeated(2, &self.items)
+    }
+
+    fn clear(&mut self) {
+        *self = PodTemplateList::default();
+    }
+}
+
+// ============================================================================
+// Volumes
+// ============================================================================
+
+impl Message for Volume {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+        message::encode(2, &self.volume_source, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            2 => message::merge(wire_type, &mut self.volume_source, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name) + message::encoded_len(2, &self.volume_source)
+    }
+
+    fn clear(&mut self) {
+        *self = Volume::default();
+    }
+}
+
+impl Message for VolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.host_path {
+            message::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.empty_dir {
+            message::encode(2, value, buf);
+        }
+        encode_json_source(
+            3,
+            GCE_PERSISTENT_DISK_FIELDS,
+            &self.gce_persistent_disk,
+            buf,
+        );
+        encode_json_source(
+            4,
+            AWS_ELASTIC_BLOCK_STORE_FIELDS,
+            &self.aws_elastic_block_store,
+            buf,
+        );
+        encode_json_source(5, GIT_REPO_FIELDS, &self.git_repo, buf);
+        if let Some(ref value) = self.secret {
+            message::encode(6, value, buf);
+        }
+        if let Some(ref value) = self.nfs {
+            message::encode(7, value, buf);
+        }
+        if let Some(ref value) = self.iscsi {
+            message::encode(8, value, buf);
+        }
+        if let Some(ref value) = self.glusterfs {
+            message::encode(9, value, buf);
+        }
+        if let Some(ref value) = self.persistent_volume_claim {
+            message::encode(10, value, buf);
+        }
+        encode_json_source(11, RBD_FIELDS, &self.rbd, buf);
+        encode_json_source(12, FLEX_VOLUME_FIELDS, &self.flex_volume, buf);
+        encode_json_source(13, CINDER_FIELDS, &self.cinder, buf);
+        encode_json_source(14, CEPHFS_FIELDS, &self.cephfs, buf);
+        encode_json_source(15, FLOCKER_FIELDS, &self.flocker, buf);
+        if let Some(ref value) = self.downward_api {
+            message::encode(16, value, buf);
+        }
+        encode_json_source(17, FC_FIELDS, &self.fc, buf);
+        encode_json_source(18, AZURE_FILE_FIELDS, &self.azure_file, buf);
+        if let Some(ref value) = self.config_map {
+            message::encode(19, value, buf);
+        }
+        encode_json_source(20, VSPHERE_VOLUME_FIELDS, &self.vsphere_volume, buf);
+        encode_json_source(21, QUOBYTE_FIELDS, &self.quobyte, buf);
+        encode_json_source(22, AZURE_DISK_FIELDS, &self.azure_disk, buf);
+        encode_json_source(
+            23,
+            PHOTON_PERSISTENT_DISK_FIELDS,
+            &self.photon_persistent_disk,
+            buf,
+        );
+        encode_json_source(24, PORTWORX_VOLUME_FIELDS, &self.portworx_volume, buf);
+        encode_json_source(25, SCALE_IO_FIELDS, &self.scale_io, buf);
+        if let Some(ref value) = self.projected {
+            message::encode(26, value, buf);
+        }
+        encode_json_source(27, STORAGE_OS_FIELDS, &self.storage_os, buf);
+        if let Some(ref value) = self.csi {
+            message::encode(28, value, buf);
+        }
+        if let Some(ref value) = self.ephemeral {
+            message::encode(29, value, buf);
+        }
+        if let Some(ref value) = self.image {
+            message::encode(30, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.host_path.get_or_insert_default(), buf, ctx),
+            2 => message::merge(wire_type, self.empty_dir.get_or_insert_default(), buf, ctx),
+            3 => merge_json_source(
+                wire_type,
+                GCE_PERSISTENT_DISK_FIELDS,
+                &mut self.gce_persistent_disk,
+                buf,
+                ctx,
+            ),
+            4 => merge_json_source(
+                wire_type,
+                AWS_ELASTIC_BLOCK_STORE_FIELDS,
+                &mut self.aws_elastic_block_store,
+                buf,
+                ctx,
+            ),
+            5 => merge_json_source(wire_type, GIT_REPO_FIELDS, &mut self.git_repo, buf, ctx),
+            6 => message::merge(wire_type, self.secret.get_or_insert_default(), buf, ctx),
+            7 => message::merge(wire_type, self.nfs.get_or_insert_default(), buf, ctx),
+            8 => message::merge(wire_type, self.iscsi.get_or_insert_default(), buf, ctx),
+            9 => message::merge(wire_type, self.glusterfs.get_or_insert_default(), buf, ctx),
+            10 => message::merge(
+                wire_type,
+                self.persistent_volume_claim.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            11 => merge_json_source(wire_type, RBD_FIELDS, &mut self.rbd, buf, ctx),
+            12 => merge_json_source(
+                wire_type,
+                FLEX_VOLUME_FIELDS,
+                &mut self.flex_volume,
+                buf,
+                ctx,
+            ),
+            13 => merge_json_source(wire_type, CINDER_FIELDS, &mut self.cinder, buf, ctx),
+            14 => merge_json_source(wire_type, CEPHFS_FIELDS, &mut self.cephfs, buf, ctx),
+            15 => merge_json_source(wire_type, FLOCKER_FIELDS, &mut self.flocker, buf, ctx),
+            16 => message::merge(
+                wire_type,
+                self.downward_api.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            17 => merge_json_source(wire_type, FC_FIELDS, &mut self.fc, buf, ctx),
+            18 => merge_json_source(wire_type, AZURE_FILE_FIELDS, &mut self.azure_file, buf, ctx),
+            19 => message::merge(wire_type, self.config_map.get_or_insert_default(), buf, ctx),
+            20 => merge_json_source(
+                wire_type,
+                VSPHERE_VOLUME_FIELDS,
+                &mut self.vsphere_volume,
+                buf,
+                ctx,
+            ),
+            21 => merge_json_source(wire_type, QUOBYTE_FIELDS, &mut self.quobyte, buf, ctx),
+            22 => merge_json_source(wire_type, AZURE_DISK_FIELDS, &mut self.azure_disk, buf, ctx),
+            23 => merge_json_source(
+                wire_type,
+                PHOTON_PERSISTENT_DISK_FIELDS,
+                &mut self.photon_persistent_disk,
+                buf,
+                ctx,
+            ),
+            24 => merge_json_source(
+                wire_type,
+                PORTWORX_VOLUME_FIELDS,
+                &mut self.portworx_volume,
+                buf,
+                ctx,
+            ),
+            25 => merge_json_source(wire_type, SCALE_IO_FIELDS, &mut self.scale_io, buf, ctx),
+            26 => message::merge(wire_type, self.projected.get_or_insert_default(), buf, ctx),
+            27 => merge_json_source(wire_type, STORAGE_OS_FIELDS, &mut self.storage_os, buf, ctx),
+            28 => message::merge(wire_type, self.csi.get_or_insert_default(), buf, ctx),
+            29 => message::merge(wire_type, self.ephemeral.get_or_insert_default(), buf, ctx),
+            30 => message::merge(wire_type, self.image.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.host_path
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + self
+                .empty_dir
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+            + json_source_encoded_len(3, GCE_PERSISTENT_DISK_FIELDS, &self.gce_persistent_disk)
+            + json_source_encoded_len(
+                4,
+                AWS_ELASTIC_BLOCK_STORE_FIELDS,
+                &self.aws_elastic_block_store,
+            )
+            + json_source_encoded_len(5, GIT_REPO_FIELDS, &self.git_repo)
+            + self
+                .secret
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(6, v))
+            + self.nfs.as_ref().map_or(0, |v| message::encoded_len(7, v))
+            + self
+                .iscsi
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(8, v))
+            + self
+                .glusterfs
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(9, v))
+            + self
+                .persistent_volume_claim
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(10, v))
+            + json_source_encoded_len(11, RBD_FIELDS, &self.rbd)
+            + json_source_encoded_len(12, FLEX_VOLUME_FIELDS, &self.flex_volume)
+            + json_source_encoded_len(13, CINDER_FIELDS, &self.cinder)
+            + json_source_encoded_len(14, CEPHFS_FIELDS, &self.cephfs)
+            + json_source_encoded_len(15, FLOCKER_FIELDS, &self.flocker)
+            + self
+                .downward_api
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(16, v))
+            + json_source_encoded_len(17, FC_FIELDS, &self.fc)
+            + json_source_encoded_len(18, AZURE_FILE_FIELDS, &self.azure_file)
+            + self
+                .config_map
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(19, v))
+            + json_source_encoded_len(20, VSPHERE_VOLUME_FIELDS, &self.vsphere_volume)
+            + json_source_encoded_len(21, QUOBYTE_FIELDS, &self.quobyte)
+            + json_source_encoded_len(22, AZURE_DISK_FIELDS, &self.azure_disk)
+            + json_source_encoded_len(
+                23,
+                PHOTON_PERSISTENT_DISK_FIELDS,
+                &self.photon_persistent_disk,
+            )
+            + json_source_encoded_len(24, PORTWORX_VOLUME_FIELDS, &self.portworx_volume)
+            + json_source_encoded_len(25, SCALE_IO_FIELDS, &self.scale_io)
+            + self
+                .projected
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(26, v))
+            + json_source_encoded_len(27, STORAGE_OS_FIELDS, &self.storage_os)
+            + self.csi.as_ref().map_or(0, |v| message::encoded_len(28, v))
+            + self
+                .ephemeral
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(29, v))
+            + self
+                .image
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(30, v))
+    }
+
+    fn clear(&mut self) {
+        *self = VolumeSource::default();
+    }
+}
+
+// ============================================================================
+// Legacy in-tree volume sources
+// ============================================================================
+//
+// The deprecated in-tree volume sources are modelled as raw JSON objects, so
+// each one is encoded from a table of its upstream message fields. Object keys
+// the message does not define, or whose value has the wrong JSON type, have no
+// wire representation and are not written.
+
+type JsonObject = serde_json::Map<String, serde_json::Value>;
+
+#[derive(Clone, Copy)]
+enum JsonFieldKind {
+    String,
+    Bool,
+    Int32,
+    StringList,
+    StringMap,
+    Message(&'static [JsonField]),
+}
+
+struct JsonField {
+    tag: u32,
+    name: &'static str,
+    kind: JsonFieldKind,
+}
+
+const fn json_field(tag: u32, name: &'static str, kind: JsonFieldKind) -> JsonField {
+    JsonField { tag, name, kind }
+}
+
+const LOCAL_OBJECT_REFERENCE_FIELDS: &[JsonField] = &[json_field(1, "name", JsonFieldKind::String)];
+
+const GCE_PERSISTENT_DISK_FIELDS: &[JsonField] = &[
+    json_field(1, "pdName", JsonFieldKind::String),
+    json_field(2, "fsType", JsonFieldKind::String),
+    json_field(3, "partition", JsonFieldKind::Int32),
+    json_field(4, "readOnly", JsonFieldKind::Bool),
+];
+
+const AWS_ELASTIC_BLOCK_STORE_FIELDS: &[JsonField] = &[
+    json_field(1, "volumeID", JsonFieldKind::String),
+    json_field(2, "fsType", JsonFieldKind::String),
+    json_field(3, "partition", JsonFieldKind::Int32),
+    json_field(4, "readOnly", JsonFieldKind::Bool),
+];
+
+const GIT_REPO_FIELDS: &[JsonField] = &[
+    json_field(1, "repository", JsonFieldKind::String),
+    json_field(2, "revision", JsonFieldKind::String),
+    json_field(3, "directory", JsonFieldKind::String),
+];
+
+const RBD_FIELDS: &[JsonField] = &[
+    json_field(1, "monitors", JsonFieldKind::StringList),
+    json_field(2, "image", JsonFieldKind::String),
+    json_field(3, "fsType", JsonFieldKind::String),
+    json_field(4, "pool", JsonFieldKind::String),
+    json_field(5, "user", JsonFieldKind::String),
+    json_field(6, "keyring", JsonFieldKind::String),
+    json_field(
+        7,
+        "secretRef",
+        JsonFieldKind::Message(LOCAL_OBJECT_REFERENCE_FIELDS),
+    ),
+    json_field(8, "readOnly", JsonFieldKind::Bool),
+];
+
+const FLEX_VOLUME_FIELDS: &[JsonField] = &[
+    json_field(1, "driver", JsonFieldKind::String),
+    json_field(2, "fsType", JsonFieldKind::String),
+    json_field(
+        3,
+        "secretRef",
+        JsonFieldKind::Message(LOCAL_OBJECT_REFERENCE_FIELDS),
+    ),
+    json_field(4, "readOnly", JsonFieldKind::Bool),
+    json_field(5, "options", JsonFieldKind::StringMap),
+];
+
+const CINDER_FIELDS: &[JsonField] = &[
+    json_field(1, "volumeID", JsonFieldKind::String),
+    json_field(2, "fsType", JsonFieldKind::String),
+    json_field(3, "readOnly", JsonFieldKind::Bool),
+    json_field(
+        4,
+        "secretRef",
+        JsonFieldKind::Message(LOCAL_OBJECT_REFERENCE_FIELDS),
+    ),
+];
+
+const CEPHFS_FIELDS: &[JsonField] = &[
+    json_field(1, "monitors", JsonFieldKind::StringList),
+    json_field(2, "path", JsonFieldKind::String),
+    json_field(3, "user", JsonFieldKind::String),
+    json_field(4, "secretFile", JsonFieldKind::String),
+    json_field(
+        5,
+        "secretRef",
+        JsonFieldKind::Message(LOCAL_OBJECT_REFERENCE_FIELDS),
+    ),
+    json_field(6, "readOnly", JsonFieldKind::Bool),
+];
+
+const FLOCKER_FIELDS: &[JsonField] = &[
+    json_field(1, "datasetName", JsonFieldKind::String),
+    json_field(2, "datasetUUID", JsonFieldKind::String),
+];
+
+const FC_FIELDS: &[JsonField] = &[
+    json_field(1, "targetWWNs", JsonFieldKind::StringList),
+    json_field(2, "lun", JsonFieldKind::Int32),
+    json_field(3, "fsType", JsonFieldKind::String),
+    json_field(4, "readOnly", JsonFieldKind::Bool),
+    json_field(5, "wwids", JsonFieldKind::StringList),
+];
+
+const AZURE_FILE_FIELDS: &[JsonField] = &[
+    json_field(1, "secretName", JsonFieldKind::String),
+    json_field(2, "shareName", JsonFieldKind::String),
+    json_field(3, "readOnly", JsonFieldKind::Bool),
+];
+
+const VSPHERE_VOLUME_FIELDS: &[JsonField] = &[
+    json_field(1, "volumePath", JsonFieldKind::String),
+    json_field(2, "fsType", JsonFieldKind::String),
+    json_field(3, "storagePolicyName", JsonFieldKind::String),
+    json_field(4, "storagePolicyID", JsonFieldKind::String),
+];
+
+const QUOBYTE_FIELDS: &[JsonField] = &[
+    json_field(1, "registry", JsonFieldKind::String),
+    json_field(2, "volume", JsonFieldKind::String),
+    json_field(3, "readOnly", JsonFieldKind::Bool),
+    json_field(4, "user", JsonFieldKind::String),
+    json_field(5, "group", JsonFieldKind::String),
+    json_field(6, "tenant", JsonFieldKind::String),
+];
+
+const AZURE_DISK_FIELDS: &[JsonField] = &[
+    json_field(1, "diskName", JsonFieldKind::String),
+    json_field(2, "diskURI", JsonFieldKind::String),
+    json_field(3, "cachingMode", JsonFieldKind::String),
+    json_field(4, "fsType", JsonFieldKind::String),
+    json_field(5, "readOnly", JsonFieldKind::Bool),
+    json_field(6, "kind", JsonFieldKind::String),
+];
+
+const PHOTON_PERSISTENT_DISK_FIELDS: &[JsonField] = &[
+    json_field(1, "pdID", JsonFieldKind::String),
+    json_field(2, "fsType", JsonFieldKind::String),
+];
+
+const PORTWORX_VOLUME_FIELDS: &[JsonField] = &[
+    json_field(1, "volumeID", JsonFieldKind::String),
+    json_field(2, "fsType", JsonFieldKind::String),
+    json_field(3, "readOnly", JsonFieldKind::Bool),
+];
+
+const SCALE_IO_FIELDS: &[JsonField] = &[
+    json_field(1, "gateway", JsonFieldKind::String),
+    json_field(2, "system", JsonFieldKind::String),
+    json_field(
+        3,
+        "secretRef",
+        JsonFieldKind::Message(LOCAL_OBJECT_REFERENCE_FIELDS),
+    ),
+    json_field(4, "sslEnabled", JsonFieldKind::Bool),
+    json_field(5, "protectionDomain", JsonFieldKind::String),
+    json_field(6, "storagePool", JsonFieldKind::String),
+    json_field(7, "storageMode", JsonFieldKind::String),
+    json_field(8, "volumeName", JsonFieldKind::String),
+    json_field(9, "fsType", JsonFieldKind::String),
+    json_field(10, "readOnly", JsonFieldKind::Bool),
+];
+
+const STORAGE_OS_FIELDS: &[JsonField] = &[
+    json_field(1, "volumeName", JsonFieldKind::String),
+    json_field(2, "volumeNamespace", JsonFieldKind::String),
+    json_field(3, "fsType", JsonFieldKind::String),
+    json_field(4, "readOnly", JsonFieldKind::Bool),
+    json_field(
+        5,
+        "secretRef",
+        JsonFieldKind::Message(LOCAL_OBJECT_REFERENCE_FIELDS),
+    ),
+];
+
+fn json_int32(value: &serde_json::Value) -> Option<i32> {
+    value.as_i64().and_then(|v| i32::try_from(v).ok())
+}
+
+fn json_string_map(value: &serde_json::Value) -> BTreeMap<String, String> {
+    value
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+        .collect()
+}
+
+fn encode_json_source<B>(
+    tag: u32,
+    fields: &[JsonField],
+    value: &Option<serde_json::Value>,
+    buf: &mut B,
+) where
+    B: BufMut,
+{
+    if let Some(serde_json::Value::Object(object)) = value {
+        encode_json_message(tag, fields, object, buf);
+    }
+}
+
+fn encode_json_message<B>(tag: u32, fields: &[JsonField], object: &JsonObject, buf: &mut B)
+where
+    B: BufMut,
+{
+    encode_key(tag, WireType::LengthDelimited, buf);
+    encode_varint(json_fields_encoded_len(fields, object) as u64, buf);
+    for field in fields {
+        let Some(value) = object.get(field.name) else {
+            continue;
+        };
+        match (field.kind, value) {
+            (JsonFieldKind::String, serde_json::Value::String(v)) => {
+                string::encode(field.tag, v, buf)
+            }
+            (JsonFieldKind::Bool, serde_json::Value::Bool(v)) => bool::encode(field.tag, v, buf),
+            (JsonFieldKind::Int32, v) => {
+                if let Some(v) = json_int32(v) {
+                    int32::encode(field.tag, &v, buf);
+                }
+            }
+            (JsonFieldKind::StringList, serde_json::Value::Array(items)) => {
+                for item in items {
+                    if let serde_json::Value::String(v) = item {
+                        string::encode(field.tag, v, buf);
+                    }
+                }
+            }
+            (JsonFieldKind::StringMap, v @ serde_json::Value::Object(_)) => btree_map::encode(
+                string::encode,
+                string::encoded_len,
+                string::encode,
+                string::encoded_len,
+                field.tag,
+                &json_string_map(v),
+                buf,
+            ),
+            (JsonFieldKind::Message(nested), serde_json::Value::Object(v)) => {
+                encode_json_message(field.tag, nested, v, buf)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn json_source_encoded_len(
+    tag: u32,
+    fields: &[JsonField],
+    value: &Option<serde_json::Value>,
+) -> usize {
+    match value {
+        Some(serde_json::Value::Object(object)) => json_message_encoded_len(tag, fields, object),
+        _ => 0,
+    }
+}
+
+fn json_message_encoded_len(tag: u32, fields: &[JsonField], object: &JsonObject) -> usize {
+    let len = json_fields_encoded_len(fields, object);
+    key_len(tag) + encoded_len_varint(len as u64) + len
+}
+
+fn json_fields_encoded_len(fields: &[JsonField], object: &JsonObject) -> usize {
+    fields
+        .iter()
+        .filter_map(|field| Some((field, object.get(field.name)?)))
+        .map(|(field, value)| match (field.kind, value) {
+            (JsonFieldKind::String, serde_json::Value::String(v)) => {
+                string::encoded_len(field.tag, v)
+            }
+            (JsonFieldKind::Bool, serde_json::Value::Bool(v)) => bool::encoded_len(field.tag, v),
+            (JsonFieldKind::Int32, v) => {
+                json_int32(v).map_or(0, |v| int32::encoded_len(field.tag, &v))
+            }
+            (JsonFieldKind::StringList, serde_json::Value::Array(items)) => items
+                .iter()
+                .filter_map(|item| match item {
+                    serde_json::Value::String(v) => Some(string::encoded_len(field.tag, v)),
+                    _ => None,
+                })
+                .sum(),
+            (JsonFieldKind::StringMap, v @ serde_json::Value::Object(_)) => btree_map::encoded_len(
+                string::encoded_len,
+                string::encoded_len,
+                field.tag,
+                &json_string_map(v),
+            ),
+            (JsonFieldKind::Message(nested), serde_json::Value::Object(v)) => {
+                json_message_encoded_len(field.tag, nested, v)
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+fn merge_json_source<B>(
+    wire_type: WireType,
+    fields: &[JsonField],
+    value: &mut Option<serde_json::Value>,
+    buf: &mut B,
+    ctx: DecodeContext,
+) -> Result<(), DecodeError>
+where
+    B: Buf,
+{
+    let value = value.get_or_insert_with(|| serde_json::Value::Object(JsonObject::new()));
+    merge_json_message(wire_type, fields, value, buf, ctx)
+}
+
+fn merge_json_message<B>(
+    wire_type: WireType,
+    fields: &[JsonField],
+    value: &mut serde_json::Value,
+    buf: &mut B,
+    ctx: DecodeContext,
+) -> Result<(), DecodeError>
+where
+    B: Buf,
+{
+    check_wire_type(WireType::LengthDelimited, wire_type)?;
+    if !value.is_object() {
+        *value = serde_json::Value::Object(JsonObject::new());
+    }
+    let object = value
+        .as_object_mut()
+        .expect("value was just made an object");
+    merge_loop(object, buf, ctx, |object, buf, ctx| {
+        let (tag, wire_type) = decode_key(buf)?;
+        match fields.iter().find(|field| field.tag == tag) {
+            Some(field) => merge_json_field(field, wire_type, object, buf, ctx),
+            None => skip_field(wire_type, tag, buf, ctx),
+        }
+    })
+}
+
+fn merge_json_field<B>(
+    field: &JsonField,
+    wire_type: WireType,
+    object: &mut JsonObject,
+    buf: &mut B,
+    ctx: DecodeContext,
+) -> Result<(), DecodeError>
+where
+    B: Buf,
+{
+    let name = field.name.to_string();
+    match field.kind {
+        JsonFieldKind::String => {
+            let mut value = String::new();
+            string::merge(wire_type, &mut value, buf, ctx)?;
+            object.insert(name, value.into());
+        }
+        JsonFieldKind::Bool => {
+            let mut value = false;
+            bool::merge(wire_type, &mut value, buf, ctx)?;
+            object.insert(name, value.into());
+        }
+        JsonFieldKind::Int32 => {
+            let mut value = 0;
+            int32::merge(wire_type, &mut value, buf, ctx)?;
+            object.insert(name, value.into());
+        }
+        JsonFieldKind::StringList => {
+            let mut value = String::new();
+            string::merge(wire_type, &mut value, buf, ctx)?;
+            let items = object
+                .entry(name)
+                .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+            match items {
+                serde_json::Value::Array(items) => items.push(value.into()),
+                other => *other = serde_json::Value::Array(vec![value.into()]),
+            }
+        }
+        JsonFieldKind::StringMap => {
+            let mut entries = object.get(&name).map(json_string_map).unwrap_or_default();
+            btree_map::merge(string::merge, string::merge, &mut entries, buf, ctx)?;
+            object.insert(name, entries.into_iter().collect());
+        }
+        JsonFieldKind::Message(nested) => {
+            let value = object
+                .entry(name)
+                .or_insert_with(|| serde_json::Value::Object(JsonObject::new()));
+            merge_json_message(wire_type, nested, value, buf, ctx)?;
+        }
+    }
+    Ok(())
+}
+
+impl Message for VolumeMount {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+        bool::encode(2, &self.read_only, buf);
+        string::encode(3, &self.mount_path, buf);
+        string::encode(4, &self.sub_path, buf);
+        if let Some(ref value) = self.mount_propagation {
+            string::encode(5, value, buf);
+        }
+        string::encode(6, &self.sub_path_expr, buf);
+        if let Some(ref value) = self.recursive_read_only {
+            string::encode(7, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            2 => bool::merge(wire_type, &mut self.read_only, buf, ctx),
+            3 => string::merge(wire_type, &mut self.mount_path, buf, ctx),
+            4 => string::merge(wire_type, &mut self.sub_path, buf, ctx),
+            5 => string::merge(
+                wire_type,
+                self.mount_propagation.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            6 => string::merge(wire_type, &mut self.sub_path_expr, buf, ctx),
+            7 => string::merge(
+                wire_type,
+                self.recursive_read_only.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name)
+            + bool::encoded_len(2, &self.read_only)
+            + string::encoded_len(3, &self.mount_path)
+            + string::encoded_len(4, &self.sub_path)
+            + self
+                .mount_propagation
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(5, v))
+            + string::encoded_len(6, &self.sub_path_expr)
+            + self
+                .recursive_read_only
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(7, v))
+    }
+
+    fn clear(&mut self) {
+        *self = VolumeMount::default();
+    }
+}
+
+impl Message for VolumeDevice {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+        string::encode(2, &self.device_path, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            2 => string::merge(wire_type, &mut self.device_path, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name) + string::encoded_len(2, &self.device_path)
+    }
+
+    fn clear(&mut self) {
+        *self = VolumeDevice::default();
+    }
+}
+
+impl Message for HostPathVolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.path, buf);
+        if let Some(ref value) = self.type_ {
+            string::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.path, buf, ctx),
+            2 => string::merge(wire_type, self.type_.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.path)
+            + self.type_.as_ref().map_or(0, |v| string::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = HostPathVolumeSource::default();
+    }
+}
+
+impl Message for EmptyDirVolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.medium {
+            string::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.size_limit {
+            message::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, self.medium.get_or_insert_default(), buf, ctx),
+            2 => message::merge(wire_type, self.size_limit.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.medium
+            .as_ref()
+            .map_or(0, |v| string::encoded_len(1, v))
+            + self
+                .size_limit
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = EmptyDirVolumeSource::default();
+    }
+}
+
+impl Message for SecretVolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.secret_name {
+            string::encode(1, value, buf);
+        }
+        message::encode_repeated(2, &self.items, buf);
+        if let Some(ref value) = self.default_mode {
+            int32::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.optional {
+            bool::encode(4, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(
+                wire_type,
+                self.secret_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            2 => message::merge_repeated(wire_type, &mut self.items, buf, ctx),
+            3 => int32::merge(
+                wire_type,
+                self.default_mode.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => bool::merge(wire_type, self.optional.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.secret_name
+            .as_ref()
+            .map_or(0, |v| string::encoded_len(1, v))
+            + message::encoded_len_repeated(2, &self.items)
+            + self
+                .default_mode
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(3, v))
+            + self
+                .optional
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(4, v))
+    }
+
+    fn clear(&mut self) {
+        *self = SecretVolumeSource::default();
+    }
+}
+
+impl Message for NFSVolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.server, buf);
+        string::encode(2, &self.path, buf);
+        bool::encode(3, &self.read_only, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.server, buf, ctx),
+            2 => string::merge(wire_type, &mut self.path, buf, ctx),
+            3 => bool::merge(wire_type, &mut self.read_only, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.server)
+            + string::encoded_len(2, &self.path)
+            + bool::encoded_len(3, &self.read_only)
+    }
+
+    fn clear(&mut self) {
+        *self = NFSVolumeSource::default();
+    }
+}
+
+impl Message for ISCSIVolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.target_portal, buf);
+        string::encode(2, &self.iqn, buf);
+        int32::encode(3, &self.lun, buf);
+        string::encode(4, &self.iscsi_interface, buf);
+        string::encode(5, &self.fs_type, buf);
+        bool::encode(6, &self.read_only, buf);
+        string::encode_repeated(7, &self.portals, buf);
+        bool::encode(8, &self.chap_auth_discovery, buf);
+        if let Some(ref value) = self.secret_ref {
+            message::encode(10, value, buf);
+        }
+        bool::encode(11, &self.chap_auth_session, buf);
+        if let Some(ref value) = self.initiator_name {
+            string::encode(12, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.target_portal, buf, ctx),
+            2 => string::merge(wire_type, &mut self.iqn, buf, ctx),
+            3 => int32::merge(wire_type, &mut self.lun, buf, ctx),
+            4 => string::merge(wire_type, &mut self.iscsi_interface, buf, ctx),
+            5 => string::merge(wire_type, &mut self.fs_type, buf, ctx),
+            6 => bool::merge(wire_type, &mut self.read_only, buf, ctx),
+            7 => string::merge_repeated(wire_type, &mut self.portals, buf, ctx),
+            8 => bool::merge(wire_type, &mut self.chap_auth_discovery, buf, ctx),
+            10 => message::merge(wire_type, self.secret_ref.get_or_insert_default(), buf, ctx),
+            11 => bool::merge(wire_type, &mut self.chap_auth_session, buf, ctx),
+            12 => string::merge(
+                wire_type,
+                self.initiator_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.target_portal)
+            + string::encoded_len(2, &self.iqn)
+            + int32::encoded_len(3, &self.lun)
+            + string::encoded_len(4, &self.iscsi_interface)
+            + string::encoded_len(5, &self.fs_type)
+            + bool::encoded_len(6, &self.read_only)
+            + string::encoded_len_repeated(7, &self.portals)
+            + bool::encoded_len(8, &self.chap_auth_discovery)
+            + self
+                .secret_ref
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(10, v))
+            + bool::encoded_len(11, &self.chap_auth_session)
+            + self
+                .initiator_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(12, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ISCSIVolumeSource::default();
+    }
+}
+
+impl Message for GlusterfsVolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.endpoints, buf);
+        string::encode(2, &self.path, buf);
+        bool::encode(3, &self.read_only, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.endpoints, buf, ctx),
+            2 => string::merge(wire_type, &mut self.path, buf, ctx),
+            3 => bool::merge(wire_type, &mut self.read_only, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.endpoints)
+            + string::encoded_len(2, &self.path)
+            + bool::encoded_len(3, &self.read_only)
+    }
+
+    fn clear(&mut self) {
+        *self = GlusterfsVolumeSource::default();
+    }
+}
+
+impl Message for PersistentVolumeClaimVolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.claim_name, buf);
+        bool::encode(2, &self.read_only, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.claim_name, buf, ctx),
+            2 => bool::merge(wire_type, &mut self.read_only, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.claim_name) + bool::encoded_len(2, &self.read_only)
+    }
+
+    fn clear(&mut self) {
+        *self = PersistentVolumeClaimVolumeSource::default();
+    }
+}
+
+impl Message for DownwardAPIVolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode_repeated(1, &self.items, buf);
+        if let Some(ref value) = self.default_mode {
+            int32::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge_repeated(wire_type, &mut self.items, buf, ctx),
+            2 => int32::merge(
+                wire_type,
+                self.default_mode.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len_repeated(1, &self.items)
+            + self
+                .default_mode
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = DownwardAPIVolumeSource::default();
+    }
+}
+
+impl Message for DownwardAPIVolumeFile {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.path, buf);
+        if let Some(ref value) = self.field_ref {
+            message::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.resource_field_ref {
+            message::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.mode {
+            int32::encode(4, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.path, buf, ctx),
+            2 => message::merge(wire_type, self.field_ref.get_or_insert_default(), buf, ctx),
+            3 => message::merge(
+                wire_type,
+                self.resource_field_ref.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => int32::merge(wire_type, self.mode.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.path)
+            + self
+                .field_ref
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+            + self
+                .resource_field_ref
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+            + self.mode.as_ref().map_or(0, |v| int32::encoded_len(4, v))
+    }
+
+    fn clear(&mut self) {
+        *self = DownwardAPIVolumeFile::default();
+    }
+}
+
+impl Message for KeyToPath {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.key, buf);
+        string::encode(2, &self.path, buf);
+        if let Some(ref value) = self.mode {
+            int32::encode(3, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.key, buf, ctx),
+            2 => string::merge(wire_type, &mut self.path, buf, ctx),
+            3 => int32::merge(wire_type, self.mode.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.key)
+            + string::encoded_len(2, &self.path)
+            + self.mode.as_ref().map_or(0, |v| int32::encoded_len(3, v))
+    }
+
+    fn clear(&mut self) {
+        *self = KeyToPath::default();
+    }
+}
+
+impl Message for ConfigMapVolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        encode_local_object_reference(1, &self.name, buf);
+        message::encode_repeated(2, &self.items, buf);
+        if let Some(ref value) = self.default_mode {
+            int32::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.optional {
+            bool::encode(4, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => merge_local_object_reference(wire_type, &mut self.name, buf, ctx),
+            2 => message::merge_repeated(wire_type, &mut self.items, buf, ctx),
+            3 => int32::merge(
+                wire_type,
+                self.default_mode.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => bool::merge(wire_type, self.optional.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        local_object_reference_encoded_len(1, &self.name)
+            + message::encoded_len_repeated(2, &self.items)
+            + self
+                .default_mode
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(3, v))
+            + self
+                .optional
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(4, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ConfigMapVolumeSource::default();
+    }
+}
+
+impl Message for ProjectedVolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode_repeated(1, &self.sources, buf);
+        if let Some(ref value) = self.default_mode {
+            int32::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge_repeated(wire_type, &mut self.sources, buf, ctx),
+            2 => int32::merge(
+                wire_type,
+                self.default_mode.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len_repeated(1, &self.sources)
+            + self
+                .default_mode
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ProjectedVolumeSource::default();
+    }
+}
+
+impl Message for VolumeProjection {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.secret {
+            message::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.downward_api {
+            message::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.config_map {
+            message::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.service_account_token {
+            message::encode(4, value, buf);
+        }
+        if let Some(ref value) = self.cluster_trust_bundle {
+            message::encode(5, value, buf);
+        }
+        if let Some(ref value) = self.pod_certificate {
+            message::encode(6, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.secret.get_or_insert_default(), buf, ctx),
+            2 => message::merge(
+                wire_type,
+                self.downward_api.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            3 => message::merge(wire_type, self.config_map.get_or_insert_default(), buf, ctx),
+            4 => message::merge(
+                wire_type,
+                self.service_account_token.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            5 => message::merge(
+                wire_type,
+                self.cluster_trust_bundle.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            6 => message::merge(
+                wire_type,
+                self.pod_certificate.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.secret
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + self
+                .downward_api
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+            + self
+                .config_map
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+            + self
+                .service_account_token
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(4, v))
+            + self
+                .cluster_trust_bundle
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(5, v))
+            + self
+                .pod_certificate
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(6, v))
+    }
+
+    fn clear(&mut self) {
+        *self = VolumeProjection::default();
+    }
+}
+
+impl Message for SecretProjection {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        encode_local_object_reference(1, &self.name, buf);
+        message::encode_repeated(2, &self.items, buf);
+        if let Some(ref value) = self.optional {
+            bool::encode(4, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => merge_local_object_reference(wire_type, &mut self.name, buf, ctx),
+            2 => message::merge_repeated(wire_type, &mut self.items, buf, ctx),
+            4 => bool::merge(wire_type, self.optional.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        local_object_reference_encoded_len(1, &self.name)
+            + message::encoded_len_repeated(2, &self.items)
+            + self
+                .optional
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(4, v))
+    }
+
+    fn clear(&mut self) {
+        *self = SecretProjection::default();
+    }
+}
+
+impl Message for ConfigMapProjection {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        encode_local_object_reference(1, &self.name, buf);
+        message::encode_repeated(2, &self.items, buf);
+        if let Some(ref value) = self.optional {
+            bool::encode(4, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => merge_local_object_reference(wire_type, &mut self.name, buf, ctx),
+            2 => message::merge_repeated(wire_type, &mut self.items, buf, ctx),
+            4 => bool::merge(wire_type, self.optional.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        local_object_reference_encoded_len(1, &self.name)
+            + message::encoded_len_repeated(2, &self.items)
+            + self
+                .optional
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(4, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ConfigMapProjection::default();
+    }
+}
+
+impl Message for DownwardAPIProjection {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode_repeated(1, &self.items, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge_repeated(wire_type, &mut self.items, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len_repeated(1, &self.items)
+    }
+
+    fn clear(&mut self) {
+        *self = DownwardAPIProjection::default();
+    }
+}
+
+impl Message for ServiceAccountTokenProjection {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.audience, buf);
+        if let Some(ref value) = self.expiration_seconds {
+            int64::encode(2, value, buf);
+        }
+        string::encode(3, &self.path, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.audience, buf, ctx),
+            2 => int64::merge(
+                wire_type,
+                self.expiration_seconds.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            3 => string::merge(wire_type, &mut self.path, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.audience)
+            + self
+                .expiration_seconds
+                .as_ref()
+                .map_or(0, |v| int64::encoded_len(2, v))
+            + string::encoded_len(3, &self.path)
+    }
+
+    fn clear(&mut self) {
+        *self = ServiceAccountTokenProjection::default();
+    }
+}
+
+impl Message for ClusterTrustBundleProjection {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.name {
+            string::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.signer_name {
+            string::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.label_selector {
+            message::encode(3, value, buf);
+        }
+        string::encode(4, &self.path, buf);
+        if let Some(ref value) = self.optional {
+            bool::encode(5, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, self.name.get_or_insert_default(), buf, ctx),
+            2 => string::merge(
+                wire_type,
+                self.signer_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            3 => message::merge(
+                wire_type,
+                self.label_selector.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => string::merge(wire_type, &mut self.path, buf, ctx),
+            5 => bool::merge(wire_type, self.optional.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.name.as_ref().map_or(0, |v| string::encoded_len(1, v))
+            + self
+                .signer_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(2, v))
+            + self
+                .label_selector
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(3, v))
+            + string::encoded_len(4, &self.path)
+            + self
+                .optional
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(5, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ClusterTrustBundleProjection::default();
+    }
+}
+
+impl Message for PodCertificateProjection {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.signer_name, buf);
+        string::encode(2, &self.key_type, buf);
+        if let Some(ref value) = self.max_expiration_seconds {
+            int32::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.credential_bundle_path {
+            string::encode(4, value, buf);
+        }
+        if let Some(ref value) = self.key_path {
+            string::encode(5, value, buf);
+        }
+        if let Some(ref value) = self.certificate_chain_path {
+            string::encode(6, value, buf);
+        }
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            string::encode,
+            string::encoded_len,
+            7,
+            &self.user_annotations,
+            buf,
+        );
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.signer_name, buf, ctx),
+            2 => string::merge(wire_type, &mut self.key_type, buf, ctx),
+            3 => int32::merge(
+                wire_type,
+                self.max_expiration_seconds.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => string::merge(
+                wire_type,
+                self.credential_bundle_path.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            5 => string::merge(wire_type, self.key_path.get_or_insert_default(), buf, ctx),
+            6 => string::merge(
+                wire_type,
+                self.certificate_chain_path.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            7 => btree_map::merge(
+                string::merge,
+                string::merge,
+                &mut self.user_annotations,
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.signer_name)
+            + string::encoded_len(2, &self.key_type)
+            + self
+                .max_expiration_seconds
+                .as_ref()
+                .map_or(0, |v| int32::encoded_len(3, v))
+            + self
+                .credential_bundle_path
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(4, v))
+            + self
+                .key_path
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(5, v))
+            + self
+                .certificate_chain_path
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(6, v))
+            + btree_map::encoded_len(
+                string::encoded_len,
+                string::encoded_len,
+                7,
+                &self.user_annotations,
+            )
+    }
+
+    fn clear(&mut self) {
+        *self = PodCertificateProjection::default();
+    }
+}
+
+impl Message for CSIVolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.driver, buf);
+        if let Some(ref value) = self.read_only {
+            bool::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.fs_type {
+            string::encode(3, value, buf);
+        }
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            string::encode,
+            string::encoded_len,
+            4,
+            &self.volume_attributes,
+            buf,
+        );
+        if let Some(ref value) = self.node_publish_secret_ref {
+            message::encode(5, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.driver, buf, ctx),
+            2 => bool::merge(wire_type, self.read_only.get_or_insert_default(), buf, ctx),
+            3 => string::merge(wire_type, self.fs_type.get_or_insert_default(), buf, ctx),
+            4 => btree_map::merge(
+                string::merge,
+                string::merge,
+                &mut self.volume_attributes,
+                buf,
+                ctx,
+            ),
+            5 => message::merge(
+                wire_type,
+                self.node_publish_secret_ref.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.driver)
+            + self
+                .read_only
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(2, v))
+            + self
+                .fs_type
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(3, v))
+            + btree_map::encoded_len(
+                string::encoded_len,
+                string::encoded_len,
+                4,
+                &self.volume_attributes,
+            )
+            + self
+                .node_publish_secret_ref
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(5, v))
+    }
+
+    fn clear(&mut self) {
+        *self = CSIVolumeSource::default();
+    }
+}
+
+impl Message for EphemeralVolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.volume_claim_template {
+            message::encode(1, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(
+                wire_type,
+                self.volume_claim_template.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.volume_claim_template
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+    }
+
+    fn clear(&mut self) {
+        *self = EphemeralVolumeSource::default();
+    }
+}
+
+impl Message for ImageVolumeSource {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.reference, buf);
+        if let Some(ref value) = self.pull_policy {
+            string::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.reference, buf, ctx),
+            2 => string::merge(
+                wire_type,
+                self.pull_policy.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.reference)
+            + self
+                .pull_policy
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = ImageVolumeSource::default();
+    }
+}
+
+impl Message for PersistentVolumeClaimTemplate {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.metadata {
+            message::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.spec {
+            message::encode(2, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.metadata.get_or_insert_default(), buf, ctx),
+            2 => message::merge(wire_type, self.spec.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.metadata
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + self.spec.as_ref().map_or(0, |v| message::encoded_len(2, v))
+    }
+
+    fn clear(&mut self) {
+        *self = PersistentVolumeClaimTemplate::default();
+    }
+}
+
+// ============================================================================
+// PersistentVolumeClaimSpec
+// ============================================================================
+
+impl Message for PersistentVolumeClaimSpec {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode_repeated(1, &self.access_modes, buf);
+        if let Some(ref value) = self.resources {
+            message::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.volume_name {
+            string::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.selector {
+            message::encode(4, value, buf);
+        }
+        if let Some(ref value) = self.storage_class_name {
+            string::encode(5, value, buf);
+        }
+        if let Some(ref value) = self.volume_mode {
+            string::encode(6, value, buf);
+        }
+        if let Some(ref value) = self.data_source {
+            message::encode(7, value, buf);
+        }
+        if let Some(ref value) = self.data_source_ref {
+            message::encode(8, value, buf);
+        }
+        if let Some(ref value) = self.volume_attributes_class_name {
+            string::encode(9, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge_repeated(wire_type, &mut self.access_modes, buf, ctx),
+            2 => message::merge(wire_type, self.resources.get_or_insert_default(), buf, ctx),
+            3 => string::merge(
+                wire_type,
+                self.volume_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            4 => message::merge(wire_type, self.selector.get_or_insert_default(), buf, ctx),
+            5 => string::merge(
+                wire_type,
+                self.storage_class_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            6 => string::merge(
+                wire_type,
+                self.volume_mode.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            7 => message::merge(
+                wire_type,
+                self.data_source.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            8 => message::merge(
+                wire_type,
+                self.data_source_ref.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            9 => string::merge(
+                wire_type,
+                self.volume_attributes_class_name.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len_repeated(1, &self.access_modes)
+            + self
+                .resources
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(2, v))
+            + self
+                .volume_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(3, v))
+            + self
+                .selector
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(4, v))
+            + self
+                .storage_class_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(5, v))
+            + self
+                .volume_mode
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(6, v))
+            + self
+                .data_source
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(7, v))
+            + self
+                .data_source_ref
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(8, v))
+            + self
+                .volume_attributes_class_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(9, v))
+    }
+
+    fn clear(&mut self) {
+        *self = PersistentVolumeClaimSpec::default();
+    }
+}
+
+impl Message for VolumeResourceRequirements {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            message::encode,
+            message::encoded_len,
+            1,
+            &self.limits,
+            buf,
+        );
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            message::encode,
+            message::encoded_len,
+            2,
+            &self.requests,
+            buf,
+        );
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => btree_map::merge(string::merge, message::merge, &mut self.limits, buf, ctx),
+            2 => btree_map::merge(string::merge, message::merge, &mut self.requests, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        btree_map::encoded_len(string::encoded_len, message::encoded_len, 1, &self.limits)
+            + btree_map::encoded_len(string::encoded_len, message::encoded_len, 2, &self.requests)
+    }
+
+    fn clear(&mut self) {
+        *self = VolumeResourceRequirements::default();
+    }
+}
+
+impl Message for TypedObjectReference {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.api_group {
+            string::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.kind {
+            string::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.name {
+            string::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.namespace {
+            string::encode(4, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, self.api_group.get_or_insert_default(), buf, ctx),
+            2 => string::merge(wire_type, self.kind.get_or_insert_default(), buf, ctx),
+            3 => string::merge(wire_type, self.name.get_or_insert_default(), buf, ctx),
+            4 => string::merge(wire_type, self.namespace.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.api_group
+            .as_ref()
+            .map_or(0, |v| string::encoded_len(1, v))
+            + self.kind.as_ref().map_or(0, |v| string::encoded_len(2, v))
+            + self.name.as_ref().map_or(0, |v| string::encoded_len(3, v))
+            + self
+                .namespace
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(4, v))
+    }
+
+    fn clear(&mut self) {
+        *self = TypedObjectReference::default();
+    }
+}
+
+// ============================================================================
+// LabelSelector
+// ============================================================================
+//
+// Affinity terms and topology spread constraints embed the internal selector
+// types, which share the meta/v1 LabelSelector wire format.
+
+impl Message for internal_selector::LabelSelector {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            string::encode,
+            string::encoded_len,
+            1,
+            &self.match_labels,
+            buf,
+        );
+        message::encode_repeated(2, &self.match_expressions, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => btree_map::merge(
+                string::merge,
+                string::merge,
+                &mut self.match_labels,
+                buf,
+                ctx,
+            ),
+            2 => message::merge_repeated(wire_type, &mut self.match_expressions, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        btree_map::encoded_len(
+            string::encoded_len,
+            string::encoded_len,
+            1,
+            &self.match_labels,
+        ) + message::encoded_len_repeated(2, &self.match_expressions)
+    }
+
+    fn clear(&mut self) {
+        *self = internal_selector::LabelSelector::default();
+    }
+}
+
+impl Message for internal_selector::LabelSelectorRequirement {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.key, buf);
+        string::encode(2, &self.operator, buf);
+        string::encode_repeated(3, &self.values, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.key, buf, ctx),
+            2 => string::merge(wire_type, &mut self.operator, buf, ctx),
+            3 => string::merge_repeated(wire_type, &mut self.values, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.key)
+            + string::encoded_len(2, &self.operator)
+            + string::encoded_len_repeated(3, &self.values)
+    }
+
+    fn clear(&mut self) {
+        *self = internal_selector::LabelSelectorRequirement::default();
+    }
+}
+
+// ============================================================================
+// Flattened LocalObjectReference
+// ============================================================================
+//
+// Key selectors and ConfigMap/Secret volume sources inline the referenced
+// name, while the wire format keeps it in an embedded LocalObjectReference.
+
+fn encode_local_object_reference<B>(tag: u32, name: &Option<String>, buf: &mut B)
+where
+    B: BufMut,
+{
+    if let Some(name) = name {
+        encode_key(tag, WireType::LengthDelimited, buf);
+        encode_varint(string::encoded_len(1, name) as u64, buf);
+        string::encode(1, name, buf);
+    }
+}
+
+fn merge_local_object_reference<B>(
+    wire_type: WireType,
+    name: &mut Option<String>,
+    buf: &mut B,
+    ctx: DecodeContext,
+) -> Result<(), DecodeError>
+where
+    B: Buf,
+{
+    let mut reference = LocalObjectReference { name: name.take() };
+    let result = message::merge(wire_type, &mut reference, buf, ctx);
+    *name = reference.name;
+    result
+}
+
+fn local_object_reference_encoded_len(tag: u32, name: &Option<String>) -> usize {
+    name.as_ref().map_or(0, |name| {
+        let len = string::encoded_len(1, name);
+        key_len(tag) + encoded_len_varint(len as u64) + len
+    })
+}
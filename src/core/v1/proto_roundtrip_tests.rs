@@ -0,0 +1,583 @@
+use super::ObjectReference;
+use super::ephemeral::EphemeralContainer;
+use super::pod::PodSpec;
+use super::selector::ConfigMapKeySelector;
+use super::volume::{Volume, VolumeSource};
+use prost::Message;
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+
+fn assert_proto_roundtrip<T>(value: serde_json::Value) -> T
+where
+    T: Message + Default + PartialEq + Debug + DeserializeOwned,
+{
+    let original: T = serde_json::from_value(value).expect("deserialize fixture");
+    let bytes = original.encode_to_vec();
+    assert_eq!(bytes.len(), original.encoded_len());
+
+    let decoded = T::decode(bytes.as_slice()).expect("decode message");
+    assert_eq!(decoded, original);
+    decoded
+}
+
+#[test]
+fn proto_roundtrip_object_reference() {
+    let reference = ObjectReference {
+        kind: Some("Pod".to_string()),
+        namespace: Some("default".to_string()),
+        name: Some("web-0".to_string()),
+        uid: Some("uid-a".to_string()),
+        api_version: Some("v1".to_string()),
+        field_path: Some("spec.containers{app}".to_string()),
+        ..Default::default()
+    };
+    let bytes = reference.encode_to_vec();
+    assert_eq!(bytes.len(), reference.encoded_len());
+
+    let decoded = ObjectReference::decode(bytes.as_slice()).expect("decode ObjectReference");
+    assert_eq!(decoded, reference);
+}
+
+#[test]
+fn proto_roundtrip_object_reference_empty() {
+    let reference = ObjectReference::default();
+    assert!(reference.encode_to_vec().is_empty());
+    assert_eq!(
+        ObjectReference::decode(&[][..]).expect("decode ObjectReference"),
+        reference
+    );
+}
+
+#[test]
+fn proto_roundtrip_pod_spec_ephemeral_container() {
+    let spec = PodSpec {
+        ephemeral_containers: vec![EphemeralContainer {
+            name: "debugger".to_string(),
+            image: "busybox".to_string(),
+            command: vec!["sh".to_string()],
+            target_container_name: "web".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let bytes = spec.encode_to_vec();
+    assert_eq!(bytes.len(), spec.encoded_len());
+
+    let decoded = PodSpec::decode(bytes.as_slice()).expect("decode PodSpec");
+    assert_eq!(decoded, spec);
+}
+
+#[test]
+fn proto_encode_key_selector_nests_local_object_reference() {
+    let selector = ConfigMapKeySelector {
+        name: Some("a".to_string()),
+        key: "k".to_string(),
+        optional: None,
+    };
+    let bytes = selector.encode_to_vec();
+    assert_eq!(bytes, [0x0a, 0x03, 0x0a, 0x01, b'a', 0x12, 0x01, b'k']);
+    assert_eq!(
+        ConfigMapKeySelector::decode(bytes.as_slice()).expect("decode ConfigMapKeySelector"),
+        selector
+    );
+}
+
+#[test]
+fn proto_roundtrip_pod_spec_scheduling_fields() {
+    assert_proto_roundtrip::<PodSpec>(serde_json::json!({
+        "restartPolicy": "Always",
+        "terminationGracePeriodSeconds": 30,
+        "activeDeadlineSeconds": 600,
+        "dnsPolicy": "ClusterFirst",
+        "nodeSelector": {"disktype": "ssd"},
+        "serviceAccountName": "builder",
+        "automountServiceAccountToken": false,
+        "nodeName": "node-1",
+        "hostNetwork": true,
+        "shareProcessNamespace": true,
+        "imagePullSecrets": [{"name": "registry"}],
+        "hostname": "web",
+        "subdomain": "svc",
+        "schedulerName": "default-scheduler",
+        "priorityClassName": "high",
+        "priority": 1000,
+        "dnsConfig": {
+            "nameservers": ["10.0.0.10"],
+            "searches": ["svc.cluster.local"],
+            "options": [{"name": "ndots", "value": "2"}]
+        },
+        "hostAliases": [{"ip": "10.0.0.1", "hostnames": ["gateway"]}],
+        "readinessGates": [{"conditionType": "example.com/ready"}],
+        "runtimeClassName": "gvisor",
+        "enableServiceLinks": false,
+        "preemptionPolicy": "Never",
+        "overhead": {"cpu": "250m"},
+        "os": {"name": "linux"},
+        "schedulingGates": [{"name": "example.com/gate"}],
+        "resourceClaims": [{"name": "gpu", "resourceClaimName": "gpu-claim"}]
+    }));
+}
+
+#[test]
+fn proto_roundtrip_container_fields() {
+    assert_proto_roundtrip::<PodSpec>(serde_json::json!({
+        "initContainers": [{
+            "name": "init",
+            "image": "busybox",
+            "restartPolicy": "Always",
+            "restartPolicyRules": [{
+                "action": "Restart",
+                "exitCodes": {"operator": "In", "values": [42]}
+            }]
+        }],
+        "containers": [{
+            "name": "web",
+            "image": "nginx:1.27",
+            "command": ["nginx"],
+            "args": ["-g", "daemon off;"],
+            "workingDir": "/srv",
+            "ports": [{
+                "name": "http",
+                "hostPort": 8080,
+                "containerPort": 80,
+                "protocol": "TCP",
+                "hostIP": "0.0.0.0"
+            }],
+            "resizePolicy": [{"resourceName": "cpu", "restartPolicy": "NotRequired"}],
+            "terminationMessagePath": "/dev/termination-log",
+            "terminationMessagePolicy": "File",
+            "imagePullPolicy": "IfNotPresent",
+            "stdin": true,
+            "stdinOnce": true,
+            "tty": true
+        }]
+    }));
+}
+
+#[test]
+fn proto_roundtrip_resource_requirements() {
+    assert_proto_roundtrip::<PodSpec>(serde_json::json!({
+        "containers": [{
+            "name": "web",
+            "resources": {
+                "limits": {"cpu": "500m", "memory": "128Mi"},
+                "requests": {"cpu": "250m", "memory": "64Mi"},
+                "claims": [{"name": "gpu", "request": "a100"}]
+            }
+        }]
+    }));
+}
+
+#[test]
+fn proto_roundtrip_environment() {
+    assert_proto_roundtrip::<PodSpec>(serde_json::json!({
+        "containers": [{
+            "name": "web",
+            "env": [
+                {"name": "MODE", "value": "production"},
+                {"name": "POD_NAME", "valueFrom": {
+                    "fieldRef": {"apiVersion": "v1", "fieldPath": "metadata.name"}
+                }},
+                {"name": "CPU_LIMIT", "valueFrom": {
+                    "resourceFieldRef": {"containerName": "web", "resource": "limits.cpu", "divisor": "1m"}
+                }},
+                {"name": "LEVEL", "valueFrom": {
+                    "configMapKeyRef": {"name": "settings", "key": "level", "optional": true}
+                }},
+                {"name": "TOKEN", "valueFrom": {
+                    "secretKeyRef": {"name": "credentials", "key": "token"}
+                }},
+                {"name": "FLAG", "valueFrom": {
+                    "fileKeyRef": {"volumeName": "config", "path": "flags.env", "key": "FLAG"}
+                }}
+            ],
+            "envFrom": [
+                {"prefix": "CM_", "configMapRef": {"name": "settings", "optional": false}},
+                {"secretRef": {"name": "credentials"}}
+            ]
+        }]
+    }));
+}
+
+#[test]
+fn proto_roundtrip_probes_and_lifecycle() {
+    assert_proto_roundtrip::<PodSpec>(serde_json::json!({
+        "containers": [{
+            "name": "web",
+            "livenessProbe": {
+                "httpGet": {
+                    "path": "/healthz",
+                    "port": 8080,
+                    "host": "localhost",
+                    "scheme": "HTTP",
+                    "httpHeaders": [{"name": "X-Probe", "value": "1"}]
+                },
+                "initialDelaySeconds": 5,
+                "timeoutSeconds": 2,
+                "periodSeconds": 10,
+                "successThreshold": 1,
+                "failureThreshold": 3,
+                "terminationGracePeriodSeconds": 15
+            },
+            "readinessProbe": {"tcpSocket": {"port": "http"}},
+            "startupProbe": {"grpc": {"port": 9090, "service": "health"}},
+            "lifecycle": {
+                "postStart": {"exec": {"command": ["touch", "/ready"]}},
+                "preStop": {"sleep": {"seconds": 5}},
+                "stopSignal": "SIGTERM"
+            }
+        }]
+    }));
+}
+
+#[test]
+fn proto_roundtrip_security_contexts() {
+    assert_proto_roundtrip::<PodSpec>(serde_json::json!({
+        "securityContext": {
+            "seLinuxOptions": {"user": "u", "role": "r", "type": "t", "level": "s0"},
+            "windowsOptions": {
+                "gmsaCredentialSpecName": "gmsa",
+                "runAsUserName": "ContainerUser",
+                "hostProcess": false
+            },
+            "runAsUser": 1000,
+            "runAsGroup": 3000,
+            "runAsNonRoot": true,
+            "supplementalGroups": [4000, 5000],
+            "supplementalGroupsPolicy": "Strict",
+            "fsGroup": 2000,
+            "fsGroupChangePolicy": "OnRootMismatch",
+            "sysctls": [{"name": "net.core.somaxconn", "value": "1024"}],
+            "seccompProfile": {"type": "RuntimeDefault"},
+            "appArmorProfile": {"type": "Localhost", "localhostProfile": "k8s-apparmor"},
+            "seLinuxChangePolicy": "MountOption"
+        },
+        "containers": [{
+            "name": "web",
+            "securityContext": {
+                "capabilities": {"add": ["NET_BIND_SERVICE"], "drop": ["ALL"]},
+                "privileged": false,
+                "runAsUser": 1000,
+                "allowPrivilegeEscalation": false,
+                "procMount": "Default",
+                "seccompProfile": {"type": "Localhost", "localhostProfile": "profiles/web.json"}
+            }
+        }]
+    }));
+}
+
+#[test]
+fn proto_roundtrip_affinity() {
+    let term = serde_json::json!({
+        "labelSelector": {
+            "matchLabels": {"app": "web"},
+            "matchExpressions": [{"key": "tier", "operator": "In", "values": ["frontend"]}]
+        },
+        "namespaces": ["default"],
+        "topologyKey": "kubernetes.io/hostname",
+        "namespaceSelector": {"matchLabels": {"team": "a"}},
+        "matchLabelKeys": ["pod-template-hash"],
+        "mismatchLabelKeys": ["tenant"]
+    });
+    assert_proto_roundtrip::<PodSpec>(serde_json::json!({
+        "affinity": {
+            "nodeAffinity": {
+                "requiredDuringSchedulingIgnoredDuringExecution": {
+                    "nodeSelectorTerms": [{
+                        "matchExpressions": [{"key": "zone", "operator": "In", "values": ["a", "b"]}],
+                        "matchFields": [{"key": "metadata.name", "operator": "NotIn", "values": ["node-9"]}]
+                    }]
+                },
+                "preferredDuringSchedulingIgnoredDuringExecution": [{
+                    "weight": 10,
+                    "preference": {"matchExpressions": [{"key": "ssd", "operator": "Exists"}]}
+                }]
+            },
+            "podAffinity": {
+                "requiredDuringSchedulingIgnoredDuringExecution": [term.clone()]
+            },
+            "podAntiAffinity": {
+                "preferredDuringSchedulingIgnoredDuringExecution": [{
+                    "weight": 50,
+                    "podAffinityTerm": term
+                }]
+            }
+        }
+    }));
+}
+
+#[test]
+fn proto_roundtrip_tolerations_and_topology_spread() {
+    assert_proto_roundtrip::<PodSpec>(serde_json::json!({
+        "tolerations": [{
+            "key": "dedicated",
+            "operator": "Equal",
+            "value": "gpu",
+            "effect": "NoExecute",
+            "tolerationSeconds": 300
+        }],
+        "topologySpreadConstraints": [{
+            "maxSkew": 1,
+            "topologyKey": "topology.kubernetes.io/zone",
+            "whenUnsatisfiable": "DoNotSchedule",
+            "labelSelector": {"matchLabels": {"app": "web"}},
+            "minDomains": 2,
+            "nodeAffinityPolicy": "Honor",
+            "nodeTaintsPolicy": "Ignore",
+            "matchLabelKeys": ["pod-template-hash"]
+        }]
+    }));
+}
+
+#[test]
+fn proto_roundtrip_volume_mounts_and_devices() {
+    assert_proto_roundtrip::<PodSpec>(serde_json::json!({
+        "containers": [{
+            "name": "web",
+            "volumeMounts": [{
+                "name": "data",
+                "readOnly": true,
+                "recursiveReadOnly": "Enabled",
+                "mountPath": "/data",
+                "subPath": "web",
+                "mountPropagation": "HostToContainer"
+            }, {
+                "name": "logs",
+                "mountPath": "/logs",
+                "subPathExpr": "$(POD_NAME)"
+            }],
+            "volumeDevices": [{"name": "raw", "devicePath": "/dev/xvda"}]
+        }]
+    }));
+}
+
+#[test]
+fn proto_roundtrip_basic_volume_sources() {
+    assert_proto_roundtrip::<PodSpec>(serde_json::json!({
+        "volumes": [
+            {"name": "host", "hostPath": {"path": "/var/log", "type": "Directory"}},
+            {"name": "scratch", "emptyDir": {"medium": "Memory", "sizeLimit": "1Gi"}},
+            {"name": "secret", "secret": {
+                "secretName": "tls",
+                "items": [{"key": "tls.crt", "path": "cert.pem", "mode": 256}],
+                "defaultMode": 420,
+                "optional": true
+            }},
+            {"name": "nfs", "nfs": {"server": "nfs.local", "path": "/exports", "readOnly": true}},
+            {"name": "iscsi", "iscsi": {
+                "targetPortal": "10.0.0.2:3260",
+                "iqn": "iqn.2001-04.com.example:storage",
+                "lun": 0,
+                "iscsiInterface": "default",
+                "fsType": "ext4",
+                "portals": ["10.0.0.3:3260"],
+                "chapAuthDiscovery": true,
+                "secretRef": {"name": "chap"}
+            }},
+            {"name": "gluster", "glusterfs": {"endpoints": "glusterfs-cluster", "path": "vol", "readOnly": true}},
+            {"name": "claim", "persistentVolumeClaim": {"claimName": "data", "readOnly": true}},
+            {"name": "downward", "downwardAPI": {
+                "items": [{
+                    "path": "labels",
+                    "fieldRef": {"fieldPath": "metadata.labels"},
+                    "mode": 292
+                }, {
+                    "path": "cpu",
+                    "resourceFieldRef": {"containerName": "web", "resource": "requests.cpu"}
+                }],
+                "defaultMode": 420
+            }},
+            {"name": "config", "configMap": {
+                "name": "settings",
+                "items": [{"key": "app.yaml", "path": "app.yaml"}],
+                "defaultMode": 420,
+                "optional": false
+            }},
+            {"name": "csi", "csi": {
+                "driver": "csi.example.com",
+                "readOnly": true,
+                "fsType": "ext4",
+                "volumeAttributes": {"size": "small"},
+                "nodePublishSecretRef": {"name": "csi-secret"}
+            }},
+            {"name": "image", "image": {"reference": "registry.example.com/data:v1", "pullPolicy": "IfNotPresent"}}
+        ]
+    }));
+}
+
+#[test]
+fn proto_roundtrip_projected_volume_source() {
+    assert_proto_roundtrip::<PodSpec>(serde_json::json!({
+        "volumes": [{
+            "name": "projected",
+            "projected": {
+                "defaultMode": 420,
+                "sources": [
+                    {"secret": {"name": "tls", "items": [{"key": "ca.crt", "path": "ca.crt"}], "optional": true}},
+                    {"configMap": {"name": "settings"}},
+                    {"downwardAPI": {"items": [{"path": "name", "fieldRef": {"fieldPath": "metadata.name"}}]}},
+                    {"serviceAccountToken": {"audience": "vault", "expirationSeconds": 3600, "path": "token"}},
+                    {"clusterTrustBundle": {
+                        "signerName": "example.com/signer",
+                        "labelSelector": {"matchLabels": {"trust": "root"}},
+                        "optional": true,
+                        "path": "bundle.pem"
+                    }},
+                    {"podCertificate": {
+                        "signerName": "example.com/signer",
+                        "keyType": "ED25519",
+                        "maxExpirationSeconds": 86400,
+                        "credentialBundlePath": "credentials.pem"
+                    }}
+                ]
+            }
+        }]
+    }));
+}
+
+#[test]
+fn proto_roundtrip_ephemeral_volume_claim_template() {
+    assert_proto_roundtrip::<PodSpec>(serde_json::json!({
+        "volumes": [{
+            "name": "scratch",
+            "ephemeral": {
+                "volumeClaimTemplate": {
+                    "metadata": {"labels": {"type": "scratch"}},
+                    "spec": {
+                        "accessModes": ["ReadWriteOnce"],
+                        "selector": {"matchLabels": {"tier": "fast"}},
+                        "resources": {
+                            "requests": {"storage": "1Gi"},
+                            "limits": {"storage": "2Gi"}
+                        },
+                        "volumeName": "pv-1",
+                        "storageClassName": "fast",
+                        "volumeMode": "Filesystem",
+                        "dataSource": {"apiGroup": "snapshot.storage.k8s.io", "kind": "VolumeSnapshot", "name": "snap"},
+                        "dataSourceRef": {
+                            "apiGroup": "snapshot.storage.k8s.io",
+                            "kind": "VolumeSnapshot",
+                            "name": "snap",
+                            "namespace": "default"
+                        },
+                        "volumeAttributesClassName": "gold"
+                    }
+                }
+            }
+        }]
+    }));
+}
+
+#[test]
+fn proto_roundtrip_legacy_volume_sources() {
+    let secret_ref = serde_json::json!({"name": "secret"});
+    let spec = assert_proto_roundtrip::<PodSpec>(serde_json::json!({
+        "volumes": [
+            {"name": "gce", "gcePersistentDisk": {"pdName": "disk-1", "fsType": "ext4", "partition": 1, "readOnly": true}},
+            {"name": "aws", "awsElasticBlockStore": {"volumeID": "vol-1", "fsType": "ext4", "partition": 2, "readOnly": false}},
+            {"name": "git", "gitRepo": {"repository": "https://example.com/repo.git", "revision": "main", "directory": "."}},
+            {"name": "rbd", "rbd": {
+                "monitors": ["10.0.0.1:6789", "10.0.0.2:6789"],
+                "image": "foo",
+                "fsType": "ext4",
+                "pool": "kube",
+                "user": "admin",
+                "keyring": "/etc/ceph/keyring",
+                "secretRef": secret_ref,
+                "readOnly": true
+            }},
+            {"name": "flex", "flexVolume": {
+                "driver": "example/lvm",
+                "fsType": "ext4",
+                "secretRef": secret_ref,
+                "readOnly": true,
+                "options": {"size": "1G", "volumegroup": "vg0"}
+            }},
+            {"name": "cinder", "cinder": {"volumeID": "c-1", "fsType": "ext4", "readOnly": true, "secretRef": secret_ref}},
+            {"name": "cephfs", "cephfs": {
+                "monitors": ["10.0.0.1:6789"],
+                "path": "/",
+                "user": "admin",
+                "secretFile": "/etc/ceph/secret",
+                "secretRef": secret_ref,
+                "readOnly": false
+            }},
+            {"name": "flocker", "flocker": {"datasetName": "data", "datasetUUID": "uuid-1"}},
+            {"name": "fc", "fc": {"targetWWNs": ["500a0982991b8dc5"], "lun": 2, "fsType": "ext4", "readOnly": true, "wwids": ["3600508b400105e21"]}},
+            {"name": "azure-file", "azureFile": {"secretName": "azure", "shareName": "share", "readOnly": true}},
+            {"name": "vsphere", "vsphereVolume": {
+                "volumePath": "[datastore1] volumes/myDisk",
+                "fsType": "ext4",
+                "storagePolicyName": "gold",
+                "storagePolicyID": "policy-1"
+            }},
+            {"name": "quobyte", "quobyte": {
+                "registry": "registry:7861",
+                "volume": "vol",
+                "readOnly": true,
+                "user": "root",
+                "group": "root",
+                "tenant": "tenant"
+            }},
+            {"name": "azure-disk", "azureDisk": {
+                "diskName": "disk",
+                "diskURI": "https://example.blob.core.windows.net/vhds/disk.vhd",
+                "cachingMode": "ReadOnly",
+                "fsType": "ext4",
+                "readOnly": true,
+                "kind": "Managed"
+            }},
+            {"name": "photon", "photonPersistentDisk": {"pdID": "pd-1", "fsType": "ext4"}},
+            {"name": "portworx", "portworxVolume": {"volumeID": "px-1", "fsType": "ext4", "readOnly": true}},
+            {"name": "scaleio", "scaleIO": {
+                "gateway": "https://gateway:443/api",
+                "system": "scaleio",
+                "secretRef": secret_ref,
+                "sslEnabled": true,
+                "protectionDomain": "pd",
+                "storagePool": "sp",
+                "storageMode": "ThinProvisioned",
+                "volumeName": "vol",
+                "fsType": "xfs",
+                "readOnly": false
+            }},
+            {"name": "storageos", "storageos": {
+                "volumeName": "vol",
+                "volumeNamespace": "default",
+                "fsType": "ext4",
+                "readOnly": true,
+                "secretRef": secret_ref
+            }}
+        ]
+    }));
+    assert_eq!(spec.volumes.len(), 17);
+}
+
+#[test]
+fn proto_encode_legacy_volume_source_follows_upstream_fields() {
+    let volume = Volume {
+        name: "d".to_string(),
+        volume_source: VolumeSource {
+            gce_persistent_disk: Some(serde_json::json!({
+                "pdName": "a",
+                "partition": 1,
+                "readOnly": true,
+                "unknown": "dropped"
+            })),
+            ..Default::default()
+        },
+    };
+    let bytes = volume.encode_to_vec();
+    assert_eq!(
+        bytes,
+        [
+            0x0a, 0x01, b'd', 0x12, 0x09, 0x1a, 0x07, 0x0a, 0x01, b'a', 0x18, 0x01, 0x20, 0x01
+        ]
+    );
+
+    let decoded = Volume::decode(bytes.as_slice()).expect("decode Volume");
+    assert_eq!(
+        decoded.volume_source.gce_persistent_disk,
+        Some(serde_json::json!({"pdName": "a", "partition": 1, "readOnly": true}))
+    );
+}
@@ -133,7 +133,7 @@ pub struct ReplicationControllerList {
     pub metadata: Option<ListMeta>,
 
     /// List of replication controllers.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ReplicationController>,
 }
 
@@ -106,7 +106,7 @@ pub struct LimitRangeList {
     pub metadata: Option<ListMeta>,
 
     /// Items is a list of LimitRange objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<LimitRange>,
 }
 
@@ -180,11 +180,99 @@ pub mod resource_name {
 
     /// Name prefix for storage resource limits
     pub const ATTACHABLE_VOLUMES_PREFIX: &str = "attachable-volumes-";
+
+    /// Name prefix for resource requests in quota
+    pub const DEFAULT_REQUESTS_PREFIX: &str = "requests.";
+
+    /// Pods, number
+    pub const PODS: &str = "pods";
+
+    /// CPU request, in cores. (500m = .5 cores)
+    pub const REQUESTS_CPU: &str = "requests.cpu";
+
+    /// Memory request, in bytes. (500Gi = 500GiB = 500 * 1024 * 1024 * 1024)
+    pub const REQUESTS_MEMORY: &str = "requests.memory";
+
+    /// Local ephemeral storage request, in bytes
+    pub const REQUESTS_EPHEMERAL_STORAGE: &str = "requests.ephemeral-storage";
+
+    /// CPU limit, in cores. (500m = .5 cores)
+    pub const LIMITS_CPU: &str = "limits.cpu";
+
+    /// Memory limit, in bytes. (500Gi = 500GiB = 500 * 1024 * 1024 * 1024)
+    pub const LIMITS_MEMORY: &str = "limits.memory";
+
+    /// Local ephemeral storage limit, in bytes
+    pub const LIMITS_EPHEMERAL_STORAGE: &str = "limits.ephemeral-storage";
+}
+
+/// Returns true for resources managed outside Kubernetes, such as `nvidia.com/gpu`.
+///
+/// Mirrors `IsExtendedResourceName`: the name must be fully qualified outside
+/// the `kubernetes.io` domain and must not use the quota `requests.` prefix.
+pub fn is_extended_resource_name(name: &str) -> bool {
+    let native = !name.contains('/') || name.contains(resource_name::DEFAULT_NAMESPACE_PREFIX);
+    if native || name.starts_with(resource_name::DEFAULT_REQUESTS_PREFIX) {
+        return false;
+    }
+    let name_for_quota = format!("{}{}", resource_name::DEFAULT_REQUESTS_PREFIX, name);
+    crate::common::validation::is_qualified_name(&name_for_quota).is_empty()
 }
 
 /// ResourceList is a set of (resource name, quantity) pairs.
 pub type ResourceList = BTreeMap<ResourceName, Quantity>;
 
+/// Stable representations of a [`ResourceList`] for persisting and hashing.
+pub trait ResourceListExt {
+    /// Returns a sorted copy with every quantity in canonical form.
+    fn canonicalized(&self) -> BTreeMap<String, Quantity>;
+
+    /// Returns a sorted `name=quantity` list with canonical quantities.
+    ///
+    /// Equal resource lists always produce the same string, so it can be fed
+    /// directly into a hasher.
+    fn canonical_string(&self) -> String;
+
+    /// Returns the entries for standard resources, such as `cpu`, `memory`,
+    /// `ephemeral-storage` and `hugepages-*`.
+    ///
+    /// Together with [`ResourceListExt::extended`] this partitions the list.
+    fn standard(&self) -> ResourceList;
+
+    /// Returns the entries for extended resources (see [`is_extended_resource_name`]).
+    fn extended(&self) -> ResourceList;
+}
+
+impl ResourceListExt for ResourceList {
+    fn canonicalized(&self) -> BTreeMap<String, Quantity> {
+        self.iter()
+            .map(|(name, quantity)| (name.clone(), quantity.canonicalized()))
+            .collect()
+    }
+
+    fn canonical_string(&self) -> String {
+        self.canonicalized()
+            .iter()
+            .map(|(name, quantity)| format!("{}={}", name, quantity))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn standard(&self) -> ResourceList {
+        self.iter()
+            .filter(|(name, _)| !is_extended_resource_name(name))
+            .map(|(name, quantity)| (name.clone(), quantity.clone()))
+            .collect()
+    }
+
+    fn extended(&self) -> ResourceList {
+        self.iter()
+            .filter(|(name, _)| is_extended_resource_name(name))
+            .map(|(name, quantity)| (name.clone(), quantity.clone()))
+            .collect()
+    }
+}
+
 /// ScopedResourceSelectorRequirement represents a scope selector requirement.
 ///
 /// Corresponds to [Kubernetes ScopedResourceSelectorRequirement](https://github.com/kubernetes/api/blob/master/core/v1/types.go#L7813)
@@ -287,7 +375,7 @@ pub struct ResourceQuotaList {
     pub metadata: Option<ListMeta>,
 
     /// Items is a list of ResourceQuota objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ResourceQuota>,
 }
 
@@ -334,6 +422,54 @@ pub struct ResourceRequirements {
 mod tests {
     use super::*;
 
+    #[test]
+    fn resource_list_canonicalized_is_sorted_and_normalized() {
+        let resources: ResourceList = BTreeMap::from([
+            ("memory".to_string(), Quantity::from("1024Mi")),
+            ("cpu".to_string(), Quantity::from("1000m")),
+        ]);
+
+        let canonical = resources.canonicalized();
+        let entries: Vec<(&str, &str)> = canonical
+            .iter()
+            .map(|(name, quantity)| (name.as_str(), quantity.as_str()))
+            .collect();
+        assert_eq!(entries, vec![("cpu", "1"), ("memory", "1Gi")]);
+        assert_eq!(resources.canonical_string(), "cpu=1,memory=1Gi");
+
+        let equivalent: ResourceList = BTreeMap::from([
+            ("cpu".to_string(), Quantity::from("1")),
+            ("memory".to_string(), Quantity::from("1Gi")),
+        ]);
+        assert_eq!(equivalent.canonicalized(), canonical);
+    }
+
+    #[test]
+    fn resource_list_partitions_standard_and_extended() {
+        let resources: ResourceList = BTreeMap::from([
+            ("cpu".to_string(), Quantity::from("500m")),
+            ("memory".to_string(), Quantity::from("1Gi")),
+            ("nvidia.com/gpu".to_string(), Quantity::from("2")),
+        ]);
+
+        let standard = resources.standard();
+        assert_eq!(standard.keys().collect::<Vec<_>>(), vec!["cpu", "memory"]);
+        let extended = resources.extended();
+        assert_eq!(extended.keys().collect::<Vec<_>>(), vec!["nvidia.com/gpu"]);
+        assert_eq!(extended["nvidia.com/gpu"].as_str(), "2");
+    }
+
+    #[test]
+    fn is_extended_resource_name_cases() {
+        assert!(is_extended_resource_name("nvidia.com/gpu"));
+        assert!(is_extended_resource_name("example.com/dongle"));
+        assert!(!is_extended_resource_name("cpu"));
+        assert!(!is_extended_resource_name("hugepages-2Mi"));
+        assert!(!is_extended_resource_name("kubernetes.io/batch"));
+        assert!(!is_extended_resource_name("requests.nvidia.com/gpu"));
+        assert!(!is_extended_resource_name("example.com/-bad"));
+    }
+
     #[test]
     fn limit_range_item_defaults_for_container() {
         let mut item = LimitRangeItem {
@@ -0,0 +1,332 @@
+//! Pod resource accounting helpers
+//!
+//! Based on k8s.io/component-helpers/resource/helpers.go
+
+use crate::common::Quantity;
+use crate::core::internal::container_restart_policy;
+use crate::core::v1::node::Node;
+use crate::core::v1::pod::{Pod, PodSpec, pod_phase};
+use crate::core::v1::resource::{ResourceList, ResourceRequirements};
+
+impl PodSpec {
+    /// Returns the resources the scheduler reserves for this pod.
+    ///
+    /// Mirrors `PodRequests`: regular containers are summed, each regular init
+    /// container is compared against that sum (together with the sidecars started
+    /// before it), restartable init containers count for the whole pod lifetime,
+    /// pod-level requests take precedence, and `overhead` is added on top.
+    ///
+    /// Fails if a quantity cannot be parsed, or if two quantities of the same
+    /// resource cannot be added or compared.
+    pub fn effective_requests(&self) -> Result<ResourceList, String> {
+        let mut requests = self.aggregate_container_resources(|resources| &resources.requests)?;
+
+        if let Some(pod_resources) = &self.resources {
+            for (name, quantity) in &pod_resources.requests {
+                requests.insert(name.clone(), validated(quantity)?);
+            }
+        }
+
+        add_resource_list(&mut requests, &self.overhead)?;
+        Ok(requests)
+    }
+
+    /// Returns the effective limits of this pod.
+    ///
+    /// Mirrors `PodLimits`: containers are aggregated as in
+    /// [`PodSpec::effective_requests`], pod-level limits take precedence, and
+    /// `overhead` is only added to resources that already have a limit.
+    pub fn effective_limits(&self) -> Result<ResourceList, String> {
+        let mut limits = self.aggregate_container_resources(|resources| &resources.limits)?;
+
+        if let Some(pod_resources) = &self.resources {
+            for (name, quantity) in &pod_resources.limits {
+                limits.insert(name.clone(), validated(quantity)?);
+            }
+        }
+
+        for (name, overhead) in &self.overhead {
+            if let Some(limit) = limits.get(name) {
+                let sum = limit.add(overhead)?;
+                limits.insert(name.clone(), sum);
+            }
+        }
+        Ok(limits)
+    }
+
+    fn aggregate_container_resources(
+        &self,
+        select: impl Fn(&ResourceRequirements) -> &ResourceList,
+    ) -> Result<ResourceList, String> {
+        let mut total = ResourceList::new();
+        for container in &self.containers {
+            if let Some(resources) = &container.resources {
+                add_resource_list(&mut total, select(resources))?;
+            }
+        }
+
+        let mut sidecar_total = ResourceList::new();
+        let mut init_total = ResourceList::new();
+        for container in &self.init_containers {
+            let container_resources = container
+                .resources
+                .as_ref()
+                .map(|resources| select(resources).clone())
+                .unwrap_or_default();
+            if container.restart_policy.as_deref() == Some(container_restart_policy::ALWAYS) {
+                add_resource_list(&mut total, &container_resources)?;
+                add_resource_list(&mut sidecar_total, &container_resources)?;
+                continue;
+            }
+            let mut running = sidecar_total.clone();
+            add_resource_list(&mut running, &container_resources)?;
+            max_resource_list(&mut init_total, &running)?;
+        }
+        max_resource_list(&mut total, &init_total)?;
+        Ok(total)
+    }
+}
+
+/// Sums the effective requests of every pod that still holds node resources.
+///
+/// Pods in a terminal phase (`Succeeded` or `Failed`) are skipped. Fails if a
+/// quantity cannot be parsed or added.
+pub fn sum_pod_requests(pods: &[Pod]) -> Result<ResourceList, String> {
+    let mut total = ResourceList::new();
+    for pod in pods {
+        let terminated = pod
+            .status
+            .as_ref()
+            .and_then(|status| status.phase.as_deref())
+            .is_some_and(|phase| phase == pod_phase::SUCCEEDED || phase == pod_phase::FAILED);
+        if terminated {
+            continue;
+        }
+        if let Some(spec) = &pod.spec {
+            add_resource_list(&mut total, &spec.effective_requests()?)?;
+        }
+    }
+    Ok(total)
+}
+
+/// Returns the node's allocatable resources minus the requests of `pods`.
+///
+/// Only resources reported in `status.allocatable` are returned; a resource that
+/// is overcommitted is clamped to zero. Fails if a quantity cannot be parsed.
+pub fn node_allocatable_remaining(node: &Node, pods: &[Pod]) -> Result<ResourceList, String> {
+    let requested = sum_pod_requests(pods)?;
+    let Some(status) = &node.status else {
+        return Ok(ResourceList::new());
+    };
+    status
+        .allocatable
+        .iter()
+        .map(|(name, allocatable)| {
+            let remaining = match requested.get(name) {
+                Some(used) if allocatable.cmp(used)?.is_lt() => Quantity::from("0"),
+                Some(used) => allocatable.sub(used)?,
+                None => validated(allocatable)?,
+            };
+            Ok((name.clone(), remaining))
+        })
+        .collect()
+}
+
+fn add_resource_list(into: &mut ResourceList, other: &ResourceList) -> Result<(), String> {
+    for (name, quantity) in other {
+        let sum = match into.get(name) {
+            Some(current) => current.add(quantity)?,
+            None => validated(quantity)?,
+        };
+        into.insert(name.clone(), sum);
+    }
+    Ok(())
+}
+
+fn max_resource_list(into: &mut ResourceList, other: &ResourceList) -> Result<(), String> {
+    for (name, quantity) in other {
+        let replace = match into.get(name) {
+            Some(current) => current.cmp(quantity)?.is_lt(),
+            None => true,
+        };
+        if replace {
+            into.insert(name.clone(), validated(quantity)?);
+        }
+    }
+    Ok(())
+}
+
+/// Returns a copy of `quantity`, or the parse error if it is malformed.
+fn validated(quantity: &Quantity) -> Result<Quantity, String> {
+    Quantity::from_str_validated(quantity.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::v1::node::NodeStatus;
+    use crate::core::v1::pod::{Container, PodStatus};
+
+    fn resources(cpu: &str, memory: &str) -> ResourceList {
+        ResourceList::from([
+            ("cpu".to_string(), Quantity::from(cpu)),
+            ("memory".to_string(), Quantity::from(memory)),
+        ])
+    }
+
+    fn container(name: &str, cpu: &str, memory: &str) -> Container {
+        Container {
+            name: name.to_string(),
+            resources: Some(ResourceRequirements {
+                requests: resources(cpu, memory),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn pod(phase: &str, spec: PodSpec) -> Pod {
+        Pod {
+            spec: Some(spec),
+            status: Some(PodStatus {
+                phase: Some(phase.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn canonical(list: &ResourceList) -> Vec<(String, String)> {
+        list.iter()
+            .map(|(name, quantity)| (name.clone(), quantity.canonicalized().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_effective_requests_init_containers_and_overhead() {
+        let mut sidecar = container("proxy", "100m", "64Mi");
+        sidecar.restart_policy = Some(container_restart_policy::ALWAYS.to_string());
+        let spec = PodSpec {
+            containers: vec![container("app", "200m", "128Mi")],
+            init_containers: vec![sidecar, container("migrate", "1", "64Mi")],
+            overhead: resources("50m", "32Mi"),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            canonical(&spec.effective_requests().unwrap()),
+            vec![
+                ("cpu".to_string(), "1150m".to_string()),
+                ("memory".to_string(), "224Mi".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sum_pod_requests_skips_terminated_pods() {
+        let running = |cpu: &str, memory: &str| {
+            pod(
+                pod_phase::RUNNING,
+                PodSpec {
+                    containers: vec![container("app", cpu, memory)],
+                    overhead: resources("10m", "16Mi"),
+                    ..Default::default()
+                },
+            )
+        };
+        let succeeded = pod(
+            pod_phase::SUCCEEDED,
+            PodSpec {
+                containers: vec![container("job", "4", "4Gi")],
+                ..Default::default()
+            },
+        );
+
+        let pods = vec![
+            running("250m", "256Mi"),
+            running("500m", "512Mi"),
+            succeeded,
+        ];
+        assert_eq!(
+            canonical(&sum_pod_requests(&pods).unwrap()),
+            vec![
+                ("cpu".to_string(), "770m".to_string()),
+                ("memory".to_string(), "800Mi".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_node_allocatable_remaining() {
+        let node = Node {
+            status: Some(NodeStatus {
+                allocatable: ResourceList::from([
+                    ("cpu".to_string(), Quantity::from("2")),
+                    ("memory".to_string(), Quantity::from("1Gi")),
+                    ("pods".to_string(), Quantity::from("110")),
+                ]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let pods = vec![
+            pod(
+                pod_phase::RUNNING,
+                PodSpec {
+                    containers: vec![container("app", "500m", "256Mi")],
+                    ..Default::default()
+                },
+            ),
+            pod(
+                pod_phase::PENDING,
+                PodSpec {
+                    containers: vec![container("app", "3", "256Mi")],
+                    ..Default::default()
+                },
+            ),
+        ];
+
+        assert_eq!(
+            canonical(&node_allocatable_remaining(&node, &pods).unwrap()),
+            vec![
+                ("cpu".to_string(), "0".to_string()),
+                ("memory".to_string(), "512Mi".to_string()),
+                ("pods".to_string(), "110".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resource_totals_report_invalid_quantities() {
+        let spec = PodSpec {
+            containers: vec![
+                container("app", "250m", "128Mi"),
+                container("bad", "x", "1Mi"),
+            ],
+            ..Default::default()
+        };
+        assert!(spec.effective_requests().is_err());
+
+        let pods = vec![pod(pod_phase::RUNNING, spec)];
+        assert!(sum_pod_requests(&pods).is_err());
+        assert!(node_allocatable_remaining(&Node::default(), &pods).is_err());
+    }
+
+    #[test]
+    fn test_resource_totals_report_invalid_quantity_on_unique_resource() {
+        let mut bad = container("bad", "250m", "128Mi");
+        if let Some(resources) = bad.resources.as_mut() {
+            resources
+                .requests
+                .insert("example.com/gpu".to_string(), Quantity::from("two"));
+        }
+        let spec = PodSpec {
+            containers: vec![container("app", "250m", "128Mi"), bad],
+            ..Default::default()
+        };
+        assert!(spec.effective_requests().is_err());
+
+        let pods = vec![pod(pod_phase::RUNNING, spec)];
+        assert!(sum_pod_requests(&pods).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+//! ResourceList comparison helpers
+
+use std::collections::BTreeMap;
+
+use crate::common::Quantity;
+use crate::core::v1::resource::{ResourceList, ResourceName};
+
+/// The difference between two resource lists, keyed by resource name.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct ResourceListDiff {
+    /// Resources present only in the new list.
+    pub added: ResourceList,
+
+    /// Resources present only in the old list.
+    pub removed: ResourceList,
+
+    /// Resources present in both lists with different amounts, as `(old, new)`.
+    pub changed: BTreeMap<ResourceName, (Quantity, Quantity)>,
+}
+
+impl ResourceListDiff {
+    /// Returns true when both lists hold the same amounts.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Computes which resources were added, removed or changed between `old` and `new`.
+///
+/// Quantities are compared by value, so `1024Mi` and `1Gi` are equal. Quantities
+/// that fail to parse are compared as strings.
+pub fn diff(old: &ResourceList, new: &ResourceList) -> ResourceListDiff {
+    let mut result = ResourceListDiff::default();
+
+    for (name, old_quantity) in old {
+        match new.get(name) {
+            None => {
+                result.removed.insert(name.clone(), old_quantity.clone());
+            }
+            Some(new_quantity) if !same_amount(old_quantity, new_quantity) => {
+                result
+                    .changed
+                    .insert(name.clone(), (old_quantity.clone(), new_quantity.clone()));
+            }
+            Some(_) => {}
+        }
+    }
+
+    for (name, new_quantity) in new {
+        if !old.contains_key(name) {
+            result.added.insert(name.clone(), new_quantity.clone());
+        }
+    }
+
+    result
+}
+
+fn same_amount(a: &Quantity, b: &Quantity) -> bool {
+    match a.cmp(b) {
+        Ok(ordering) => ordering.is_eq(),
+        Err(_) => a == b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::v1::resource_name;
+
+    fn resources(entries: &[(&str, &str)]) -> ResourceList {
+        entries
+            .iter()
+            .map(|(name, quantity)| (name.to_string(), Quantity::from(*quantity)))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_added_and_removed() {
+        let old = resources(&[(resource_name::CPU, "500m"), ("nvidia.com/gpu", "1")]);
+        let new = resources(&[(resource_name::CPU, "1"), (resource_name::STORAGE, "10Gi")]);
+
+        let diff = diff(&old, &new);
+        assert_eq!(diff.added, resources(&[(resource_name::STORAGE, "10Gi")]));
+        assert_eq!(diff.removed, resources(&[("nvidia.com/gpu", "1")]));
+        assert_eq!(
+            diff.changed.get(resource_name::CPU),
+            Some(&(Quantity::from("500m"), Quantity::from("1")))
+        );
+    }
+
+    #[test]
+    fn test_diff_equal_value_different_format() {
+        let old = resources(&[(resource_name::MEMORY, "1024Mi"), (resource_name::CPU, "1")]);
+        let new = resources(&[
+            (resource_name::MEMORY, "1Gi"),
+            (resource_name::CPU, "1000m"),
+        ]);
+
+        let diff = diff(&old, &new);
+        assert!(diff.is_empty(), "unexpected diff: {:?}", diff);
+    }
+}
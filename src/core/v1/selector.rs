@@ -5,6 +5,7 @@
 
 use crate::common::ApplyDefault;
 use crate::common::util::Quantity;
+use crate::common::validation::{BadValue, Error, Path, invalid};
 use serde::{Deserialize, Serialize};
 
 /// ObjectFieldSelector selects a field from a pod object.
@@ -53,6 +54,7 @@ pub mod object_field_path {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::validation::ErrorType;
 
     #[test]
     fn test_object_field_selector_default_api_version() {
@@ -65,6 +67,51 @@ mod tests {
 
         assert_eq!(selector.api_version, object_field_selector_api_version::V1);
     }
+
+    #[test]
+    fn test_resource_field_selector_resolve_divisor() {
+        let mut selector = ResourceFieldSelector {
+            container_name: "app".to_string(),
+            resource: resource_field_selector_resource::LIMITS_MEMORY.to_string(),
+            divisor: Some(Quantity::from("1Mi")),
+        };
+        assert_eq!(selector.resolve(&Quantity::from("512Mi")).unwrap(), "512");
+        assert_eq!(selector.resolve(&Quantity::from("1Gi")).unwrap(), "1024");
+
+        selector.resource = resource_field_selector_resource::REQUESTS_CPU.to_string();
+        selector.divisor = Some(Quantity::from("1m"));
+        assert_eq!(selector.resolve(&Quantity::from("250m")).unwrap(), "250");
+
+        // Partial units round up; no divisor reports whole units.
+        selector.divisor = None;
+        assert_eq!(selector.resolve(&Quantity::from("250m")).unwrap(), "1");
+
+        selector.divisor = Some(Quantity::from("0"));
+        let err = selector.resolve(&Quantity::from("250m")).unwrap_err();
+        assert_eq!(err.error_type, ErrorType::Invalid);
+        assert_eq!(err.field, "divisor");
+
+        selector.divisor = Some(Quantity::from("1m"));
+        let err = selector.resolve(&Quantity::from("lots")).unwrap_err();
+        assert_eq!(err.field, "resource");
+    }
+
+    #[test]
+    fn test_resource_field_selector_resolve_large_byte_value() {
+        let selector = ResourceFieldSelector {
+            container_name: "app".to_string(),
+            resource: resource_field_selector_resource::LIMITS_MEMORY.to_string(),
+            divisor: Some(Quantity::from("1")),
+        };
+        assert_eq!(
+            selector.resolve(&Quantity::from("999999999999")).unwrap(),
+            "999999999999"
+        );
+        assert_eq!(
+            selector.resolve(&Quantity::from("1234567890123")).unwrap(),
+            "1234567890123"
+        );
+    }
 }
 
 /// ResourceFieldSelector represents container resources (cpu, memory) and their output format.
@@ -85,6 +132,43 @@ pub struct ResourceFieldSelector {
     pub divisor: Option<Quantity>,
 }
 
+impl ResourceFieldSelector {
+    /// Formats `available` in units of the divisor, as exposed through the
+    /// downward API. An unset divisor means "1".
+    ///
+    /// The quotient is rounded up, not down: upstream
+    /// `ExtractResourceValueByContainerNameAndResource` takes the ceiling, so
+    /// 250m of CPU with the default divisor reports `1` rather than `0`.
+    /// A divisor that is not a positive quantity is reported on `divisor`;
+    /// an unparseable or overflowing value is reported on `resource`.
+    pub fn resolve(&self, available: &Quantity) -> Result<String, Error> {
+        let one = Quantity::from("1");
+        let divisor = self.divisor.as_ref().unwrap_or(&one);
+        let divisor_err = |msg: &str| {
+            invalid(
+                &Path::new("divisor"),
+                BadValue::String(divisor.0.clone()),
+                msg,
+            )
+        };
+        match divisor.sign() {
+            Ok(std::cmp::Ordering::Greater) => {}
+            Ok(_) => return Err(divisor_err("must be greater than zero")),
+            Err(msg) => return Err(divisor_err(&msg)),
+        }
+        available
+            .div_ceil(divisor)
+            .map(|value| value.to_string())
+            .map_err(|msg| {
+                invalid(
+                    &Path::new("resource"),
+                    BadValue::String(available.0.clone()),
+                    &msg,
+                )
+            })
+    }
+}
+
 /// Constants for ResourceFieldSelector resources
 pub mod resource_field_selector_resource {
     pub const LIMITS_CPU: &str = "limits.cpu";
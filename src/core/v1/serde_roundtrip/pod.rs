@@ -58,3 +58,35 @@ fn serde_roundtrip_pod() {
 fn serde_roundtrip_pod_list() {
     assert_serde_roundtrip(&pod_list_basic());
 }
+
+#[test]
+fn serde_empty_pod_list_matches_api_server() {
+    let list = PodList {
+        items: vec![],
+        ..pod_list_basic()
+    };
+
+    // The API server always emits `items`, even for an empty list.
+    let expected = serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "PodList",
+        "metadata": {"resourceVersion": "1"},
+        "items": []
+    });
+    assert_eq!(serde_json::to_value(&list).unwrap(), expected);
+}
+
+#[test]
+fn serde_pod_list_without_items_deserializes_empty() {
+    let list: PodList = serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "PodList",
+        "metadata": {}
+    }))
+    .unwrap();
+    assert!(list.items.is_empty());
+    assert_eq!(
+        serde_json::to_value(&list).unwrap()["items"],
+        serde_json::json!([])
+    );
+}
@@ -10,6 +10,7 @@ use crate::core::internal::{
     IPFamily, IPFamilyPolicy, ServiceAffinity, ServiceExternalTrafficPolicy,
     ServiceInternalTrafficPolicy, ServiceType,
 };
+use crate::core::v1::Pod;
 use crate::core::v1::reference::ObjectReference;
 use crate::impl_unimplemented_prost_message;
 use serde::{Deserialize, Serialize};
@@ -370,7 +371,7 @@ pub struct ServiceList {
     pub metadata: Option<ListMeta>,
 
     /// Items is the list of services.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Service>,
 }
 
@@ -478,10 +479,46 @@ pub struct EndpointsList {
     pub metadata: Option<ListMeta>,
 
     /// Items is the list of endpoints.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Endpoints>,
 }
 
+// ============================================================================
+// Pod Selection
+// ============================================================================
+
+impl Service {
+    /// Returns true if `pod` backs this service.
+    ///
+    /// The pod must live in the service's namespace and carry every label in
+    /// `spec.selector`. ExternalName services and services without a selector
+    /// (manually managed endpoints) select no pods; the endpoints controller
+    /// ignores the selector of an ExternalName service.
+    pub fn selects(&self, pod: &Pod) -> bool {
+        let Some(selector) = self
+            .spec
+            .as_ref()
+            .filter(|spec| spec.type_ != Some(ServiceType::ExternalName))
+            .map(|spec| &spec.selector)
+            .filter(|selector| !selector.is_empty())
+        else {
+            return false;
+        };
+        if pod.metadata().namespace() != self.metadata().namespace() {
+            return false;
+        }
+        let labels = &pod.metadata().labels;
+        selector
+            .iter()
+            .all(|(key, value)| labels.get(key) == Some(value))
+    }
+
+    /// Returns the pods in `pods` that back this service (see [`Service::selects`]).
+    pub fn matching_pods<'a>(&self, pods: &'a [Pod]) -> Vec<&'a Pod> {
+        pods.iter().filter(|pod| self.selects(pod)).collect()
+    }
+}
+
 // ============================================================================
 // Trait Implementations for Service, ServiceList, Endpoints, and EndpointsList
 // ============================================================================
@@ -866,3 +903,88 @@ impl_unimplemented_prost_message!(Service);
 impl_unimplemented_prost_message!(ServiceList);
 impl_unimplemented_prost_message!(Endpoints);
 impl_unimplemented_prost_message!(EndpointsList);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pod(name: &str, labels: &[(&str, &str)]) -> Pod {
+        Pod {
+            metadata: Some(ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some("default".to_string()),
+                labels: labels
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn service(selector: &[(&str, &str)]) -> Service {
+        Service {
+            metadata: Some(ObjectMeta {
+                name: Some("web".to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            }),
+            spec: Some(ServiceSpec {
+                selector: selector
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_service_selects_matching_pods() {
+        let pods = vec![
+            pod("web-0", &[("app", "web"), ("tier", "frontend")]),
+            pod("web-1", &[("app", "web")]),
+            pod("db-0", &[("app", "db"), ("tier", "frontend")]),
+        ];
+        let svc = service(&[("app", "web"), ("tier", "frontend")]);
+
+        assert!(svc.selects(&pods[0]));
+        assert!(!svc.selects(&pods[1]));
+        assert_eq!(svc.matching_pods(&pods), vec![&pods[0]]);
+
+        let mut other_namespace = pods[0].clone();
+        other_namespace.metadata.as_mut().unwrap().namespace = Some("staging".to_string());
+        assert!(!svc.selects(&other_namespace));
+    }
+
+    #[test]
+    fn test_service_without_selector_selects_nothing() {
+        let pods = vec![pod("web-0", &[("app", "web")])];
+        let svc = Service {
+            spec: Some(ServiceSpec {
+                type_: Some(ServiceType::ExternalName),
+                external_name: "db.example.com".to_string(),
+                ..Default::default()
+            }),
+            ..service(&[])
+        };
+
+        assert!(!svc.selects(&pods[0]));
+        assert!(svc.matching_pods(&pods).is_empty());
+    }
+
+    #[test]
+    fn test_external_name_service_with_selector_selects_nothing() {
+        let pods = vec![pod("web-0", &[("app", "web")])];
+        let mut svc = service(&[("app", "web")]);
+        assert!(svc.selects(&pods[0]));
+
+        let spec = svc.spec.as_mut().unwrap();
+        spec.type_ = Some(ServiceType::ExternalName);
+        spec.external_name = "web.example.com".to_string();
+        assert!(!svc.selects(&pods[0]));
+        assert!(svc.matching_pods(&pods).is_empty());
+    }
+}
@@ -8,7 +8,7 @@ use crate::common::meta::{ListMeta, ObjectMeta};
 use crate::common::{ApplyDefault, HasTypeMeta, ResourceSchema, TypeMeta};
 use crate::core::v1::pod::PodSpec;
 use crate::core::v1::volume::apply_volume_defaults;
-use crate::{impl_unimplemented_prost_message, impl_versioned_object};
+use crate::impl_versioned_object;
 use serde::{Deserialize, Serialize};
 
 /// PodTemplate describes a template for creating copies of a predefined pod.
@@ -53,7 +53,7 @@ pub struct PodTemplateList {
     pub metadata: Option<ListMeta>,
 
     /// List of pod templates.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<PodTemplate>,
 }
 
@@ -180,23 +180,24 @@ impl ApplyDefault for PodTemplateList {
     }
 }
 
-// ----------------------------------------------------------------------------
-// Protobuf Placeholder
-// ----------------------------------------------------------------------------
-
-impl_unimplemented_prost_message!(PodTemplate);
-impl_unimplemented_prost_message!(PodTemplateList);
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::v1::pod::PodSpec;
-    use crate::core::v1::selector::ObjectFieldSelector;
+    use crate::common::util::{IntOrString, Quantity};
+    use crate::core::internal::selector::{LabelSelector, LabelSelectorRequirement};
+    use crate::core::v1::affinity::{Affinity, PodAffinityTerm, PodAntiAffinity};
+    use crate::core::v1::env::{EnvVar, EnvVarSource};
+    use crate::core::v1::pod::{Container, ContainerPort, PodSpec};
+    use crate::core::v1::probe::{HTTPGetAction, Probe, ProbeHandler};
+    use crate::core::v1::resource::ResourceRequirements;
+    use crate::core::v1::selector::{ConfigMapKeySelector, ObjectFieldSelector};
+    use crate::core::v1::toleration::Toleration;
     use crate::core::v1::volume::{
         ConfigMapVolumeSource, DownwardAPIVolumeFile, DownwardAPIVolumeSource,
         HostPathVolumeSource, ImageVolumeSource, ProjectedVolumeSource, SecretVolumeSource,
         ServiceAccountTokenProjection, Volume, VolumeProjection, VolumeSource, host_path_type,
     };
+    use prost::Message;
 
     #[test]
     fn test_pod_template_apply_default_sets_spec_defaults() {
@@ -333,6 +334,120 @@ mod tests {
         let host_path = &spec.volumes[6].volume_source.host_path.as_ref().unwrap();
         assert_eq!(host_path.type_.as_deref(), Some(host_path_type::UNSET));
     }
+
+    #[test]
+    fn test_pod_template_list_proto_roundtrip() {
+        let spec = PodSpec {
+            containers: vec![Container {
+                name: "web".to_string(),
+                image: Some("nginx:1.27".to_string()),
+                args: vec!["--port=8080".to_string()],
+                ports: vec![ContainerPort {
+                    name: Some("http".to_string()),
+                    container_port: 8080,
+                    protocol: Some("TCP".to_string()),
+                    ..Default::default()
+                }],
+                env: vec![EnvVar {
+                    name: "MODE".to_string(),
+                    value_from: Some(EnvVarSource {
+                        config_map_key_ref: Some(ConfigMapKeySelector {
+                            name: Some("settings".to_string()),
+                            key: "mode".to_string(),
+                            optional: Some(true),
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+                resources: Some(ResourceRequirements {
+                    requests: [("cpu".to_string(), Quantity("250m".to_string()))].into(),
+                    ..Default::default()
+                }),
+                readiness_probe: Some(Probe {
+                    probe_handler: ProbeHandler {
+                        http_get: Some(HTTPGetAction {
+                            path: "/healthz".to_string(),
+                            port: IntOrString::String("http".to_string()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                    period_seconds: Some(5),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }],
+            volumes: vec![Volume {
+                name: "config".to_string(),
+                volume_source: VolumeSource {
+                    config_map: Some(ConfigMapVolumeSource {
+                        name: Some("settings".to_string()),
+                        default_mode: Some(0o644),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            }],
+            tolerations: vec![Toleration {
+                key: "dedicated".to_string(),
+                operator: "Exists".to_string(),
+                effect: "NoSchedule".to_string(),
+                ..Default::default()
+            }],
+            affinity: Some(Affinity {
+                pod_anti_affinity: Some(PodAntiAffinity {
+                    required_during_scheduling_ignored_during_execution: vec![PodAffinityTerm {
+                        label_selector: Some(LabelSelector {
+                            match_expressions: vec![LabelSelectorRequirement {
+                                key: "app".to_string(),
+                                operator: "In".to_string(),
+                                values: vec!["web".to_string()],
+                            }],
+                            ..Default::default()
+                        }),
+                        topology_key: "kubernetes.io/hostname".to_string(),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            node_selector: [("disk".to_string(), "ssd".to_string())].into(),
+            host_network: true,
+            ..Default::default()
+        };
+        let template = PodTemplate {
+            metadata: Some(ObjectMeta {
+                name: Some("web".to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            }),
+            template: Some(PodTemplateSpec {
+                metadata: Some(ObjectMeta {
+                    labels: [("app".to_string(), "web".to_string())].into(),
+                    ..Default::default()
+                }),
+                spec: Some(spec),
+            }),
+            ..Default::default()
+        };
+        let list = PodTemplateList {
+            metadata: Some(ListMeta {
+                resource_version: Some("42".to_string()),
+                ..Default::default()
+            }),
+            items: vec![template.clone(), PodTemplate::default()],
+            ..Default::default()
+        };
+
+        let bytes = list.encode_to_vec();
+        assert_eq!(bytes.len(), list.encoded_len());
+
+        let decoded = PodTemplateList::decode(bytes.as_slice()).expect("decode PodTemplateList");
+        assert_eq!(decoded, list);
+        assert_eq!(decoded.items[0].template, template.template);
+    }
 }
 
 /// Applies default values to a PodTemplateSpec, including PodSpec, volumes,
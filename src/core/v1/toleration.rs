@@ -5,6 +5,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::core::v1::pod::PodSpec;
+
 /// Toleration represents a toleration for a node taint.
 ///
 /// Tolerations allow Pods to be scheduled onto nodes with matching taints.
@@ -58,5 +60,84 @@ pub mod toleration_effect {
     pub const NO_EXECUTE: &str = "NoExecute";
 }
 
+/// Well-known taint keys set by the node lifecycle controller
+pub mod taint_key {
+    /// The node is not ready (NodeReady condition is False)
+    pub const NODE_NOT_READY: &str = "node.kubernetes.io/not-ready";
+
+    /// The node controller cannot reach the node (NodeReady condition is Unknown)
+    pub const NODE_UNREACHABLE: &str = "node.kubernetes.io/unreachable";
+}
+
+/// Default tolerationSeconds added by the DefaultTolerationSeconds admission plugin.
+pub const DEFAULT_TOLERATION_SECONDS: i64 = 300;
+
+/// Adds the NoExecute tolerations for not-ready and unreachable nodes, as the
+/// DefaultTolerationSeconds admission plugin does.
+///
+/// A toleration is only added when no existing one already covers the key: an
+/// empty key matches every key and an empty effect matches every effect.
+///
+/// Based on plugin/pkg/admission/defaulttolerationseconds/admission.go
+pub fn add_default_toleration_seconds(spec: &mut PodSpec) {
+    for key in [taint_key::NODE_NOT_READY, taint_key::NODE_UNREACHABLE] {
+        let tolerated = spec.tolerations.iter().any(|toleration| {
+            (toleration.key.is_empty() || toleration.key == key)
+                && (toleration.effect.is_empty()
+                    || toleration.effect == toleration_effect::NO_EXECUTE)
+        });
+        if !tolerated {
+            spec.tolerations.push(Toleration {
+                key: key.to_string(),
+                operator: toleration_operator::EXISTS.to_string(),
+                effect: toleration_effect::NO_EXECUTE.to_string(),
+                toleration_seconds: Some(DEFAULT_TOLERATION_SECONDS),
+                ..Default::default()
+            });
+        }
+    }
+}
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_default_toleration_seconds() {
+        let mut spec = PodSpec {
+            tolerations: vec![Toleration {
+                key: "dedicated".to_string(),
+                operator: toleration_operator::EQUAL.to_string(),
+                value: "gpu".to_string(),
+                effect: toleration_effect::NO_SCHEDULE.to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        add_default_toleration_seconds(&mut spec);
+        let added: Vec<_> = spec.tolerations[1..]
+            .iter()
+            .map(|t| (t.key.as_str(), t.effect.as_str(), t.toleration_seconds))
+            .collect();
+        assert_eq!(
+            added,
+            vec![
+                (
+                    taint_key::NODE_NOT_READY,
+                    toleration_effect::NO_EXECUTE,
+                    Some(300)
+                ),
+                (
+                    taint_key::NODE_UNREACHABLE,
+                    toleration_effect::NO_EXECUTE,
+                    Some(300)
+                ),
+            ]
+        );
+
+        let before = spec.tolerations.clone();
+        add_default_toleration_seconds(&mut spec);
+        assert_eq!(spec.tolerations, before);
+    }
+}
@@ -5,9 +5,177 @@
 use crate::common::ToInternal;
 use crate::common::validation::{ErrorList, Path};
 use crate::core::internal::validation::affinity as internal_affinity_validation;
-use crate::core::v1::affinity::Affinity;
+use crate::core::v1::affinity::{Affinity, NodeSelectorRequirement};
 
 pub fn validate_affinity(affinity: &Affinity, path: &Path) -> ErrorList {
     let internal_affinity = affinity.clone().to_internal();
     internal_affinity_validation::validate_affinity(&internal_affinity, path)
 }
+
+/// Validates the pod-affinity side of an affinity: `podAffinity` and
+/// `podAntiAffinity` terms.
+///
+/// Every term needs a non-empty `topologyKey` and valid `labelSelector`/
+/// `namespaceSelector`, and preferred terms need a weight in 1-100.
+pub fn validate_pod_affinity(affinity: &Affinity, path: &Path) -> ErrorList {
+    let internal_affinity = affinity.clone().to_internal();
+    let mut all_errs = ErrorList::new();
+
+    if let Some(ref pod_affinity) = internal_affinity.pod_affinity {
+        all_errs.extend(internal_affinity_validation::validate_pod_affinity(
+            pod_affinity,
+            &path.child("podAffinity"),
+        ));
+    }
+    if let Some(ref pod_anti_affinity) = internal_affinity.pod_anti_affinity {
+        all_errs.extend(internal_affinity_validation::validate_pod_anti_affinity(
+            pod_anti_affinity,
+            &path.child("podAntiAffinity"),
+        ));
+    }
+
+    all_errs
+}
+
+/// Validates a node affinity `matchExpressions` requirement.
+///
+/// `In`/`NotIn` need at least one value, `Exists`/`DoesNotExist` must have
+/// none, and `Gt`/`Lt` need exactly one integer value.
+pub fn validate_node_selector_requirement(req: &NodeSelectorRequirement, path: &Path) -> ErrorList {
+    internal_affinity_validation::validate_node_selector_requirement(
+        &req.clone().to_internal(),
+        path,
+        true,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::validation::ErrorType;
+    use crate::core::internal::selector::LabelSelector;
+    use crate::core::v1::affinity::{
+        PodAffinity, PodAffinityTerm, PodAntiAffinity, WeightedPodAffinityTerm,
+        node_selector_operator,
+    };
+
+    fn term(topology_key: &str) -> PodAffinityTerm {
+        PodAffinityTerm {
+            label_selector: Some(LabelSelector {
+                match_labels: [("app".to_string(), "web".to_string())].into(),
+                ..Default::default()
+            }),
+            topology_key: topology_key.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_pod_affinity_empty_topology_key() {
+        let affinity = Affinity {
+            pod_affinity: Some(PodAffinity {
+                required_during_scheduling_ignored_during_execution: vec![
+                    term("kubernetes.io/hostname"),
+                    term(""),
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let errs = validate_pod_affinity(&affinity, &Path::new("affinity"));
+        assert_eq!(errs.len(), 1, "{:?}", errs.errors);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Required);
+        assert_eq!(
+            errs.errors[0].field,
+            "affinity.podAffinity.requiredDuringSchedulingIgnoredDuringExecution[1].topologyKey"
+        );
+    }
+
+    #[test]
+    fn test_validate_pod_anti_affinity_weight_out_of_range() {
+        let weighted = |weight| WeightedPodAffinityTerm {
+            weight,
+            pod_affinity_term: Some(term("topology.kubernetes.io/zone")),
+        };
+        let affinity = Affinity {
+            pod_anti_affinity: Some(PodAntiAffinity {
+                preferred_during_scheduling_ignored_during_execution: vec![
+                    weighted(100),
+                    weighted(0),
+                    weighted(101),
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let errs = validate_pod_affinity(&affinity, &Path::new("affinity"));
+        assert_eq!(errs.len(), 2, "{:?}", errs.errors);
+        for (err, i) in errs.errors.iter().zip([1, 2]) {
+            assert_eq!(err.error_type, ErrorType::Invalid);
+            assert_eq!(
+                err.field,
+                format!(
+                    "affinity.podAntiAffinity.preferredDuringSchedulingIgnoredDuringExecution[{i}].weight"
+                )
+            );
+        }
+    }
+
+    fn requirement(operator: &str, values: &[&str]) -> NodeSelectorRequirement {
+        NodeSelectorRequirement {
+            key: "node.kubernetes.io/instance-type".to_string(),
+            operator: operator.to_string(),
+            values: values.iter().map(|value| value.to_string()).collect(),
+        }
+    }
+
+    fn errors(req: &NodeSelectorRequirement) -> Vec<(String, ErrorType)> {
+        validate_node_selector_requirement(req, &Path::new("req"))
+            .errors
+            .into_iter()
+            .map(|err| (err.field, err.error_type))
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_node_selector_requirement_set_operators() {
+        for operator in [node_selector_operator::IN, node_selector_operator::NOT_IN] {
+            assert!(errors(&requirement(operator, &["m5.large"])).is_empty());
+            assert_eq!(
+                errors(&requirement(operator, &[])),
+                vec![("req.values".to_string(), ErrorType::Required)]
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_node_selector_requirement_existence_operators() {
+        for operator in [
+            node_selector_operator::EXISTS,
+            node_selector_operator::DOES_NOT_EXIST,
+        ] {
+            assert!(errors(&requirement(operator, &[])).is_empty());
+            assert_eq!(
+                errors(&requirement(operator, &["m5.large"])),
+                vec![("req.values".to_string(), ErrorType::Invalid)]
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_node_selector_requirement_numeric_operators() {
+        for operator in [node_selector_operator::GT, node_selector_operator::LT] {
+            assert!(errors(&requirement(operator, &["4"])).is_empty());
+            assert_eq!(
+                errors(&requirement(operator, &["4", "8"])),
+                vec![("req.values".to_string(), ErrorType::Invalid)]
+            );
+            assert_eq!(
+                errors(&requirement(operator, &["large"])),
+                vec![("req.values[0]".to_string(), ErrorType::Invalid)]
+            );
+        }
+    }
+}
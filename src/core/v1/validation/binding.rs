@@ -0,0 +1,12 @@
+//! Binding validation for Kubernetes core/v1 API.
+
+use crate::common::ToInternal;
+use crate::common::validation::ErrorList;
+use crate::core::internal::validation::binding as internal_binding_validation;
+use crate::core::v1::Binding;
+
+/// Validates a Binding.
+pub fn validate_binding(binding: &Binding) -> ErrorList {
+    let internal_binding = binding.clone().to_internal();
+    internal_binding_validation::validate_binding(&internal_binding)
+}
@@ -169,9 +169,13 @@ fn validate_config_map_update_with_path(
 // Secret Validation
 // ============================================================================
 
-/// Validates a Secret
-pub fn validate_secret(secret: &Secret) -> ErrorList {
-    validate_secret_with_path(secret, &Path::nil())
+/// Validates a Secret, including the keys required by its type:
+/// `tls.crt`/`tls.key` for TLS, `.dockerconfigjson` (or `.dockercfg`) for
+/// docker config secrets, `username` or `password` for basic auth,
+/// `ssh-privatekey` for SSH auth, and the service account name annotation for
+/// service account tokens.
+pub fn validate_secret(secret: &Secret, path: &Path) -> ErrorList {
+    validate_secret_with_path(secret, path)
 }
 
 fn validate_secret_with_path(secret: &Secret, path: &Path) -> ErrorList {
@@ -441,8 +445,7 @@ fn validate_service_account_update_with_path(
 ///
 /// Keys must follow these rules:
 /// - consist of alphanumeric characters, '-', '_' or '.'
-/// - start with an alphanumeric character
-/// - end with an alphanumeric character
+/// - not be '.' or '..', and not start with '..'
 pub(crate) fn is_config_map_key(key: &str) -> Vec<String> {
     let mut errors = Vec::new();
 
@@ -458,18 +461,12 @@ pub(crate) fn is_config_map_key(key: &str) -> Vec<String> {
         ));
     }
 
-    // Check first character
-    if let Some(first) = key.chars().next() {
-        if !first.is_alphanumeric() {
-            errors.push("must start with an alphanumeric character".to_string());
-        }
-    }
-
-    // Check last character
-    if let Some(last) = key.chars().last() {
-        if !last.is_alphanumeric() {
-            errors.push("must end with an alphanumeric character".to_string());
-        }
+    // Keys become file names when projected into volumes
+    match key {
+        "." => errors.push("must not be '.'".to_string()),
+        ".." => errors.push("must not be '..'".to_string()),
+        _ if key.starts_with("..") => errors.push("must not start with '..'".to_string()),
+        _ => {}
     }
 
     // Check all characters
@@ -493,6 +490,7 @@ pub(crate) fn is_config_map_key(key: &str) -> Vec<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::validation::ErrorType;
     use crate::common::{ObjectMeta, TypeMeta};
     use std::collections::BTreeMap;
 
@@ -588,7 +586,7 @@ mod tests {
             .data
             .insert("password".to_string(), vec![1, 2, 3].into());
 
-        let errs = validate_secret(&secret);
+        let errs = validate_secret(&secret, &Path::nil());
         assert!(errs.is_empty(), "Expected no errors, got: {:?}", errs);
     }
 
@@ -597,7 +595,7 @@ mod tests {
         let mut secret = create_test_secret("test-tls-secret");
         secret.type_ = Some(secret_type::TLS.to_string());
 
-        let errs = validate_secret(&secret);
+        let errs = validate_secret(&secret, &Path::nil());
         assert!(!errs.is_empty(), "Expected errors for missing TLS keys");
     }
 
@@ -612,7 +610,7 @@ mod tests {
             .data
             .insert(secret_data_key::TLS_KEY.to_string(), vec![4, 5, 6].into());
 
-        let errs = validate_secret(&secret);
+        let errs = validate_secret(&secret, &Path::nil());
         assert!(errs.is_empty(), "Expected no errors, got: {:?}", errs);
     }
 
@@ -621,7 +619,7 @@ mod tests {
         let mut secret = create_test_secret("test-basic-auth-secret");
         secret.type_ = Some(secret_type::BASIC_AUTH.to_string());
 
-        let errs = validate_secret(&secret);
+        let errs = validate_secret(&secret, &Path::nil());
         assert!(
             !errs.is_empty(),
             "Expected errors for missing username and password"
@@ -637,7 +635,7 @@ mod tests {
             vec![1, 2].into(),
         );
 
-        let errs = validate_secret(&secret);
+        let errs = validate_secret(&secret, &Path::nil());
         assert!(errs.is_empty(), "Expected no errors, got: {:?}", errs);
     }
 
@@ -646,10 +644,83 @@ mod tests {
         let mut secret = create_test_secret("test-ssh-secret");
         secret.type_ = Some(secret_type::SSH_AUTH.to_string());
 
-        let errs = validate_secret(&secret);
+        let errs = validate_secret(&secret, &Path::nil());
         assert!(!errs.is_empty(), "Expected errors for missing SSH key");
     }
 
+    #[test]
+    fn test_validate_secret_tls_missing_key_is_required() {
+        let mut secret = create_test_secret("test-tls-secret");
+        secret.type_ = Some(secret_type::TLS.to_string());
+        secret
+            .data
+            .insert(secret_data_key::TLS_CERT.to_string(), vec![1, 2, 3].into());
+
+        let errs = validate_secret(&secret, &Path::new("secret"));
+        assert_eq!(errs.len(), 1, "{:?}", errs);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Required);
+        assert_eq!(errs.errors[0].field, "secret.data[tls.key]");
+    }
+
+    #[test]
+    fn test_validate_secret_dockerconfigjson_requires_key() {
+        let mut secret = create_test_secret("test-docker-secret");
+        secret.type_ = Some("kubernetes.io/dockerconfigjson".to_string());
+
+        let errs = validate_secret(&secret, &Path::new("secret"));
+        assert_eq!(errs.len(), 1, "{:?}", errs);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Required);
+        assert_eq!(errs.errors[0].field, "secret.data[.dockerconfigjson]");
+
+        secret.data.insert(
+            secret_data_key::DOCKER_CONFIG_JSON.to_string(),
+            br#"{"auths":{}}"#.to_vec().into(),
+        );
+        let errs = validate_secret(&secret, &Path::new("secret"));
+        assert!(errs.is_empty(), "Expected no errors, got: {:?}", errs);
+    }
+
+    #[test]
+    fn test_validate_secret_basic_auth_missing_both_fields() {
+        let mut secret = create_test_secret("test-basic-auth-secret");
+        secret.type_ = Some(secret_type::BASIC_AUTH.to_string());
+
+        let errs = validate_secret(&secret, &Path::new("secret"));
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec!["secret.data[username]", "secret.data[password]"]
+        );
+        assert!(
+            errs.errors
+                .iter()
+                .all(|e| e.error_type == ErrorType::Required)
+        );
+    }
+
+    #[test]
+    fn test_validate_secret_service_account_token_requires_annotation() {
+        let mut secret = create_test_secret("test-sa-token");
+        secret.type_ = Some(secret_type::SERVICE_ACCOUNT_TOKEN.to_string());
+
+        let errs = validate_secret(&secret, &Path::new("secret"));
+        assert_eq!(errs.len(), 1, "{:?}", errs);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Required);
+        assert_eq!(
+            errs.errors[0].field,
+            "secret.metadata.annotations[kubernetes.io/service-account.name]"
+        );
+
+        secret
+            .metadata
+            .as_mut()
+            .unwrap()
+            .annotations
+            .insert(SERVICE_ACCOUNT_NAME_KEY.to_string(), "builder".to_string());
+        let errs = validate_secret(&secret, &Path::new("secret"));
+        assert!(errs.is_empty(), "Expected no errors, got: {:?}", errs);
+    }
+
     // ServiceAccount tests
     #[test]
     fn test_validate_service_account_valid() {
@@ -3,6 +3,7 @@
 //! These constants define limits, ranges, and valid values for validation.
 //! Ported from k8s.io/kubernetes/pkg/apis/core/validation/validation.go
 
+use crate::core::v1::toleration::toleration_operator;
 use std::collections::HashSet;
 use std::sync::LazyLock;
 
@@ -256,6 +257,17 @@ pub static SUPPORTED_TAINT_EFFECTS: LazyLock<HashSet<&'static str>> = LazyLock::
     s
 });
 
+// ============================================================================
+// Toleration Operator Constants
+// ============================================================================
+
+pub static SUPPORTED_TOLERATION_OPERATORS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    let mut s = HashSet::new();
+    s.insert(toleration_operator::EQUAL);
+    s.insert(toleration_operator::EXISTS);
+    s
+});
+
 // ============================================================================
 // Resize Resource Constants
 // ============================================================================
@@ -13,6 +13,12 @@ pub fn validate_env(vars: &[EnvVar], path: &Path) -> ErrorList {
     internal_env_validation::validate_env(&internal_vars, path)
 }
 
+/// Validates a single environment variable.
+pub fn validate_env_var(var: &EnvVar, path: &Path) -> ErrorList {
+    let internal_var: internal::EnvVar = var.clone().to_internal();
+    internal_env_validation::validate_env_var(&internal_var, path)
+}
+
 /// Validates a list of EnvFromSource entries.
 pub fn validate_env_from(vars: &[EnvFromSource], path: &Path) -> ErrorList {
     let internal_vars: Vec<internal::EnvFromSource> =
@@ -27,7 +33,9 @@ pub fn validate_env_from(vars: &[EnvFromSource], path: &Path) -> ErrorList {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::v1::env::{ConfigMapEnvSource, SecretEnvSource};
+    use crate::common::validation::ErrorType;
+    use crate::core::v1::env::{ConfigMapEnvSource, EnvVarSource, SecretEnvSource};
+    use crate::core::v1::selector::ObjectFieldSelector;
     use crate::core::v1::validation::selector::{is_valid_config_map_key, is_valid_env_var_name};
 
     #[test]
@@ -125,4 +133,38 @@ mod tests {
                 .any(|e| e.detail.contains("may not have more than one"))
         );
     }
+
+    #[test]
+    fn test_validate_env_var_non_identifier_name() {
+        let var = EnvVar {
+            name: "MY-VAR".to_string(),
+            value: "value".to_string(),
+            value_from: None,
+        };
+
+        let errs = validate_env_var(&var, &Path::new("env").index(0));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(errs.errors[0].field, "env[0].name");
+    }
+
+    #[test]
+    fn test_validate_env_var_value_and_value_from_conflict() {
+        let var = EnvVar {
+            name: "POD_NAME".to_string(),
+            value: "static".to_string(),
+            value_from: Some(EnvVarSource {
+                field_ref: Some(ObjectFieldSelector {
+                    api_version: "v1".to_string(),
+                    field_path: "metadata.name".to_string(),
+                }),
+                ..Default::default()
+            }),
+        };
+
+        let errs = validate_env_var(&var, &Path::new("env").index(0));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(errs.errors[0].field, "env[0].valueFrom");
+    }
 }
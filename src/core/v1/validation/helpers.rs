@@ -56,13 +56,7 @@ pub fn validate_port_name(name: &str, path: &Path) -> ErrorList {
     all_errs
 }
 
-/// Validates a port that can be either a number or a name (IntOrString)
-pub fn validate_port_num_or_name(port: &IntOrString, path: &Path) -> ErrorList {
-    match port {
-        IntOrString::Int(num) => validate_port_number(*num, path),
-        IntOrString::String(name) => validate_port_name(name, path),
-    }
-}
+pub use crate::core::internal::validation::helpers::validate_port_num_or_name;
 
 /// Validates that a node port is in the valid range (30000-32767)
 pub fn validate_node_port(port: i32, path: &Path) -> ErrorList {
@@ -108,6 +102,29 @@ pub fn validate_protocol(protocol: &str, path: &Path) -> ErrorList {
     all_errs
 }
 
+// ============================================================================
+// Versioned Enum Validation
+// ============================================================================
+
+/// Validates a versioned string field against its `supported` values.
+///
+/// Use this for fields whose internal counterpart is an enum: conversion maps
+/// unknown strings onto a known variant, so they have to be caught on the
+/// versioned value before converting.
+pub fn validate_versioned_enum(value: &str, supported: &HashSet<&str>, path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    if !supported.contains(value) {
+        let mut valid: Vec<&str> = supported.iter().copied().collect();
+        valid.sort_unstable();
+        all_errs.push(not_supported(
+            path,
+            BadValue::String(value.to_string()),
+            &valid,
+        ));
+    }
+    all_errs
+}
+
 // ============================================================================
 // DNS Validation
 // ============================================================================
@@ -402,6 +419,20 @@ mod tests {
         assert!(!validate_port_name("verylongportname", &path).is_empty()); // Too long (>15)
     }
 
+    #[test]
+    fn test_validate_port_num_or_name() {
+        let path = Path::new("port");
+
+        assert!(validate_port_num_or_name(&IntOrString::Int(8080), &path).is_empty());
+        assert!(validate_port_num_or_name(&IntOrString::String("http".into()), &path).is_empty());
+
+        let errs = validate_port_num_or_name(&IntOrString::Int(0), &path);
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].field, "port");
+        assert!(!validate_port_num_or_name(&IntOrString::String("HTTP".into()), &path).is_empty());
+        assert!(!validate_port_num_or_name(&IntOrString::String(String::new()), &path).is_empty());
+    }
+
     #[test]
     fn test_validate_protocol() {
         let path = Path::nil();
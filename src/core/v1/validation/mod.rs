@@ -15,6 +15,7 @@
 //! `ErrorList` for all validation errors found.
 
 pub mod affinity;
+pub mod binding;
 pub mod config;
 pub mod constants;
 pub mod container;
@@ -40,17 +41,25 @@ pub mod template;
 pub mod volume;
 
 // Re-export public API
+pub use binding::validate_binding;
 pub use config::{validate_config_map, validate_secret, validate_service_account};
+pub use dns::validate_pod_dns_config;
 pub use endpoints::validate_endpoints;
+pub use env::{validate_env, validate_env_from, validate_env_var};
 pub use events::{EventRequestVersion, validate_event_create, validate_event_update};
 pub use namespace::{validate_namespace, validate_namespace_update};
-pub use node::{validate_node, validate_node_update};
-pub use pod::{validate_pod, validate_pod_spec, validate_pod_update};
+pub use node::{validate_node, validate_node_update, validate_taints};
+pub use pod::{validate_pod, validate_pod_spec, validate_pod_status_resize, validate_pod_update};
+pub use pod_spec::{
+    validate_host_aliases, validate_scheduling_gates, validate_topology_spread_constraints,
+    validate_unique_container_names,
+};
 pub use replication_controller::{
     validate_replication_controller, validate_replication_controller_status_update,
     validate_replication_controller_update,
 };
 pub use resource_quota::{validate_limit_range, validate_resource_quota};
+pub use resources::validate_pod_overhead;
 pub use security::{validate_pod_security_context, validate_sysctls};
 pub use service::{validate_service, validate_service_spec, validate_service_update};
 pub use storage::{
@@ -60,4 +69,7 @@ pub use storage::{
 pub use template::{
     validate_pod_template, validate_pod_template_spec, validate_pod_template_update,
 };
-pub use volume::{validate_volume, validate_volumes};
+pub use volume::{
+    validate_projected_volume, validate_volume, validate_volume_mount, validate_volume_mounts,
+    validate_volumes,
+};
@@ -24,3 +24,49 @@ pub fn validate_namespace_status_update(new: &Namespace, old: &Namespace) -> Err
     let internal_old = old.clone().to_internal();
     internal_namespace_validation::validate_namespace_status_update(&internal_new, &internal_old)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ObjectMeta;
+    use crate::common::validation::ErrorType;
+    use crate::core::v1::namespace::{NamespaceSpec, finalizer_name};
+
+    fn namespace_with_finalizers(finalizers: &[&str]) -> Namespace {
+        Namespace {
+            metadata: Some(ObjectMeta {
+                name: Some("team-a".to_string()),
+                ..Default::default()
+            }),
+            spec: Some(NamespaceSpec {
+                finalizers: finalizers.iter().map(|f| f.to_string()).collect(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_namespace_standard_finalizers() {
+        let namespace = namespace_with_finalizers(&[
+            finalizer_name::KUBERNETES,
+            "orphan",
+            "example.com/cleanup",
+        ]);
+        let errs = validate_namespace(&namespace);
+        assert!(errs.is_empty(), "expected no errors, got: {errs:?}");
+    }
+
+    #[test]
+    fn test_validate_namespace_invalid_finalizers() {
+        let namespace = namespace_with_finalizers(&["cleanup", "example.com/bad name"]);
+        let errs = validate_namespace(&namespace);
+        let fields: Vec<_> = errs
+            .errors
+            .iter()
+            .filter(|e| e.error_type == ErrorType::Invalid)
+            .map(|e| e.field.as_str())
+            .collect();
+        assert!(fields.iter().any(|f| f.ends_with("spec.finalizers[0]")));
+        assert!(fields.iter().any(|f| f.ends_with("spec.finalizers[1]")));
+    }
+}
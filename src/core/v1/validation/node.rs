@@ -1,14 +1,43 @@
 //! Node validation for Kubernetes core/v1 API.
 
 use crate::common::ToInternal;
-use crate::common::validation::ErrorList;
+use crate::common::validation::{ErrorList, Path};
 use crate::core::internal::validation::node as internal_node_validation;
-use crate::core::v1::node::Node;
+use crate::core::v1::node::{Node, Taint};
 
 /// Validates a Node.
 pub fn validate_node(node: &Node) -> ErrorList {
-    let internal_node = node.clone().to_internal();
-    internal_node_validation::validate_node(&internal_node)
+    let mut internal_node = node.clone().to_internal();
+    // Taint effects are lost in conversion, so taints are checked on the v1 values.
+    internal_node.spec.taints.clear();
+    let mut all_errs = internal_node_validation::validate_node(&internal_node);
+    if let Some(spec) = node.spec.as_ref() {
+        all_errs.extend(validate_taints(
+            &spec.taints,
+            &Path::new("spec").child("taints"),
+        ));
+    }
+    all_errs
+}
+
+/// Validates a list of node taints, rooted at `path`.
+///
+/// Each key must be a qualified name, each value a valid label value and each
+/// effect one of `NoSchedule`, `PreferNoSchedule` or `NoExecute`. A key may
+/// appear at most once per effect.
+pub fn validate_taints(taints: &[Taint], path: &Path) -> ErrorList {
+    // Same checks as the internal validate_node_taints, run on the v1 strings
+    // because missing and unknown effects do not survive conversion.
+    internal_node_validation::validate_taint_fields(
+        taints.iter().map(|taint| {
+            (
+                taint.key.as_str(),
+                taint.value.as_deref().unwrap_or_default(),
+                taint.effect.as_deref().unwrap_or_default(),
+            )
+        }),
+        path,
+    )
 }
 
 /// Validates Node update.
@@ -17,3 +46,76 @@ pub fn validate_node_update(new: &Node, old: &Node) -> ErrorList {
     let internal_old = old.clone().to_internal();
     internal_node_validation::validate_node_update(&internal_new, &internal_old)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::validation::ErrorType;
+    use crate::core::v1::node::{NodeSpec, taint_effect};
+
+    fn taint(key: &str, value: &str, effect: &str) -> Taint {
+        Taint {
+            key: key.to_string(),
+            value: Some(value.to_string()),
+            effect: Some(effect.to_string()),
+            time_added: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_taints_invalid_effect() {
+        let taints = vec![
+            taint("dedicated", "gpu", taint_effect::NO_SCHEDULE),
+            taint("dedicated", "gpu", "NoRun"),
+        ];
+
+        let errs = validate_taints(&taints, &Path::new("spec").child("taints"));
+        assert_eq!(errs.len(), 1, "unexpected errors: {:?}", errs.errors);
+        assert_eq!(errs.errors[0].field, "spec.taints[1].effect");
+        assert_eq!(errs.errors[0].error_type, ErrorType::NotSupported);
+    }
+
+    #[test]
+    fn test_validate_taints_duplicate_key_and_effect() {
+        let taints = vec![
+            taint("dedicated", "gpu", taint_effect::NO_SCHEDULE),
+            taint("dedicated", "gpu", taint_effect::NO_EXECUTE),
+            taint("dedicated", "db", taint_effect::NO_SCHEDULE),
+        ];
+
+        let errs = validate_taints(&taints, &Path::new("spec").child("taints"));
+        assert_eq!(errs.len(), 1, "unexpected errors: {:?}", errs.errors);
+        assert_eq!(errs.errors[0].field, "spec.taints[2]");
+        assert_eq!(errs.errors[0].error_type, ErrorType::Duplicate);
+    }
+
+    #[test]
+    fn test_validate_node_checks_taints_on_v1_values() {
+        let node = Node {
+            metadata: Some(crate::common::ObjectMeta {
+                name: Some("node-1".to_string()),
+                ..Default::default()
+            }),
+            spec: Some(NodeSpec {
+                taints: vec![
+                    taint("dedicated", "gpu", "NoRun"),
+                    taint("dedicated", "gpu", taint_effect::NO_SCHEDULE),
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        // NoRun would convert to NoSchedule; it must not be reported as a duplicate.
+        let errs = validate_node(&node);
+        let errors: Vec<_> = errs
+            .errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.error_type))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![("spec.taints[0].effect", ErrorType::NotSupported)]
+        );
+    }
+}
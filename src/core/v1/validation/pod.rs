@@ -1,30 +1,324 @@
 //! Pod validation
 //!
-//! Validates Pod metadata and PodSpec.
+//! Validates Pod metadata, PodSpec and resize status.
 
 use crate::common::ToInternal;
-use crate::common::validation::ErrorList;
+use crate::common::validation::{BadValue, ErrorList, Path, invalid, required};
 use crate::core::internal::validation::pod as internal_pod_validation;
 use crate::core::v1::Pod;
+use crate::core::v1::pod::{PodStatus, pod_condition_type, pod_resize_status};
+use crate::core::v1::validation::pod_spec;
 
 /// Validates a Pod.
 pub fn validate_pod(pod: &Pod) -> ErrorList {
+    let mut all_errs = validate_pod_restart_policy(pod);
     let internal_pod = pod.clone().to_internal();
-    internal_pod_validation::validate_pod(&internal_pod)
+    all_errs.extend(internal_pod_validation::validate_pod(&internal_pod));
+    all_errs
 }
 
 /// Validates a PodSpec.
-pub fn validate_pod_spec(
-    spec: &crate::core::v1::PodSpec,
-    path: &crate::common::validation::Path,
-) -> ErrorList {
-    let internal_spec = spec.clone().to_internal();
-    internal_pod_validation::validate_pod_spec(&internal_spec, path)
+pub fn validate_pod_spec(spec: &crate::core::v1::PodSpec, path: &Path) -> ErrorList {
+    pod_spec::validate_pod_spec(spec, path)
 }
 
 /// Validates Pod update.
 pub fn validate_pod_update(new: &Pod, old: &Pod) -> ErrorList {
+    let mut all_errs = validate_pod_restart_policy(new);
     let internal_new = new.clone().to_internal();
     let internal_old = old.clone().to_internal();
-    internal_pod_validation::validate_pod_update(&internal_new, &internal_old)
+    all_errs.extend(internal_pod_validation::validate_pod_update(
+        &internal_new,
+        &internal_old,
+    ));
+    all_errs
+}
+
+/// Validates that the resize fields of a PodStatus agree with each other.
+///
+/// A true `PodResizeInProgress` condition requires `resize` to be
+/// `InProgress`, and while a resize is in progress every container status
+/// must report the resources currently enacted.
+pub fn validate_pod_status_resize(status: &PodStatus, path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+
+    let condition_in_progress = status.conditions.iter().any(|condition| {
+        condition.type_ == pod_condition_type::POD_RESIZE_IN_PROGRESS && condition.status == "True"
+    });
+    let resize = status.resize.as_deref();
+    if condition_in_progress && resize != Some(pod_resize_status::IN_PROGRESS) {
+        all_errs.push(invalid(
+            &path.child("resize"),
+            BadValue::String(resize.unwrap_or_default().to_string()),
+            &format!(
+                "must be {} while the {} condition is true",
+                pod_resize_status::IN_PROGRESS,
+                pod_condition_type::POD_RESIZE_IN_PROGRESS
+            ),
+        ));
+    }
+
+    if condition_in_progress || resize == Some(pod_resize_status::IN_PROGRESS) {
+        for (i, container_status) in status.container_statuses.iter().enumerate() {
+            if container_status.resources.is_none() {
+                all_errs.push(required(
+                    &path.child("containerStatuses").index(i).child("resources"),
+                    "must be reported while a resize is in progress",
+                ));
+            }
+        }
+    }
+
+    all_errs
+}
+
+fn validate_pod_restart_policy(pod: &Pod) -> ErrorList {
+    pod_spec::validate_restart_policy(
+        pod.spec
+            .as_ref()
+            .and_then(|spec| spec.restart_policy.as_deref()),
+        &Path::new("spec").child("restartPolicy"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::validation::ErrorType;
+    use crate::core::v1::pod::{Container, ContainerStatus, PodCondition, PodSpec};
+    use crate::core::v1::pod_resources::PodResourceClaim;
+    use crate::core::v1::probe::Probe;
+    use crate::core::v1::resource::{ResourceClaim, ResourceRequirements};
+
+    fn container(name: &str) -> Container {
+        Container {
+            name: name.to_string(),
+            image: Some("nginx:1.25".to_string()),
+            image_pull_policy: Some("IfNotPresent".to_string()),
+            termination_message_policy: Some("File".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn spec() -> PodSpec {
+        PodSpec {
+            containers: vec![container("app")],
+            restart_policy: Some("Always".to_string()),
+            dns_policy: Some("ClusterFirst".to_string()),
+            termination_grace_period_seconds: Some(30),
+            ..Default::default()
+        }
+    }
+
+    fn errors_at<'a>(errs: &'a ErrorList, field: &str) -> Vec<&'a ErrorType> {
+        errs.errors
+            .iter()
+            .filter(|err| err.field == field)
+            .map(|err| &err.error_type)
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_pod_spec_invalid_restart_policy() {
+        let mut spec = spec();
+        spec.restart_policy = Some("Sometimes".to_string());
+
+        let errs = validate_pod_spec(&spec, &Path::new("spec"));
+        assert_eq!(
+            errors_at(&errs, "spec.restartPolicy"),
+            vec![&ErrorType::NotSupported]
+        );
+    }
+
+    #[test]
+    fn test_validate_pod_reports_restart_policy_under_spec() {
+        let mut spec = spec();
+        spec.restart_policy = Some("Sometimes".to_string());
+        let pod = Pod {
+            spec: Some(spec),
+            ..Default::default()
+        };
+
+        let errs = validate_pod(&pod);
+        assert_eq!(
+            errors_at(&errs, "spec.restartPolicy"),
+            vec![&ErrorType::NotSupported]
+        );
+    }
+
+    #[test]
+    fn test_validate_pod_spec_init_container_readiness_probe() {
+        let mut spec = spec();
+        let mut init = container("setup");
+        init.readiness_probe = Some(Probe::default());
+        spec.init_containers = vec![init];
+
+        let errs = validate_pod_spec(&spec, &Path::new("spec"));
+        assert_eq!(
+            errors_at(&errs, "spec.initContainers[0].readinessProbe"),
+            vec![&ErrorType::Forbidden]
+        );
+    }
+
+    #[test]
+    fn test_validate_pod_spec_sidecar_readiness_probe_allowed() {
+        let mut spec = spec();
+        let mut sidecar = container("proxy");
+        sidecar.restart_policy = Some("Always".to_string());
+        sidecar.readiness_probe = Some(Probe::default());
+        spec.init_containers = vec![sidecar];
+
+        let errs = validate_pod_spec(&spec, &Path::new("spec"));
+        assert!(
+            errors_at(&errs, "spec.initContainers[0].readinessProbe").is_empty(),
+            "sidecar probe must not be forbidden, got: {errs:?}"
+        );
+    }
+
+    #[test]
+    fn test_validate_pod_spec_negative_grace_period() {
+        let mut spec = spec();
+        spec.termination_grace_period_seconds = Some(-1);
+
+        let errs = validate_pod_spec(&spec, &Path::new("spec"));
+        assert_eq!(
+            errors_at(&errs, "spec.terminationGracePeriodSeconds"),
+            vec![&ErrorType::Invalid]
+        );
+    }
+
+    #[test]
+    fn test_validate_pod_spec_active_deadline_must_be_positive() {
+        let mut spec = spec();
+        spec.active_deadline_seconds = Some(0);
+
+        let errs = validate_pod_spec(&spec, &Path::new("spec"));
+        assert_eq!(
+            errors_at(&errs, "spec.activeDeadlineSeconds"),
+            vec![&ErrorType::Invalid]
+        );
+    }
+
+    fn pod_claim(name: &str, claim_name: Option<&str>, template: Option<&str>) -> PodResourceClaim {
+        PodResourceClaim {
+            name: name.to_string(),
+            resource_claim_name: claim_name.map(str::to_string),
+            resource_claim_template_name: template.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_validate_pod_spec_dangling_resource_claim_reference() {
+        let mut spec = spec();
+        spec.resource_claims = vec![pod_claim("gpu", None, Some("gpu-template"))];
+        spec.containers[0].resources = Some(ResourceRequirements {
+            claims: vec![ResourceClaim {
+                name: "fpga".to_string(),
+                request: String::new(),
+            }],
+            ..Default::default()
+        });
+
+        let errs = validate_pod_spec(&spec, &Path::new("spec"));
+        assert_eq!(
+            errors_at(&errs, "spec.containers[0].resources.claims[0]"),
+            vec![&ErrorType::NotFound]
+        );
+        assert!(errors_at(&errs, "spec.resourceClaims[0]").is_empty());
+    }
+
+    #[test]
+    fn test_validate_pod_spec_resource_claim_names_and_sources() {
+        let mut spec = spec();
+        spec.resource_claims = vec![
+            pod_claim("gpu", Some("shared-gpu"), None),
+            pod_claim("gpu", None, Some("gpu-template")),
+            pod_claim("both", Some("a"), Some("b")),
+            pod_claim("neither", None, None),
+        ];
+
+        let errs = validate_pod_spec(&spec, &Path::new("spec"));
+        assert_eq!(
+            errors_at(&errs, "spec.resourceClaims[1].name"),
+            vec![&ErrorType::Duplicate]
+        );
+        assert_eq!(
+            errors_at(&errs, "spec.resourceClaims[2]"),
+            vec![&ErrorType::Invalid]
+        );
+        assert_eq!(
+            errors_at(&errs, "spec.resourceClaims[3]"),
+            vec![&ErrorType::Invalid]
+        );
+        assert!(errors_at(&errs, "spec.resourceClaims[0]").is_empty());
+        assert!(errors_at(&errs, "spec.resourceClaims[0].name").is_empty());
+    }
+
+    fn container_status(name: &str, resources: Option<ResourceRequirements>) -> ContainerStatus {
+        ContainerStatus {
+            name: name.to_string(),
+            state: None,
+            last_state: None,
+            ready: true,
+            restart_count: 0,
+            image: None,
+            image_id: None,
+            container_id: None,
+            started: Some(true),
+            allocated_resources: None,
+            resources,
+            volume_mounts: Vec::new(),
+            user: None,
+            allocated_resources_status: Vec::new(),
+            stop_signal: None,
+        }
+    }
+
+    fn resizing_status(resize: Option<&str>) -> PodStatus {
+        PodStatus {
+            conditions: vec![PodCondition {
+                type_: pod_condition_type::POD_RESIZE_IN_PROGRESS.to_string(),
+                status: "True".to_string(),
+                last_probe_time: None,
+                last_transition_time: None,
+                reason: None,
+                message: None,
+                observed_generation: None,
+            }],
+            container_statuses: vec![container_status(
+                "app",
+                Some(ResourceRequirements::default()),
+            )],
+            resize: resize.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_pod_status_resize_consistent() {
+        let status = resizing_status(Some(pod_resize_status::IN_PROGRESS));
+        let errs = validate_pod_status_resize(&status, &Path::new("status"));
+        assert!(errs.is_empty(), "unexpected errors: {errs:?}");
+
+        let idle = PodStatus {
+            resize: Some(pod_resize_status::DEFERRED.to_string()),
+            container_statuses: vec![container_status("app", None)],
+            ..Default::default()
+        };
+        assert!(validate_pod_status_resize(&idle, &Path::new("status")).is_empty());
+    }
+
+    #[test]
+    fn test_validate_pod_status_resize_inconsistent() {
+        let mut status = resizing_status(Some(pod_resize_status::DEFERRED));
+        status.container_statuses[0].resources = None;
+
+        let errs = validate_pod_status_resize(&status, &Path::new("status"));
+        assert_eq!(errors_at(&errs, "status.resize"), vec![&ErrorType::Invalid]);
+        assert_eq!(
+            errors_at(&errs, "status.containerStatuses[0].resources"),
+            vec![&ErrorType::Required]
+        );
+        assert_eq!(errs.len(), 2);
+    }
 }
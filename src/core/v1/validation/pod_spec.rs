@@ -1,12 +1,340 @@
 //! PodSpec validation for Kubernetes core/v1 API.
 
 use crate::common::ToInternal;
-use crate::common::validation::{ErrorList, Path};
+use crate::common::validation::{BadValue, ErrorList, Path, not_supported};
 use crate::core::internal::validation::pod_spec as internal_pod_spec_validation;
-use crate::core::v1::pod::PodSpec;
+use crate::core::v1::pod::{HostAlias, PodSchedulingGate, PodSpec, restart_policy};
+use crate::core::v1::topology::TopologySpreadConstraint;
+
+const SUPPORTED_RESTART_POLICIES: [&str; 3] = [
+    restart_policy::ALWAYS,
+    restart_policy::ON_FAILURE,
+    restart_policy::NEVER,
+];
 
 /// Validates a PodSpec.
 pub fn validate_pod_spec(spec: &PodSpec, path: &Path) -> ErrorList {
+    let mut all_errs =
+        validate_restart_policy(spec.restart_policy.as_deref(), &path.child("restartPolicy"));
     let internal_spec = spec.clone().to_internal();
-    internal_pod_spec_validation::validate_pod_spec(&internal_spec, path)
+    all_errs.extend(internal_pod_spec_validation::validate_pod_spec(
+        &internal_spec,
+        path,
+    ));
+    all_errs
+}
+
+/// Validates `spec.ephemeralContainers`, rooted at `path`.
+///
+/// Ephemeral containers may not set ports, resources, probes or lifecycle
+/// hooks, and `targetContainerName` must name a regular or init container.
+pub fn validate_ephemeral_containers(spec: &PodSpec, path: &Path) -> ErrorList {
+    internal_pod_spec_validation::validate_pod_spec_ephemeral_containers(
+        &spec.clone().to_internal(),
+        path,
+    )
+}
+
+/// Validates `spec.hostAliases`, rooted at `path`.
+pub fn validate_host_aliases(aliases: &[HostAlias], path: &Path) -> ErrorList {
+    let internal_aliases: Vec<_> = aliases
+        .iter()
+        .cloned()
+        .map(|alias| alias.to_internal())
+        .collect();
+    internal_pod_spec_validation::validate_host_aliases(&internal_aliases, path)
+}
+
+/// Validates `spec.schedulingGates`, rooted at `path`.
+///
+/// Gate names must be qualified names and may not repeat.
+pub fn validate_scheduling_gates(gates: &[PodSchedulingGate], path: &Path) -> ErrorList {
+    let internal_gates: Vec<_> = gates
+        .iter()
+        .cloned()
+        .map(|gate| gate.to_internal())
+        .collect();
+    internal_pod_spec_validation::validate_scheduling_gates(&internal_gates, path)
+}
+
+/// Validates `spec.topologySpreadConstraints`, rooted at `path`.
+///
+/// Each constraint is validated individually, and `(topologyKey,
+/// whenUnsatisfiable)` pairs must be unique across the list.
+pub fn validate_topology_spread_constraints(
+    constraints: &[TopologySpreadConstraint],
+    path: &Path,
+) -> ErrorList {
+    internal_pod_spec_validation::validate_topology_spread_constraints(constraints, path)
+}
+
+/// Validates that container names are unique across `containers`,
+/// `initContainers` and `ephemeralContainers`, rooted at the pod spec `path`.
+///
+/// This is the check the internal pod spec validation runs, so a collision is
+/// reported on the later container. Empty names are left to the per-container
+/// validators.
+pub fn validate_unique_container_names(spec: &PodSpec, path: &Path) -> ErrorList {
+    internal_pod_spec_validation::validate_unique_container_names(&spec.clone().to_internal(), path)
+}
+
+/// Validates the pod-level restartPolicy.
+///
+/// The internal type is an enum, so unknown values are lost in conversion and
+/// must be caught on the versioned string. An unset policy is defaulted to
+/// `Always`.
+pub(crate) fn validate_restart_policy(policy: Option<&str>, path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    if let Some(policy) = policy
+        && !SUPPORTED_RESTART_POLICIES.contains(&policy)
+    {
+        all_errs.push(not_supported(
+            path,
+            BadValue::String(policy.to_string()),
+            &SUPPORTED_RESTART_POLICIES,
+        ));
+    }
+    all_errs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Quantity;
+    use crate::common::validation::ErrorType;
+    use crate::core::v1::EphemeralContainer;
+    use crate::core::v1::pod::{Container, ContainerPort, PodDNSConfig};
+    use crate::core::v1::resource::ResourceRequirements;
+    use crate::core::v1::validation::dns::{DnsValidationOptions, validate_pod_dns_config};
+
+    fn spec_with(ephemeral: EphemeralContainer) -> PodSpec {
+        PodSpec {
+            containers: vec![Container {
+                name: "app".to_string(),
+                image: Some("nginx".to_string()),
+                ..Default::default()
+            }],
+            ephemeral_containers: vec![ephemeral],
+            ..Default::default()
+        }
+    }
+
+    fn debugger() -> EphemeralContainer {
+        EphemeralContainer {
+            name: "debugger".to_string(),
+            image: "busybox".to_string(),
+            target_container_name: "app".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn path() -> Path {
+        Path::new("spec").child("ephemeralContainers")
+    }
+
+    #[test]
+    fn test_validate_ephemeral_containers_valid() {
+        let errs = validate_ephemeral_containers(&spec_with(debugger()), &path());
+        assert!(errs.is_empty(), "unexpected errors: {:?}", errs);
+    }
+
+    #[test]
+    fn test_validate_ephemeral_containers_forbids_ports() {
+        let mut ephemeral = debugger();
+        ephemeral.ports = vec![ContainerPort {
+            name: None,
+            container_port: 8080,
+            protocol: Some("TCP".to_string()),
+            host_port: None,
+            host_ip: None,
+        }];
+
+        let errs = validate_ephemeral_containers(&spec_with(ephemeral), &path());
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Forbidden);
+        assert_eq!(errs.errors[0].field, "spec.ephemeralContainers[0].ports");
+    }
+
+    #[test]
+    fn test_validate_ephemeral_containers_forbids_resources() {
+        let mut ephemeral = debugger();
+        ephemeral.resources = Some(ResourceRequirements {
+            requests: [("cpu".to_string(), Quantity::from("100m"))].into(),
+            ..Default::default()
+        });
+
+        let errs = validate_ephemeral_containers(&spec_with(ephemeral), &path());
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Forbidden);
+        assert_eq!(
+            errs.errors[0].field,
+            "spec.ephemeralContainers[0].resources"
+        );
+    }
+
+    #[test]
+    fn test_validate_ephemeral_containers_unknown_target() {
+        let mut ephemeral = debugger();
+        ephemeral.target_container_name = "sidecar".to_string();
+
+        let errs = validate_ephemeral_containers(&spec_with(ephemeral), &path());
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::NotFound);
+        assert_eq!(
+            errs.errors[0].field,
+            "spec.ephemeralContainers[0].targetContainerName"
+        );
+    }
+
+    #[test]
+    fn test_validate_host_aliases_bad_ip() {
+        let aliases = vec![
+            HostAlias {
+                ip: "10.0.0.300".to_string(),
+                hostnames: vec!["db.internal".to_string()],
+            },
+            HostAlias {
+                ip: "127.0.0.1".to_string(),
+                hostnames: vec![],
+            },
+            HostAlias {
+                ip: "::1".to_string(),
+                hostnames: vec!["Local_Host".to_string()],
+            },
+        ];
+
+        let errs = validate_host_aliases(&aliases, &Path::new("spec").child("hostAliases"));
+        let fields: Vec<_> = errs
+            .errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.error_type))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("spec.hostAliases[0].ip", ErrorType::Invalid),
+                ("spec.hostAliases[1].hostnames", ErrorType::Required),
+                ("spec.hostAliases[2].hostnames[0]", ErrorType::Invalid),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_pod_dns_config_too_many_nameservers() {
+        let config = PodDNSConfig {
+            nameservers: vec![
+                "10.0.0.10".to_string(),
+                "10.0.0.11".to_string(),
+                "10.0.0.12".to_string(),
+                "dns.example.com".to_string(),
+            ],
+            searches: vec!["svc.cluster.local".to_string()],
+            options: vec![],
+        };
+
+        let errs = validate_pod_dns_config(
+            Some(&config),
+            Some("None"),
+            &Path::new("spec").child("dnsConfig"),
+            DnsValidationOptions::default(),
+        );
+        let fields: Vec<_> = errs
+            .errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.error_type))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("spec.dnsConfig.nameservers", ErrorType::Invalid),
+                ("spec.dnsConfig.nameservers[3]", ErrorType::Invalid),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_scheduling_gates_names() {
+        let mut spec = PodSpec::default();
+        spec.add_scheduling_gate("example.com/quota");
+        spec.add_scheduling_gate("not a qualified name");
+        spec.scheduling_gates.push(PodSchedulingGate {
+            name: "example.com/quota".to_string(),
+        });
+
+        let errs = validate_scheduling_gates(
+            &spec.scheduling_gates,
+            &Path::new("spec").child("schedulingGates"),
+        );
+        let fields: Vec<_> = errs
+            .errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.error_type))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("spec.schedulingGates[1]", ErrorType::Invalid),
+                ("spec.schedulingGates[2]", ErrorType::Duplicate),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_topology_spread_constraints_duplicate_pair() {
+        use crate::core::v1::topology::when_unsatisfiable;
+
+        let constraint = |max_skew: i32, when: &str| TopologySpreadConstraint {
+            max_skew,
+            topology_key: "topology.kubernetes.io/zone".to_string(),
+            when_unsatisfiable: when.to_string(),
+            ..Default::default()
+        };
+        let constraints = vec![
+            constraint(1, when_unsatisfiable::DO_NOT_SCHEDULE),
+            constraint(2, when_unsatisfiable::SCHEDULE_ANYWAY),
+            constraint(3, when_unsatisfiable::DO_NOT_SCHEDULE),
+        ];
+
+        let errs = validate_topology_spread_constraints(
+            &constraints,
+            &Path::new("spec").child("topologySpreadConstraints"),
+        );
+        let fields: Vec<_> = errs
+            .errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.error_type))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![(
+                "spec.topologySpreadConstraints[2].{topologyKey, whenUnsatisfiable}",
+                ErrorType::Duplicate
+            )]
+        );
+    }
+
+    #[test]
+    fn test_validate_unique_container_names_across_lists() {
+        let mut spec = spec_with(EphemeralContainer {
+            name: "app".to_string(),
+            image: "busybox".to_string(),
+            ..Default::default()
+        });
+        spec.init_containers = vec![Container {
+            name: "setup".to_string(),
+            image: Some("busybox".to_string()),
+            ..Default::default()
+        }];
+
+        let errs = validate_unique_container_names(&spec, &Path::new("spec"));
+        let fields: Vec<_> = errs
+            .errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.error_type))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![("spec.ephemeralContainers[0].name", ErrorType::Duplicate)]
+        );
+    }
 }
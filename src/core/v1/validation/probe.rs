@@ -28,6 +28,16 @@ const SUPPORTED_HTTP_SCHEMES_ARRAY: &[&str] = &[uri_scheme::HTTP, uri_scheme::HT
 // Probe Validation Functions
 // ============================================================================
 
+/// Validates the checks shared by every probe type.
+///
+/// Exactly one handler must be set and the timing fields (initialDelaySeconds,
+/// timeoutSeconds, periodSeconds, successThreshold, failureThreshold) must be
+/// non-negative. Use the liveness/startup variants to also require
+/// successThreshold to be 1.
+pub fn validate_probe(probe: &Probe, path: &Path) -> ErrorList {
+    validate_probe_v1(probe, &None, path)
+}
+
 /// Validates a liveness probe.
 ///
 /// Liveness probes have special requirements:
@@ -46,7 +56,7 @@ pub(crate) fn validate_liveness_probe_v1(
     let mut all_errs = ErrorList::new();
 
     if let Some(p) = probe {
-        all_errs.extend(validate_probe(p, grace_period, path));
+        all_errs.extend(validate_probe_v1(p, grace_period, path));
 
         // Liveness probe must have successThreshold = 1
         if let Some(success_threshold) = p.success_threshold {
@@ -81,7 +91,7 @@ pub(crate) fn validate_readiness_probe_v1(
     let mut all_errs = ErrorList::new();
 
     if let Some(p) = probe {
-        all_errs.extend(validate_probe(p, grace_period, path));
+        all_errs.extend(validate_probe_v1(p, grace_period, path));
 
         // Readiness probe must not have terminationGracePeriodSeconds set
         if p.termination_grace_period_seconds.is_some() {
@@ -114,7 +124,7 @@ pub(crate) fn validate_startup_probe_v1(
     let mut all_errs = ErrorList::new();
 
     if let Some(p) = probe {
-        all_errs.extend(validate_probe(p, grace_period, path));
+        all_errs.extend(validate_probe_v1(p, grace_period, path));
 
         // Startup probe must have successThreshold = 1
         if let Some(success_threshold) = p.success_threshold {
@@ -131,94 +141,12 @@ pub(crate) fn validate_startup_probe_v1(
     all_errs
 }
 
-/// Validates a probe.
+/// Validates a probe against the pod's termination grace period.
 ///
-/// Validates:
-/// - Probe handler (exactly one type must be specified)
-/// - initialDelaySeconds >= 0
-/// - timeoutSeconds >= 0
-/// - periodSeconds >= 0
-/// - successThreshold >= 0
-/// - failureThreshold >= 0
-/// - terminationGracePeriodSeconds > 0 (if set)
-#[allow(dead_code)]
-fn validate_probe(probe: &Probe, grace_period: &Option<i64>, path: &Path) -> ErrorList {
-    let mut all_errs = ErrorList::new();
-
-    // Validate the probe handler
-    all_errs.extend(validate_probe_handler(
-        &probe.probe_handler,
-        grace_period,
-        path,
-    ));
-
-    // Validate initialDelaySeconds is non-negative
-    if let Some(initial_delay) = probe.initial_delay_seconds {
-        if initial_delay < 0 {
-            all_errs.push(invalid(
-                &path.child("initialDelaySeconds"),
-                BadValue::Int(initial_delay.into()),
-                "must be non-negative",
-            ));
-        }
-    }
-
-    // Validate timeoutSeconds is non-negative
-    if let Some(timeout) = probe.timeout_seconds {
-        if timeout < 0 {
-            all_errs.push(invalid(
-                &path.child("timeoutSeconds"),
-                BadValue::Int(timeout.into()),
-                "must be non-negative",
-            ));
-        }
-    }
-
-    // Validate periodSeconds is non-negative
-    if let Some(period) = probe.period_seconds {
-        if period < 0 {
-            all_errs.push(invalid(
-                &path.child("periodSeconds"),
-                BadValue::Int(period.into()),
-                "must be non-negative",
-            ));
-        }
-    }
-
-    // Validate successThreshold is non-negative
-    if let Some(success_threshold) = probe.success_threshold {
-        if success_threshold < 0 {
-            all_errs.push(invalid(
-                &path.child("successThreshold"),
-                BadValue::Int(success_threshold.into()),
-                "must be non-negative",
-            ));
-        }
-    }
-
-    // Validate failureThreshold is non-negative
-    if let Some(failure_threshold) = probe.failure_threshold {
-        if failure_threshold < 0 {
-            all_errs.push(invalid(
-                &path.child("failureThreshold"),
-                BadValue::Int(failure_threshold.into()),
-                "must be non-negative",
-            ));
-        }
-    }
-
-    // Validate terminationGracePeriodSeconds is positive if set
-    if let Some(term_grace) = probe.termination_grace_period_seconds {
-        if term_grace <= 0 {
-            all_errs.push(invalid(
-                &path.child("terminationGracePeriodSeconds"),
-                BadValue::Int(term_grace),
-                "must be greater than 0",
-            ));
-        }
-    }
-
-    all_errs
+/// Same checks as [`validate_probe`], which passes no grace period.
+fn validate_probe_v1(probe: &Probe, grace_period: &Option<i64>, path: &Path) -> ErrorList {
+    let internal_probe = probe.clone().to_internal();
+    internal_probe_validation::validate_probe(&internal_probe, grace_period, path)
 }
 
 /// Validates a probe handler.
@@ -805,7 +733,54 @@ mod tests {
             termination_grace_period_seconds: Some(-1),
         };
 
-        let errs = validate_probe(&probe, &Some(30), &Path::nil());
+        let errs = validate_probe_v1(&probe, &Some(30), &Path::nil());
         assert!(errs.len() >= 6); // Should have errors for all negative fields
     }
+
+    fn exec_probe() -> Probe {
+        Probe {
+            probe_handler: ProbeHandler {
+                exec: Some(ExecAction {
+                    command: vec!["true".to_string()],
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_liveness_probe_success_threshold_two() {
+        let probe = Probe {
+            success_threshold: Some(2),
+            ..exec_probe()
+        };
+
+        assert!(validate_probe(&probe, &Path::new("livenessProbe")).is_empty());
+        assert!(validate_readiness_probe(&probe, &Path::new("readinessProbe")).is_empty());
+
+        let errs = validate_liveness_probe(&probe, &Path::new("livenessProbe"));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(errs.errors[0].field, "livenessProbe.successThreshold");
+        assert_eq!(errs.errors[0].detail, "must be 1");
+
+        let errs = validate_startup_probe(&probe, &Path::new("startupProbe"));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].field, "startupProbe.successThreshold");
+    }
+
+    #[test]
+    fn test_validate_probe_negative_period_seconds() {
+        let probe = Probe {
+            period_seconds: Some(-5),
+            ..exec_probe()
+        };
+
+        let errs = validate_probe(&probe, &Path::new("readinessProbe"));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(errs.errors[0].field, "readinessProbe.periodSeconds");
+        assert_eq!(errs.errors[0].detail, "must be non-negative");
+    }
 }
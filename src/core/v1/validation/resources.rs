@@ -5,7 +5,7 @@
 use crate::common::validation::{BadValue, ErrorList, Path, forbidden, invalid, required};
 use crate::common::{Quantity, ToInternal};
 use crate::core::internal::validation::resources as internal_resources_validation;
-use crate::core::v1::resource::{ResourceClaim, ResourceRequirements};
+use crate::core::v1::resource::{ResourceClaim, ResourceList, ResourceRequirements};
 use std::collections::HashSet;
 use std::sync::LazyLock;
 
@@ -86,6 +86,13 @@ pub fn validate_pod_resource_requirements(
     )
 }
 
+/// Validates `spec.overhead`, as set from a RuntimeClass.
+///
+/// Every entry needs a valid container resource name and a non-negative quantity.
+pub fn validate_pod_overhead(overhead: &ResourceList, path: &Path) -> ErrorList {
+    internal_resources_validation::validate_pod_overhead(overhead, path)
+}
+
 #[allow(dead_code)]
 pub(crate) fn validate_pod_resource_requirements_v1(
     requirements: &ResourceRequirements,
@@ -286,7 +293,7 @@ pub(crate) fn validate_resource_quantity_value(
     let mut all_errs = ErrorList::new();
 
     // Check if quantity is negative
-    if quantity.sign().unwrap_or(std::cmp::Ordering::Equal).is_lt() {
+    if quantity.is_negative() {
         all_errs.push(invalid(
             path,
             BadValue::String(quantity.to_string()),
@@ -410,6 +417,7 @@ fn is_overcommit_allowed(name: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::validation::ErrorType;
 
     #[test]
     fn test_is_hugepage_resource() {
@@ -459,6 +467,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_pod_overhead_negative_value() {
+        let overhead = ResourceList::from([
+            ("cpu".to_string(), Quantity::from("250m")),
+            ("memory".to_string(), Quantity::from("-120Mi")),
+        ]);
+
+        let errs = validate_pod_overhead(&overhead, &Path::new("spec").child("overhead"));
+        assert_eq!(errs.len(), 1, "{:?}", errs.errors);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(errs.errors[0].field, "spec.overhead[memory]");
+        assert_eq!(errs.errors[0].detail, "must be non-negative");
+    }
+
+    #[test]
+    fn test_validate_pod_overhead_invalid_resource_name() {
+        let overhead = ResourceList::from([
+            ("cpu".to_string(), Quantity::from("250m")),
+            ("gpus".to_string(), Quantity::from("1")),
+        ]);
+
+        let errs = validate_pod_overhead(&overhead, &Path::new("spec").child("overhead"));
+        assert_eq!(errs.len(), 1, "{:?}", errs.errors);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(errs.errors[0].field, "spec.overhead[gpus]");
+    }
+
     #[test]
     fn test_validate_resource_requirements_request_exceeds_limit() {
         let mut requirements = ResourceRequirements::default();
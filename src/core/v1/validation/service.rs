@@ -57,3 +57,71 @@ pub fn validate_service_status_update(
     let internal_old = old_service.clone().to_internal();
     internal_service_validation::validate_service_status_update(&internal_new, &internal_old, path)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::IntOrString;
+    use crate::common::validation::ErrorType;
+
+    fn spec_with_target_port(target_port: IntOrString) -> ServiceSpec {
+        ServiceSpec {
+            ports: vec![ServicePort {
+                name: "web".to_string(),
+                protocol: "TCP".to_string(),
+                port: 80,
+                target_port: Some(target_port),
+                ..Default::default()
+            }],
+            selector: [("app".to_string(), "web".to_string())].into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_service_spec_numeric_target_port() {
+        let spec = spec_with_target_port(IntOrString::Int(8080));
+        let errs = validate_service_spec(&spec, &Path::new("spec"));
+        assert!(errs.is_empty(), "unexpected errors: {:?}", errs);
+
+        let spec = spec_with_target_port(IntOrString::Int(70000));
+        let errs = validate_service_spec(&spec, &Path::new("spec"));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(errs.errors[0].field, "spec.ports[0].targetPort");
+        assert_eq!(
+            errs.errors[0].detail,
+            "must be between 1 and 65535, inclusive"
+        );
+    }
+
+    #[test]
+    fn test_validate_service_spec_string_target_port() {
+        let spec = spec_with_target_port(IntOrString::String("http-alt".to_string()));
+        let errs = validate_service_spec(&spec, &Path::new("spec"));
+        assert!(errs.is_empty(), "unexpected errors: {:?}", errs);
+
+        let spec = spec_with_target_port(IntOrString::String("metrics-endpoint".to_string()));
+        let errs = validate_service_spec(&spec, &Path::new("spec"));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(errs.errors[0].field, "spec.ports[0].targetPort");
+        assert_eq!(errs.errors[0].detail, "must be no more than 15 characters");
+    }
+
+    #[test]
+    fn test_validate_service_spec_app_protocol() {
+        let mut spec = spec_with_target_port(IntOrString::Int(8080));
+        spec.ports[0].app_protocol = Some("kubernetes.io/h2c".to_string());
+        assert!(validate_service_spec(&spec, &Path::new("spec")).is_empty());
+
+        spec.ports[0].app_protocol = Some("not a protocol".to_string());
+        let errs = validate_service_spec(&spec, &Path::new("spec"));
+        assert!(!errs.is_empty());
+        assert!(
+            errs.errors
+                .iter()
+                .all(|e| e.field == "spec.ports[0].appProtocol")
+        );
+    }
+}
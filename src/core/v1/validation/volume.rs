@@ -1,12 +1,20 @@
 //! Volume validation for core v1 API.
 
-use crate::common::validation::{ErrorList, Path};
+use crate::common::validation::{BadValue, ErrorList, Path, not_supported};
 use crate::common::{FromInternal, ToInternal};
 use crate::core::internal::validation::volume as internal_volume_validation;
 use crate::core::v1::pod::Container;
-use crate::core::v1::volume::{Volume, VolumeDevice, VolumeMount, VolumeSource};
+use crate::core::v1::volume::{
+    ProjectedVolumeSource, Volume, VolumeDevice, VolumeMount, VolumeSource, mount_propagation_mode,
+};
 use std::collections::HashMap;
 
+const SUPPORTED_MOUNT_PROPAGATION_MODES: [&str; 3] = [
+    mount_propagation_mode::NONE,
+    mount_propagation_mode::HOST_TO_CONTAINER,
+    mount_propagation_mode::BIDIRECTIONAL,
+];
+
 /// Validates a list of volumes.
 ///
 /// Returns a map of volume names to their sources for reference by volume mounts.
@@ -31,6 +39,13 @@ pub fn validate_volume(volume: &Volume, path: &Path) -> ErrorList {
     internal_volume_validation::validate_volume(&internal_volume, path)
 }
 
+/// Validates a projected volume source, rejecting paths that collide across
+/// its configMap, secret, downwardAPI and serviceAccountToken projections.
+pub fn validate_projected_volume(projected: &ProjectedVolumeSource, path: &Path) -> ErrorList {
+    let internal_projected = projected.clone().to_internal();
+    internal_volume_validation::validate_projected_volume_source(&internal_projected, path)
+}
+
 /// Validates volume mounts.
 pub fn validate_volume_mounts(
     mounts: &[VolumeMount],
@@ -39,6 +54,13 @@ pub fn validate_volume_mounts(
     container: &Container,
     path: &Path,
 ) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    for (i, mnt) in mounts.iter().enumerate() {
+        all_errs.extend(validate_mount_propagation_mode(
+            mnt.mount_propagation.as_deref(),
+            &path.index(i).child("mountPropagation"),
+        ));
+    }
     let internal_mounts: Vec<crate::core::internal::VolumeMount> = mounts
         .iter()
         .cloned()
@@ -48,13 +70,48 @@ pub fn validate_volume_mounts(
         .iter()
         .map(|(name, source)| (name.clone(), source.clone().to_internal()))
         .collect();
-    internal_volume_validation::validate_volume_mounts(
+    all_errs.extend(internal_volume_validation::validate_volume_mounts(
         &internal_mounts,
         vol_devices,
         &internal_volumes,
         container,
         path,
-    )
+    ));
+    all_errs
+}
+
+/// Validates a single volume mount of `container`, without checking it
+/// against the pod's volumes or the container's other mounts.
+pub fn validate_volume_mount(mnt: &VolumeMount, container: &Container, path: &Path) -> ErrorList {
+    let mut all_errs = validate_mount_propagation_mode(
+        mnt.mount_propagation.as_deref(),
+        &path.child("mountPropagation"),
+    );
+    let internal_mount = mnt.clone().to_internal();
+    all_errs.extend(internal_volume_validation::validate_volume_mount(
+        &internal_mount,
+        container,
+        path,
+    ));
+    all_errs
+}
+
+/// Validates the mountPropagation mode.
+///
+/// The internal type is an enum, so unknown values are lost in conversion and
+/// must be caught on the versioned string.
+fn validate_mount_propagation_mode(mode: Option<&str>, path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    if let Some(mode) = mode
+        && !SUPPORTED_MOUNT_PROPAGATION_MODES.contains(&mode)
+    {
+        all_errs.push(not_supported(
+            path,
+            BadValue::String(mode.to_string()),
+            &SUPPORTED_MOUNT_PROPAGATION_MODES,
+        ));
+    }
+    all_errs
 }
 
 /// Validates volume devices.
@@ -80,3 +137,210 @@ pub fn validate_volume_devices(
         path,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::validation::ErrorType;
+    use crate::core::v1::volume::{
+        ConfigMapProjection, EmptyDirVolumeSource, KeyToPath, ServiceAccountTokenProjection,
+        VolumeProjection,
+    };
+
+    fn config_map_projection(name: &str, path: &str) -> VolumeProjection {
+        VolumeProjection {
+            config_map: Some(ConfigMapProjection {
+                name: Some(name.to_string()),
+                items: vec![KeyToPath {
+                    key: "data".to_string(),
+                    path: path.to_string(),
+                    mode: None,
+                }],
+                optional: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn token_projection(path: &str) -> VolumeProjection {
+        VolumeProjection {
+            service_account_token: Some(ServiceAccountTokenProjection {
+                audience: "api".to_string(),
+                expiration_seconds: Some(3600),
+                path: path.to_string(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_projected_volume_duplicate_paths() {
+        let projected = ProjectedVolumeSource {
+            sources: vec![
+                config_map_projection("ca", "token"),
+                token_projection("token"),
+            ],
+            default_mode: None,
+        };
+
+        let errs = validate_projected_volume(&projected, &Path::new("projected"));
+        assert_eq!(errs.len(), 1, "{:?}", errs);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(
+            errs.errors[0].field,
+            "projected.sources[1].serviceAccountToken"
+        );
+        assert_eq!(errs.errors[0].detail, "conflicting duplicate paths");
+    }
+
+    #[test]
+    fn test_validate_projected_volume_paths_must_be_relative() {
+        let projected = ProjectedVolumeSource {
+            sources: vec![
+                config_map_projection("ca", "ca.crt"),
+                token_projection("/var/run/token"),
+                token_projection("../token"),
+            ],
+            default_mode: None,
+        };
+
+        let errs = validate_projected_volume(&projected, &Path::new("projected"));
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "projected.sources[1].serviceAccountToken.path",
+                "projected.sources[2].serviceAccountToken.path",
+            ]
+        );
+    }
+
+    fn mount(name: &str, mount_path: &str) -> VolumeMount {
+        VolumeMount {
+            name: name.to_string(),
+            mount_path: mount_path.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn validate_mounts(mounts: &[VolumeMount]) -> ErrorList {
+        let empty_dir = VolumeSource {
+            empty_dir: Some(EmptyDirVolumeSource::default()),
+            ..Default::default()
+        };
+        let volumes = HashMap::from([
+            ("data".to_string(), empty_dir.clone()),
+            ("cache".to_string(), empty_dir),
+        ]);
+        validate_volume_mounts(
+            mounts,
+            &HashMap::new(),
+            &volumes,
+            &Container::default(),
+            &Path::new("volumeMounts"),
+        )
+    }
+
+    #[test]
+    fn test_validate_volume_mounts_duplicate_mount_path() {
+        let errs = validate_mounts(&[
+            mount("data", "/var/lib/app"),
+            mount("cache", "/var/lib/app"),
+        ]);
+        assert_eq!(errs.len(), 1, "{:?}", errs);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(errs.errors[0].field, "volumeMounts[1].mountPath");
+        assert_eq!(errs.errors[0].detail, "must be unique");
+
+        let errs = validate_mounts(&[mount("data", "var/lib/app")]);
+        assert_eq!(errs.len(), 1, "{:?}", errs);
+        assert_eq!(errs.errors[0].field, "volumeMounts[0].mountPath");
+        assert_eq!(errs.errors[0].detail, "must be an absolute path");
+    }
+
+    #[test]
+    fn test_validate_volume_mounts_sub_path() {
+        let mut escaping = mount("data", "/var/lib/app");
+        escaping.sub_path = "logs/../../etc".to_string();
+        let mut both = mount("cache", "/var/cache");
+        both.sub_path = "tmp".to_string();
+        both.sub_path_expr = "$(POD_NAME)".to_string();
+
+        let errs = validate_mounts(&[escaping, both]);
+        let fields: Vec<_> = errs.errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec!["volumeMounts[0].subPath", "volumeMounts[1].subPathExpr"]
+        );
+        assert_eq!(errs.errors[0].detail, "must not contain '..'");
+    }
+
+    #[test]
+    fn test_validate_volume_mount_sub_path_rules() {
+        let container = Container::default();
+        let path = Path::new("containers")
+            .index(0)
+            .child("volumeMounts")
+            .index(0);
+
+        let mut traversal = mount("data", "/var/lib/app");
+        traversal.sub_path = "../secrets".to_string();
+        let errs = validate_volume_mount(&traversal, &container, &path);
+        assert_eq!(errs.len(), 1, "{:?}", errs);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(
+            errs.errors[0].field,
+            "containers[0].volumeMounts[0].subPath"
+        );
+
+        let mut both = mount("data", "/var/lib/app");
+        both.sub_path = "logs".to_string();
+        both.sub_path_expr = "logs/$(POD_NAME)".to_string();
+        let errs = validate_volume_mount(&both, &container, &path);
+        assert_eq!(errs.len(), 1, "{:?}", errs);
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(
+            errs.errors[0].field,
+            "containers[0].volumeMounts[0].subPathExpr"
+        );
+        assert_eq!(
+            errs.errors[0].detail,
+            "subPathExpr and subPath are mutually exclusive"
+        );
+    }
+
+    #[test]
+    fn test_validate_volume_mount_path_and_propagation() {
+        let privileged = Container {
+            security_context: Some(crate::core::v1::SecurityContext {
+                privileged: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let path = Path::new("volumeMounts").index(0);
+
+        let mut mnt = mount("data", "/var/lib/app:rw");
+        mnt.read_only = true;
+        mnt.mount_propagation = Some(mount_propagation_mode::BIDIRECTIONAL.to_string());
+        let errs = validate_volume_mount(&mnt, &privileged, &path);
+        let fields: Vec<_> = errs
+            .errors
+            .iter()
+            .map(|e| (e.field.as_str(), e.error_type))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("volumeMounts[0].mountPath", ErrorType::Invalid),
+                ("volumeMounts[0].mountPropagation", ErrorType::Forbidden),
+            ]
+        );
+
+        let mut windows = mount("data", "C:\\data");
+        windows.mount_propagation = Some("Shared".to_string());
+        let errs = validate_volume_mount(&windows, &privileged, &path);
+        assert_eq!(errs.len(), 1, "{:?}", errs);
+        assert_eq!(errs.errors[0].error_type, ErrorType::NotSupported);
+    }
+}
@@ -147,7 +147,7 @@ pub mod host_path_type {
 }
 
 /// Represents a host path mapped into a pod.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct HostPathVolumeSource {
     /// path of the directory on the host.
@@ -807,7 +807,7 @@ impl ApplyDefault for ImageVolumeSource {
     fn apply_default(&mut self) {
         let pull_policy = self.pull_policy.as_deref().unwrap_or("");
         if pull_policy.is_empty() {
-            let is_latest = image_tag_or_latest(&self.reference) == "latest";
+            let is_latest = crate::common::parse_image_ref(&self.reference).is_latest();
             self.pull_policy = Some(if is_latest {
                 "Always".to_string()
             } else {
@@ -849,17 +849,3 @@ fn volume_source_is_empty(source: &VolumeSource) -> bool {
         && source.ephemeral.is_none()
         && source.image.is_none()
 }
-
-fn image_tag_or_latest(image: &str) -> &str {
-    let (name, _) = image.split_once('@').unwrap_or((image, ""));
-    let last_slash = name.rfind('/');
-    if let Some(colon) = name.rfind(':') {
-        if last_slash.map(|slash| colon > slash).unwrap_or(true) {
-            let tag = &name[colon + 1..];
-            if !tag.is_empty() {
-                return tag;
-            }
-        }
-    }
-    "latest"
-}
@@ -6,7 +6,6 @@ use crate::common::{
     ApplyDefault, HasTypeMeta, ListMeta, ObjectMeta, ResourceSchema, TypeMeta, VersionedObject,
 };
 use crate::core::v1::ObjectReference;
-use crate::impl_unimplemented_prost_message;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::sync::OnceLock;
@@ -329,12 +328,7 @@ impl ApplyDefault for EndpointSliceList {
     }
 }
 
-// ----------------------------------------------------------------------------
-// Protobuf Placeholder (using macro)
-// ----------------------------------------------------------------------------
-
-impl_unimplemented_prost_message!(EndpointSlice);
-impl_unimplemented_prost_message!(EndpointSliceList);
+// Protobuf: see proto.rs
 
 // ============================================================================
 // Tests
@@ -354,6 +348,11 @@ mod conversion;
 #[cfg(test)]
 mod conversion_roundtrip_tests;
 
+mod proto;
+
+#[cfg(test)]
+mod proto_roundtrip_tests;
+
 // AsRefStr / AsRef<str> implementations for enums
 crate::impl_as_str_ref!(AddressType, {
     IPv4 => address_type::IPV4,
@@ -0,0 +1,447 @@
+//! Protobuf encodings for discovery/v1 types
+//!
+//! Field numbers follow k8s.io/api/discovery/v1/generated.proto. TypeMeta is
+//! carried by the runtime envelope, not by the messages themselves.
+
+use prost::bytes::{Buf, BufMut};
+use prost::encoding::{
+    DecodeContext, WireType, bool, btree_map, int32, message, skip_field, string,
+};
+use prost::{DecodeError, Message};
+
+use super::{
+    AddressType, Endpoint, EndpointConditions, EndpointHints, EndpointPort, EndpointSlice,
+    EndpointSliceList, ForNode, ForZone, address_type,
+};
+use crate::common::traits::AsRefStr;
+
+// ============================================================================
+// EndpointSlice
+// ============================================================================
+
+impl Message for EndpointSlice {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref metadata) = self.metadata {
+            message::encode(1, metadata, buf);
+        }
+        message::encode_repeated(2, &self.endpoints, buf);
+        message::encode_repeated(3, &self.ports, buf);
+        string::encode(4, &self.address_type.as_str().to_string(), buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.metadata.get_or_insert_default(), buf, ctx),
+            2 => message::merge_repeated(wire_type, &mut self.endpoints, buf, ctx),
+            3 => message::merge_repeated(wire_type, &mut self.ports, buf, ctx),
+            4 => {
+                let mut value = String::new();
+                string::merge(wire_type, &mut value, buf, ctx)?;
+                // AddressType cannot hold values added by newer servers, so
+                // an unknown one is skipped instead of failing the decode.
+                match value.as_str() {
+                    address_type::IPV4 => self.address_type = AddressType::IPv4,
+                    address_type::IPV6 => self.address_type = AddressType::IPv6,
+                    address_type::FQDN => self.address_type = AddressType::FQDN,
+                    _ => {}
+                }
+                Ok(())
+            }
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.metadata
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + message::encoded_len_repeated(2, &self.endpoints)
+            + message::encoded_len_repeated(3, &self.ports)
+            + string::encoded_len(4, &self.address_type.as_str().to_string())
+    }
+
+    fn clear(&mut self) {
+        *self = EndpointSlice::default();
+    }
+}
+
+impl Message for EndpointSliceList {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref metadata) = self.metadata {
+            message::encode(1, metadata, buf);
+        }
+        message::encode_repeated(2, &self.items, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge(wire_type, self.metadata.get_or_insert_default(), buf, ctx),
+            2 => message::merge_repeated(wire_type, &mut self.items, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.metadata
+            .as_ref()
+            .map_or(0, |v| message::encoded_len(1, v))
+            + message::encoded_len_repeated(2, &self.items)
+    }
+
+    fn clear(&mut self) {
+        *self = EndpointSliceList::default();
+    }
+}
+
+// ============================================================================
+// Endpoint
+// ============================================================================
+
+impl Message for Endpoint {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode_repeated(1, &self.addresses, buf);
+        message::encode(2, &self.conditions, buf);
+        if let Some(ref value) = self.hostname {
+            string::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.target_ref {
+            message::encode(4, value, buf);
+        }
+        btree_map::encode(
+            string::encode,
+            string::encoded_len,
+            string::encode,
+            string::encoded_len,
+            5,
+            &self.deprecated_topology,
+            buf,
+        );
+        if let Some(ref value) = self.node_name {
+            string::encode(6, value, buf);
+        }
+        if let Some(ref value) = self.zone {
+            string::encode(7, value, buf);
+        }
+        if let Some(ref value) = self.hints {
+            message::encode(8, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge_repeated(wire_type, &mut self.addresses, buf, ctx),
+            2 => message::merge(wire_type, &mut self.conditions, buf, ctx),
+            3 => string::merge(wire_type, self.hostname.get_or_insert_default(), buf, ctx),
+            4 => message::merge(wire_type, self.target_ref.get_or_insert_default(), buf, ctx),
+            5 => btree_map::merge(
+                string::merge,
+                string::merge,
+                &mut self.deprecated_topology,
+                buf,
+                ctx,
+            ),
+            6 => string::merge(wire_type, self.node_name.get_or_insert_default(), buf, ctx),
+            7 => string::merge(wire_type, self.zone.get_or_insert_default(), buf, ctx),
+            8 => message::merge(wire_type, self.hints.get_or_insert_default(), buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len_repeated(1, &self.addresses)
+            + message::encoded_len(2, &self.conditions)
+            + self
+                .hostname
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(3, v))
+            + self
+                .target_ref
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(4, v))
+            + btree_map::encoded_len(
+                string::encoded_len,
+                string::encoded_len,
+                5,
+                &self.deprecated_topology,
+            )
+            + self
+                .node_name
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(6, v))
+            + self.zone.as_ref().map_or(0, |v| string::encoded_len(7, v))
+            + self
+                .hints
+                .as_ref()
+                .map_or(0, |v| message::encoded_len(8, v))
+    }
+
+    fn clear(&mut self) {
+        *self = Endpoint::default();
+    }
+}
+
+// ============================================================================
+// EndpointConditions
+// ============================================================================
+
+// Each condition is tri-state: unset (unknown), true or false. An explicit
+// `false` is still written so that it is not decoded as unknown.
+impl Message for EndpointConditions {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.ready {
+            bool::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.serving {
+            bool::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.terminating {
+            bool::encode(3, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => bool::merge(wire_type, self.ready.get_or_insert_default(), buf, ctx),
+            2 => bool::merge(wire_type, self.serving.get_or_insert_default(), buf, ctx),
+            3 => bool::merge(
+                wire_type,
+                self.terminating.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.ready.as_ref().map_or(0, |v| bool::encoded_len(1, v))
+            + self.serving.as_ref().map_or(0, |v| bool::encoded_len(2, v))
+            + self
+                .terminating
+                .as_ref()
+                .map_or(0, |v| bool::encoded_len(3, v))
+    }
+
+    fn clear(&mut self) {
+        *self = EndpointConditions::default();
+    }
+}
+
+// ============================================================================
+// EndpointHints
+// ============================================================================
+
+impl Message for EndpointHints {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        message::encode_repeated(1, &self.for_zones, buf);
+        message::encode_repeated(2, &self.for_nodes, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => message::merge_repeated(wire_type, &mut self.for_zones, buf, ctx),
+            2 => message::merge_repeated(wire_type, &mut self.for_nodes, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len_repeated(1, &self.for_zones)
+            + message::encoded_len_repeated(2, &self.for_nodes)
+    }
+
+    fn clear(&mut self) {
+        *self = EndpointHints::default();
+    }
+}
+
+impl Message for ForZone {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name)
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+    }
+}
+
+impl Message for ForNode {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        string::encode(1, &self.name, buf);
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, &mut self.name, buf, ctx),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        string::encoded_len(1, &self.name)
+    }
+
+    fn clear(&mut self) {
+        self.name.clear();
+    }
+}
+
+// ============================================================================
+// EndpointPort
+// ============================================================================
+
+impl Message for EndpointPort {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        if let Some(ref value) = self.name {
+            string::encode(1, value, buf);
+        }
+        if let Some(ref value) = self.protocol {
+            string::encode(2, value, buf);
+        }
+        if let Some(ref value) = self.port {
+            int32::encode(3, value, buf);
+        }
+        if let Some(ref value) = self.app_protocol {
+            string::encode(4, value, buf);
+        }
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        match tag {
+            1 => string::merge(wire_type, self.name.get_or_insert_default(), buf, ctx),
+            2 => string::merge(wire_type, self.protocol.get_or_insert_default(), buf, ctx),
+            3 => int32::merge(wire_type, self.port.get_or_insert_default(), buf, ctx),
+            4 => string::merge(
+                wire_type,
+                self.app_protocol.get_or_insert_default(),
+                buf,
+                ctx,
+            ),
+            _ => skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.name.as_ref().map_or(0, |v| string::encoded_len(1, v))
+            + self
+                .protocol
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(2, v))
+            + self.port.as_ref().map_or(0, |v| int32::encoded_len(3, v))
+            + self
+                .app_protocol
+                .as_ref()
+                .map_or(0, |v| string::encoded_len(4, v))
+    }
+
+    fn clear(&mut self) {
+        *self = EndpointPort::default();
+    }
+}
@@ -0,0 +1,105 @@
+use super::{
+    AddressType, Endpoint, EndpointConditions, EndpointPort, EndpointSlice, EndpointSliceList,
+};
+use crate::common::{ListMeta, ObjectMeta};
+use crate::core::v1::ObjectReference;
+use prost::Message;
+use std::collections::BTreeMap;
+
+fn terminating_endpoint_slice() -> EndpointSlice {
+    EndpointSlice {
+        metadata: Some(ObjectMeta {
+            name: Some("example-abc".to_string()),
+            namespace: Some("default".to_string()),
+            labels: BTreeMap::from([(
+                "kubernetes.io/service-name".to_string(),
+                "example".to_string(),
+            )]),
+            ..Default::default()
+        }),
+        address_type: AddressType::IPv4,
+        endpoints: vec![Endpoint {
+            addresses: vec!["10.0.0.5".to_string()],
+            conditions: EndpointConditions {
+                ready: Some(false),
+                serving: Some(true),
+                terminating: Some(true),
+            },
+            hostname: Some("pod-a".to_string()),
+            target_ref: Some(ObjectReference {
+                kind: Some("Pod".to_string()),
+                namespace: Some("default".to_string()),
+                name: Some("pod-a".to_string()),
+                uid: Some("uid-a".to_string()),
+                ..Default::default()
+            }),
+            deprecated_topology: BTreeMap::from([(
+                "kubernetes.io/hostname".to_string(),
+                "node-a".to_string(),
+            )]),
+            node_name: Some("node-a".to_string()),
+            zone: Some("us-east-1a".to_string()),
+            hints: None,
+        }],
+        ports: vec![EndpointPort {
+            name: Some("http".to_string()),
+            protocol: Some("TCP".to_string()),
+            port: Some(8080),
+            app_protocol: None,
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn proto_roundtrip_endpoint_slice_terminating() {
+    let slice = terminating_endpoint_slice();
+    let bytes = slice.encode_to_vec();
+    assert_eq!(bytes.len(), slice.encoded_len());
+
+    let decoded = EndpointSlice::decode(bytes.as_slice()).expect("decode EndpointSlice");
+    assert_eq!(decoded, slice);
+}
+
+#[test]
+fn proto_endpoint_conditions_preserve_presence() {
+    let unknown = EndpointConditions::default();
+    let decoded = EndpointConditions::decode(unknown.encode_to_vec().as_slice()).unwrap();
+    assert_eq!(decoded, unknown);
+
+    let not_ready = EndpointConditions {
+        ready: Some(false),
+        ..Default::default()
+    };
+    let decoded = EndpointConditions::decode(not_ready.encode_to_vec().as_slice()).unwrap();
+    assert_eq!(decoded.ready, Some(false));
+    assert_eq!(decoded.serving, None);
+}
+
+#[test]
+fn proto_roundtrip_endpoint_slice_list() {
+    let list = EndpointSliceList {
+        metadata: Some(ListMeta {
+            resource_version: Some("42".to_string()),
+            ..Default::default()
+        }),
+        items: vec![terminating_endpoint_slice()],
+        ..Default::default()
+    };
+
+    let decoded = EndpointSliceList::decode(list.encode_to_vec().as_slice()).unwrap();
+    assert_eq!(decoded, list);
+}
+
+#[test]
+fn proto_decode_endpoint_slice_unknown_address_type() {
+    let mut bytes = EndpointSlice {
+        address_type: AddressType::FQDN,
+        ..Default::default()
+    }
+    .encode_to_vec();
+    prost::encoding::string::encode(4, &"IPv8".to_string(), &mut bytes);
+
+    let decoded = EndpointSlice::decode(bytes.as_slice()).expect("decode EndpointSlice");
+    assert_eq!(decoded.address_type, AddressType::FQDN);
+}
@@ -0,0 +1,243 @@
+//! Conversions between v1beta1 and internal discovery types
+//!
+//! Based on k8s.io/kubernetes/pkg/apis/discovery/v1beta1/conversion.go
+//!
+//! v1beta1 carries endpoint topology as a single label map, while v1 and the
+//! internal representation split it into `nodeName`, `zone` and a residual
+//! `deprecatedTopology` map. Converting v1beta1 -> v1 goes through the internal
+//! types: `v1::EndpointSlice::from_internal(v1beta1_slice.to_internal())`.
+
+use crate::common::{FromInternal, ToInternal, TypeMeta};
+use crate::core::internal::Protocol;
+use crate::core::v1::ObjectReference;
+use crate::core::v1::conversion::{
+    meta_to_option_object_meta, option_object_meta_to_meta, protocol_to_option_string,
+};
+use crate::discovery::internal;
+use std::collections::BTreeMap;
+
+use super::{
+    AddressType, Endpoint, EndpointConditions, EndpointHints, EndpointPort, EndpointSlice,
+    EndpointSliceList, ForZone, topology_key,
+};
+
+// ============================================================================
+// Conversion Helper Functions
+// ============================================================================
+
+fn option_string_to_option_protocol(s: Option<String>) -> Option<Protocol> {
+    match s.as_deref() {
+        Some("TCP") => Some(Protocol::Tcp),
+        Some("UDP") => Some(Protocol::Udp),
+        Some("SCTP") => Some(Protocol::Sctp),
+        Some(_) => Some(Protocol::default()),
+        None => None,
+    }
+}
+
+fn address_type_to_internal(value: AddressType) -> internal::AddressType {
+    match value {
+        AddressType::IPv4 => internal::AddressType::IPv4,
+        AddressType::IPv6 => internal::AddressType::IPv6,
+        AddressType::FQDN => internal::AddressType::FQDN,
+    }
+}
+
+fn address_type_from_internal(value: internal::AddressType) -> AddressType {
+    match value {
+        internal::AddressType::IPv4 => AddressType::IPv4,
+        internal::AddressType::IPv6 => AddressType::IPv6,
+        internal::AddressType::FQDN => AddressType::FQDN,
+    }
+}
+
+fn endpoint_conditions_to_internal(value: EndpointConditions) -> internal::EndpointConditions {
+    internal::EndpointConditions {
+        ready: value.ready,
+        serving: value.serving,
+        terminating: value.terminating,
+    }
+}
+
+fn endpoint_conditions_from_internal(value: internal::EndpointConditions) -> EndpointConditions {
+    EndpointConditions {
+        ready: value.ready,
+        serving: value.serving,
+        terminating: value.terminating,
+    }
+}
+
+fn endpoint_hints_to_internal(value: EndpointHints) -> internal::EndpointHints {
+    internal::EndpointHints {
+        for_zones: value
+            .for_zones
+            .into_iter()
+            .map(|zone| internal::ForZone { name: zone.name })
+            .collect(),
+        for_nodes: Vec::new(),
+    }
+}
+
+fn endpoint_hints_from_internal(value: internal::EndpointHints) -> EndpointHints {
+    // forNodes has no v1beta1 equivalent and is dropped.
+    EndpointHints {
+        for_zones: value
+            .for_zones
+            .into_iter()
+            .map(|zone| ForZone { name: zone.name })
+            .collect(),
+    }
+}
+
+fn endpoint_port_to_internal(value: EndpointPort) -> internal::EndpointPort {
+    internal::EndpointPort {
+        name: value.name,
+        protocol: option_string_to_option_protocol(value.protocol),
+        port: value.port,
+        app_protocol: value.app_protocol,
+    }
+}
+
+fn endpoint_port_from_internal(value: internal::EndpointPort) -> EndpointPort {
+    EndpointPort {
+        name: value.name,
+        protocol: value.protocol.and_then(protocol_to_option_string),
+        port: value.port,
+        app_protocol: value.app_protocol,
+    }
+}
+
+// ============================================================================
+// Endpoint Topology Split
+// ============================================================================
+
+fn endpoint_to_internal(value: Endpoint) -> internal::Endpoint {
+    let mut deprecated_topology = value.topology;
+
+    // The zone label always moves into the dedicated field.
+    let zone = deprecated_topology.remove(topology_key::ZONE);
+
+    // The hostname label becomes nodeName. When v1beta1 also sets nodeName to a
+    // different value, both are kept so the original object can be restored.
+    let node_name = match (
+        value.node_name,
+        deprecated_topology.get(topology_key::HOSTNAME),
+    ) {
+        (Some(node_name), Some(hostname)) if *hostname != node_name => Some(node_name),
+        (node_name, _) => deprecated_topology
+            .remove(topology_key::HOSTNAME)
+            .or(node_name),
+    };
+
+    internal::Endpoint {
+        addresses: value.addresses,
+        conditions: endpoint_conditions_to_internal(value.conditions),
+        hostname: value.hostname,
+        target_ref: value.target_ref.map(ObjectReference::to_internal),
+        deprecated_topology,
+        node_name,
+        zone,
+        hints: value.hints.map(endpoint_hints_to_internal),
+    }
+}
+
+fn endpoint_from_internal(value: internal::Endpoint) -> Endpoint {
+    let mut topology = BTreeMap::new();
+    if !value.deprecated_topology.is_empty() || value.zone.is_some() || value.node_name.is_some() {
+        topology.extend(value.deprecated_topology);
+        if let Some(zone) = value.zone {
+            topology.insert(topology_key::ZONE.to_string(), zone);
+        }
+        if let Some(ref node_name) = value.node_name {
+            topology
+                .entry(topology_key::HOSTNAME.to_string())
+                .or_insert_with(|| node_name.clone());
+        }
+    }
+
+    Endpoint {
+        addresses: value.addresses,
+        conditions: endpoint_conditions_from_internal(value.conditions),
+        hostname: value.hostname,
+        target_ref: value.target_ref.map(ObjectReference::from_internal),
+        topology,
+        node_name: value.node_name,
+        hints: value.hints.map(endpoint_hints_from_internal),
+    }
+}
+
+// ============================================================================
+// EndpointSlice Conversions
+// ============================================================================
+
+impl ToInternal<internal::EndpointSlice> for EndpointSlice {
+    fn to_internal(self) -> internal::EndpointSlice {
+        internal::EndpointSlice {
+            type_meta: TypeMeta::default(),
+            metadata: option_object_meta_to_meta(self.metadata),
+            address_type: address_type_to_internal(self.address_type),
+            endpoints: self
+                .endpoints
+                .into_iter()
+                .map(endpoint_to_internal)
+                .collect(),
+            ports: self
+                .ports
+                .into_iter()
+                .map(endpoint_port_to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::EndpointSlice> for EndpointSlice {
+    fn from_internal(value: internal::EndpointSlice) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: meta_to_option_object_meta(value.metadata),
+            address_type: address_type_from_internal(value.address_type),
+            endpoints: value
+                .endpoints
+                .into_iter()
+                .map(endpoint_from_internal)
+                .collect(),
+            ports: value
+                .ports
+                .into_iter()
+                .map(endpoint_port_from_internal)
+                .collect(),
+        }
+    }
+}
+
+// ============================================================================
+// EndpointSliceList Conversions
+// ============================================================================
+
+impl ToInternal<internal::EndpointSliceList> for EndpointSliceList {
+    fn to_internal(self) -> internal::EndpointSliceList {
+        internal::EndpointSliceList {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            items: self
+                .items
+                .into_iter()
+                .map(EndpointSlice::to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::EndpointSliceList> for EndpointSliceList {
+    fn from_internal(value: internal::EndpointSliceList) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            items: value
+                .items
+                .into_iter()
+                .map(EndpointSlice::from_internal)
+                .collect(),
+        }
+    }
+}
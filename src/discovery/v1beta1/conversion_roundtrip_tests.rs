@@ -0,0 +1,95 @@
+use super::{
+    AddressType, Endpoint, EndpointConditions, EndpointHints, EndpointPort, EndpointSlice, ForZone,
+    topology_key,
+};
+use crate::common::test_utils::assert_conversion_roundtrip;
+use crate::common::{FromInternal, ObjectMeta, ToInternal, TypeMeta};
+use crate::discovery::{internal, v1};
+use std::collections::BTreeMap;
+
+fn endpoint_slice_with_topology() -> EndpointSlice {
+    EndpointSlice {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta {
+            name: Some("example-abc".to_string()),
+            namespace: Some("default".to_string()),
+            ..Default::default()
+        }),
+        address_type: AddressType::IPv4,
+        endpoints: vec![Endpoint {
+            addresses: vec!["10.1.2.3".to_string()],
+            conditions: EndpointConditions {
+                ready: Some(true),
+                serving: Some(true),
+                terminating: Some(false),
+            },
+            hostname: None,
+            target_ref: None,
+            topology: BTreeMap::from([
+                (topology_key::HOSTNAME.to_string(), "node-1".to_string()),
+                (topology_key::ZONE.to_string(), "us-west-2a".to_string()),
+                ("example.com/rack".to_string(), "rack-7".to_string()),
+            ]),
+            node_name: Some("node-1".to_string()),
+            hints: Some(EndpointHints {
+                for_zones: vec![ForZone {
+                    name: "us-west-2a".to_string(),
+                }],
+            }),
+        }],
+        ports: vec![EndpointPort {
+            name: Some("http".to_string()),
+            protocol: Some("TCP".to_string()),
+            port: Some(80),
+            app_protocol: None,
+        }],
+    }
+}
+
+#[test]
+fn conversion_roundtrip_endpoint_slice() {
+    assert_conversion_roundtrip::<EndpointSlice, internal::EndpointSlice>(
+        endpoint_slice_with_topology(),
+    );
+}
+
+#[test]
+fn topology_splits_into_node_name_and_zone() {
+    let mut slice = endpoint_slice_with_topology();
+    slice.endpoints[0].node_name = None;
+
+    let v1_slice = v1::EndpointSlice::from_internal(slice.to_internal());
+    let endpoint = &v1_slice.endpoints[0];
+
+    assert_eq!(endpoint.node_name.as_deref(), Some("node-1"));
+    assert_eq!(endpoint.zone.as_deref(), Some("us-west-2a"));
+    assert_eq!(
+        endpoint.deprecated_topology,
+        BTreeMap::from([("example.com/rack".to_string(), "rack-7".to_string())])
+    );
+}
+
+#[test]
+fn node_name_and_zone_rejoin_topology() {
+    let v1_slice = v1::EndpointSlice {
+        endpoints: vec![v1::Endpoint {
+            addresses: vec!["10.1.2.3".to_string()],
+            node_name: Some("node-1".to_string()),
+            zone: Some("us-west-2a".to_string()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let slice = EndpointSlice::from_internal(v1_slice.to_internal());
+    let endpoint = &slice.endpoints[0];
+
+    assert_eq!(endpoint.node_name.as_deref(), Some("node-1"));
+    assert_eq!(
+        endpoint.topology,
+        BTreeMap::from([
+            (topology_key::HOSTNAME.to_string(), "node-1".to_string()),
+            (topology_key::ZONE.to_string(), "us-west-2a".to_string()),
+        ])
+    );
+}
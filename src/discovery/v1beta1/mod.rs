@@ -0,0 +1,272 @@
+//! Kubernetes Discovery v1beta1 API types
+//!
+//! This module contains types from the Kubernetes discovery.k8s.io/v1beta1 API group.
+//!
+//! Source: https://github.com/kubernetes/api/blob/master/discovery/v1beta1/types.go
+
+use crate::common::{
+    ApplyDefault, HasTypeMeta, ListMeta, ObjectMeta, ResourceSchema, TypeMeta, VersionedObject,
+};
+use crate::core::v1::ObjectReference;
+use crate::impl_unimplemented_prost_message;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+pub use crate::discovery::v1::{AddressType, EndpointConditions, EndpointPort, ForZone};
+
+pub mod conversion;
+
+// ============================================================================
+// EndpointSlice
+// ============================================================================
+
+/// EndpointSlice represents a subset of the endpoints that implement a service.
+///
+/// Corresponds to [Kubernetes EndpointSlice](https://github.com/kubernetes/api/blob/master/discovery/v1beta1/types.go#L29)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointSlice {
+    /// Standard type metadata.
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard object's metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ObjectMeta>,
+    /// addressType specifies the type of address carried by this EndpointSlice.
+    #[serde(default)]
+    pub address_type: AddressType,
+    /// endpoints is a list of unique endpoints in this slice.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub endpoints: Vec<Endpoint>,
+    /// ports specifies the list of network ports exposed by each endpoint.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub ports: Vec<EndpointPort>,
+}
+
+/// EndpointSliceList represents a list of endpoint slices.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointSliceList {
+    /// Standard type metadata.
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard list metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMeta>,
+    /// items is the list of endpoint slices.
+    #[serde(default)]
+    pub items: Vec<EndpointSlice>,
+}
+
+// ============================================================================
+// Endpoint
+// ============================================================================
+
+/// Endpoint represents a single logical "backend" implementing a service.
+///
+/// Unlike v1, topology is carried as a single label map. The hostname and zone
+/// keys are split into `nodeName` and `zone` when converting to other versions.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Endpoint {
+    /// addresses of this endpoint.
+    #[serde(default)]
+    pub addresses: Vec<String>,
+    /// conditions contains information about the current status of the endpoint.
+    #[serde(default)]
+    pub conditions: EndpointConditions,
+    /// hostname of this endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    /// targetRef is a reference to a Kubernetes object that represents this endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_ref: Option<ObjectReference>,
+    /// topology contains arbitrary topology information associated with the endpoint.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub topology: BTreeMap<String, String>,
+    /// nodeName represents the name of the Node hosting this endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_name: Option<String>,
+    /// hints contains information associated with how an endpoint should be consumed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hints: Option<EndpointHints>,
+}
+
+/// EndpointHints provides hints describing how an endpoint should be consumed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointHints {
+    /// forZones indicates the zone(s) this endpoint should be consumed by.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub for_zones: Vec<ForZone>,
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Well-known topology keys split out of `Endpoint.topology`.
+pub mod topology_key {
+    /// Maps to `nodeName` in v1.
+    pub const HOSTNAME: &str = "kubernetes.io/hostname";
+    /// Maps to `zone` in v1.
+    pub const ZONE: &str = "topology.kubernetes.io/zone";
+}
+
+// ============================================================================
+// Trait Implementations for EndpointSlice and EndpointSliceList
+// ============================================================================
+
+// ----------------------------------------------------------------------------
+// ResourceSchema Implementation
+// ----------------------------------------------------------------------------
+
+impl ResourceSchema for EndpointSlice {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "discovery.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "EndpointSlice"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "endpointslices"
+    }
+
+    fn group_static() -> &'static str {
+        "discovery.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "EndpointSlice"
+    }
+    fn resource_static() -> &'static str {
+        "endpointslices"
+    }
+}
+
+impl ResourceSchema for EndpointSliceList {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "discovery.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "EndpointSliceList"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "endpointslices"
+    }
+
+    fn group_static() -> &'static str {
+        "discovery.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "EndpointSliceList"
+    }
+    fn resource_static() -> &'static str {
+        "endpointslices"
+    }
+}
+
+// ----------------------------------------------------------------------------
+// HasTypeMeta Implementation
+// ----------------------------------------------------------------------------
+
+impl HasTypeMeta for EndpointSlice {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for EndpointSliceList {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+// ----------------------------------------------------------------------------
+// VersionedObject Implementation
+// ----------------------------------------------------------------------------
+
+impl VersionedObject for EndpointSlice {
+    fn metadata(&self) -> &ObjectMeta {
+        self.metadata
+            .as_ref()
+            .unwrap_or_else(|| static_default_object_meta())
+    }
+
+    fn metadata_mut(&mut self) -> &mut ObjectMeta {
+        self.metadata.get_or_insert_with(ObjectMeta::default)
+    }
+}
+
+// Helper function for static default ObjectMeta
+fn static_default_object_meta() -> &'static ObjectMeta {
+    static DEFAULT: OnceLock<ObjectMeta> = OnceLock::new();
+    DEFAULT.get_or_init(ObjectMeta::default)
+}
+
+// ----------------------------------------------------------------------------
+// ApplyDefaults Implementation
+// ----------------------------------------------------------------------------
+
+impl ApplyDefault for EndpointSlice {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "discovery.k8s.io/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "EndpointSlice".to_string();
+        }
+    }
+}
+
+impl ApplyDefault for EndpointSliceList {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "discovery.k8s.io/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "EndpointSliceList".to_string();
+        }
+        for item in &mut self.items {
+            item.apply_default();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Protobuf Placeholder (using macro)
+// ----------------------------------------------------------------------------
+
+impl_unimplemented_prost_message!(EndpointSlice);
+impl_unimplemented_prost_message!(EndpointSliceList);
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod conversion_roundtrip_tests;
+#[cfg(test)]
+mod trait_tests;
@@ -7,6 +7,9 @@ use crate::common::validation::{
     BadValue, ErrorList, Path, invalid, is_dns1123_subdomain, is_qualified_name,
     name_is_dns_subdomain, required, validate_object_meta,
 };
+use crate::core::internal::validation::events::{
+    self as core_events_validation, EVENT_REQUEST_VERSION_EVENTS_V1,
+};
 use crate::events::internal::{Event, EventList, EventSeries};
 
 // ============================================================================
@@ -226,6 +229,30 @@ fn validate_event_with_path(event: &Event, base_path: &Path) -> ErrorList {
     all_errs
 }
 
+/// Validates an Event update.
+///
+/// Delegates to the core events validator for events.k8s.io/v1 requests and
+/// additionally rejects a decreasing `series.count`.
+pub fn validate_event_update(new_event: &Event, old_event: &Event) -> ErrorList {
+    let mut all_errs = core_events_validation::validate_event_update(
+        new_event,
+        old_event,
+        EVENT_REQUEST_VERSION_EVENTS_V1,
+    );
+
+    let new_count = new_event.series.as_ref().map_or(0, |series| series.count);
+    let old_count = old_event.series.as_ref().map_or(0, |series| series.count);
+    if new_count < old_count {
+        all_errs.push(invalid(
+            &Path::new("series").child("count"),
+            BadValue::Int(new_count as i64),
+            &format!("must not decrease (was {})", old_count),
+        ));
+    }
+
+    all_errs
+}
+
 /// Validates EventSeries
 fn validate_event_series(series: &EventSeries, base_path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
@@ -140,7 +140,7 @@ pub struct EventList {
     pub metadata: Option<ListMeta>,
 
     /// items is a list of schema objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Event>,
 }
 
@@ -18,8 +18,111 @@ pub fn validate_event(event: &Event) -> ErrorList {
     internal::validation::validate_event(&internal_event)
 }
 
+/// Validates an update to a v1 Event by converting both to internal.
+pub fn validate_event_update(new: &Event, old: &Event) -> ErrorList {
+    let internal_new = new.clone().to_internal();
+    let internal_old = old.clone().to_internal();
+    internal::validation::validate_event_update(&internal_new, &internal_old)
+}
+
+impl Event {
+    /// Validates this Event as an update of `old`.
+    ///
+    /// See [`validate_event_update`].
+    pub fn validate_update(&self, old: &Event) -> ErrorList {
+        validate_event_update(self, old)
+    }
+}
+
 /// Validates a v1 EventList by converting to internal and delegating validation.
 pub fn validate_event_list(list: &EventList) -> ErrorList {
     let internal_list = list.clone().to_internal();
     internal::validation::validate_event_list(&internal_list)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::validation::ErrorType;
+    use crate::common::{MicroTime, ObjectMeta};
+    use crate::core::v1::ObjectReference;
+    use crate::events::v1::EventSeries;
+
+    fn event() -> Event {
+        Event {
+            metadata: Some(ObjectMeta {
+                name: Some("web-0.17a2b3c4".to_string()),
+                namespace: Some("default".to_string()),
+                resource_version: Some("10".to_string()),
+                ..Default::default()
+            }),
+            event_time: MicroTime::from_str("2024-01-15T10:00:00.123456Z").unwrap(),
+            series: Some(EventSeries {
+                count: 2,
+                last_observed_time: MicroTime::from_str("2024-01-15T10:01:00Z").unwrap(),
+            }),
+            reporting_controller: "k8s.io/kubelet".to_string(),
+            reporting_instance: "kubelet-node-a".to_string(),
+            action: "Pulling".to_string(),
+            reason: "BackOff".to_string(),
+            regarding: ObjectReference {
+                kind: Some("Pod".to_string()),
+                namespace: Some("default".to_string()),
+                name: Some("web-0".to_string()),
+                ..Default::default()
+            },
+            type_: "Warning".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_update_series_count_bump() {
+        let old = event();
+        let mut new = old.clone();
+        new.series = Some(EventSeries {
+            count: 3,
+            last_observed_time: MicroTime::from_str("2024-01-15T10:02:00Z").unwrap(),
+        });
+
+        let errs = new.validate_update(&old);
+        assert!(errs.is_empty(), "unexpected errors: {errs:?}");
+
+        let errs = old.validate_update(&new);
+        assert_eq!(errs.len(), 1, "{errs:?}");
+        assert_eq!(errs.errors[0].field, "series.count");
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+    }
+
+    #[test]
+    fn test_validate_update_regarding_is_immutable() {
+        let old = event();
+        let mut new = old.clone();
+        new.regarding.name = Some("web-1".to_string());
+
+        let errs = new.validate_update(&old);
+        assert_eq!(errs.len(), 1, "{errs:?}");
+        assert_eq!(errs.errors[0].field, "involvedObject");
+        assert_eq!(errs.errors[0].error_type, ErrorType::Forbidden);
+    }
+
+    #[test]
+    fn test_validate_update_descriptive_fields_are_immutable() {
+        let old = event();
+        let mut new = old.clone();
+        new.reason = "Pulled".to_string();
+        new.action = "Started".to_string();
+        new.type_ = "Normal".to_string();
+        new.note = "pulled image".to_string();
+        new.reporting_instance = "kubelet-node-b".to_string();
+
+        let errs = new.validate_update(&old);
+        let mut fields: Vec<_> = errs.errors.iter().map(|e| e.field.as_str()).collect();
+        fields.sort_unstable();
+        assert_eq!(
+            fields,
+            ["action", "message", "reason", "reportingInstance", "type"],
+            "{errs:?}"
+        );
+    }
+}
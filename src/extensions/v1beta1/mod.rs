@@ -290,7 +290,7 @@ pub struct DeploymentList {
     pub metadata: Option<ListMeta>,
 
     /// Items is the list of Deployments.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Deployment>,
 }
 
@@ -494,7 +494,7 @@ pub struct DaemonSetList {
     pub metadata: Option<ListMeta>,
 
     /// A list of daemon sets.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<DaemonSet>,
 }
 
@@ -629,7 +629,7 @@ pub struct ReplicaSetList {
     pub metadata: Option<ListMeta>,
 
     /// List of ReplicaSets.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ReplicaSet>,
 }
 
@@ -831,7 +831,7 @@ pub struct IngressList {
     pub metadata: Option<ListMeta>,
 
     /// Items is the list of Ingress.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Ingress>,
 }
 
@@ -978,7 +978,7 @@ pub struct NetworkPolicyList {
     pub metadata: Option<ListMeta>,
 
     /// Items is a list of schema objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<NetworkPolicy>,
 }
 
@@ -1,4 +1,5 @@
 use super::*;
+use crate::common::validation::{ErrorType, Path};
 use crate::common::{ObjectMeta, TypeMeta};
 use crate::flowcontrol::v1 as flowcontrol;
 
@@ -40,3 +41,43 @@ fn test_validate_priority_level_configuration_requires_type() {
     let errors = validate_priority_level_configuration(&plc);
     assert!(!errors.is_empty(), "expected validation errors");
 }
+
+#[test]
+fn test_validate_priority_level_configuration_limited_requires_queuing() {
+    let spec = flowcontrol::PriorityLevelConfigurationSpec {
+        r#type: Some(flowcontrol::PriorityLevelEnablement::Limited),
+        limited: Some(flowcontrol::LimitedPriorityLevelConfiguration {
+            nominal_concurrency_shares: Some(30),
+            limit_response: Some(flowcontrol::LimitResponse {
+                r#type: flowcontrol::LimitResponseType::Queue,
+                queuing: None,
+            }),
+            ..Default::default()
+        }),
+        exempt: None,
+    };
+
+    let errors = validate_priority_level_configuration_spec(&spec, "workload", &Path::new("spec"));
+    assert_eq!(errors.len(), 1, "unexpected errors: {:?}", errors);
+    assert_eq!(errors.errors[0].error_type, ErrorType::Required);
+    assert_eq!(errors.errors[0].field, "spec.limited.limitResponse.queuing");
+}
+
+#[test]
+fn test_validate_priority_level_configuration_invalid_type() {
+    // Only the reserved "exempt" level may use the Exempt type.
+    let spec = flowcontrol::PriorityLevelConfigurationSpec {
+        r#type: Some(flowcontrol::PriorityLevelEnablement::Exempt),
+        ..Default::default()
+    };
+    let errors = validate_priority_level_configuration_spec(&spec, "workload", &Path::new("spec"));
+    assert_eq!(errors.len(), 1, "unexpected errors: {:?}", errors);
+    assert_eq!(errors.errors[0].error_type, ErrorType::Invalid);
+    assert_eq!(errors.errors[0].field, "spec.type");
+
+    // Unknown types are rejected when decoding.
+    let decoded = serde_json::from_str::<flowcontrol::PriorityLevelConfigurationSpec>(
+        r#"{"type": "Unlimited"}"#,
+    );
+    assert!(decoded.is_err());
+}
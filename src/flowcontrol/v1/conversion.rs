@@ -0,0 +1,654 @@
+//! Conversions between flowcontrol v1 and internal types.
+//!
+//! Source: k8s.io/kubernetes/pkg/apis/flowcontrol/v1/zz_generated.conversion.go
+
+use crate::common::{FromInternal, ObjectMeta, Timestamp, ToInternal, TypeMeta};
+use crate::flowcontrol::internal;
+
+use super::{
+    ConditionStatus, ExemptPriorityLevelConfiguration, FlowDistinguisherMethod,
+    FlowDistinguisherMethodType, FlowSchema, FlowSchemaCondition, FlowSchemaConditionType,
+    FlowSchemaList, FlowSchemaSpec, FlowSchemaStatus, GroupSubject, LimitResponse,
+    LimitResponseType, LimitedPriorityLevelConfiguration, NonResourcePolicyRule,
+    PolicyRulesWithSubjects, PriorityLevelConfiguration, PriorityLevelConfigurationCondition,
+    PriorityLevelConfigurationConditionType, PriorityLevelConfigurationList,
+    PriorityLevelConfigurationReference, PriorityLevelConfigurationSpec,
+    PriorityLevelConfigurationStatus, PriorityLevelEnablement, QueuingConfiguration,
+    ResourcePolicyRule, ServiceAccountSubject, Subject, SubjectKind, UserSubject,
+};
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn option_object_meta_to_meta(meta: Option<ObjectMeta>) -> ObjectMeta {
+    meta.unwrap_or_default()
+}
+
+fn meta_to_option_object_meta(meta: ObjectMeta) -> Option<ObjectMeta> {
+    if meta.is_empty() { None } else { Some(meta) }
+}
+
+fn option_i32_to_i32(value: Option<i32>) -> i32 {
+    value.unwrap_or_default()
+}
+
+fn i32_to_option_i32(value: i32) -> Option<i32> {
+    if value == 0 { None } else { Some(value) }
+}
+
+fn option_string_to_timestamp(value: Option<String>) -> Timestamp {
+    value
+        .and_then(|s| Timestamp::from_str(&s).ok())
+        .unwrap_or_else(Timestamp::zero)
+}
+
+fn timestamp_to_option_string(value: Timestamp) -> Option<String> {
+    if value.is_zero() {
+        None
+    } else {
+        Some(value.to_rfc3339())
+    }
+}
+
+// ============================================================================
+// Enum Conversions
+// ============================================================================
+
+impl From<FlowDistinguisherMethodType> for internal::FlowDistinguisherMethodType {
+    fn from(value: FlowDistinguisherMethodType) -> Self {
+        match value {
+            FlowDistinguisherMethodType::ByUser => internal::FlowDistinguisherMethodType::ByUser,
+            FlowDistinguisherMethodType::ByNamespace => {
+                internal::FlowDistinguisherMethodType::ByNamespace
+            }
+        }
+    }
+}
+
+impl From<internal::FlowDistinguisherMethodType> for FlowDistinguisherMethodType {
+    fn from(value: internal::FlowDistinguisherMethodType) -> Self {
+        match value {
+            internal::FlowDistinguisherMethodType::ByUser => FlowDistinguisherMethodType::ByUser,
+            internal::FlowDistinguisherMethodType::ByNamespace => {
+                FlowDistinguisherMethodType::ByNamespace
+            }
+        }
+    }
+}
+
+impl From<SubjectKind> for internal::SubjectKind {
+    fn from(value: SubjectKind) -> Self {
+        match value {
+            SubjectKind::User => internal::SubjectKind::User,
+            SubjectKind::Group => internal::SubjectKind::Group,
+            SubjectKind::ServiceAccount => internal::SubjectKind::ServiceAccount,
+        }
+    }
+}
+
+impl From<internal::SubjectKind> for SubjectKind {
+    fn from(value: internal::SubjectKind) -> Self {
+        match value {
+            internal::SubjectKind::User => SubjectKind::User,
+            internal::SubjectKind::Group => SubjectKind::Group,
+            internal::SubjectKind::ServiceAccount => SubjectKind::ServiceAccount,
+        }
+    }
+}
+
+impl From<PriorityLevelEnablement> for internal::PriorityLevelEnablement {
+    fn from(value: PriorityLevelEnablement) -> Self {
+        match value {
+            PriorityLevelEnablement::Exempt => internal::PriorityLevelEnablement::Exempt,
+            PriorityLevelEnablement::Limited => internal::PriorityLevelEnablement::Limited,
+        }
+    }
+}
+
+impl From<internal::PriorityLevelEnablement> for PriorityLevelEnablement {
+    fn from(value: internal::PriorityLevelEnablement) -> Self {
+        match value {
+            internal::PriorityLevelEnablement::Exempt => PriorityLevelEnablement::Exempt,
+            internal::PriorityLevelEnablement::Limited => PriorityLevelEnablement::Limited,
+        }
+    }
+}
+
+impl From<LimitResponseType> for internal::LimitResponseType {
+    fn from(value: LimitResponseType) -> Self {
+        match value {
+            LimitResponseType::Queue => internal::LimitResponseType::Queue,
+            LimitResponseType::Reject => internal::LimitResponseType::Reject,
+        }
+    }
+}
+
+impl From<internal::LimitResponseType> for LimitResponseType {
+    fn from(value: internal::LimitResponseType) -> Self {
+        match value {
+            internal::LimitResponseType::Queue => LimitResponseType::Queue,
+            internal::LimitResponseType::Reject => LimitResponseType::Reject,
+        }
+    }
+}
+
+impl From<ConditionStatus> for internal::ConditionStatus {
+    fn from(value: ConditionStatus) -> Self {
+        match value {
+            ConditionStatus::True => internal::ConditionStatus::True,
+            ConditionStatus::False => internal::ConditionStatus::False,
+            ConditionStatus::Unknown => internal::ConditionStatus::Unknown,
+        }
+    }
+}
+
+impl From<internal::ConditionStatus> for ConditionStatus {
+    fn from(value: internal::ConditionStatus) -> Self {
+        match value {
+            internal::ConditionStatus::True => ConditionStatus::True,
+            internal::ConditionStatus::False => ConditionStatus::False,
+            internal::ConditionStatus::Unknown => ConditionStatus::Unknown,
+        }
+    }
+}
+
+impl From<FlowSchemaConditionType> for internal::FlowSchemaConditionType {
+    fn from(value: FlowSchemaConditionType) -> Self {
+        match value {
+            FlowSchemaConditionType::Dangling => internal::FlowSchemaConditionType::Dangling,
+        }
+    }
+}
+
+impl From<internal::FlowSchemaConditionType> for FlowSchemaConditionType {
+    fn from(value: internal::FlowSchemaConditionType) -> Self {
+        match value {
+            internal::FlowSchemaConditionType::Dangling => FlowSchemaConditionType::Dangling,
+        }
+    }
+}
+
+impl From<PriorityLevelConfigurationConditionType>
+    for internal::PriorityLevelConfigurationConditionType
+{
+    fn from(value: PriorityLevelConfigurationConditionType) -> Self {
+        match value {
+            PriorityLevelConfigurationConditionType::ConcurrencyShared => {
+                internal::PriorityLevelConfigurationConditionType::ConcurrencyShared
+            }
+        }
+    }
+}
+
+impl From<internal::PriorityLevelConfigurationConditionType>
+    for PriorityLevelConfigurationConditionType
+{
+    fn from(value: internal::PriorityLevelConfigurationConditionType) -> Self {
+        match value {
+            internal::PriorityLevelConfigurationConditionType::ConcurrencyShared => {
+                PriorityLevelConfigurationConditionType::ConcurrencyShared
+            }
+        }
+    }
+}
+
+// ============================================================================
+// FlowSchema Supporting Type Conversions
+// ============================================================================
+
+impl ToInternal<internal::Subject> for Subject {
+    fn to_internal(self) -> internal::Subject {
+        internal::Subject {
+            kind: self.kind.into(),
+            user: self
+                .user
+                .map(|user| internal::UserSubject { name: user.name }),
+            group: self
+                .group
+                .map(|group| internal::GroupSubject { name: group.name }),
+            service_account: self
+                .service_account
+                .map(|sa| internal::ServiceAccountSubject {
+                    namespace: sa.namespace,
+                    name: sa.name,
+                }),
+        }
+    }
+}
+
+impl FromInternal<internal::Subject> for Subject {
+    fn from_internal(value: internal::Subject) -> Self {
+        Self {
+            kind: value.kind.into(),
+            user: value.user.map(|user| UserSubject { name: user.name }),
+            group: value.group.map(|group| GroupSubject { name: group.name }),
+            service_account: value.service_account.map(|sa| ServiceAccountSubject {
+                namespace: sa.namespace,
+                name: sa.name,
+            }),
+        }
+    }
+}
+
+impl ToInternal<internal::ResourcePolicyRule> for ResourcePolicyRule {
+    fn to_internal(self) -> internal::ResourcePolicyRule {
+        internal::ResourcePolicyRule {
+            verbs: self.verbs,
+            api_groups: self.api_groups,
+            resources: self.resources,
+            cluster_scope: self.cluster_scope.unwrap_or(false),
+            namespaces: self.namespaces,
+        }
+    }
+}
+
+impl FromInternal<internal::ResourcePolicyRule> for ResourcePolicyRule {
+    fn from_internal(value: internal::ResourcePolicyRule) -> Self {
+        Self {
+            verbs: value.verbs,
+            api_groups: value.api_groups,
+            resources: value.resources,
+            cluster_scope: value.cluster_scope.then_some(true),
+            namespaces: value.namespaces,
+        }
+    }
+}
+
+impl ToInternal<internal::NonResourcePolicyRule> for NonResourcePolicyRule {
+    fn to_internal(self) -> internal::NonResourcePolicyRule {
+        internal::NonResourcePolicyRule {
+            verbs: self.verbs,
+            non_resource_urls: self.non_resource_urls,
+        }
+    }
+}
+
+impl FromInternal<internal::NonResourcePolicyRule> for NonResourcePolicyRule {
+    fn from_internal(value: internal::NonResourcePolicyRule) -> Self {
+        Self {
+            verbs: value.verbs,
+            non_resource_urls: value.non_resource_urls,
+        }
+    }
+}
+
+impl ToInternal<internal::PolicyRulesWithSubjects> for PolicyRulesWithSubjects {
+    fn to_internal(self) -> internal::PolicyRulesWithSubjects {
+        internal::PolicyRulesWithSubjects {
+            subjects: self
+                .subjects
+                .into_iter()
+                .map(Subject::to_internal)
+                .collect(),
+            resource_rules: self
+                .resource_rules
+                .into_iter()
+                .map(ResourcePolicyRule::to_internal)
+                .collect(),
+            non_resource_rules: self
+                .non_resource_rules
+                .into_iter()
+                .map(NonResourcePolicyRule::to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::PolicyRulesWithSubjects> for PolicyRulesWithSubjects {
+    fn from_internal(value: internal::PolicyRulesWithSubjects) -> Self {
+        Self {
+            subjects: value
+                .subjects
+                .into_iter()
+                .map(Subject::from_internal)
+                .collect(),
+            resource_rules: value
+                .resource_rules
+                .into_iter()
+                .map(ResourcePolicyRule::from_internal)
+                .collect(),
+            non_resource_rules: value
+                .non_resource_rules
+                .into_iter()
+                .map(NonResourcePolicyRule::from_internal)
+                .collect(),
+        }
+    }
+}
+
+impl ToInternal<internal::FlowSchemaSpec> for FlowSchemaSpec {
+    fn to_internal(self) -> internal::FlowSchemaSpec {
+        internal::FlowSchemaSpec {
+            priority_level_configuration: internal::PriorityLevelConfigurationReference {
+                name: self.priority_level_configuration.name,
+            },
+            matching_precedence: option_i32_to_i32(self.matching_precedence),
+            distinguisher_method: self.distinguisher_method.map(|method| {
+                internal::FlowDistinguisherMethod {
+                    r#type: method.r#type.into(),
+                }
+            }),
+            rules: self
+                .rules
+                .into_iter()
+                .map(PolicyRulesWithSubjects::to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::FlowSchemaSpec> for FlowSchemaSpec {
+    fn from_internal(value: internal::FlowSchemaSpec) -> Self {
+        Self {
+            priority_level_configuration: PriorityLevelConfigurationReference {
+                name: value.priority_level_configuration.name,
+            },
+            matching_precedence: i32_to_option_i32(value.matching_precedence),
+            distinguisher_method: value.distinguisher_method.map(|method| {
+                FlowDistinguisherMethod {
+                    r#type: method.r#type.into(),
+                }
+            }),
+            rules: value
+                .rules
+                .into_iter()
+                .map(PolicyRulesWithSubjects::from_internal)
+                .collect(),
+        }
+    }
+}
+
+impl ToInternal<internal::FlowSchemaStatus> for FlowSchemaStatus {
+    fn to_internal(self) -> internal::FlowSchemaStatus {
+        internal::FlowSchemaStatus {
+            conditions: self
+                .conditions
+                .into_iter()
+                .map(|condition| internal::FlowSchemaCondition {
+                    r#type: condition.r#type.unwrap_or_default().into(),
+                    status: condition.status.unwrap_or_default().into(),
+                    last_transition_time: option_string_to_timestamp(
+                        condition.last_transition_time,
+                    ),
+                    reason: condition.reason,
+                    message: condition.message,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::FlowSchemaStatus> for FlowSchemaStatus {
+    fn from_internal(value: internal::FlowSchemaStatus) -> Self {
+        Self {
+            conditions: value
+                .conditions
+                .into_iter()
+                .map(|condition| FlowSchemaCondition {
+                    r#type: Some(condition.r#type.into()),
+                    status: Some(condition.status.into()),
+                    last_transition_time: timestamp_to_option_string(
+                        condition.last_transition_time,
+                    ),
+                    reason: condition.reason,
+                    message: condition.message,
+                })
+                .collect(),
+        }
+    }
+}
+
+// ============================================================================
+// PriorityLevelConfiguration Supporting Type Conversions
+// ============================================================================
+
+impl ToInternal<internal::LimitResponse> for LimitResponse {
+    fn to_internal(self) -> internal::LimitResponse {
+        internal::LimitResponse {
+            r#type: self.r#type.into(),
+            queuing: self.queuing.map(|queuing| internal::QueuingConfiguration {
+                queues: option_i32_to_i32(queuing.queues),
+                hand_size: option_i32_to_i32(queuing.hand_size),
+                queue_length_limit: option_i32_to_i32(queuing.queue_length_limit),
+            }),
+        }
+    }
+}
+
+impl FromInternal<internal::LimitResponse> for LimitResponse {
+    fn from_internal(value: internal::LimitResponse) -> Self {
+        Self {
+            r#type: value.r#type.into(),
+            queuing: value.queuing.map(|queuing| QueuingConfiguration {
+                queues: i32_to_option_i32(queuing.queues),
+                hand_size: i32_to_option_i32(queuing.hand_size),
+                queue_length_limit: i32_to_option_i32(queuing.queue_length_limit),
+            }),
+        }
+    }
+}
+
+impl ToInternal<internal::LimitedPriorityLevelConfiguration> for LimitedPriorityLevelConfiguration {
+    fn to_internal(self) -> internal::LimitedPriorityLevelConfiguration {
+        internal::LimitedPriorityLevelConfiguration {
+            nominal_concurrency_shares: option_i32_to_i32(self.nominal_concurrency_shares),
+            limit_response: self
+                .limit_response
+                .map(LimitResponse::to_internal)
+                .unwrap_or_default(),
+            lendable_percent: self.lendable_percent,
+            borrowing_limit_percent: self.borrowing_limit_percent,
+        }
+    }
+}
+
+impl FromInternal<internal::LimitedPriorityLevelConfiguration>
+    for LimitedPriorityLevelConfiguration
+{
+    fn from_internal(value: internal::LimitedPriorityLevelConfiguration) -> Self {
+        Self {
+            // v1 makes the field a pointer; the internal value is always carried over.
+            nominal_concurrency_shares: Some(value.nominal_concurrency_shares),
+            limit_response: Some(LimitResponse::from_internal(value.limit_response)),
+            lendable_percent: value.lendable_percent,
+            borrowing_limit_percent: value.borrowing_limit_percent,
+        }
+    }
+}
+
+impl ToInternal<internal::ExemptPriorityLevelConfiguration> for ExemptPriorityLevelConfiguration {
+    fn to_internal(self) -> internal::ExemptPriorityLevelConfiguration {
+        internal::ExemptPriorityLevelConfiguration {
+            nominal_concurrency_shares: self.nominal_concurrency_shares,
+            lendable_percent: self.lendable_percent,
+        }
+    }
+}
+
+impl FromInternal<internal::ExemptPriorityLevelConfiguration> for ExemptPriorityLevelConfiguration {
+    fn from_internal(value: internal::ExemptPriorityLevelConfiguration) -> Self {
+        Self {
+            nominal_concurrency_shares: value.nominal_concurrency_shares,
+            lendable_percent: value.lendable_percent,
+        }
+    }
+}
+
+impl ToInternal<internal::PriorityLevelConfigurationSpec> for PriorityLevelConfigurationSpec {
+    fn to_internal(self) -> internal::PriorityLevelConfigurationSpec {
+        internal::PriorityLevelConfigurationSpec {
+            r#type: self.r#type.unwrap_or_default().into(),
+            limited: self
+                .limited
+                .map(LimitedPriorityLevelConfiguration::to_internal),
+            exempt: self
+                .exempt
+                .map(ExemptPriorityLevelConfiguration::to_internal),
+        }
+    }
+}
+
+impl FromInternal<internal::PriorityLevelConfigurationSpec> for PriorityLevelConfigurationSpec {
+    fn from_internal(value: internal::PriorityLevelConfigurationSpec) -> Self {
+        Self {
+            r#type: Some(value.r#type.into()),
+            limited: value
+                .limited
+                .map(LimitedPriorityLevelConfiguration::from_internal),
+            exempt: value
+                .exempt
+                .map(ExemptPriorityLevelConfiguration::from_internal),
+        }
+    }
+}
+
+impl ToInternal<internal::PriorityLevelConfigurationStatus> for PriorityLevelConfigurationStatus {
+    fn to_internal(self) -> internal::PriorityLevelConfigurationStatus {
+        internal::PriorityLevelConfigurationStatus {
+            conditions: self
+                .conditions
+                .into_iter()
+                .map(|condition| internal::PriorityLevelConfigurationCondition {
+                    r#type: condition.r#type.unwrap_or_default().into(),
+                    status: condition.status.unwrap_or_default().into(),
+                    last_transition_time: option_string_to_timestamp(
+                        condition.last_transition_time,
+                    ),
+                    reason: condition.reason,
+                    message: condition.message,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::PriorityLevelConfigurationStatus> for PriorityLevelConfigurationStatus {
+    fn from_internal(value: internal::PriorityLevelConfigurationStatus) -> Self {
+        Self {
+            conditions: value
+                .conditions
+                .into_iter()
+                .map(|condition| PriorityLevelConfigurationCondition {
+                    r#type: Some(condition.r#type.into()),
+                    status: Some(condition.status.into()),
+                    last_transition_time: timestamp_to_option_string(
+                        condition.last_transition_time,
+                    ),
+                    reason: condition.reason,
+                    message: condition.message,
+                })
+                .collect(),
+        }
+    }
+}
+
+// ============================================================================
+// Top-level Conversions
+// ============================================================================
+
+impl ToInternal<internal::FlowSchema> for FlowSchema {
+    fn to_internal(self) -> internal::FlowSchema {
+        internal::FlowSchema {
+            type_meta: TypeMeta::default(),
+            metadata: option_object_meta_to_meta(self.metadata),
+            spec: self.spec.map(FlowSchemaSpec::to_internal),
+            status: self.status.map(FlowSchemaStatus::to_internal),
+        }
+    }
+}
+
+impl FromInternal<internal::FlowSchema> for FlowSchema {
+    fn from_internal(value: internal::FlowSchema) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: meta_to_option_object_meta(value.metadata),
+            spec: value.spec.map(FlowSchemaSpec::from_internal),
+            status: value.status.map(FlowSchemaStatus::from_internal),
+        }
+    }
+}
+
+impl ToInternal<internal::FlowSchemaList> for FlowSchemaList {
+    fn to_internal(self) -> internal::FlowSchemaList {
+        internal::FlowSchemaList {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            items: self
+                .items
+                .into_iter()
+                .map(FlowSchema::to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::FlowSchemaList> for FlowSchemaList {
+    fn from_internal(value: internal::FlowSchemaList) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            items: value
+                .items
+                .into_iter()
+                .map(FlowSchema::from_internal)
+                .collect(),
+        }
+    }
+}
+
+impl ToInternal<internal::PriorityLevelConfiguration> for PriorityLevelConfiguration {
+    fn to_internal(self) -> internal::PriorityLevelConfiguration {
+        internal::PriorityLevelConfiguration {
+            type_meta: TypeMeta::default(),
+            metadata: option_object_meta_to_meta(self.metadata),
+            spec: self.spec.map(PriorityLevelConfigurationSpec::to_internal),
+            status: self
+                .status
+                .map(PriorityLevelConfigurationStatus::to_internal),
+        }
+    }
+}
+
+impl FromInternal<internal::PriorityLevelConfiguration> for PriorityLevelConfiguration {
+    fn from_internal(value: internal::PriorityLevelConfiguration) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: meta_to_option_object_meta(value.metadata),
+            spec: value
+                .spec
+                .map(PriorityLevelConfigurationSpec::from_internal),
+            status: value
+                .status
+                .map(PriorityLevelConfigurationStatus::from_internal),
+        }
+    }
+}
+
+impl ToInternal<internal::PriorityLevelConfigurationList> for PriorityLevelConfigurationList {
+    fn to_internal(self) -> internal::PriorityLevelConfigurationList {
+        internal::PriorityLevelConfigurationList {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            items: self
+                .items
+                .into_iter()
+                .map(PriorityLevelConfiguration::to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::PriorityLevelConfigurationList> for PriorityLevelConfigurationList {
+    fn from_internal(value: internal::PriorityLevelConfigurationList) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            items: value
+                .items
+                .into_iter()
+                .map(PriorityLevelConfiguration::from_internal)
+                .collect(),
+        }
+    }
+}
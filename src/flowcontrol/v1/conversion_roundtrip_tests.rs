@@ -0,0 +1,96 @@
+use super::{
+    ConditionStatus, FlowDistinguisherMethod, FlowDistinguisherMethodType, FlowSchema,
+    FlowSchemaSpec, LimitResponse, LimitResponseType, LimitedPriorityLevelConfiguration,
+    PolicyRulesWithSubjects, PriorityLevelConfiguration, PriorityLevelConfigurationCondition,
+    PriorityLevelConfigurationConditionType, PriorityLevelConfigurationReference,
+    PriorityLevelConfigurationSpec, PriorityLevelConfigurationStatus, PriorityLevelEnablement,
+    QueuingConfiguration, ResourcePolicyRule, Subject, SubjectKind, UserSubject,
+};
+use crate::common::test_utils::assert_conversion_roundtrip;
+use crate::common::{ObjectMeta, TypeMeta};
+use crate::flowcontrol::internal;
+
+fn flow_schema_basic() -> FlowSchema {
+    FlowSchema {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta {
+            name: Some("workload-low".to_string()),
+            ..Default::default()
+        }),
+        spec: Some(FlowSchemaSpec {
+            priority_level_configuration: PriorityLevelConfigurationReference {
+                name: "workload-low".to_string(),
+            },
+            matching_precedence: Some(9000),
+            distinguisher_method: Some(FlowDistinguisherMethod {
+                r#type: FlowDistinguisherMethodType::ByNamespace,
+            }),
+            rules: vec![PolicyRulesWithSubjects {
+                subjects: vec![Subject {
+                    kind: SubjectKind::User,
+                    user: Some(UserSubject {
+                        name: "alice".to_string(),
+                    }),
+                    ..Default::default()
+                }],
+                resource_rules: vec![ResourcePolicyRule {
+                    verbs: vec!["*".to_string()],
+                    api_groups: vec!["*".to_string()],
+                    resources: vec!["*".to_string()],
+                    cluster_scope: Some(true),
+                    namespaces: vec!["*".to_string()],
+                }],
+                non_resource_rules: vec![],
+            }],
+        }),
+        status: None,
+    }
+}
+
+fn priority_level_basic() -> PriorityLevelConfiguration {
+    PriorityLevelConfiguration {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta {
+            name: Some("workload-low".to_string()),
+            ..Default::default()
+        }),
+        spec: Some(PriorityLevelConfigurationSpec {
+            r#type: Some(PriorityLevelEnablement::Limited),
+            limited: Some(LimitedPriorityLevelConfiguration {
+                nominal_concurrency_shares: Some(100),
+                limit_response: Some(LimitResponse {
+                    r#type: LimitResponseType::Queue,
+                    queuing: Some(QueuingConfiguration {
+                        queues: Some(128),
+                        hand_size: Some(6),
+                        queue_length_limit: Some(50),
+                    }),
+                }),
+                lendable_percent: Some(90),
+                borrowing_limit_percent: None,
+            }),
+            exempt: None,
+        }),
+        status: Some(PriorityLevelConfigurationStatus {
+            conditions: vec![PriorityLevelConfigurationCondition {
+                r#type: Some(PriorityLevelConfigurationConditionType::ConcurrencyShared),
+                status: Some(ConditionStatus::True),
+                last_transition_time: Some("2024-01-15T10:00:00Z".to_string()),
+                reason: "Shared".to_string(),
+                message: String::new(),
+            }],
+        }),
+    }
+}
+
+#[test]
+fn conversion_roundtrip_flow_schema() {
+    assert_conversion_roundtrip::<FlowSchema, internal::FlowSchema>(flow_schema_basic());
+}
+
+#[test]
+fn conversion_roundtrip_priority_level_configuration() {
+    assert_conversion_roundtrip::<PriorityLevelConfiguration, internal::PriorityLevelConfiguration>(
+        priority_level_basic(),
+    );
+}
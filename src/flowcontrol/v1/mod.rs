@@ -3,13 +3,13 @@
 //! This module contains types from the Kubernetes flowcontrol.apiserver.k8s.io/v1 API group.
 
 use crate::common::{
-    ApplyDefault, HasTypeMeta, ListMeta, ObjectMeta, ResourceSchema, TypeMeta,
-    UnimplementedConversion, VersionedObject,
+    ApplyDefault, HasTypeMeta, ListMeta, ObjectMeta, ResourceSchema, TypeMeta, VersionedObject,
 };
 use crate::impl_unimplemented_prost_message;
 use serde::{Deserialize, Serialize};
 use std::sync::OnceLock;
 
+pub mod conversion;
 pub mod validation;
 
 // ============================================================================
@@ -754,15 +754,6 @@ impl ApplyDefault for PriorityLevelConfigurationList {
     }
 }
 
-// ----------------------------------------------------------------------------
-// Version Conversion Placeholder (using UnimplementedConversion)
-// ----------------------------------------------------------------------------
-
-impl UnimplementedConversion for FlowSchema {}
-impl UnimplementedConversion for FlowSchemaList {}
-impl UnimplementedConversion for PriorityLevelConfiguration {}
-impl UnimplementedConversion for PriorityLevelConfigurationList {}
-
 // ----------------------------------------------------------------------------
 // Protobuf Placeholder (using macro)
 // ----------------------------------------------------------------------------
@@ -779,6 +770,8 @@ impl_unimplemented_prost_message!(PriorityLevelConfigurationList);
 #[cfg(test)]
 mod tests {}
 
+#[cfg(test)]
+mod conversion_roundtrip_tests;
 #[cfg(test)]
 mod trait_tests;
 
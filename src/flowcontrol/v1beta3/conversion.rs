@@ -0,0 +1,220 @@
+//! Conversions between v1beta3 and internal flowcontrol types
+//!
+//! Based on k8s.io/kubernetes/pkg/apis/flowcontrol/v1beta3/conversion.go
+//!
+//! v1beta3 carries `nominalConcurrencyShares` as a plain integer whose zero
+//! value is defaulted, while v1 makes it optional. An explicit zero is recorded
+//! in the [`PRIORITY_LEVEL_PRESERVE_ZERO_CONCURRENCY_SHARES_KEY`] annotation on
+//! the way out of the internal types, and the annotation is dropped on the way
+//! back in. Converting v1beta3 -> v1 goes through the internal types:
+//! `v1::PriorityLevelConfiguration::from_internal(v1beta3_plc.to_internal())`.
+
+use crate::common::{FromInternal, ObjectMeta, ToInternal, TypeMeta};
+use crate::flowcontrol::internal;
+
+use super::{
+    ExemptPriorityLevelConfiguration, FlowSchema, FlowSchemaList, FlowSchemaSpec, FlowSchemaStatus,
+    LimitResponse, LimitedPriorityLevelConfiguration,
+    PRIORITY_LEVEL_PRESERVE_ZERO_CONCURRENCY_SHARES_KEY, PriorityLevelConfiguration,
+    PriorityLevelConfigurationList, PriorityLevelConfigurationSpec,
+    PriorityLevelConfigurationStatus,
+};
+
+// ============================================================================
+// Conversion Helper Functions
+// ============================================================================
+
+fn option_object_meta_to_meta(meta: Option<ObjectMeta>) -> ObjectMeta {
+    meta.unwrap_or_default()
+}
+
+fn meta_to_option_object_meta(meta: ObjectMeta) -> Option<ObjectMeta> {
+    if meta.is_empty() { None } else { Some(meta) }
+}
+
+// ============================================================================
+// PriorityLevelConfiguration Supporting Type Conversions
+// ============================================================================
+
+impl ToInternal<internal::LimitedPriorityLevelConfiguration> for LimitedPriorityLevelConfiguration {
+    fn to_internal(self) -> internal::LimitedPriorityLevelConfiguration {
+        internal::LimitedPriorityLevelConfiguration {
+            nominal_concurrency_shares: self.nominal_concurrency_shares,
+            limit_response: self
+                .limit_response
+                .map(LimitResponse::to_internal)
+                .unwrap_or_default(),
+            lendable_percent: self.lendable_percent,
+            borrowing_limit_percent: self.borrowing_limit_percent,
+        }
+    }
+}
+
+impl FromInternal<internal::LimitedPriorityLevelConfiguration>
+    for LimitedPriorityLevelConfiguration
+{
+    fn from_internal(value: internal::LimitedPriorityLevelConfiguration) -> Self {
+        Self {
+            nominal_concurrency_shares: value.nominal_concurrency_shares,
+            limit_response: Some(LimitResponse::from_internal(value.limit_response)),
+            lendable_percent: value.lendable_percent,
+            borrowing_limit_percent: value.borrowing_limit_percent,
+        }
+    }
+}
+
+impl ToInternal<internal::PriorityLevelConfigurationSpec> for PriorityLevelConfigurationSpec {
+    fn to_internal(self) -> internal::PriorityLevelConfigurationSpec {
+        internal::PriorityLevelConfigurationSpec {
+            r#type: self.r#type.unwrap_or_default().into(),
+            limited: self
+                .limited
+                .map(LimitedPriorityLevelConfiguration::to_internal),
+            exempt: self
+                .exempt
+                .map(ExemptPriorityLevelConfiguration::to_internal),
+        }
+    }
+}
+
+impl FromInternal<internal::PriorityLevelConfigurationSpec> for PriorityLevelConfigurationSpec {
+    fn from_internal(value: internal::PriorityLevelConfigurationSpec) -> Self {
+        Self {
+            r#type: Some(value.r#type.into()),
+            limited: value
+                .limited
+                .map(LimitedPriorityLevelConfiguration::from_internal),
+            exempt: value
+                .exempt
+                .map(ExemptPriorityLevelConfiguration::from_internal),
+        }
+    }
+}
+
+// ============================================================================
+// Top-level Conversions
+// ============================================================================
+
+impl ToInternal<internal::FlowSchema> for FlowSchema {
+    fn to_internal(self) -> internal::FlowSchema {
+        internal::FlowSchema {
+            type_meta: TypeMeta::default(),
+            metadata: option_object_meta_to_meta(self.metadata),
+            spec: self.spec.map(FlowSchemaSpec::to_internal),
+            status: self.status.map(FlowSchemaStatus::to_internal),
+        }
+    }
+}
+
+impl FromInternal<internal::FlowSchema> for FlowSchema {
+    fn from_internal(value: internal::FlowSchema) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: meta_to_option_object_meta(value.metadata),
+            spec: value.spec.map(FlowSchemaSpec::from_internal),
+            status: value.status.map(FlowSchemaStatus::from_internal),
+        }
+    }
+}
+
+impl ToInternal<internal::FlowSchemaList> for FlowSchemaList {
+    fn to_internal(self) -> internal::FlowSchemaList {
+        internal::FlowSchemaList {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            items: self
+                .items
+                .into_iter()
+                .map(FlowSchema::to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::FlowSchemaList> for FlowSchemaList {
+    fn from_internal(value: internal::FlowSchemaList) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            items: value
+                .items
+                .into_iter()
+                .map(FlowSchema::from_internal)
+                .collect(),
+        }
+    }
+}
+
+impl ToInternal<internal::PriorityLevelConfiguration> for PriorityLevelConfiguration {
+    fn to_internal(self) -> internal::PriorityLevelConfiguration {
+        let mut metadata = option_object_meta_to_meta(self.metadata);
+        metadata
+            .annotations
+            .remove(PRIORITY_LEVEL_PRESERVE_ZERO_CONCURRENCY_SHARES_KEY);
+
+        internal::PriorityLevelConfiguration {
+            type_meta: TypeMeta::default(),
+            metadata,
+            spec: self.spec.map(PriorityLevelConfigurationSpec::to_internal),
+            status: self
+                .status
+                .map(PriorityLevelConfigurationStatus::to_internal),
+        }
+    }
+}
+
+impl FromInternal<internal::PriorityLevelConfiguration> for PriorityLevelConfiguration {
+    fn from_internal(value: internal::PriorityLevelConfiguration) -> Self {
+        let mut metadata = value.metadata;
+        let zero_shares = value
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.limited.as_ref())
+            .is_some_and(|limited| limited.nominal_concurrency_shares == 0);
+        if zero_shares {
+            metadata.annotations.insert(
+                PRIORITY_LEVEL_PRESERVE_ZERO_CONCURRENCY_SHARES_KEY.to_string(),
+                String::new(),
+            );
+        }
+
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: meta_to_option_object_meta(metadata),
+            spec: value
+                .spec
+                .map(PriorityLevelConfigurationSpec::from_internal),
+            status: value
+                .status
+                .map(PriorityLevelConfigurationStatus::from_internal),
+        }
+    }
+}
+
+impl ToInternal<internal::PriorityLevelConfigurationList> for PriorityLevelConfigurationList {
+    fn to_internal(self) -> internal::PriorityLevelConfigurationList {
+        internal::PriorityLevelConfigurationList {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            items: self
+                .items
+                .into_iter()
+                .map(PriorityLevelConfiguration::to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::PriorityLevelConfigurationList> for PriorityLevelConfigurationList {
+    fn from_internal(value: internal::PriorityLevelConfigurationList) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            items: value
+                .items
+                .into_iter()
+                .map(PriorityLevelConfiguration::from_internal)
+                .collect(),
+        }
+    }
+}
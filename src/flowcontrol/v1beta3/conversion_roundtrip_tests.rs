@@ -0,0 +1,157 @@
+use super::{
+    LimitResponse, LimitResponseType, LimitedPriorityLevelConfiguration,
+    PRIORITY_LEVEL_PRESERVE_ZERO_CONCURRENCY_SHARES_KEY, PriorityLevelConfiguration,
+    PriorityLevelConfigurationList, PriorityLevelConfigurationSpec, PriorityLevelEnablement,
+    QueuingConfiguration,
+};
+use crate::common::test_utils::assert_conversion_roundtrip;
+use crate::common::{ApplyDefault, FromInternal, ListMeta, ObjectMeta, ToInternal, TypeMeta};
+use crate::flowcontrol::{internal, v1};
+
+fn priority_level_with_borrowing() -> PriorityLevelConfiguration {
+    PriorityLevelConfiguration {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta {
+            name: Some("workload-high".to_string()),
+            ..Default::default()
+        }),
+        spec: Some(PriorityLevelConfigurationSpec {
+            r#type: Some(PriorityLevelEnablement::Limited),
+            limited: Some(LimitedPriorityLevelConfiguration {
+                nominal_concurrency_shares: 40,
+                limit_response: Some(LimitResponse {
+                    r#type: LimitResponseType::Queue,
+                    queuing: Some(QueuingConfiguration {
+                        queues: Some(128),
+                        hand_size: Some(6),
+                        queue_length_limit: Some(50),
+                    }),
+                }),
+                lendable_percent: Some(50),
+                borrowing_limit_percent: Some(200),
+            }),
+            exempt: None,
+        }),
+        status: None,
+    }
+}
+
+fn priority_level_with_zero_shares() -> PriorityLevelConfiguration {
+    let mut plc = priority_level_with_borrowing();
+    let limited = plc.spec.as_mut().unwrap().limited.as_mut().unwrap();
+    limited.nominal_concurrency_shares = 0;
+    limited.lendable_percent = None;
+    limited.borrowing_limit_percent = None;
+    plc
+}
+
+#[test]
+fn conversion_roundtrip_priority_level_with_borrowing() {
+    assert_conversion_roundtrip::<PriorityLevelConfiguration, internal::PriorityLevelConfiguration>(
+        priority_level_with_borrowing(),
+    );
+}
+
+#[test]
+fn conversion_roundtrip_priority_level_list() {
+    let mut item = priority_level_with_borrowing();
+    item.apply_default();
+    assert_conversion_roundtrip::<
+        PriorityLevelConfigurationList,
+        internal::PriorityLevelConfigurationList,
+    >(PriorityLevelConfigurationList {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ListMeta {
+            resource_version: Some("1".to_string()),
+            ..Default::default()
+        }),
+        items: vec![item],
+    });
+}
+
+#[test]
+fn borrowing_carries_over_to_v1() {
+    let v1_plc = v1::PriorityLevelConfiguration::from_internal(
+        priority_level_with_borrowing().to_internal(),
+    );
+    let limited = v1_plc.spec.unwrap().limited.unwrap();
+
+    assert_eq!(limited.nominal_concurrency_shares, Some(40));
+    assert_eq!(limited.lendable_percent, Some(50));
+    assert_eq!(limited.borrowing_limit_percent, Some(200));
+}
+
+#[test]
+fn unset_borrowing_stays_none_from_v1() {
+    let v1_plc = v1::PriorityLevelConfiguration {
+        spec: Some(v1::PriorityLevelConfigurationSpec {
+            r#type: Some(PriorityLevelEnablement::Limited),
+            limited: Some(v1::LimitedPriorityLevelConfiguration {
+                nominal_concurrency_shares: Some(10),
+                ..Default::default()
+            }),
+            exempt: None,
+        }),
+        ..Default::default()
+    };
+
+    let plc = PriorityLevelConfiguration::from_internal(v1_plc.to_internal());
+    let limited = plc.spec.unwrap().limited.unwrap();
+
+    assert_eq!(limited.nominal_concurrency_shares, 10);
+    assert_eq!(limited.lendable_percent, None);
+    assert_eq!(limited.borrowing_limit_percent, None);
+}
+
+#[test]
+fn zero_shares_default_without_annotation() {
+    let mut plc = priority_level_with_zero_shares();
+    plc.apply_default();
+
+    let limited = plc.spec.unwrap().limited.unwrap();
+    assert_eq!(limited.nominal_concurrency_shares, 30);
+}
+
+#[test]
+fn zero_shares_from_v1_are_preserved() {
+    let v1_plc = v1::PriorityLevelConfiguration {
+        metadata: Some(ObjectMeta {
+            name: Some("workload-zero".to_string()),
+            ..Default::default()
+        }),
+        spec: Some(v1::PriorityLevelConfigurationSpec {
+            r#type: Some(PriorityLevelEnablement::Limited),
+            limited: Some(v1::LimitedPriorityLevelConfiguration {
+                nominal_concurrency_shares: Some(0),
+                ..Default::default()
+            }),
+            exempt: None,
+        }),
+        ..Default::default()
+    };
+
+    let mut plc = PriorityLevelConfiguration::from_internal(v1_plc.to_internal());
+    assert!(
+        plc.metadata
+            .as_ref()
+            .unwrap()
+            .annotations
+            .contains_key(PRIORITY_LEVEL_PRESERVE_ZERO_CONCURRENCY_SHARES_KEY)
+    );
+
+    plc.apply_default();
+    assert_eq!(
+        plc.spec
+            .as_ref()
+            .unwrap()
+            .limited
+            .as_ref()
+            .unwrap()
+            .nominal_concurrency_shares,
+        0
+    );
+
+    // The annotation does not leak into the internal object.
+    let internal = plc.to_internal();
+    assert!(internal.metadata.annotations.is_empty());
+}
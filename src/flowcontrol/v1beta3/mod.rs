@@ -0,0 +1,437 @@
+//! Kubernetes FlowControl v1beta3 API types
+//!
+//! This module contains types from the Kubernetes flowcontrol.apiserver.k8s.io/v1beta3 API group.
+//!
+//! Source: https://github.com/kubernetes/api/blob/master/flowcontrol/v1beta3/types.go
+
+use crate::common::{
+    ApplyDefault, HasTypeMeta, ListMeta, ObjectMeta, ResourceSchema, TypeMeta, VersionedObject,
+};
+use crate::impl_unimplemented_prost_message;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+pub use crate::flowcontrol::v1::{
+    ConditionStatus, ExemptPriorityLevelConfiguration, FlowDistinguisherMethod,
+    FlowDistinguisherMethodType, FlowSchemaCondition, FlowSchemaConditionType, FlowSchemaSpec,
+    FlowSchemaStatus, GroupSubject, LimitResponse, LimitResponseType, NonResourcePolicyRule,
+    PolicyRulesWithSubjects, PriorityLevelConfigurationCondition,
+    PriorityLevelConfigurationConditionType, PriorityLevelConfigurationReference,
+    PriorityLevelConfigurationStatus, PriorityLevelEnablement, QueuingConfiguration,
+    ResourcePolicyRule, ServiceAccountSubject, Subject, SubjectKind, UserSubject,
+};
+
+pub mod conversion;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Annotation recording that a zero `nominalConcurrencyShares` was set
+/// explicitly and must survive a roundtrip through v1beta3 defaulting.
+pub const PRIORITY_LEVEL_PRESERVE_ZERO_CONCURRENCY_SHARES_KEY: &str =
+    "flowcontrol.k8s.io/v1beta3-preserve-zero-concurrency-shares";
+
+/// Default `nominalConcurrencyShares` applied to a Limited priority level.
+pub const PRIORITY_LEVEL_CONFIGURATION_DEFAULT_NOMINAL_CONCURRENCY_SHARES: i32 = 30;
+
+// ============================================================================
+// FlowSchema
+// ============================================================================
+
+/// FlowSchema defines the schema of a group of flows.
+///
+/// Corresponds to [Kubernetes FlowSchema](https://github.com/kubernetes/api/blob/master/flowcontrol/v1beta3/types.go#L107)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowSchema {
+    /// Standard type metadata.
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard object's metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ObjectMeta>,
+    /// Spec is the specification of the desired behavior of a FlowSchema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<FlowSchemaSpec>,
+    /// Status is the current status of a FlowSchema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<FlowSchemaStatus>,
+}
+
+/// FlowSchemaList is a list of FlowSchema objects.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowSchemaList {
+    /// Standard type metadata.
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard list metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMeta>,
+    /// Items is a list of FlowSchemas.
+    #[serde(default)]
+    pub items: Vec<FlowSchema>,
+}
+
+// ============================================================================
+// PriorityLevelConfiguration
+// ============================================================================
+
+/// PriorityLevelConfiguration represents the configuration of a priority level.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PriorityLevelConfiguration {
+    /// Standard type metadata.
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard object's metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ObjectMeta>,
+    /// Spec is the specification of the desired behavior of a priority level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<PriorityLevelConfigurationSpec>,
+    /// Status is the current status of a priority level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PriorityLevelConfigurationStatus>,
+}
+
+/// PriorityLevelConfigurationList is a list of PriorityLevelConfiguration objects.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PriorityLevelConfigurationList {
+    /// Standard type metadata.
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard list metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMeta>,
+    /// Items is a list of priority levels.
+    #[serde(default)]
+    pub items: Vec<PriorityLevelConfiguration>,
+}
+
+/// PriorityLevelConfigurationSpec specifies the configuration of a priority level.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PriorityLevelConfigurationSpec {
+    /// Type indicates whether this priority level is subject to limitation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<PriorityLevelEnablement>,
+    /// Limited specifies how requests are handled for a Limited priority level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limited: Option<LimitedPriorityLevelConfiguration>,
+    /// Exempt specifies how requests are handled for an exempt priority level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exempt: Option<ExemptPriorityLevelConfiguration>,
+}
+
+/// LimitedPriorityLevelConfiguration specifies how to handle requests that are subject to limits.
+///
+/// Unlike v1, `nominalConcurrencyShares` is a plain integer: zero is
+/// replaced by the default unless
+/// [`PRIORITY_LEVEL_PRESERVE_ZERO_CONCURRENCY_SHARES_KEY`] is set.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitedPriorityLevelConfiguration {
+    /// NominalConcurrencyShares contributes to the computation of the NominalConcurrencyLimit.
+    #[serde(default)]
+    pub nominal_concurrency_shares: i32,
+    /// LimitResponse indicates what to do with requests that can not be executed right now.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_response: Option<LimitResponse>,
+    /// LendablePercent prescribes the fraction of the level's NominalCL that can be borrowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lendable_percent: Option<i32>,
+    /// BorrowingLimitPercent configures a limit on how many seats this priority level can borrow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub borrowing_limit_percent: Option<i32>,
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+
+// ----------------------------------------------------------------------------
+// ResourceSchema Implementation
+// ----------------------------------------------------------------------------
+
+impl ResourceSchema for FlowSchema {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "flowcontrol.apiserver.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta3"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "FlowSchema"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "flowschemas"
+    }
+
+    fn group_static() -> &'static str {
+        "flowcontrol.apiserver.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta3"
+    }
+    fn kind_static() -> &'static str {
+        "FlowSchema"
+    }
+    fn resource_static() -> &'static str {
+        "flowschemas"
+    }
+}
+
+impl ResourceSchema for FlowSchemaList {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "flowcontrol.apiserver.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta3"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "FlowSchemaList"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "flowschemas"
+    }
+
+    fn group_static() -> &'static str {
+        "flowcontrol.apiserver.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta3"
+    }
+    fn kind_static() -> &'static str {
+        "FlowSchemaList"
+    }
+    fn resource_static() -> &'static str {
+        "flowschemas"
+    }
+}
+
+impl ResourceSchema for PriorityLevelConfiguration {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "flowcontrol.apiserver.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta3"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "PriorityLevelConfiguration"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "prioritylevelconfigurations"
+    }
+
+    fn group_static() -> &'static str {
+        "flowcontrol.apiserver.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta3"
+    }
+    fn kind_static() -> &'static str {
+        "PriorityLevelConfiguration"
+    }
+    fn resource_static() -> &'static str {
+        "prioritylevelconfigurations"
+    }
+}
+
+impl ResourceSchema for PriorityLevelConfigurationList {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "flowcontrol.apiserver.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta3"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "PriorityLevelConfigurationList"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "prioritylevelconfigurations"
+    }
+
+    fn group_static() -> &'static str {
+        "flowcontrol.apiserver.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta3"
+    }
+    fn kind_static() -> &'static str {
+        "PriorityLevelConfigurationList"
+    }
+    fn resource_static() -> &'static str {
+        "prioritylevelconfigurations"
+    }
+}
+
+// ----------------------------------------------------------------------------
+// HasTypeMeta Implementation
+// ----------------------------------------------------------------------------
+
+impl HasTypeMeta for FlowSchema {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for FlowSchemaList {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for PriorityLevelConfiguration {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for PriorityLevelConfigurationList {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+// ----------------------------------------------------------------------------
+// VersionedObject Implementation
+// ----------------------------------------------------------------------------
+
+impl VersionedObject for FlowSchema {
+    fn metadata(&self) -> &ObjectMeta {
+        self.metadata
+            .as_ref()
+            .unwrap_or_else(|| static_default_object_meta())
+    }
+
+    fn metadata_mut(&mut self) -> &mut ObjectMeta {
+        self.metadata.get_or_insert_with(ObjectMeta::default)
+    }
+}
+
+impl VersionedObject for PriorityLevelConfiguration {
+    fn metadata(&self) -> &ObjectMeta {
+        self.metadata
+            .as_ref()
+            .unwrap_or_else(|| static_default_object_meta())
+    }
+
+    fn metadata_mut(&mut self) -> &mut ObjectMeta {
+        self.metadata.get_or_insert_with(ObjectMeta::default)
+    }
+}
+
+// Helper function for static default ObjectMeta
+fn static_default_object_meta() -> &'static ObjectMeta {
+    static DEFAULT: OnceLock<ObjectMeta> = OnceLock::new();
+    DEFAULT.get_or_init(ObjectMeta::default)
+}
+
+// ----------------------------------------------------------------------------
+// ApplyDefaults Implementation
+// ----------------------------------------------------------------------------
+
+impl ApplyDefault for FlowSchema {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "flowcontrol.apiserver.k8s.io/v1beta3".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "FlowSchema".to_string();
+        }
+    }
+}
+
+impl ApplyDefault for FlowSchemaList {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "flowcontrol.apiserver.k8s.io/v1beta3".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "FlowSchemaList".to_string();
+        }
+        for item in &mut self.items {
+            item.apply_default();
+        }
+    }
+}
+
+impl ApplyDefault for PriorityLevelConfiguration {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "flowcontrol.apiserver.k8s.io/v1beta3".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "PriorityLevelConfiguration".to_string();
+        }
+
+        // A zero nominalConcurrencyShares is only kept when the roundtrip
+        // annotation says it was set explicitly.
+        let preserve_zero = self.metadata.as_ref().is_some_and(|meta| {
+            meta.annotations
+                .contains_key(PRIORITY_LEVEL_PRESERVE_ZERO_CONCURRENCY_SHARES_KEY)
+        });
+        if !preserve_zero
+            && let Some(limited) = self.spec.as_mut().and_then(|spec| spec.limited.as_mut())
+            && limited.nominal_concurrency_shares == 0
+        {
+            limited.nominal_concurrency_shares =
+                PRIORITY_LEVEL_CONFIGURATION_DEFAULT_NOMINAL_CONCURRENCY_SHARES;
+        }
+    }
+}
+
+impl ApplyDefault for PriorityLevelConfigurationList {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "flowcontrol.apiserver.k8s.io/v1beta3".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "PriorityLevelConfigurationList".to_string();
+        }
+        for item in &mut self.items {
+            item.apply_default();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Protobuf Placeholder (using macro)
+// ----------------------------------------------------------------------------
+
+impl_unimplemented_prost_message!(FlowSchema);
+impl_unimplemented_prost_message!(FlowSchemaList);
+impl_unimplemented_prost_message!(PriorityLevelConfiguration);
+impl_unimplemented_prost_message!(PriorityLevelConfigurationList);
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod conversion_roundtrip_tests;
+#[cfg(test)]
+mod trait_tests;
@@ -30,7 +30,11 @@ pub fn register(registry: &mut Registry) {
     );
 
     // ---- core/v1/Secret ----
-    register_type::<crate::core::v1::config::Secret, crate::core::internal::config::Secret, _>(
+    register_type_with_path::<
+        crate::core::v1::config::Secret,
+        crate::core::internal::config::Secret,
+        _,
+    >(
         registry,
         "core/v1/Secret",
         crate::core::v1::validation::config::validate_secret,
@@ -111,7 +111,7 @@ pub struct ImageReviewList {
     pub metadata: Option<ListMeta>,
 
     /// List of ImageReview resources.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ImageReview>,
 }
 
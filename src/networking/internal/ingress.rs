@@ -211,7 +211,7 @@ pub struct IngressList {
     #[serde(flatten)]
     pub type_meta: TypeMeta,
     pub metadata: crate::common::ListMeta,
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Ingress>,
 }
 
@@ -87,6 +87,6 @@ pub struct IngressClassList {
     #[serde(flatten)]
     pub type_meta: TypeMeta,
     pub metadata: crate::common::ListMeta,
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<IngressClass>,
 }
@@ -232,7 +232,7 @@ pub struct NetworkPolicyList {
     #[serde(flatten)]
     pub type_meta: TypeMeta,
     pub metadata: crate::common::ListMeta,
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<NetworkPolicy>,
 }
 
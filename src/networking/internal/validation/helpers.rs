@@ -3,7 +3,6 @@
 use std::net::IpAddr;
 
 use crate::common::IntOrString;
-use crate::common::meta::label_selector_operator;
 use crate::common::validation::*;
 use crate::networking::v1::ingress::IngressBackend;
 use crate::networking::v1::network_policy::{IPBlock, NetworkPolicyPort};
@@ -145,74 +144,6 @@ pub(crate) fn validate_port_name(port: &str, path: &Path) -> ErrorList {
     all_errs
 }
 
-pub(crate) fn validate_label_selector(
-    selector: &crate::common::LabelSelector,
-    path: &Path,
-) -> ErrorList {
-    let mut all_errs = ErrorList::new();
-    all_errs.extend(validate_labels(
-        &selector.match_labels,
-        &path.child("matchLabels"),
-    ));
-
-    for (i, requirement) in selector.match_expressions.iter().enumerate() {
-        let req_path = path.child("matchExpressions").index(i);
-        if requirement.key.is_empty() {
-            all_errs.push(required(&req_path.child("key"), "key is required"));
-        } else {
-            all_errs.extend(validate_qualified_name(
-                &requirement.key,
-                &req_path.child("key"),
-            ));
-        }
-
-        let operator = requirement.operator.as_str();
-        match operator {
-            label_selector_operator::IN | label_selector_operator::NOT_IN => {
-                if requirement.values.is_empty() {
-                    all_errs.push(required(
-                        &req_path.child("values"),
-                        "values must be non-empty for In/NotIn operators",
-                    ));
-                }
-            }
-            label_selector_operator::EXISTS | label_selector_operator::DOES_NOT_EXIST => {
-                if !requirement.values.is_empty() {
-                    all_errs.push(invalid(
-                        &req_path.child("values"),
-                        BadValue::String(format!("{:?}", requirement.values)),
-                        "values must be empty for Exists/DoesNotExist operators",
-                    ));
-                }
-            }
-            _ => {
-                all_errs.push(not_supported(
-                    &req_path.child("operator"),
-                    BadValue::String(requirement.operator.clone()),
-                    &[
-                        label_selector_operator::IN,
-                        label_selector_operator::NOT_IN,
-                        label_selector_operator::EXISTS,
-                        label_selector_operator::DOES_NOT_EXIST,
-                    ],
-                ));
-            }
-        }
-
-        for (j, value) in requirement.values.iter().enumerate() {
-            for msg in is_valid_label_value(value) {
-                all_errs.push(invalid(
-                    &req_path.child("values").index(j),
-                    BadValue::String(value.to_string()),
-                    &msg,
-                ));
-            }
-        }
-    }
-
-    all_errs
-}
-
 /// Validates an IngressBackend
 pub(crate) fn validate_ingress_backend(backend: &IngressBackend, path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
@@ -71,7 +71,7 @@ pub struct IngressList {
     #[serde(default)]
     pub metadata: Option<ListMeta>,
     /// Items is the list of Ingress objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Ingress>,
 }
 
@@ -41,7 +41,7 @@ pub struct IngressClassList {
     #[serde(default)]
     pub metadata: Option<ListMeta>,
     /// Items is the list of IngressClass objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<IngressClass>,
 }
 
@@ -45,7 +45,7 @@ pub struct IPAddressList {
     #[serde(default)]
     pub metadata: Option<ListMeta>,
     /// Items is the list of IPAddress objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<IPAddress>,
 }
 
@@ -62,7 +62,7 @@ pub struct NetworkPolicyList {
     #[serde(default)]
     pub metadata: Option<ListMeta>,
     /// Items is a list of NetworkPolicy objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<NetworkPolicy>,
 }
 
@@ -45,7 +45,7 @@ pub struct ServiceCIDRList {
     #[serde(default)]
     pub metadata: Option<ListMeta>,
     /// Items is the list of ServiceCIDR objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ServiceCIDR>,
 }
 
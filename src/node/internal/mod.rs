@@ -49,7 +49,7 @@ pub struct RuntimeClassList {
     #[serde(default, skip_serializing_if = "ListMeta::is_empty")]
     pub metadata: ListMeta,
     /// Items is a list of RuntimeClass objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<RuntimeClass>,
 }
 
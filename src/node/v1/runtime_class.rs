@@ -74,7 +74,7 @@ pub struct RuntimeClassList {
     pub metadata: Option<ListMeta>,
 
     /// Items is a list of schema objects.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<RuntimeClass>,
 }
 
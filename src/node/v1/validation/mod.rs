@@ -3,7 +3,12 @@
 //! These wrappers convert v1 types to internal types before validation.
 
 use crate::common::ToInternal;
-use crate::common::validation::ErrorList;
+use crate::common::validation::{ErrorList, Path};
+use crate::core::v1::Toleration;
+use crate::core::v1::validation::constants::{
+    SUPPORTED_TAINT_EFFECTS, SUPPORTED_TOLERATION_OPERATORS,
+};
+use crate::core::v1::validation::helpers::validate_versioned_enum;
 use crate::node::internal;
 
 use super::{RuntimeClass, RuntimeClassList};
@@ -14,14 +19,26 @@ use super::{RuntimeClass, RuntimeClassList};
 
 /// Validates a v1 RuntimeClass by converting to internal and delegating validation.
 pub fn validate_runtime_class(obj: &RuntimeClass) -> ErrorList {
+    let mut all_errs = validate_scheduling_tolerations(obj, &Path::nil());
     let internal_obj = obj.clone().to_internal();
-    internal::validation::validate_runtime_class(&internal_obj)
+    all_errs.extend(internal::validation::validate_runtime_class(&internal_obj));
+    all_errs
 }
 
 /// Validates a v1 RuntimeClassList by converting to internal and delegating validation.
 pub fn validate_runtime_class_list(obj: &RuntimeClassList) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    for (i, item) in obj.items.iter().enumerate() {
+        all_errs.extend(validate_scheduling_tolerations(
+            item,
+            &Path::new("items").index(i),
+        ));
+    }
     let internal_obj = obj.clone().to_internal();
-    internal::validation::validate_runtime_class_list(&internal_obj)
+    all_errs.extend(internal::validation::validate_runtime_class_list(
+        &internal_obj,
+    ));
+    all_errs
 }
 
 /// Validates a v1 RuntimeClass update by converting to internal and delegating validation.
@@ -30,3 +47,123 @@ pub fn validate_runtime_class_update(obj: &RuntimeClass, old: &RuntimeClass) ->
     let internal_old = old.clone().to_internal();
     internal::validation::validate_runtime_class_update(&internal_obj, &internal_old)
 }
+
+/// Validates the operator and effect of each `scheduling.tolerations` entry.
+/// Empty values are allowed.
+fn validate_scheduling_tolerations(obj: &RuntimeClass, base_path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    let Some(scheduling) = obj.scheduling.as_ref() else {
+        return all_errs;
+    };
+    let fld_path = base_path.child("scheduling").child("tolerations");
+    for (i, toleration) in scheduling.tolerations.iter().enumerate() {
+        all_errs.extend(validate_toleration_enums(toleration, &fld_path.index(i)));
+    }
+    all_errs
+}
+
+fn validate_toleration_enums(toleration: &Toleration, idx_path: &Path) -> ErrorList {
+    let mut all_errs = ErrorList::new();
+    if !toleration.operator.is_empty() {
+        all_errs.extend(validate_versioned_enum(
+            &toleration.operator,
+            &SUPPORTED_TOLERATION_OPERATORS,
+            &idx_path.child("operator"),
+        ));
+    }
+    if !toleration.effect.is_empty() {
+        all_errs.extend(validate_versioned_enum(
+            &toleration.effect,
+            &SUPPORTED_TAINT_EFFECTS,
+            &idx_path.child("effect"),
+        ));
+    }
+    all_errs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ObjectMeta;
+    use crate::common::validation::ErrorType;
+    use crate::core::v1::{toleration_effect, toleration_operator};
+    use crate::node::v1::Scheduling;
+    use std::collections::BTreeMap;
+
+    fn runtime_class(scheduling: Scheduling) -> RuntimeClass {
+        RuntimeClass {
+            metadata: Some(ObjectMeta {
+                name: Some("gvisor".to_string()),
+                ..Default::default()
+            }),
+            handler: "runsc".to_string(),
+            scheduling: Some(scheduling),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_runtime_class_invalid_toleration_operator() {
+        let obj = runtime_class(Scheduling {
+            node_selector: BTreeMap::from([("sandbox".to_string(), "gvisor".to_string())]),
+            tolerations: vec![
+                Toleration {
+                    key: "sandbox".to_string(),
+                    operator: toleration_operator::EXISTS.to_string(),
+                    effect: toleration_effect::NO_SCHEDULE.to_string(),
+                    ..Default::default()
+                },
+                Toleration {
+                    key: "sandbox".to_string(),
+                    operator: "In".to_string(),
+                    value: "gvisor".to_string(),
+                    effect: "NoRun".to_string(),
+                    ..Default::default()
+                },
+            ],
+        });
+
+        let errs = validate_runtime_class(&obj);
+        assert_eq!(errs.len(), 2, "unexpected errors: {:?}", errs.errors);
+        assert!(
+            errs.errors[0]
+                .field
+                .ends_with("scheduling.tolerations[1].operator")
+        );
+        assert_eq!(errs.errors[0].error_type, ErrorType::NotSupported);
+        assert!(
+            errs.errors[1]
+                .field
+                .ends_with("scheduling.tolerations[1].effect")
+        );
+        assert_eq!(errs.errors[1].error_type, ErrorType::NotSupported);
+    }
+
+    #[test]
+    fn test_validate_runtime_class_scheduling_field_paths() {
+        let obj = runtime_class(Scheduling {
+            node_selector: BTreeMap::from([("bad key!".to_string(), "gvisor".to_string())]),
+            tolerations: vec![Toleration {
+                operator: toleration_operator::EQUAL.to_string(),
+                value: "gvisor".to_string(),
+                ..Default::default()
+            }],
+        });
+        let list = RuntimeClassList {
+            type_meta: Default::default(),
+            metadata: None,
+            items: vec![obj],
+        };
+
+        let errs = validate_runtime_class_list(&list);
+        assert_eq!(errs.len(), 2, "unexpected errors: {:?}", errs.errors);
+        assert_eq!(
+            errs.errors[0].field,
+            "items[0].scheduling.nodeSelector[bad key!]"
+        );
+        assert_eq!(
+            errs.errors[1].field,
+            "items[0].scheduling.tolerations[0].operator"
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! Conversions between v1beta1 and internal node types
+//!
+//! v1beta1 RuntimeClass has the same layout as v1 (`handler`, `overhead` and
+//! `scheduling` at the top level) and shares its `Overhead`/`Scheduling` types,
+//! so conversions delegate to the v1 implementations.
+
+use crate::common::{FromInternal, ToInternal, TypeMeta};
+use crate::node::{internal, v1};
+
+use super::{RuntimeClass, RuntimeClassList};
+
+// ============================================================================
+// RuntimeClass Conversions
+// ============================================================================
+
+impl ToInternal<internal::RuntimeClass> for RuntimeClass {
+    fn to_internal(self) -> internal::RuntimeClass {
+        v1::RuntimeClass {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            handler: self.handler,
+            overhead: self.overhead,
+            scheduling: self.scheduling,
+        }
+        .to_internal()
+    }
+}
+
+impl FromInternal<internal::RuntimeClass> for RuntimeClass {
+    fn from_internal(value: internal::RuntimeClass) -> Self {
+        let value = v1::RuntimeClass::from_internal(value);
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            handler: value.handler,
+            overhead: value.overhead,
+            scheduling: value.scheduling,
+        }
+    }
+}
+
+// ============================================================================
+// RuntimeClassList Conversions
+// ============================================================================
+
+impl ToInternal<internal::RuntimeClassList> for RuntimeClassList {
+    fn to_internal(self) -> internal::RuntimeClassList {
+        internal::RuntimeClassList {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata.unwrap_or_default(),
+            items: self
+                .items
+                .into_iter()
+                .map(ToInternal::to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::RuntimeClassList> for RuntimeClassList {
+    fn from_internal(value: internal::RuntimeClassList) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: (!value.metadata.is_empty()).then_some(value.metadata),
+            items: value
+                .items
+                .into_iter()
+                .map(RuntimeClass::from_internal)
+                .collect(),
+        }
+    }
+}
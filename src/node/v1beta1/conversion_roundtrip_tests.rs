@@ -0,0 +1,70 @@
+use super::{Overhead, RuntimeClass, RuntimeClassList, Scheduling};
+use crate::common::test_utils::assert_conversion_roundtrip;
+use crate::common::util::Quantity;
+use crate::common::{ApplyDefault, FromInternal, ListMeta, ObjectMeta, ToInternal, TypeMeta};
+use crate::core::v1::Toleration;
+use crate::node::{internal, v1};
+use std::collections::BTreeMap;
+
+fn runtime_class_with_overhead() -> RuntimeClass {
+    RuntimeClass {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta {
+            name: Some("gvisor".to_string()),
+            ..Default::default()
+        }),
+        handler: "runsc".to_string(),
+        overhead: Some(Overhead {
+            pod_fixed: BTreeMap::from([
+                ("cpu".to_string(), Quantity("250m".to_string())),
+                ("memory".to_string(), Quantity("120Mi".to_string())),
+            ]),
+        }),
+        scheduling: Some(Scheduling {
+            node_selector: BTreeMap::from([("sandbox".to_string(), "gvisor".to_string())]),
+            tolerations: vec![Toleration {
+                key: "sandbox".to_string(),
+                operator: "Equal".to_string(),
+                value: "gvisor".to_string(),
+                effect: "NoSchedule".to_string(),
+                toleration_seconds: None,
+            }],
+        }),
+    }
+}
+
+#[test]
+fn conversion_roundtrip_runtime_class() {
+    assert_conversion_roundtrip::<RuntimeClass, internal::RuntimeClass>(
+        runtime_class_with_overhead(),
+    );
+}
+
+#[test]
+fn conversion_roundtrip_runtime_class_list() {
+    let mut item = runtime_class_with_overhead();
+    item.apply_default();
+
+    assert_conversion_roundtrip::<RuntimeClassList, internal::RuntimeClassList>(RuntimeClassList {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ListMeta {
+            resource_version: Some("7".to_string()),
+            ..Default::default()
+        }),
+        items: vec![item],
+    });
+}
+
+#[test]
+fn runtime_class_converts_to_v1_preserving_overhead_and_scheduling() {
+    let v1beta1 = runtime_class_with_overhead();
+
+    let v1_class = v1::RuntimeClass::from_internal(v1beta1.clone().to_internal());
+    assert_eq!(v1_class.metadata, v1beta1.metadata);
+    assert_eq!(v1_class.handler, "runsc");
+    assert_eq!(v1_class.overhead, v1beta1.overhead);
+    assert_eq!(v1_class.scheduling, v1beta1.scheduling);
+
+    let back = RuntimeClass::from_internal(v1_class.to_internal());
+    assert_eq!(back, v1beta1);
+}
@@ -0,0 +1,222 @@
+//! Kubernetes Node v1beta1 API types
+//!
+//! This module contains the RuntimeClass types from node.k8s.io/v1beta1.
+//!
+//! Source: https://github.com/kubernetes/api/blob/master/node/v1beta1/types.go
+
+use crate::common::{
+    ApplyDefault, HasTypeMeta, ListMeta, ObjectMeta, ResourceSchema, TypeMeta, VersionedObject,
+};
+use crate::impl_unimplemented_prost_message;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+pub use crate::node::v1::{Overhead, Scheduling};
+
+pub mod conversion;
+
+// ============================================================================
+// RuntimeClass
+// ============================================================================
+
+/// RuntimeClass defines a class of container runtime supported in the cluster.
+///
+/// Corresponds to [Kubernetes RuntimeClass](https://github.com/kubernetes/api/blob/master/node/v1beta1/types.go#L36)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeClass {
+    /// TypeMeta describes the type of this object.
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard object's metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ObjectMeta>,
+
+    /// Handler specifies the underlying runtime and configuration that the CRI
+    /// implementation will use to handle pods of this class.
+    pub handler: String,
+
+    /// Overhead represents the resource overhead associated with running a pod for a
+    /// given RuntimeClass.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overhead: Option<Overhead>,
+
+    /// Scheduling holds the scheduling constraints to ensure that pods running
+    /// with this RuntimeClass are scheduled to nodes that support it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheduling: Option<Scheduling>,
+}
+
+// ============================================================================
+// RuntimeClassList
+// ============================================================================
+
+/// RuntimeClassList is a list of RuntimeClass objects.
+///
+/// Corresponds to [Kubernetes RuntimeClassList](https://github.com/kubernetes/api/blob/master/node/v1beta1/types.go#L104)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeClassList {
+    /// TypeMeta describes the type of this object.
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard list metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMeta>,
+
+    /// Items is a list of schema objects.
+    #[serde(default)]
+    pub items: Vec<RuntimeClass>,
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+
+// ----------------------------------------------------------------------------
+// ResourceSchema Implementation
+// ----------------------------------------------------------------------------
+
+impl ResourceSchema for RuntimeClass {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "node.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "RuntimeClass"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "runtimeclasses"
+    }
+
+    fn group_static() -> &'static str {
+        "node.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "RuntimeClass"
+    }
+    fn resource_static() -> &'static str {
+        "runtimeclasses"
+    }
+}
+
+impl ResourceSchema for RuntimeClassList {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "node.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "RuntimeClassList"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "runtimeclasses"
+    }
+
+    fn group_static() -> &'static str {
+        "node.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "RuntimeClassList"
+    }
+    fn resource_static() -> &'static str {
+        "runtimeclasses"
+    }
+}
+
+// ----------------------------------------------------------------------------
+// HasTypeMeta Implementation
+// ----------------------------------------------------------------------------
+
+impl HasTypeMeta for RuntimeClass {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for RuntimeClassList {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+// ----------------------------------------------------------------------------
+// VersionedObject Implementation
+// ----------------------------------------------------------------------------
+
+impl VersionedObject for RuntimeClass {
+    fn metadata(&self) -> &ObjectMeta {
+        self.metadata.as_ref().unwrap_or_else(|| {
+            static DEFAULT: OnceLock<ObjectMeta> = OnceLock::new();
+            DEFAULT.get_or_init(ObjectMeta::default)
+        })
+    }
+
+    fn metadata_mut(&mut self) -> &mut ObjectMeta {
+        self.metadata.get_or_insert_with(ObjectMeta::default)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ApplyDefaults Implementation
+// ----------------------------------------------------------------------------
+
+impl ApplyDefault for RuntimeClass {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "node.k8s.io/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "RuntimeClass".to_string();
+        }
+    }
+}
+
+impl ApplyDefault for RuntimeClassList {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "node.k8s.io/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "RuntimeClassList".to_string();
+        }
+        for item in &mut self.items {
+            item.apply_default();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Protobuf Placeholder
+// ----------------------------------------------------------------------------
+
+impl_unimplemented_prost_message!(RuntimeClass);
+impl_unimplemented_prost_message!(RuntimeClassList);
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod conversion_roundtrip_tests;
+#[cfg(test)]
+mod trait_tests;
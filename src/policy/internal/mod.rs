@@ -5,7 +5,9 @@
 //!
 //! Source: https://github.com/kubernetes/kubernetes/blob/master/pkg/apis/policy/types.go
 
-use crate::common::meta::{Condition, LabelSelector};
+use crate::common::meta::{
+    Condition, LabelSelector, LabelSelectorRequirement, label_selector_operator,
+};
 use crate::common::time::Timestamp;
 use crate::common::util::IntOrString;
 use crate::common::{ListMeta, ObjectMeta, TypeMeta};
@@ -47,6 +49,38 @@ pub mod unhealthy_pod_eviction_policy_type {
     pub const ALWAYS_ALLOW: &str = "AlwaysAllow";
 }
 
+// ============================================================================
+// v1beta1 Selector Compatibility
+// ============================================================================
+
+/// Label key used to carry policy/v1beta1 empty-selector semantics through the
+/// internal and policy/v1 representations.
+pub const V1BETA1_EMPTY_SELECTOR_MATCH_KEY: &str =
+    "pdb.kubernetes.io/deprecated-v1beta1-empty-selector-match";
+
+/// The internal selector that a policy/v1beta1 empty selector (which matches no
+/// pods) converts to. Mirrors `NonV1beta1MatchNoneSelector`.
+pub fn non_v1beta1_match_none_selector() -> LabelSelector {
+    v1beta1_compat_selector(label_selector_operator::EXISTS)
+}
+
+/// The policy/v1beta1 selector that matches every pod. Mirrors
+/// `V1beta1MatchAllSelector`; the internal and policy/v1 equivalent is `{}`.
+pub fn v1beta1_match_all_selector() -> LabelSelector {
+    v1beta1_compat_selector(label_selector_operator::DOES_NOT_EXIST)
+}
+
+fn v1beta1_compat_selector(operator: &str) -> LabelSelector {
+    LabelSelector {
+        match_expressions: vec![LabelSelectorRequirement {
+            key: V1BETA1_EMPTY_SELECTOR_MATCH_KEY.to_string(),
+            operator: operator.to_string(),
+            values: Vec::new(),
+        }],
+        ..Default::default()
+    }
+}
+
 // ============================================================================
 // PodDisruptionBudgetSpec
 // ============================================================================
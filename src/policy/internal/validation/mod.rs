@@ -2,11 +2,10 @@
 //!
 //! Ported from k8s.io/kubernetes/pkg/apis/policy/validation/validation.go
 
-use crate::common::meta::{LabelSelector, label_selector_operator};
 use crate::common::util::IntOrString;
 use crate::common::validation::{
-    BadValue, ErrorList, Path, invalid, is_valid_label_value, not_supported, required,
-    validate_labels, validate_object_meta, validate_object_meta_update, validate_qualified_name,
+    BadValue, ErrorList, Path, invalid, required, validate_label_selector, validate_object_meta,
+    validate_object_meta_update,
 };
 use crate::policy::internal::{
     PodDisruptionBudget, PodDisruptionBudgetList, PodDisruptionBudgetSpec,
@@ -153,7 +152,14 @@ pub fn validate_pod_disruption_budget_status_update(
 // PodDisruptionBudgetSpec Validation
 // ============================================================================
 
-fn validate_pod_disruption_budget_spec(spec: &PodDisruptionBudgetSpec, path: &Path) -> ErrorList {
+/// Validates a PodDisruptionBudgetSpec.
+///
+/// Exactly one of minAvailable and maxUnavailable must be set, percentages must
+/// fall within 0-100%, and the selector must be valid.
+pub fn validate_pod_disruption_budget_spec(
+    spec: &PodDisruptionBudgetSpec,
+    path: &Path,
+) -> ErrorList {
     let mut all_errs = ErrorList::new();
 
     if let Some(ref selector) = spec.selector {
@@ -240,77 +246,6 @@ fn validate_int_or_percent(value: &IntOrString, path: &Path) -> ErrorList {
     all_errs
 }
 
-fn validate_label_selector(selector: &LabelSelector, path: &Path) -> ErrorList {
-    let mut all_errs = ErrorList::new();
-    all_errs.extend(validate_labels(
-        &selector.match_labels,
-        &path.child("matchLabels"),
-    ));
-
-    for (i, requirement) in selector.match_expressions.iter().enumerate() {
-        let req_path = path.child("matchExpressions").index(i);
-        if requirement.key.is_empty() {
-            all_errs.push(required(&req_path.child("key"), "key is required"));
-        } else {
-            all_errs.extend(validate_qualified_name(
-                &requirement.key,
-                &req_path.child("key"),
-            ));
-        }
-
-        match requirement.operator.as_str() {
-            label_selector_operator::IN | label_selector_operator::NOT_IN => {
-                if requirement.values.is_empty() {
-                    all_errs.push(required(
-                        &req_path.child("values"),
-                        "values must be non-empty for In/NotIn operators",
-                    ));
-                }
-            }
-            label_selector_operator::EXISTS | label_selector_operator::DOES_NOT_EXIST => {
-                if !requirement.values.is_empty() {
-                    all_errs.push(invalid(
-                        &req_path.child("values"),
-                        BadValue::String(format!("{:?}", requirement.values)),
-                        "values must be empty for Exists/DoesNotExist operators",
-                    ));
-                }
-            }
-            _ => {
-                all_errs.push(not_supported(
-                    &req_path.child("operator"),
-                    BadValue::String(requirement.operator.clone()),
-                    &[
-                        label_selector_operator::IN,
-                        label_selector_operator::NOT_IN,
-                        label_selector_operator::EXISTS,
-                        label_selector_operator::DOES_NOT_EXIST,
-                    ],
-                ));
-            }
-        }
-
-        for (j, value) in requirement.values.iter().enumerate() {
-            if value.is_empty() {
-                all_errs.push(required(
-                    &req_path.child("values").index(j),
-                    "value must be non-empty",
-                ));
-                continue;
-            }
-            for msg in is_valid_label_value(value) {
-                all_errs.push(invalid(
-                    &req_path.child("values").index(j),
-                    BadValue::String(value.clone()),
-                    &msg,
-                ));
-            }
-        }
-    }
-
-    all_errs
-}
-
 // ============================================================================
 // Tests
 // ============================================================================
@@ -320,6 +255,7 @@ mod tests {
     use super::*;
     use crate::common::ListMeta;
     use crate::common::TypeMeta;
+    use crate::common::meta::LabelSelector;
 
     fn base_pdb() -> PodDisruptionBudget {
         PodDisruptionBudget {
@@ -363,6 +299,34 @@ mod tests {
         assert!(errs.errors.iter().any(|e| e.field == "spec"));
     }
 
+    #[test]
+    fn test_validate_pdb_unhealthy_pod_eviction_policy() {
+        let mut obj = base_pdb();
+        obj.spec.unhealthy_pod_eviction_policy =
+            Some(crate::policy::internal::UnhealthyPodEvictionPolicyType::AlwaysAllow);
+        let errs = validate_pod_disruption_budget(&obj);
+        assert!(errs.is_empty(), "expected no errors, got {errs:?}");
+    }
+
+    #[test]
+    fn test_invalid_unhealthy_pod_eviction_policy_is_rejected() {
+        // The policy is an enum, so an unknown value is rejected when the
+        // versioned object is decoded, before validation runs.
+        let err = serde_json::from_value::<crate::policy::v1::PodDisruptionBudgetSpec>(
+            serde_json::json!({
+                "minAvailable": 1,
+                "unhealthyPodEvictionPolicy": "Sometimes",
+            }),
+        )
+        .unwrap_err();
+        let msg = err.to_string();
+        assert!(
+            msg.contains("unknown variant `Sometimes`"),
+            "unexpected error: {msg}"
+        );
+        assert!(msg.contains("IfHealthyBudget") && msg.contains("AlwaysAllow"));
+    }
+
     #[test]
     fn test_validate_pdb_percent_invalid() {
         let mut obj = base_pdb();
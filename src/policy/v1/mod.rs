@@ -10,6 +10,7 @@ use crate::common::util::IntOrString;
 use crate::common::{
     ApplyDefault, HasTypeMeta, ListMeta, ObjectMeta, ResourceSchema, TypeMeta, VersionedObject,
 };
+pub use crate::common::{DeleteOptions, Preconditions};
 use crate::impl_unimplemented_prost_message;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -189,7 +190,7 @@ pub struct PodDisruptionBudgetList {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<ListMeta>,
     /// Items is a list of PodDisruptionBudgets.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<PodDisruptionBudget>,
 }
 
@@ -217,46 +218,6 @@ pub struct Eviction {
     pub delete_options: Option<DeleteOptions>,
 }
 
-/// DeleteOptions represents options for deleting a resource.
-///
-/// This is a simplified version suitable for the Eviction API.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
-#[serde(rename_all = "camelCase")]
-pub struct DeleteOptions {
-    /// GracePeriodSeconds is the duration in seconds before the object should be deleted.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub grace_period_seconds: Option<i64>,
-
-    /// Preconditions must be fulfilled before the operation can be executed.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub preconditions: Option<Preconditions>,
-
-    /// PropagationPolicy determines whether and how garbage collection will be performed.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub propagation_policy: Option<String>,
-
-    /// DryRun will cause the request to be executed without persisting the resource.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub dry_run: Vec<String>,
-
-    /// When present, indicates that modifications should not be persisted.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub dry_run_all: Option<bool>,
-}
-
-/// Preconditions must be fulfilled before an operation can be executed.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
-#[serde(rename_all = "camelCase")]
-pub struct Preconditions {
-    /// UID specifies the UID of the resource being operated on.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub uid: Option<String>,
-
-    /// ResourceVersion specifies the version of the resource being operated on.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub resource_version: Option<String>,
-}
-
 // ============================================================================
 // Tests
 // ============================================================================
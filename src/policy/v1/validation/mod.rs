@@ -30,6 +30,14 @@ pub fn validate_pod_disruption_budget(obj: &PodDisruptionBudget) -> ErrorList {
     all_errs
 }
 
+/// Validates a v1 PodDisruptionBudgetSpec by converting to internal and delegating validation.
+pub fn validate_pod_disruption_budget_spec(
+    spec: &PodDisruptionBudgetSpec,
+    path: &Path,
+) -> ErrorList {
+    internal::validation::validate_pod_disruption_budget_spec(&to_internal_spec(spec.clone()), path)
+}
+
 /// Validates a v1 PodDisruptionBudgetList by converting to internal and delegating validation.
 pub fn validate_pod_disruption_budget_list(obj: &PodDisruptionBudgetList) -> ErrorList {
     let mut all_errs = ErrorList::new();
@@ -143,6 +151,8 @@ fn to_internal_unhealthy_policy(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::common::validation::ErrorType;
+    use crate::common::{IntOrString, LabelSelector};
 
     #[test]
     fn test_to_internal_defaults() {
@@ -150,4 +160,32 @@ mod tests {
         let internal = to_internal_pdb(&pdb);
         assert!(internal.metadata.name.is_none());
     }
+
+    #[test]
+    fn test_validate_spec_min_and_max_both_set() {
+        let spec = PodDisruptionBudgetSpec {
+            min_available: Some(IntOrString::Int(1)),
+            max_unavailable: Some(IntOrString::String("50%".to_string())),
+            selector: Some(LabelSelector::default()),
+            unhealthy_pod_eviction_policy: Some(UnhealthyPodEvictionPolicyType::IfHealthyBudget),
+        };
+        let errs = validate_pod_disruption_budget_spec(&spec, &Path::new("spec"));
+        assert_eq!(errs.errors.len(), 1, "unexpected errors: {errs:?}");
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(errs.errors[0].field, "spec");
+    }
+
+    #[test]
+    fn test_validate_spec_requires_min_or_max() {
+        let spec = PodDisruptionBudgetSpec {
+            selector: Some(LabelSelector::default()),
+            ..Default::default()
+        };
+        let errs = validate_pod_disruption_budget_spec(&spec, &Path::new("spec"));
+        assert!(
+            errs.errors
+                .iter()
+                .any(|e| e.error_type == ErrorType::Required && e.field == "spec")
+        );
+    }
 }
@@ -0,0 +1,90 @@
+//! Conversions between v1beta1 and internal policy types
+//!
+//! v1beta1 PodDisruptionBudget has the same layout as v1 and shares its spec and
+//! status types, so conversions delegate to the v1 implementations. The only
+//! difference is the meaning of an empty selector, which is rewritten as in
+//! `Convert_v1beta1_PodDisruptionBudget_To_policy_PodDisruptionBudget`.
+
+use crate::common::{FromInternal, LabelSelector, ToInternal, TypeMeta};
+use crate::policy::{internal, v1};
+
+use super::{PodDisruptionBudget, PodDisruptionBudgetList};
+
+// ============================================================================
+// PodDisruptionBudget Conversions
+// ============================================================================
+
+impl ToInternal<internal::PodDisruptionBudget> for PodDisruptionBudget {
+    fn to_internal(self) -> internal::PodDisruptionBudget {
+        let mut value = v1::PodDisruptionBudget {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            spec: self.spec,
+            status: self.status,
+        }
+        .to_internal();
+        value.spec.selector = value.spec.selector.map(|selector| {
+            if selector == LabelSelector::default() {
+                internal::non_v1beta1_match_none_selector()
+            } else if selector == internal::v1beta1_match_all_selector() {
+                LabelSelector::default()
+            } else {
+                selector
+            }
+        });
+        value
+    }
+}
+
+impl FromInternal<internal::PodDisruptionBudget> for PodDisruptionBudget {
+    fn from_internal(mut value: internal::PodDisruptionBudget) -> Self {
+        value.spec.selector = value.spec.selector.map(|selector| {
+            if selector == internal::non_v1beta1_match_none_selector() {
+                LabelSelector::default()
+            } else if selector == LabelSelector::default() {
+                internal::v1beta1_match_all_selector()
+            } else {
+                selector
+            }
+        });
+        let value = v1::PodDisruptionBudget::from_internal(value);
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            spec: value.spec,
+            status: value.status,
+        }
+    }
+}
+
+// ============================================================================
+// PodDisruptionBudgetList Conversions
+// ============================================================================
+
+impl ToInternal<internal::PodDisruptionBudgetList> for PodDisruptionBudgetList {
+    fn to_internal(self) -> internal::PodDisruptionBudgetList {
+        internal::PodDisruptionBudgetList {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata.unwrap_or_default(),
+            items: self
+                .items
+                .into_iter()
+                .map(ToInternal::to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::PodDisruptionBudgetList> for PodDisruptionBudgetList {
+    fn from_internal(value: internal::PodDisruptionBudgetList) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: Some(value.metadata),
+            items: value
+                .items
+                .into_iter()
+                .map(PodDisruptionBudget::from_internal)
+                .collect(),
+        }
+    }
+}
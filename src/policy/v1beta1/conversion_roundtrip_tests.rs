@@ -0,0 +1,133 @@
+use super::{
+    PodDisruptionBudget, PodDisruptionBudgetList, PodDisruptionBudgetSpec,
+    PodDisruptionBudgetStatus, UnhealthyPodEvictionPolicyType,
+};
+use crate::common::test_utils::assert_conversion_roundtrip;
+use crate::common::util::IntOrString;
+use crate::common::{
+    ApplyDefault, FromInternal, LabelSelector, ListMeta, ObjectMeta, ToInternal, TypeMeta,
+};
+use crate::policy::{internal, v1};
+use std::collections::BTreeMap;
+
+fn pod_disruption_budget() -> PodDisruptionBudget {
+    PodDisruptionBudget {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta {
+            name: Some("web-pdb".to_string()),
+            namespace: Some("default".to_string()),
+            ..Default::default()
+        }),
+        spec: Some(PodDisruptionBudgetSpec {
+            min_available: Some(IntOrString::String("50%".to_string())),
+            selector: Some(LabelSelector {
+                match_labels: BTreeMap::from([("app".to_string(), "web".to_string())]),
+                ..Default::default()
+            }),
+            max_unavailable: None,
+            unhealthy_pod_eviction_policy: Some(UnhealthyPodEvictionPolicyType::AlwaysAllow),
+        }),
+        status: Some(PodDisruptionBudgetStatus {
+            observed_generation: Some(3),
+            disrupted_pods: BTreeMap::new(),
+            disruptions_allowed: Some(1),
+            current_healthy: Some(3),
+            desired_healthy: Some(2),
+            expected_pods: Some(3),
+            conditions: vec![],
+        }),
+    }
+}
+
+#[test]
+fn conversion_roundtrip_pod_disruption_budget() {
+    assert_conversion_roundtrip::<PodDisruptionBudget, internal::PodDisruptionBudget>(
+        pod_disruption_budget(),
+    );
+}
+
+#[test]
+fn conversion_roundtrip_pod_disruption_budget_list() {
+    let mut item = pod_disruption_budget();
+    item.apply_default();
+
+    assert_conversion_roundtrip::<PodDisruptionBudgetList, internal::PodDisruptionBudgetList>(
+        PodDisruptionBudgetList {
+            type_meta: TypeMeta::default(),
+            metadata: Some(ListMeta {
+                resource_version: Some("11".to_string()),
+                ..Default::default()
+            }),
+            items: vec![item],
+        },
+    );
+}
+
+#[test]
+fn pod_disruption_budget_converts_to_v1_preserving_spec() {
+    let v1beta1 = pod_disruption_budget();
+
+    let v1_pdb = v1::PodDisruptionBudget::from_internal(v1beta1.clone().to_internal());
+    assert_eq!(v1_pdb.metadata, v1beta1.metadata);
+    assert_eq!(v1_pdb.spec, v1beta1.spec);
+    assert_eq!(v1_pdb.status, v1beta1.status);
+
+    let back = PodDisruptionBudget::from_internal(v1_pdb.to_internal());
+    assert_eq!(back, v1beta1);
+}
+
+#[test]
+fn pod_disruption_budget_empty_selector_matches_no_pods_in_v1() {
+    let mut v1beta1 = pod_disruption_budget();
+    v1beta1.spec.as_mut().unwrap().selector = Some(LabelSelector::default());
+
+    let v1_pdb = v1::PodDisruptionBudget::from_internal(v1beta1.clone().to_internal());
+    assert_eq!(
+        v1_pdb.spec.as_ref().unwrap().selector,
+        Some(internal::non_v1beta1_match_none_selector())
+    );
+    assert_eq!(
+        PodDisruptionBudget::from_internal(v1_pdb.to_internal()),
+        v1beta1
+    );
+
+    // A v1 match-all selector needs an explicit expression in v1beta1.
+    let mut v1_all = v1::PodDisruptionBudget::from_internal(v1beta1.to_internal());
+    v1_all.spec.as_mut().unwrap().selector = Some(LabelSelector::default());
+    let converted = PodDisruptionBudget::from_internal(v1_all.clone().to_internal());
+    assert_eq!(
+        converted.spec.as_ref().unwrap().selector,
+        Some(internal::v1beta1_match_all_selector())
+    );
+    assert_eq!(
+        v1::PodDisruptionBudget::from_internal(converted.to_internal()),
+        v1_all
+    );
+}
+
+#[test]
+fn pod_disruption_budget_defaults_min_available_to_one() {
+    let mut pdb = pod_disruption_budget();
+    pdb.spec.as_mut().unwrap().min_available = None;
+    pdb.apply_default();
+    assert_eq!(
+        pdb.spec.as_ref().unwrap().min_available,
+        Some(IntOrString::Int(1))
+    );
+
+    // An explicit maxUnavailable suppresses the default.
+    let mut pdb = pod_disruption_budget();
+    let spec = pdb.spec.as_mut().unwrap();
+    spec.min_available = None;
+    spec.max_unavailable = Some(IntOrString::Int(1));
+    pdb.apply_default();
+    assert_eq!(pdb.spec.as_ref().unwrap().min_available, None);
+
+    // policy/v1 keeps both unset.
+    let mut v1_pdb = v1::PodDisruptionBudget {
+        spec: Some(v1::PodDisruptionBudgetSpec::default()),
+        ..Default::default()
+    };
+    v1_pdb.apply_default();
+    assert_eq!(v1_pdb.spec.as_ref().unwrap().min_available, None);
+}
@@ -0,0 +1,227 @@
+//! Kubernetes Policy v1beta1 API types
+//!
+//! This module contains the PodDisruptionBudget types from policy/v1beta1.
+//!
+//! Source: https://github.com/kubernetes/api/blob/master/policy/v1beta1/types.go
+
+use crate::common::util::IntOrString;
+use crate::common::{
+    ApplyDefault, HasTypeMeta, ListMeta, ObjectMeta, ResourceSchema, TypeMeta, VersionedObject,
+};
+use crate::impl_unimplemented_prost_message;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+pub use crate::policy::v1::{
+    PodDisruptionBudgetSpec, PodDisruptionBudgetStatus, UnhealthyPodEvictionPolicyType,
+};
+
+pub mod conversion;
+
+// ============================================================================
+// PodDisruptionBudget
+// ============================================================================
+
+/// PodDisruptionBudget is an object to define the max disruption that can be caused to a collection of pods.
+///
+/// The layout matches policy/v1, but in v1beta1 an empty (`{}`) selector
+/// selects no pods, whereas in policy/v1 it selects every pod in the namespace.
+///
+/// Corresponds to [Kubernetes PodDisruptionBudget](https://github.com/kubernetes/api/blob/master/policy/v1beta1/types.go#L157)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDisruptionBudget {
+    /// TypeMeta describes the type of this object.
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard object's metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ObjectMeta>,
+    /// Specification of the desired behavior of the PodDisruptionBudget.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec: Option<PodDisruptionBudgetSpec>,
+    /// Most recently observed status of the PodDisruptionBudget.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<PodDisruptionBudgetStatus>,
+}
+
+// ============================================================================
+// PodDisruptionBudgetList
+// ============================================================================
+
+/// PodDisruptionBudgetList is a collection of PodDisruptionBudgets.
+///
+/// Corresponds to [Kubernetes PodDisruptionBudgetList](https://github.com/kubernetes/api/blob/master/policy/v1beta1/types.go#L176)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PodDisruptionBudgetList {
+    /// TypeMeta describes the type of this object.
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard list metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMeta>,
+    /// Items is a list of PodDisruptionBudgets.
+    #[serde(default)]
+    pub items: Vec<PodDisruptionBudget>,
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+
+// ----------------------------------------------------------------------------
+// ResourceSchema Implementation
+// ----------------------------------------------------------------------------
+
+impl ResourceSchema for PodDisruptionBudget {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "policy"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "PodDisruptionBudget"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "poddisruptionbudgets"
+    }
+
+    fn group_static() -> &'static str {
+        "policy"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "PodDisruptionBudget"
+    }
+    fn resource_static() -> &'static str {
+        "poddisruptionbudgets"
+    }
+}
+
+impl ResourceSchema for PodDisruptionBudgetList {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "policy"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "PodDisruptionBudgetList"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "poddisruptionbudgets"
+    }
+
+    fn group_static() -> &'static str {
+        "policy"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "PodDisruptionBudgetList"
+    }
+    fn resource_static() -> &'static str {
+        "poddisruptionbudgets"
+    }
+}
+
+// ----------------------------------------------------------------------------
+// HasTypeMeta Implementation
+// ----------------------------------------------------------------------------
+
+impl HasTypeMeta for PodDisruptionBudget {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for PodDisruptionBudgetList {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+// ----------------------------------------------------------------------------
+// VersionedObject Implementation
+// ----------------------------------------------------------------------------
+
+impl VersionedObject for PodDisruptionBudget {
+    fn metadata(&self) -> &ObjectMeta {
+        self.metadata.as_ref().unwrap_or_else(|| {
+            static DEFAULT: OnceLock<ObjectMeta> = OnceLock::new();
+            DEFAULT.get_or_init(ObjectMeta::default)
+        })
+    }
+
+    fn metadata_mut(&mut self) -> &mut ObjectMeta {
+        self.metadata.get_or_insert_with(ObjectMeta::default)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ApplyDefaults Implementation
+// ----------------------------------------------------------------------------
+
+impl ApplyDefault for PodDisruptionBudget {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "policy/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "PodDisruptionBudget".to_string();
+        }
+        // Unlike policy/v1, v1beta1 defaults minAvailable to 1 when neither
+        // minAvailable nor maxUnavailable is set.
+        if let Some(ref mut spec) = self.spec
+            && spec.min_available.is_none()
+            && spec.max_unavailable.is_none()
+        {
+            spec.min_available = Some(IntOrString::Int(1));
+        }
+    }
+}
+
+impl ApplyDefault for PodDisruptionBudgetList {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "policy/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "PodDisruptionBudgetList".to_string();
+        }
+        for item in &mut self.items {
+            item.apply_default();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Protobuf Placeholder
+// ----------------------------------------------------------------------------
+
+impl_unimplemented_prost_message!(PodDisruptionBudget);
+impl_unimplemented_prost_message!(PodDisruptionBudgetList);
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod conversion_roundtrip_tests;
+#[cfg(test)]
+mod trait_tests;
@@ -1,10 +1,11 @@
 use crate::common::ObjectMeta;
 use crate::common::validation::{
-    ErrorList, Path, required, validate_object_meta, validate_object_meta_update,
+    ErrorList, LabelSelectorValidationOptions, Path, required,
+    validate_label_selector_with_options, validate_object_meta, validate_object_meta_update,
 };
 use crate::rbac::internal::ClusterRole;
 
-use super::{validate_label_selector, validate_policy_rule, validate_rbac_name};
+use super::{validate_policy_rule, validate_rbac_name};
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct ClusterRoleValidationOptions {
@@ -46,9 +47,12 @@ pub fn validate_cluster_role(role: &ClusterRole, opts: ClusterRoleValidationOpti
             let selector_path = Path::new("aggregationRule")
                 .child("clusterRoleSelectors")
                 .index(i);
-            all_errs.extend(validate_label_selector(
+            all_errs.extend(validate_label_selector_with_options(
                 selector,
-                opts.allow_invalid_label_value_in_selector,
+                LabelSelectorValidationOptions {
+                    allow_invalid_label_value_in_selector: opts
+                        .allow_invalid_label_value_in_selector,
+                },
                 &selector_path,
             ));
         }
@@ -7,10 +7,8 @@ mod cluster_role_binding;
 mod role;
 mod role_binding;
 
-use crate::common::meta::{LabelSelector, label_selector_operator};
 use crate::common::validation::{
-    BadValue, ErrorList, Path, invalid, is_dns1123_subdomain, is_valid_label_value, not_supported,
-    required, validate_labels, validate_qualified_name,
+    BadValue, ErrorList, Path, invalid, is_dns1123_subdomain, not_supported, required,
 };
 use crate::rbac::internal::{PolicyRule, Subject};
 use crate::rbac::v1::{api_group, subject_kind};
@@ -145,78 +143,6 @@ pub fn validate_role_binding_subject(
     all_errs
 }
 
-pub(crate) fn validate_label_selector(
-    selector: &LabelSelector,
-    allow_invalid_label_value: bool,
-    path: &Path,
-) -> ErrorList {
-    let mut all_errs = ErrorList::new();
-
-    all_errs.extend(validate_labels(
-        &selector.match_labels,
-        &path.child("matchLabels"),
-    ));
-
-    for (i, requirement) in selector.match_expressions.iter().enumerate() {
-        let req_path = path.child("matchExpressions").index(i);
-        if requirement.key.is_empty() {
-            all_errs.push(required(&req_path.child("key"), "key is required"));
-        } else {
-            all_errs.extend(validate_qualified_name(
-                &requirement.key,
-                &req_path.child("key"),
-            ));
-        }
-
-        let operator = requirement.operator.as_str();
-        match operator {
-            label_selector_operator::IN | label_selector_operator::NOT_IN => {
-                if requirement.values.is_empty() {
-                    all_errs.push(required(
-                        &req_path.child("values"),
-                        "values must be non-empty for In/NotIn operators",
-                    ));
-                }
-            }
-            label_selector_operator::EXISTS | label_selector_operator::DOES_NOT_EXIST => {
-                if !requirement.values.is_empty() {
-                    all_errs.push(invalid(
-                        &req_path.child("values"),
-                        BadValue::String(format!("{:?}", requirement.values)),
-                        "values must be empty for Exists/DoesNotExist operators",
-                    ));
-                }
-            }
-            _ => {
-                all_errs.push(not_supported(
-                    &req_path.child("operator"),
-                    BadValue::String(requirement.operator.clone()),
-                    &[
-                        label_selector_operator::IN,
-                        label_selector_operator::NOT_IN,
-                        label_selector_operator::EXISTS,
-                        label_selector_operator::DOES_NOT_EXIST,
-                    ],
-                ));
-            }
-        }
-
-        if !allow_invalid_label_value {
-            for (j, value) in requirement.values.iter().enumerate() {
-                for msg in is_valid_label_value(value) {
-                    all_errs.push(invalid(
-                        &req_path.child("values").index(j),
-                        BadValue::String(value.clone()),
-                        &msg,
-                    ));
-                }
-            }
-        }
-    }
-
-    all_errs
-}
-
 // =============================================================================
 // Tests
 // =============================================================================
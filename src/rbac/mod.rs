@@ -4,3 +4,4 @@
 
 pub mod internal;
 pub mod v1;
+pub mod v1beta1;
@@ -0,0 +1,133 @@
+//! Subject access scope helpers
+//!
+//! Summarizes which roles a subject is bound to, for displaying effective
+//! permissions.
+
+use super::{ClusterRoleBinding, RoleBinding, RoleRef, Subject};
+
+/// The roles bound to a single subject.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct SubjectAccess {
+    /// Names of the ClusterRoles granted cluster-wide through ClusterRoleBindings.
+    pub cluster_roles: Vec<String>,
+
+    /// `(namespace, roleRef)` pairs granted through RoleBindings. The role may be
+    /// a Role or a ClusterRole scoped to the binding's namespace.
+    pub namespaced_roles: Vec<(String, RoleRef)>,
+}
+
+/// Collects the roles bound to `subject` by the given bindings.
+///
+/// Subjects match when their kind, name and namespace are equal. Duplicate
+/// grants are reported once, in binding order.
+pub fn subject_bindings(
+    subject: &Subject,
+    cluster_bindings: &[ClusterRoleBinding],
+    role_bindings: &[RoleBinding],
+) -> SubjectAccess {
+    let mut access = SubjectAccess::default();
+
+    for binding in cluster_bindings {
+        if binds_subject(&binding.subjects, subject)
+            && !access.cluster_roles.contains(&binding.role_ref.name)
+        {
+            access.cluster_roles.push(binding.role_ref.name.clone());
+        }
+    }
+
+    for binding in role_bindings {
+        if !binds_subject(&binding.subjects, subject) {
+            continue;
+        }
+        let namespace = binding
+            .metadata
+            .as_ref()
+            .and_then(|meta| meta.namespace.clone())
+            .unwrap_or_default();
+        let grant = (namespace, binding.role_ref.clone());
+        if !access.namespaced_roles.contains(&grant) {
+            access.namespaced_roles.push(grant);
+        }
+    }
+
+    access
+}
+
+fn binds_subject(subjects: &[Subject], subject: &Subject) -> bool {
+    subjects.iter().any(|candidate| {
+        candidate.kind == subject.kind
+            && candidate.name == subject.name
+            && candidate.namespace == subject.namespace
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ObjectMeta;
+    use crate::rbac::v1::{api_group, subject_kind};
+
+    fn service_account(namespace: &str, name: &str) -> Subject {
+        Subject {
+            kind: subject_kind::SERVICE_ACCOUNT.to_string(),
+            api_group: String::new(),
+            name: name.to_string(),
+            namespace: namespace.to_string(),
+        }
+    }
+
+    fn role_ref(kind: &str, name: &str) -> RoleRef {
+        RoleRef {
+            api_group: api_group::RBAC.to_string(),
+            kind: kind.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_subject_bindings_cluster_and_namespace() {
+        let sa = service_account("ci", "builder");
+        let cluster_bindings = vec![
+            ClusterRoleBinding {
+                subjects: vec![sa.clone()],
+                role_ref: role_ref("ClusterRole", "view"),
+                ..Default::default()
+            },
+            ClusterRoleBinding {
+                // Same name in another namespace is a different subject.
+                subjects: vec![service_account("prod", "builder")],
+                role_ref: role_ref("ClusterRole", "cluster-admin"),
+                ..Default::default()
+            },
+        ];
+        let role_bindings = vec![
+            RoleBinding {
+                metadata: Some(ObjectMeta {
+                    name: Some("deployer".to_string()),
+                    namespace: Some("staging".to_string()),
+                    ..Default::default()
+                }),
+                subjects: vec![service_account("ci", "other"), sa.clone()],
+                role_ref: role_ref("Role", "deployer"),
+                ..Default::default()
+            },
+            RoleBinding {
+                metadata: Some(ObjectMeta {
+                    name: Some("admin".to_string()),
+                    namespace: Some("prod".to_string()),
+                    ..Default::default()
+                }),
+                subjects: vec![service_account("prod", "builder")],
+                role_ref: role_ref("ClusterRole", "admin"),
+                ..Default::default()
+            },
+        ];
+
+        let access = subject_bindings(&sa, &cluster_bindings, &role_bindings);
+        assert_eq!(access.cluster_roles, vec!["view".to_string()]);
+        assert_eq!(
+            access.namespaced_roles,
+            vec![("staging".to_string(), role_ref("Role", "deployer"))]
+        );
+    }
+}
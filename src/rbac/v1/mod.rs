@@ -2,6 +2,7 @@
 //!
 //! This module contains the RBAC v1 API types.
 
+pub mod access;
 mod conversion;
 pub mod rbac;
 pub mod validation;
@@ -11,6 +12,8 @@ pub use rbac::{
     PolicyRule, Role, RoleBinding, RoleBindingList, RoleList, RoleRef, Subject,
 };
 
+pub use access::{SubjectAccess, subject_bindings};
+
 // Re-export constant modules for use in validation
 pub use rbac::{api_group, subject_kind};
 
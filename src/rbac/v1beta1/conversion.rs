@@ -0,0 +1,223 @@
+//! Conversions between v1beta1 and internal RBAC types
+//!
+//! RBAC internal types are re-exports of v1, and v1beta1 objects carry the same
+//! fields (including `nonResourceURLs` on rules and `aggregationRule` on
+//! ClusterRole), so conversion moves the fields across and resets type_meta.
+
+use crate::common::{FromInternal, ToInternal, TypeMeta};
+use crate::rbac::internal;
+
+use super::{
+    ClusterRole, ClusterRoleBinding, ClusterRoleBindingList, ClusterRoleList, Role, RoleBinding,
+    RoleBindingList, RoleList,
+};
+
+// ============================================================================
+// Role Conversions
+// ============================================================================
+
+impl ToInternal<internal::Role> for Role {
+    fn to_internal(self) -> internal::Role {
+        internal::Role {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            rules: self.rules,
+        }
+    }
+}
+
+impl FromInternal<internal::Role> for Role {
+    fn from_internal(value: internal::Role) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            rules: value.rules,
+        }
+    }
+}
+
+impl ToInternal<internal::RoleList> for RoleList {
+    fn to_internal(self) -> internal::RoleList {
+        internal::RoleList {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            items: self
+                .items
+                .into_iter()
+                .map(ToInternal::to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::RoleList> for RoleList {
+    fn from_internal(value: internal::RoleList) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            items: value.items.into_iter().map(Role::from_internal).collect(),
+        }
+    }
+}
+
+// ============================================================================
+// RoleBinding Conversions
+// ============================================================================
+
+impl ToInternal<internal::RoleBinding> for RoleBinding {
+    fn to_internal(self) -> internal::RoleBinding {
+        internal::RoleBinding {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            subjects: self.subjects,
+            role_ref: self.role_ref,
+        }
+    }
+}
+
+impl FromInternal<internal::RoleBinding> for RoleBinding {
+    fn from_internal(value: internal::RoleBinding) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            subjects: value.subjects,
+            role_ref: value.role_ref,
+        }
+    }
+}
+
+impl ToInternal<internal::RoleBindingList> for RoleBindingList {
+    fn to_internal(self) -> internal::RoleBindingList {
+        internal::RoleBindingList {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            items: self
+                .items
+                .into_iter()
+                .map(ToInternal::to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::RoleBindingList> for RoleBindingList {
+    fn from_internal(value: internal::RoleBindingList) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            items: value
+                .items
+                .into_iter()
+                .map(RoleBinding::from_internal)
+                .collect(),
+        }
+    }
+}
+
+// ============================================================================
+// ClusterRole Conversions
+// ============================================================================
+
+impl ToInternal<internal::ClusterRole> for ClusterRole {
+    fn to_internal(self) -> internal::ClusterRole {
+        internal::ClusterRole {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            rules: self.rules,
+            aggregation_rule: self.aggregation_rule,
+        }
+    }
+}
+
+impl FromInternal<internal::ClusterRole> for ClusterRole {
+    fn from_internal(value: internal::ClusterRole) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            rules: value.rules,
+            aggregation_rule: value.aggregation_rule,
+        }
+    }
+}
+
+impl ToInternal<internal::ClusterRoleList> for ClusterRoleList {
+    fn to_internal(self) -> internal::ClusterRoleList {
+        internal::ClusterRoleList {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            items: self
+                .items
+                .into_iter()
+                .map(ToInternal::to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::ClusterRoleList> for ClusterRoleList {
+    fn from_internal(value: internal::ClusterRoleList) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            items: value
+                .items
+                .into_iter()
+                .map(ClusterRole::from_internal)
+                .collect(),
+        }
+    }
+}
+
+// ============================================================================
+// ClusterRoleBinding Conversions
+// ============================================================================
+
+impl ToInternal<internal::ClusterRoleBinding> for ClusterRoleBinding {
+    fn to_internal(self) -> internal::ClusterRoleBinding {
+        internal::ClusterRoleBinding {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            subjects: self.subjects,
+            role_ref: self.role_ref,
+        }
+    }
+}
+
+impl FromInternal<internal::ClusterRoleBinding> for ClusterRoleBinding {
+    fn from_internal(value: internal::ClusterRoleBinding) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            subjects: value.subjects,
+            role_ref: value.role_ref,
+        }
+    }
+}
+
+impl ToInternal<internal::ClusterRoleBindingList> for ClusterRoleBindingList {
+    fn to_internal(self) -> internal::ClusterRoleBindingList {
+        internal::ClusterRoleBindingList {
+            type_meta: TypeMeta::default(),
+            metadata: self.metadata,
+            items: self
+                .items
+                .into_iter()
+                .map(ToInternal::to_internal)
+                .collect(),
+        }
+    }
+}
+
+impl FromInternal<internal::ClusterRoleBindingList> for ClusterRoleBindingList {
+    fn from_internal(value: internal::ClusterRoleBindingList) -> Self {
+        Self {
+            type_meta: TypeMeta::default(),
+            metadata: value.metadata,
+            items: value
+                .items
+                .into_iter()
+                .map(ClusterRoleBinding::from_internal)
+                .collect(),
+        }
+    }
+}
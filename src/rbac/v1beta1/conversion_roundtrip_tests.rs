@@ -0,0 +1,147 @@
+use super::{
+    AggregationRule, ClusterRole, ClusterRoleBinding, ClusterRoleList, PolicyRule, Role,
+    RoleBinding, RoleRef, Subject,
+};
+use crate::common::test_utils::assert_conversion_roundtrip;
+use crate::common::{FromInternal, LabelSelector, ListMeta, ObjectMeta, ToInternal, TypeMeta};
+use crate::rbac::{internal, v1};
+use std::collections::BTreeMap;
+
+fn cluster_role_with_non_resource_urls() -> ClusterRole {
+    ClusterRole {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta {
+            name: Some("system:discovery".to_string()),
+            ..Default::default()
+        }),
+        rules: vec![
+            PolicyRule {
+                verbs: vec!["get".to_string()],
+                non_resource_urls: vec![
+                    "/healthz".to_string(),
+                    "/version".to_string(),
+                    "/apis/*".to_string(),
+                ],
+                ..Default::default()
+            },
+            PolicyRule {
+                verbs: vec!["get".to_string(), "list".to_string()],
+                api_groups: vec![String::new()],
+                resources: vec!["configmaps".to_string()],
+                resource_names: vec!["cluster-info".to_string()],
+                ..Default::default()
+            },
+        ],
+        aggregation_rule: Some(AggregationRule {
+            cluster_role_selectors: vec![LabelSelector {
+                match_labels: BTreeMap::from([(
+                    "rbac.example.com/aggregate-to-discovery".to_string(),
+                    "true".to_string(),
+                )]),
+                ..Default::default()
+            }],
+        }),
+    }
+}
+
+fn role_ref(kind: &str, name: &str) -> RoleRef {
+    RoleRef {
+        api_group: "rbac.authorization.k8s.io".to_string(),
+        kind: kind.to_string(),
+        name: name.to_string(),
+    }
+}
+
+fn service_account(name: &str) -> Subject {
+    Subject {
+        kind: "ServiceAccount".to_string(),
+        api_group: String::new(),
+        name: name.to_string(),
+        namespace: "kube-system".to_string(),
+    }
+}
+
+#[test]
+fn conversion_roundtrip_cluster_role() {
+    assert_conversion_roundtrip::<ClusterRole, internal::ClusterRole>(
+        cluster_role_with_non_resource_urls(),
+    );
+}
+
+#[test]
+fn conversion_roundtrip_cluster_role_list() {
+    let mut item = cluster_role_with_non_resource_urls();
+    item.type_meta = TypeMeta {
+        api_version: "rbac.authorization.k8s.io/v1beta1".to_string(),
+        kind: "ClusterRole".to_string(),
+    };
+
+    assert_conversion_roundtrip::<ClusterRoleList, internal::ClusterRoleList>(ClusterRoleList {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ListMeta {
+            resource_version: Some("42".to_string()),
+            ..Default::default()
+        }),
+        items: vec![item],
+    });
+}
+
+#[test]
+fn conversion_roundtrip_role_and_bindings() {
+    assert_conversion_roundtrip::<Role, internal::Role>(Role {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta {
+            name: Some("pod-reader".to_string()),
+            namespace: Some("default".to_string()),
+            ..Default::default()
+        }),
+        rules: vec![PolicyRule {
+            verbs: vec!["get".to_string(), "watch".to_string()],
+            api_groups: vec![String::new()],
+            resources: vec!["pods".to_string()],
+            ..Default::default()
+        }],
+    });
+    assert_conversion_roundtrip::<RoleBinding, internal::RoleBinding>(RoleBinding {
+        type_meta: TypeMeta::default(),
+        metadata: Some(ObjectMeta {
+            name: Some("read-pods".to_string()),
+            namespace: Some("default".to_string()),
+            ..Default::default()
+        }),
+        subjects: vec![service_account("builder")],
+        role_ref: role_ref("Role", "pod-reader"),
+    });
+    assert_conversion_roundtrip::<ClusterRoleBinding, internal::ClusterRoleBinding>(
+        ClusterRoleBinding {
+            type_meta: TypeMeta::default(),
+            metadata: Some(ObjectMeta {
+                name: Some("system:discovery".to_string()),
+                ..Default::default()
+            }),
+            subjects: vec![Subject {
+                kind: "Group".to_string(),
+                api_group: "rbac.authorization.k8s.io".to_string(),
+                name: "system:authenticated".to_string(),
+                namespace: String::new(),
+            }],
+            role_ref: role_ref("ClusterRole", "system:discovery"),
+        },
+    );
+}
+
+#[test]
+fn cluster_role_converts_to_v1_preserving_non_resource_urls() {
+    let v1beta1 = cluster_role_with_non_resource_urls();
+
+    let v1_role = v1::ClusterRole::from_internal(v1beta1.clone().to_internal());
+    assert_eq!(v1_role.metadata, v1beta1.metadata);
+    assert_eq!(
+        v1_role.rules[0].non_resource_urls,
+        vec!["/healthz", "/version", "/apis/*"]
+    );
+    assert_eq!(v1_role.aggregation_rule, v1beta1.aggregation_rule);
+
+    let back = ClusterRole::from_internal(v1_role.to_internal());
+    assert_eq!(back, v1beta1);
+}
@@ -0,0 +1,638 @@
+//! Kubernetes RBAC v1beta1 API types
+//!
+//! This module contains the RBAC types from rbac.authorization.k8s.io/v1beta1.
+//! The group is served only for migrating old manifests; its objects have the
+//! same layout as v1 and share the v1 `PolicyRule`, `Subject`, `RoleRef` and
+//! `AggregationRule` types.
+//!
+//! Source: https://github.com/kubernetes/api/blob/master/rbac/v1beta1/types.go
+
+use crate::common::{ApplyDefault, HasTypeMeta, ListMeta, ObjectMeta, ResourceSchema, TypeMeta};
+use crate::{impl_unimplemented_prost_message, impl_versioned_object};
+use serde::{Deserialize, Serialize};
+
+pub use crate::rbac::v1::{AggregationRule, PolicyRule, RoleRef, Subject};
+
+pub mod conversion;
+
+// ============================================================================
+// Role
+// ============================================================================
+
+/// Role is a namespaced, logical grouping of PolicyRules.
+///
+/// Corresponds to [Kubernetes Role](https://github.com/kubernetes/api/blob/master/rbac/v1beta1/types.go)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Role {
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard object's metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ObjectMeta>,
+
+    /// Rules holds all the PolicyRules for this Role.
+    #[serde(default)]
+    pub rules: Vec<PolicyRule>,
+}
+impl_versioned_object!(Role);
+
+/// RoleList is a collection of Roles.
+///
+/// Corresponds to [Kubernetes RoleList](https://github.com/kubernetes/api/blob/master/rbac/v1beta1/types.go)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleList {
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard list metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMeta>,
+
+    /// Items is a list of Roles.
+    #[serde(default)]
+    pub items: Vec<Role>,
+}
+
+// ============================================================================
+// RoleBinding
+// ============================================================================
+
+/// RoleBinding references a role, but does not contain it.
+///
+/// Corresponds to [Kubernetes RoleBinding](https://github.com/kubernetes/api/blob/master/rbac/v1beta1/types.go)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleBinding {
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard object's metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ObjectMeta>,
+
+    /// Subjects holds references to the objects the role applies to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subjects: Vec<Subject>,
+
+    /// RoleRef can reference a Role in the current namespace or a ClusterRole.
+    pub role_ref: RoleRef,
+}
+impl_versioned_object!(RoleBinding);
+
+/// RoleBindingList is a collection of RoleBindings.
+///
+/// Corresponds to [Kubernetes RoleBindingList](https://github.com/kubernetes/api/blob/master/rbac/v1beta1/types.go)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleBindingList {
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard list metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMeta>,
+
+    /// Items is a list of RoleBindings.
+    #[serde(default)]
+    pub items: Vec<RoleBinding>,
+}
+
+// ============================================================================
+// ClusterRole
+// ============================================================================
+
+/// ClusterRole is a cluster level, logical grouping of PolicyRules.
+///
+/// Corresponds to [Kubernetes ClusterRole](https://github.com/kubernetes/api/blob/master/rbac/v1beta1/types.go)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterRole {
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard object's metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ObjectMeta>,
+
+    /// Rules holds all the PolicyRules for this ClusterRole.
+    #[serde(default)]
+    pub rules: Vec<PolicyRule>,
+
+    /// AggregationRule is an optional field that describes how to build the Rules.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aggregation_rule: Option<AggregationRule>,
+}
+impl_versioned_object!(ClusterRole);
+
+/// ClusterRoleList is a collection of ClusterRoles.
+///
+/// Corresponds to [Kubernetes ClusterRoleList](https://github.com/kubernetes/api/blob/master/rbac/v1beta1/types.go)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterRoleList {
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard list metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMeta>,
+
+    /// Items is a list of ClusterRoles.
+    #[serde(default)]
+    pub items: Vec<ClusterRole>,
+}
+
+// ============================================================================
+// ClusterRoleBinding
+// ============================================================================
+
+/// ClusterRoleBinding references a ClusterRole, but not contain it.
+///
+/// Corresponds to [Kubernetes ClusterRoleBinding](https://github.com/kubernetes/api/blob/master/rbac/v1beta1/types.go)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterRoleBinding {
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard object's metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ObjectMeta>,
+
+    /// Subjects holds references to the objects the role applies to.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subjects: Vec<Subject>,
+
+    /// RoleRef can only reference a ClusterRole in the global namespace.
+    pub role_ref: RoleRef,
+}
+impl_versioned_object!(ClusterRoleBinding);
+
+/// ClusterRoleBindingList is a collection of ClusterRoleBindings.
+///
+/// Corresponds to [Kubernetes ClusterRoleBindingList](https://github.com/kubernetes/api/blob/master/rbac/v1beta1/types.go)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterRoleBindingList {
+    #[serde(flatten)]
+    pub type_meta: TypeMeta,
+    /// Standard list metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMeta>,
+
+    /// Items is a list of ClusterRoleBindings.
+    #[serde(default)]
+    pub items: Vec<ClusterRoleBinding>,
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+
+// ----------------------------------------------------------------------------
+// ResourceSchema Implementation
+// ----------------------------------------------------------------------------
+
+impl ResourceSchema for Role {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "Role"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "roles"
+    }
+
+    fn group_static() -> &'static str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "Role"
+    }
+    fn resource_static() -> &'static str {
+        "roles"
+    }
+}
+
+impl ResourceSchema for RoleList {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "RoleList"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "roles"
+    }
+
+    fn group_static() -> &'static str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "RoleList"
+    }
+    fn resource_static() -> &'static str {
+        "roles"
+    }
+}
+
+impl ResourceSchema for ClusterRole {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "ClusterRole"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "clusterroles"
+    }
+
+    fn group_static() -> &'static str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "ClusterRole"
+    }
+    fn resource_static() -> &'static str {
+        "clusterroles"
+    }
+}
+
+impl ResourceSchema for ClusterRoleList {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "ClusterRoleList"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "clusterroles"
+    }
+
+    fn group_static() -> &'static str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "ClusterRoleList"
+    }
+    fn resource_static() -> &'static str {
+        "clusterroles"
+    }
+}
+
+impl ResourceSchema for RoleBinding {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "RoleBinding"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "rolebindings"
+    }
+
+    fn group_static() -> &'static str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "RoleBinding"
+    }
+    fn resource_static() -> &'static str {
+        "rolebindings"
+    }
+}
+
+impl ResourceSchema for RoleBindingList {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "RoleBindingList"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "rolebindings"
+    }
+
+    fn group_static() -> &'static str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "RoleBindingList"
+    }
+    fn resource_static() -> &'static str {
+        "rolebindings"
+    }
+}
+
+impl ResourceSchema for ClusterRoleBinding {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "ClusterRoleBinding"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "clusterrolebindings"
+    }
+
+    fn group_static() -> &'static str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "ClusterRoleBinding"
+    }
+    fn resource_static() -> &'static str {
+        "clusterrolebindings"
+    }
+}
+
+impl ResourceSchema for ClusterRoleBindingList {
+    type Meta = ();
+
+    fn group(_: &Self::Meta) -> &str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version(_: &Self::Meta) -> &str {
+        "v1beta1"
+    }
+    fn kind(_: &Self::Meta) -> &str {
+        "ClusterRoleBindingList"
+    }
+    fn resource(_: &Self::Meta) -> &str {
+        "clusterrolebindings"
+    }
+
+    fn group_static() -> &'static str {
+        "rbac.authorization.k8s.io"
+    }
+    fn version_static() -> &'static str {
+        "v1beta1"
+    }
+    fn kind_static() -> &'static str {
+        "ClusterRoleBindingList"
+    }
+    fn resource_static() -> &'static str {
+        "clusterrolebindings"
+    }
+}
+
+// ----------------------------------------------------------------------------
+// HasTypeMeta Implementation
+// ----------------------------------------------------------------------------
+
+impl HasTypeMeta for Role {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for RoleList {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for ClusterRole {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for ClusterRoleList {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for RoleBinding {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for RoleBindingList {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for ClusterRoleBinding {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+impl HasTypeMeta for ClusterRoleBindingList {
+    fn type_meta(&self) -> &TypeMeta {
+        &self.type_meta
+    }
+    fn type_meta_mut(&mut self) -> &mut TypeMeta {
+        &mut self.type_meta
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ApplyDefaults Implementation
+// ----------------------------------------------------------------------------
+
+impl ApplyDefault for Role {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "rbac.authorization.k8s.io/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "Role".to_string();
+        }
+    }
+}
+
+impl ApplyDefault for RoleList {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "rbac.authorization.k8s.io/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "RoleList".to_string();
+        }
+        for item in &mut self.items {
+            item.apply_default();
+        }
+    }
+}
+
+impl ApplyDefault for ClusterRole {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "rbac.authorization.k8s.io/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "ClusterRole".to_string();
+        }
+    }
+}
+
+impl ApplyDefault for ClusterRoleList {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "rbac.authorization.k8s.io/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "ClusterRoleList".to_string();
+        }
+        for item in &mut self.items {
+            item.apply_default();
+        }
+    }
+}
+
+impl ApplyDefault for RoleBinding {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "rbac.authorization.k8s.io/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "RoleBinding".to_string();
+        }
+        if self.role_ref.api_group.is_empty() {
+            self.role_ref.api_group = "rbac.authorization.k8s.io".to_string();
+        }
+    }
+}
+
+impl ApplyDefault for RoleBindingList {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "rbac.authorization.k8s.io/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "RoleBindingList".to_string();
+        }
+        for item in &mut self.items {
+            item.apply_default();
+        }
+    }
+}
+
+impl ApplyDefault for ClusterRoleBinding {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "rbac.authorization.k8s.io/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "ClusterRoleBinding".to_string();
+        }
+        if self.role_ref.api_group.is_empty() {
+            self.role_ref.api_group = "rbac.authorization.k8s.io".to_string();
+        }
+    }
+}
+
+impl ApplyDefault for ClusterRoleBindingList {
+    fn apply_default(&mut self) {
+        if self.type_meta.api_version.is_empty() {
+            self.type_meta.api_version = "rbac.authorization.k8s.io/v1beta1".to_string();
+        }
+        if self.type_meta.kind.is_empty() {
+            self.type_meta.kind = "ClusterRoleBindingList".to_string();
+        }
+        for item in &mut self.items {
+            item.apply_default();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Protobuf Placeholder
+// ----------------------------------------------------------------------------
+
+impl_unimplemented_prost_message!(Role);
+impl_unimplemented_prost_message!(RoleList);
+impl_unimplemented_prost_message!(ClusterRole);
+impl_unimplemented_prost_message!(ClusterRoleList);
+impl_unimplemented_prost_message!(RoleBinding);
+impl_unimplemented_prost_message!(RoleBindingList);
+impl_unimplemented_prost_message!(ClusterRoleBinding);
+impl_unimplemented_prost_message!(ClusterRoleBindingList);
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod conversion_roundtrip_tests;
+#[cfg(test)]
+mod trait_tests;
@@ -26,7 +26,7 @@ pub struct DeviceClassList {
     pub type_meta: TypeMeta,
     #[serde(default)]
     pub metadata: Option<ListMeta>,
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<DeviceClass>,
 }
 
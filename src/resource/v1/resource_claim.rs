@@ -29,7 +29,7 @@ pub struct ResourceClaimList {
     pub type_meta: TypeMeta,
     #[serde(default)]
     pub metadata: Option<ListMeta>,
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ResourceClaim>,
 }
 
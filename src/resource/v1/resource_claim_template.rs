@@ -27,7 +27,7 @@ pub struct ResourceClaimTemplateList {
     pub type_meta: TypeMeta,
     #[serde(default)]
     pub metadata: Option<ListMeta>,
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<ResourceClaimTemplate>,
 }
 
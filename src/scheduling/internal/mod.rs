@@ -55,7 +55,7 @@ pub struct PriorityClassList {
     #[serde(default, skip_serializing_if = "ListMeta::is_empty")]
     pub metadata: ListMeta,
     /// PriorityClass items.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<PriorityClass>,
 }
 
@@ -72,7 +72,7 @@ pub struct PriorityClassList {
     pub metadata: Option<ListMeta>,
 
     /// items is the list of PriorityClasses.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<PriorityClass>,
 }
 
@@ -22,11 +22,20 @@ pub struct CSINodeValidationOptions {
 pub fn validate_csi_node_v1(
     obj: &storage_v1::CSINode,
     opts: CSINodeValidationOptions,
+) -> ErrorList {
+    validate_csi_node_v1_with_path(obj, opts, &Path::nil())
+}
+
+pub fn validate_csi_node_v1_with_path(
+    obj: &storage_v1::CSINode,
+    opts: CSINodeValidationOptions,
+    base_path: &Path,
 ) -> ErrorList {
     validate_csi_node_common(
         obj.metadata.as_ref().unwrap_or(&ObjectMeta::default()),
         &obj.spec,
         opts,
+        base_path,
     )
 }
 
@@ -54,6 +63,7 @@ pub fn validate_csi_node_v1beta1(
         obj.metadata.as_ref().unwrap_or(&ObjectMeta::default()),
         &spec,
         opts,
+        &Path::nil(),
     )
 }
 
@@ -61,9 +71,9 @@ fn validate_csi_node_common(
     metadata: &ObjectMeta,
     spec: &storage_v1::CSINodeSpec,
     opts: CSINodeValidationOptions,
+    base_path: &Path,
 ) -> ErrorList {
     let mut all_errs = ErrorList::new();
-    let base_path = Path::nil();
 
     all_errs.extend(validate_object_meta(
         metadata,
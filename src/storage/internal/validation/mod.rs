@@ -4,12 +4,12 @@
 
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 
-use crate::common::meta::label_selector_operator;
 use crate::common::validation::{
     BadValue, ErrorList, Path, duplicate, invalid, not_supported, required, too_long,
-    validate_labels, validate_qualified_name,
+    validate_label_selector, validate_qualified_name,
 };
-use crate::common::{LabelSelector, PersistentVolumeReclaimPolicy, TopologySelectorTerm};
+use crate::common::volume::persistent_volume_reclaim_policy;
+use crate::common::{PersistentVolumeReclaimPolicy, TopologySelectorTerm};
 
 mod csi_driver;
 mod csi_node;
@@ -19,12 +19,17 @@ mod volume_attachment;
 mod volume_attributes_class;
 
 pub use csi_driver::{validate_csi_driver_v1, validate_csi_driver_v1beta1};
-pub use csi_node::{CSINodeValidationOptions, validate_csi_node_v1, validate_csi_node_v1beta1};
+pub use csi_node::{
+    CSINodeValidationOptions, validate_csi_node_v1, validate_csi_node_v1_with_path,
+    validate_csi_node_v1beta1,
+};
 pub use csi_storage_capacity::{
     validate_csi_storage_capacity_v1, validate_csi_storage_capacity_v1alpha1,
     validate_csi_storage_capacity_v1beta1,
 };
-pub use storage_class::{validate_storage_class_v1, validate_storage_class_v1beta1};
+pub use storage_class::{
+    validate_storage_class_v1, validate_storage_class_v1_with_path, validate_storage_class_v1beta1,
+};
 pub use volume_attachment::{
     validate_volume_attachment_v1, validate_volume_attachment_v1alpha1,
     validate_volume_attachment_v1beta1,
@@ -39,6 +44,7 @@ const MAX_PROVISIONER_PARAMETER_LEN: usize = 512;
 
 const MAX_ATTACHED_VOLUME_METADATA_SIZE: usize = 256 * 1024;
 const MAX_VOLUME_ERROR_MESSAGE_SIZE: usize = 1024;
+const CSI_DRIVER_NAME_MAX_LENGTH: usize = 63;
 
 // ============================================================================
 // Shared Validation Helpers
@@ -95,11 +101,23 @@ fn validate_reclaim_policy(
     path: &Path,
 ) -> ErrorList {
     let mut all_errs = ErrorList::new();
-    let Some(_policy) = policy else {
+    let Some(policy) = policy else {
         all_errs.push(required(path, "reclaimPolicy is required"));
         return all_errs;
     };
 
+    // Recycle is only meaningful for statically provisioned PersistentVolumes.
+    let supported = [
+        persistent_volume_reclaim_policy::DELETE,
+        persistent_volume_reclaim_policy::RETAIN,
+    ];
+    if !supported.contains(&policy.as_ref()) {
+        all_errs.push(not_supported(
+            path,
+            BadValue::String(policy.as_ref().to_string()),
+            &supported,
+        ));
+    }
     all_errs
 }
 
@@ -182,70 +200,6 @@ fn validate_allowed_topologies(topologies: &[TopologySelectorTerm], path: &Path)
     all_errs
 }
 
-fn validate_label_selector(selector: &LabelSelector, path: &Path) -> ErrorList {
-    let mut all_errs = ErrorList::new();
-    all_errs.extend(validate_labels(
-        &selector.match_labels,
-        &path.child("matchLabels"),
-    ));
-
-    for (i, requirement) in selector.match_expressions.iter().enumerate() {
-        let req_path = path.child("matchExpressions").index(i);
-        if requirement.key.is_empty() {
-            all_errs.push(required(&req_path.child("key"), "key is required"));
-        } else {
-            all_errs.extend(validate_qualified_name(
-                &requirement.key,
-                &req_path.child("key"),
-            ));
-        }
-
-        let operator = requirement.operator.as_str();
-        match operator {
-            label_selector_operator::IN | label_selector_operator::NOT_IN => {
-                if requirement.values.is_empty() {
-                    all_errs.push(required(
-                        &req_path.child("values"),
-                        "values must be non-empty for In/NotIn operators",
-                    ));
-                }
-            }
-            label_selector_operator::EXISTS | label_selector_operator::DOES_NOT_EXIST => {
-                if !requirement.values.is_empty() {
-                    all_errs.push(invalid(
-                        &req_path.child("values"),
-                        BadValue::String(format!("{:?}", requirement.values)),
-                        "values must be empty for Exists/DoesNotExist operators",
-                    ));
-                }
-            }
-            _ => {
-                all_errs.push(not_supported(
-                    &req_path.child("operator"),
-                    BadValue::String(requirement.operator.clone()),
-                    &[
-                        label_selector_operator::IN,
-                        label_selector_operator::NOT_IN,
-                        label_selector_operator::EXISTS,
-                        label_selector_operator::DOES_NOT_EXIST,
-                    ],
-                ));
-            }
-        }
-
-        for (j, value) in requirement.values.iter().enumerate() {
-            if value.is_empty() {
-                all_errs.push(required(
-                    &req_path.child("values").index(j),
-                    "value must be non-empty",
-                ));
-            }
-        }
-    }
-
-    all_errs
-}
-
 fn validate_volume_attachment_source(
     source: &crate::storage::v1::VolumeAttachmentSource,
     path: &Path,
@@ -335,7 +289,14 @@ fn validate_volume_error(
 
 fn validate_csi_driver_name(name: &str, path: &Path) -> ErrorList {
     let mut all_errs = ErrorList::new();
-    for msg in crate::common::validation::is_dns1123_subdomain(name) {
+    if name.is_empty() {
+        all_errs.push(required(path, ""));
+        return all_errs;
+    }
+    if name.len() > CSI_DRIVER_NAME_MAX_LENGTH {
+        all_errs.push(too_long(path, CSI_DRIVER_NAME_MAX_LENGTH));
+    }
+    for msg in crate::common::validation::is_dns1123_subdomain(&name.to_lowercase()) {
         all_errs.push(invalid(path, BadValue::String(name.to_string()), &msg));
     }
     all_errs
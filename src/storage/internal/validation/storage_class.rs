@@ -9,7 +9,13 @@ use super::{
 };
 
 pub fn validate_storage_class_v1(obj: &storage_v1::StorageClass) -> ErrorList {
-    let base_path = Path::nil();
+    validate_storage_class_v1_with_path(obj, &Path::nil())
+}
+
+pub fn validate_storage_class_v1_with_path(
+    obj: &storage_v1::StorageClass,
+    base_path: &Path,
+) -> ErrorList {
     validate_storage_class_common(
         obj.metadata.as_ref().unwrap_or(&ObjectMeta::default()),
         &obj.provisioner,
@@ -22,7 +28,7 @@ pub fn validate_storage_class_v1(obj: &storage_v1::StorageClass) -> ErrorList {
             }
         }),
         &obj.allowed_topologies,
-        &base_path,
+        base_path,
     )
 }
 
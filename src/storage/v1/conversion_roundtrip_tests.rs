@@ -7,7 +7,8 @@ use super::{
 use crate::common::test_utils::assert_conversion_roundtrip;
 use crate::common::util::Quantity;
 use crate::common::{
-    ApplyDefault, LabelSelector, ListMeta, ObjectMeta, PersistentVolumeReclaimPolicy, TypeMeta,
+    ApplyDefault, LabelSelector, ListMeta, ObjectMeta, PersistentVolumeReclaimPolicy, ToInternal,
+    TypeMeta,
 };
 use crate::storage::internal;
 use std::collections::BTreeMap;
@@ -247,6 +248,25 @@ fn conversion_roundtrip_csi_storage_capacity() {
     );
 }
 
+#[test]
+fn conversion_roundtrip_csi_storage_capacity_without_capacity() {
+    let mut capacity = CSIStorageCapacity {
+        capacity: None,
+        ..csi_storage_capacity_basic()
+    };
+    capacity.apply_default();
+    assert_eq!(capacity.capacity, None);
+
+    let internal = capacity.clone().to_internal();
+    assert_eq!(internal.capacity, None);
+    assert_eq!(
+        internal.maximum_volume_size,
+        Some(Quantity("1Ti".to_string()))
+    );
+    assert_eq!(internal.node_topology, capacity.node_topology);
+    assert_conversion_roundtrip::<CSIStorageCapacity, internal::CSIStorageCapacity>(capacity);
+}
+
 #[test]
 fn conversion_roundtrip_csi_storage_capacity_list() {
     assert_conversion_roundtrip::<CSIStorageCapacityList, internal::CSIStorageCapacityList>(
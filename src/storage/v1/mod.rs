@@ -27,6 +27,7 @@ pub use volume_attributes_class::{VolumeAttributesClass, VolumeAttributesClassLi
 // Re-export constants
 pub use csi_driver::{fs_group_policy, volume_lifecycle_mode};
 pub use storage_class::volume_binding_mode;
+pub use validation::{validate_csi_node, validate_storage_class};
 
 #[cfg(test)]
 mod trait_tests;
@@ -1,4 +1,4 @@
-use crate::common::validation::ErrorList;
+use crate::common::validation::{ErrorList, Path};
 use crate::storage::internal::validation as internal_validation;
 use crate::storage::internal::validation::CSINodeValidationOptions;
 use crate::storage::v1 as storage_v1;
@@ -9,3 +9,80 @@ pub fn validate_csi_node_v1(
 ) -> ErrorList {
     internal_validation::validate_csi_node_v1(obj, opts)
 }
+
+/// Validates a CSINode with its fields rooted at `path`, using default options.
+pub fn validate_csi_node(obj: &storage_v1::CSINode, path: &Path) -> ErrorList {
+    internal_validation::validate_csi_node_v1_with_path(
+        obj,
+        CSINodeValidationOptions::default(),
+        path,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ObjectMeta;
+    use crate::common::validation::ErrorType;
+
+    fn driver(name: &str, topology_keys: &[&str]) -> storage_v1::CSINodeDriver {
+        storage_v1::CSINodeDriver {
+            name: name.to_string(),
+            node_id: "i-0a1b2c3d4e5f".to_string(),
+            topology_keys: topology_keys.iter().map(|key| key.to_string()).collect(),
+            allocatable: Some(storage_v1::VolumeNodeResources { count: Some(25) }),
+        }
+    }
+
+    fn csi_node(drivers: Vec<storage_v1::CSINodeDriver>) -> storage_v1::CSINode {
+        storage_v1::CSINode {
+            metadata: Some(ObjectMeta {
+                name: Some("worker-1".to_string()),
+                ..Default::default()
+            }),
+            spec: storage_v1::CSINodeSpec { drivers },
+            ..Default::default()
+        }
+    }
+
+    fn errors(obj: &storage_v1::CSINode) -> Vec<(String, ErrorType)> {
+        validate_csi_node(obj, &Path::new("csiNode"))
+            .errors
+            .into_iter()
+            .map(|err| (err.field, err.error_type))
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_csi_node_duplicate_driver() {
+        let valid = csi_node(vec![driver(
+            "ebs.csi.aws.com",
+            &["topology.ebs.csi.aws.com/zone"],
+        )]);
+        assert!(errors(&valid).is_empty());
+
+        let duplicated = csi_node(vec![
+            driver("ebs.csi.aws.com", &[]),
+            driver("ebs.csi.aws.com", &[]),
+        ]);
+        assert_eq!(
+            errors(&duplicated),
+            vec![(
+                "csiNode.spec.drivers[1].name".to_string(),
+                ErrorType::Duplicate
+            )]
+        );
+    }
+
+    #[test]
+    fn test_validate_csi_node_invalid_topology_key() {
+        let obj = csi_node(vec![driver("ebs.csi.aws.com", &["zone/with/slashes"])]);
+        assert_eq!(
+            errors(&obj),
+            vec![(
+                "csiNode.spec.drivers[0].topologyKeys".to_string(),
+                ErrorType::Invalid
+            )]
+        );
+    }
+}
@@ -11,8 +11,8 @@ mod volume_attributes_class;
 
 pub use crate::storage::internal::validation::CSINodeValidationOptions;
 pub use csi_driver::validate_csi_driver_v1;
-pub use csi_node::validate_csi_node_v1;
+pub use csi_node::{validate_csi_node, validate_csi_node_v1};
 pub use csi_storage_capacity::validate_csi_storage_capacity_v1;
-pub use storage_class::validate_storage_class_v1;
+pub use storage_class::{validate_storage_class, validate_storage_class_v1};
 pub use volume_attachment::validate_volume_attachment_v1;
 pub use volume_attributes_class::validate_volume_attributes_class_v1;
@@ -1,7 +1,77 @@
-use crate::common::validation::ErrorList;
+use crate::common::validation::{ErrorList, Path};
 use crate::storage::internal::validation as internal_validation;
 use crate::storage::v1 as storage_v1;
 
 pub fn validate_storage_class_v1(obj: &storage_v1::StorageClass) -> ErrorList {
     internal_validation::validate_storage_class_v1(obj)
 }
+
+/// Validates a StorageClass with its fields rooted at `path`.
+pub fn validate_storage_class(obj: &storage_v1::StorageClass, path: &Path) -> ErrorList {
+    internal_validation::validate_storage_class_v1_with_path(obj, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::validation::ErrorType;
+    use crate::common::{ObjectMeta, PersistentVolumeReclaimPolicy};
+    use std::collections::BTreeMap;
+
+    fn item_path() -> Path {
+        Path::new("items").index(0)
+    }
+
+    fn storage_class() -> storage_v1::StorageClass {
+        storage_v1::StorageClass {
+            metadata: Some(ObjectMeta {
+                name: Some("fast".to_string()),
+                ..Default::default()
+            }),
+            provisioner: "ebs.csi.aws.com".to_string(),
+            parameters: BTreeMap::from([("type".to_string(), "gp3".to_string())]),
+            reclaim_policy: Some(PersistentVolumeReclaimPolicy::Delete),
+            volume_binding_mode: Some(storage_v1::VolumeBindingMode::WaitForFirstConsumer),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_storage_class_valid() {
+        let errs = validate_storage_class(&storage_class(), &item_path());
+        assert!(errs.is_empty(), "expected no errors, got: {errs:?}");
+    }
+
+    #[test]
+    fn test_validate_storage_class_missing_provisioner() {
+        let mut sc = storage_class();
+        sc.provisioner.clear();
+
+        let errs = validate_storage_class(&sc, &item_path());
+        assert_eq!(errs.errors.len(), 1, "unexpected errors: {errs:?}");
+        assert_eq!(errs.errors[0].error_type, ErrorType::Required);
+        assert_eq!(errs.errors[0].field, "items[0].provisioner");
+    }
+
+    #[test]
+    fn test_validate_storage_class_recycle_not_supported() {
+        let mut sc = storage_class();
+        sc.reclaim_policy = Some(PersistentVolumeReclaimPolicy::Recycle);
+
+        let errs = validate_storage_class(&sc, &item_path());
+        assert_eq!(errs.errors.len(), 1, "unexpected errors: {errs:?}");
+        assert_eq!(errs.errors[0].error_type, ErrorType::NotSupported);
+        assert_eq!(errs.errors[0].field, "items[0].reclaimPolicy");
+    }
+
+    #[test]
+    fn test_validate_storage_class_empty_parameter_key() {
+        let mut sc = storage_class();
+        sc.parameters.insert(String::new(), "value".to_string());
+
+        let errs = validate_storage_class(&sc, &item_path());
+        assert_eq!(errs.errors.len(), 1, "unexpected errors: {errs:?}");
+        assert_eq!(errs.errors[0].error_type, ErrorType::Invalid);
+        assert_eq!(errs.errors[0].field, "items[0].parameters");
+    }
+}
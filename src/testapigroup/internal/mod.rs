@@ -270,7 +270,7 @@ pub struct CarpList {
     #[serde(default)]
     pub metadata: ListMeta,
 
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
     pub items: Vec<Carp>,
 }
 